@@ -1,3 +1,4 @@
+use anyhow::{anyhow, bail};
 use ethers::types::U256;
 use near_sdk::json_types::U128;
 use serde::{de, Deserialize, Serialize};
@@ -6,12 +7,73 @@ use std::str::FromStr;
 #[derive(Default, Clone, Debug, PartialEq, PartialOrd, Eq)]
 pub struct Amount(U256);
 
+/// The largest number of decimals, whose unit fits into `U256`
+pub const MAX_DECIMALS: u8 = 77;
+
+fn unit(decimals: u8) -> anyhow::Result<U256> {
+    if decimals > MAX_DECIMALS {
+        bail!("Token decimals must not exceed {MAX_DECIMALS}, got {decimals}");
+    }
+
+    Ok(U256::exp10(decimals as usize))
+}
+
 impl Amount {
     pub fn normalize_to_u64(&self, decimals: usize) -> u64 {
         let unit: U256 = U256::exp10(decimals);
 
         (self.0 / unit).as_u64()
     }
+
+    /// Amount in whole tokens, i.e. `100.5` for `100500000` base units with 6 decimals
+    pub fn format_units(&self, decimals: u8) -> anyhow::Result<String> {
+        let (whole, fraction) = self.0.div_mod(unit(decimals)?);
+
+        if fraction.is_zero() {
+            return Ok(whole.to_string());
+        }
+
+        let fraction = format!("{:0>width$}", fraction.to_string(), width = decimals as usize);
+
+        Ok(format!("{whole}.{}", fraction.trim_end_matches('0')))
+    }
+
+    /// Parses an amount in whole tokens into base units
+    ///
+    /// The conversion is exact: fractions finer than the token decimals are rejected
+    /// rather than rounded.
+    pub fn parse_units(value: &str, decimals: u8) -> anyhow::Result<Self> {
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+        if whole.is_empty() && fraction.is_empty()
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            bail!("Invalid amount `{value}`, expected a decimal number like 100.5");
+        }
+
+        if fraction.len() > decimals as usize {
+            bail!("Amount `{value}` has more than {decimals} decimal places");
+        }
+
+        let parse = |digits: &str| -> anyhow::Result<U256> {
+            if digits.is_empty() {
+                return Ok(U256::zero());
+            }
+
+            U256::from_dec_str(digits).map_err(|e| anyhow!("Invalid amount `{value}`: {e}"))
+        };
+
+        let fraction = parse(fraction)? * unit(decimals - fraction.len() as u8)?;
+
+        parse(whole)?
+            .checked_mul(unit(decimals)?)
+            .and_then(|whole| whole.checked_add(fraction))
+            .map(Self)
+            .ok_or_else(|| anyhow!("Amount `{value}` is too large"))
+    }
 }
 
 impl Serialize for Amount {
@@ -78,3 +140,73 @@ impl FromStr for Amount {
         Ok(Self(U256::from_dec_str(s)?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_are_formatted_in_whole_tokens() {
+        let cases = [
+            ("100500000", 6, "100.5"),
+            ("100000000", 6, "100"),
+            ("1", 6, "0.000001"),
+            ("0", 9, "0"),
+            ("1000000001", 9, "1.000000001"),
+            ("100500000000000000000", 18, "100.5"),
+            ("20000000000000000000000000", 24, "20"),
+            ("42", 0, "42"),
+        ];
+
+        for (base_units, decimals, formatted) in cases {
+            let amount = Amount::from_str(base_units).unwrap();
+
+            assert_eq!(amount.format_units(decimals).unwrap(), formatted);
+            assert_eq!(Amount::parse_units(formatted, decimals).unwrap(), amount);
+        }
+    }
+
+    #[test]
+    fn amounts_are_parsed_without_precision_loss() {
+        let cases = [
+            ("0.1", 18, "100000000000000000"),
+            ("0.3", 18, "300000000000000000"),
+            (".5", 6, "500000"),
+            ("7.", 6, "7000000"),
+            (
+                "123456789.123456789123456789",
+                18,
+                "123456789123456789123456789",
+            ),
+            ("1.10", 2, "110"),
+        ];
+
+        for (value, decimals, base_units) in cases {
+            assert_eq!(
+                Amount::parse_units(value, decimals).unwrap(),
+                Amount::from_str(base_units).unwrap(),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_amounts_are_rejected() {
+        for (value, decimals) in [
+            ("", 6),
+            (".", 6),
+            ("1.5", 0),
+            ("0.0000001", 6),
+            ("-1", 6),
+            ("1e6", 6),
+            ("1,5", 6),
+            ("1.2.3", 6),
+            ("1", MAX_DECIMALS + 1),
+        ] {
+            assert!(Amount::parse_units(value, decimals).is_err(), "{value}");
+        }
+
+        // Doesn't fit into 256 bits
+        assert!(Amount::parse_units(&"9".repeat(60), 18).is_err());
+    }
+}
@@ -20,3 +20,229 @@ pub struct RewardConfiguration {
     pub asset_confirmation_reward: Amount,
     pub asset_tracer_reward: Amount,
 }
+
+/// Rules of a HAPI Core deployment: who governs it, what reporters stake and what they earn
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct NetworkConfiguration {
+    pub authority: String,
+    pub stake_configuration: StakeConfiguration,
+    pub reward_configuration: RewardConfiguration,
+}
+
+/// Configuration field, whose value differs from the expected one
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ConfigurationDiff {
+    /// Path to the field, i.e. `stake_configuration.tracer_stake`
+    pub field: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// EVM addresses may come back with a different letter case than configured
+fn same_address(a: &str, b: &str) -> bool {
+    if a.starts_with("0x") && b.starts_with("0x") {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
+#[derive(Default)]
+struct DiffBuilder {
+    prefix: &'static str,
+    diffs: Vec<ConfigurationDiff>,
+}
+
+impl DiffBuilder {
+    fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            diffs: vec![],
+        }
+    }
+
+    fn field<T: PartialEq + ToString>(&mut self, name: &str, expected: &T, actual: &T) {
+        if expected != actual {
+            self.push(name, expected.to_string(), actual.to_string());
+        }
+    }
+
+    fn address(&mut self, name: &str, expected: &str, actual: &str) {
+        if !same_address(expected, actual) {
+            self.push(name, expected.to_string(), actual.to_string());
+        }
+    }
+
+    fn push(&mut self, name: &str, expected: String, actual: String) {
+        let field = if self.prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{name}", self.prefix)
+        };
+
+        self.diffs.push(ConfigurationDiff {
+            field,
+            expected,
+            actual,
+        });
+    }
+}
+
+impl StakeConfiguration {
+    /// Fields that differ from the expected configuration, empty if they match
+    pub fn diff(&self, expected: &StakeConfiguration) -> Vec<ConfigurationDiff> {
+        let mut diff = DiffBuilder::new("stake_configuration");
+
+        diff.address("token", &expected.token, &self.token);
+        diff.field(
+            "unlock_duration",
+            &expected.unlock_duration,
+            &self.unlock_duration,
+        );
+        diff.field(
+            "validator_stake",
+            &expected.validator_stake,
+            &self.validator_stake,
+        );
+        diff.field("tracer_stake", &expected.tracer_stake, &self.tracer_stake);
+        diff.field(
+            "publisher_stake",
+            &expected.publisher_stake,
+            &self.publisher_stake,
+        );
+        diff.field(
+            "authority_stake",
+            &expected.authority_stake,
+            &self.authority_stake,
+        );
+
+        diff.diffs
+    }
+}
+
+impl RewardConfiguration {
+    /// Fields that differ from the expected configuration, empty if they match
+    pub fn diff(&self, expected: &RewardConfiguration) -> Vec<ConfigurationDiff> {
+        let mut diff = DiffBuilder::new("reward_configuration");
+
+        diff.address("token", &expected.token, &self.token);
+        diff.field(
+            "address_confirmation_reward",
+            &expected.address_confirmation_reward,
+            &self.address_confirmation_reward,
+        );
+        diff.field(
+            "address_tracer_reward",
+            &expected.address_tracer_reward,
+            &self.address_tracer_reward,
+        );
+        diff.field(
+            "asset_confirmation_reward",
+            &expected.asset_confirmation_reward,
+            &self.asset_confirmation_reward,
+        );
+        diff.field(
+            "asset_tracer_reward",
+            &expected.asset_tracer_reward,
+            &self.asset_tracer_reward,
+        );
+
+        diff.diffs
+    }
+}
+
+impl NetworkConfiguration {
+    /// Field-level differences between the fetched configuration and the expected one
+    ///
+    /// Token and authority addresses on EVM are compared regardless of the letter case.
+    pub fn diff(&self, expected: &NetworkConfiguration) -> Vec<ConfigurationDiff> {
+        let mut diff = DiffBuilder::default();
+
+        diff.address("authority", &expected.authority, &self.authority);
+
+        let mut diffs = diff.diffs;
+        diffs.extend(self.stake_configuration.diff(&expected.stake_configuration));
+        diffs.extend(
+            self.reward_configuration
+                .diff(&expected.reward_configuration),
+        );
+
+        diffs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configuration() -> NetworkConfiguration {
+        NetworkConfiguration {
+            authority: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
+            stake_configuration: StakeConfiguration {
+                token: "0x5FbDB2315678afecb367f032d93F642f64180aa3".to_string(),
+                unlock_duration: 86400,
+                validator_stake: 1000.into(),
+                tracer_stake: 2000.into(),
+                publisher_stake: 3000.into(),
+                authority_stake: 4000.into(),
+            },
+            reward_configuration: RewardConfiguration {
+                token: "0x5FbDB2315678afecb367f032d93F642f64180aa3".to_string(),
+                address_confirmation_reward: 10.into(),
+                address_tracer_reward: 20.into(),
+                asset_confirmation_reward: 30.into(),
+                asset_tracer_reward: 40.into(),
+            },
+        }
+    }
+
+    #[test]
+    fn same_configuration_has_no_diff() {
+        let expected = configuration();
+        let mut actual = configuration();
+        actual.authority = actual.authority.to_lowercase();
+        actual.stake_configuration.token = actual.stake_configuration.token.to_lowercase();
+
+        assert_eq!(actual.diff(&expected), vec![]);
+    }
+
+    #[test]
+    fn changed_fields_are_reported() {
+        let expected = configuration();
+        let mut actual = configuration();
+        actual.authority = "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC".to_string();
+        actual.stake_configuration.tracer_stake = 1.into();
+        actual.reward_configuration.asset_tracer_reward = 0.into();
+
+        assert_eq!(
+            actual.diff(&expected),
+            vec![
+                ConfigurationDiff {
+                    field: "authority".to_string(),
+                    expected: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
+                    actual: "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC".to_string(),
+                },
+                ConfigurationDiff {
+                    field: "stake_configuration.tracer_stake".to_string(),
+                    expected: "2000".to_string(),
+                    actual: "1".to_string(),
+                },
+                ConfigurationDiff {
+                    field: "reward_configuration.asset_tracer_reward".to_string(),
+                    expected: "40".to_string(),
+                    actual: "0".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn solana_addresses_are_case_sensitive() {
+        let mut expected = configuration();
+        expected.authority = "QDWdYo5JWQ96cCEgdBXpL6TVs5whScFSzVbZgobHLrQ".to_string();
+        let mut actual = expected.clone();
+        actual.authority = expected.authority.to_lowercase();
+
+        assert_eq!(actual.diff(&expected).len(), 1);
+    }
+}
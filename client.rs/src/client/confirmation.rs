@@ -0,0 +1,114 @@
+use serde::Serialize;
+use std::{future::Future, time::Duration};
+use tokio::time::{sleep, Instant};
+
+use super::result::{ClientError, Result};
+
+pub const DEFAULT_CONFIRMATIONS: u64 = 1;
+pub const DEFAULT_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(120);
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How deep and how long to wait for a submitted transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfirmationOptions {
+    /// The number of blocks on top of the transaction one, including it
+    pub confirmations: u64,
+    /// The maximum time to wait for the required depth
+    pub timeout: Duration,
+    /// The pause between status requests
+    pub poll_interval: Duration,
+}
+
+impl Default for ConfirmationOptions {
+    fn default() -> Self {
+        Self {
+            confirmations: DEFAULT_CONFIRMATIONS,
+            timeout: DEFAULT_CONFIRMATION_TIMEOUT,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+}
+
+/// Final status of a transaction that has reached the required depth
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "status", content = "reason")]
+pub enum TxStatus {
+    Confirmed,
+    /// Transaction is included, but its execution failed with the given reason
+    Reverted(String),
+}
+
+/// Repeats the check until it returns a status or the timeout expires
+pub(crate) async fn poll_confirmation<F, Fut>(
+    options: &ConfirmationOptions,
+    mut check: F,
+) -> Result<TxStatus>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<TxStatus>>>,
+{
+    let deadline = Instant::now() + options.timeout;
+
+    loop {
+        if let Some(status) = check().await? {
+            return Ok(status);
+        }
+
+        if Instant::now() + options.poll_interval > deadline {
+            return Err(ClientError::TimeoutError(format!(
+                "transaction is not confirmed within {} s",
+                options.timeout.as_secs()
+            )));
+        }
+
+        sleep(options.poll_interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    fn options(timeout_ms: u64) -> ConfirmationOptions {
+        ConfirmationOptions {
+            confirmations: 3,
+            timeout: Duration::from_millis(timeout_ms),
+            poll_interval: Duration::from_millis(10),
+        }
+    }
+
+    #[tokio::test]
+    async fn status_is_polled_until_confirmed() {
+        let polls = AtomicU64::new(0);
+
+        let status = poll_confirmation(&options(1000), || async {
+            let depth = polls.fetch_add(1, Ordering::Relaxed) + 1;
+            Ok((depth >= 3).then_some(TxStatus::Confirmed))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(status, TxStatus::Confirmed);
+        assert_eq!(polls.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn unconfirmed_transaction_times_out() {
+        let result = poll_confirmation(&options(50), || async { Ok(None) }).await;
+
+        assert!(matches!(result, Err(ClientError::TimeoutError(_))));
+    }
+
+    #[test]
+    fn status_serialization() {
+        assert_eq!(
+            serde_json::to_value(TxStatus::Confirmed).unwrap(),
+            serde_json::json!({ "status": "confirmed" })
+        );
+        assert_eq!(
+            serde_json::to_value(TxStatus::Reverted("Unauthorized".to_string())).unwrap(),
+            serde_json::json!({ "status": "reverted", "reason": "Unauthorized" })
+        );
+    }
+}
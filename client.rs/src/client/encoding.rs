@@ -0,0 +1,110 @@
+use super::{
+    entities::{category::Category, reporter::ReporterRole},
+    result::{ClientError, Result},
+};
+
+/// Highest risk score the contracts accept
+pub const MAX_RISK: u8 = 10;
+
+/// Code of the category in contract storage and calls
+///
+/// Every contract keeps categories by their index: EVM as a `uint8` enum, Solana and NEAR
+/// as Borsh enum tags, so one code is valid on all of them.
+pub fn category_code(category: &Category) -> u8 {
+    category.clone() as u8
+}
+
+pub fn category_from_code(code: u8) -> Result<Category> {
+    Category::try_from(code)
+}
+
+/// Code of the reporter role in contract storage and calls
+///
+/// EVM keeps the role as a `uint8`, Solana and NEAR as Borsh enum tags of the same order.
+pub fn role_code(role: &ReporterRole) -> u8 {
+    role.clone() as u8
+}
+
+pub fn role_from_code(code: u8) -> Result<ReporterRole> {
+    ReporterRole::try_from(code)
+}
+
+/// Rejects a risk score before it is sent, instead of letting one of the contracts revert
+pub fn checked_risk(risk: u8) -> Result<u8> {
+    if risk > MAX_RISK {
+        return Err(ClientError::InvalidData(format!(
+            "risk {risk} is out of range 0..={MAX_RISK}"
+        )));
+    }
+
+    Ok(risk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::anchor_lang::AnchorSerialize;
+    use hapi_core_solana::{Category as SolanaCategory, ReporterRole as SolanaReporterRole};
+
+    #[test]
+    fn category_codes_match_across_contracts() {
+        let categories: Vec<Category> = (0..=u8::MAX)
+            .filter_map(|code| category_from_code(code).ok())
+            .collect();
+
+        assert_eq!(categories.len(), 21);
+        assert_eq!(categories.last(), Some(&Category::HighRiskJurisdiction));
+
+        for (code, category) in categories.into_iter().enumerate() {
+            let code = code as u8;
+            assert_eq!(category_code(&category), code, "{category}");
+
+            // Solana takes the category as an enum, encoded by its tag
+            let solana = SolanaCategory::from(category.clone());
+            assert_eq!(solana.try_to_vec().unwrap(), vec![code], "{category}");
+            assert_eq!(solana as u8, code, "{category}");
+        }
+
+        assert!(category_from_code(21).is_err());
+    }
+
+    #[test]
+    fn role_codes_match_across_contracts() {
+        let roles: Vec<ReporterRole> = (0..=u8::MAX)
+            .filter_map(|code| role_from_code(code).ok())
+            .collect();
+
+        assert_eq!(
+            roles,
+            vec![
+                ReporterRole::Validator,
+                ReporterRole::Tracer,
+                ReporterRole::Publisher,
+                ReporterRole::Authority,
+            ]
+        );
+
+        for (code, role) in roles.into_iter().enumerate() {
+            let code = code as u8;
+            assert_eq!(role_code(&role), code, "{role}");
+            assert_eq!(role_from_code(role_code(&role)).unwrap(), role);
+
+            // Solana takes the role as an enum, encoded by its tag
+            let solana = SolanaReporterRole::from(role.clone());
+            assert_eq!(solana.try_to_vec().unwrap(), vec![code], "{role}");
+            assert_eq!(role_from_code(solana as u8).unwrap(), role);
+        }
+
+        assert!(role_from_code(4).is_err());
+    }
+
+    #[test]
+    fn risk_is_limited() {
+        for risk in 0..=MAX_RISK {
+            assert_eq!(checked_risk(risk).unwrap(), risk);
+        }
+
+        assert!(checked_risk(MAX_RISK + 1).is_err());
+        assert!(checked_risk(u8::MAX).is_err());
+    }
+}
@@ -5,6 +5,10 @@ use uuid::Uuid;
 
 use super::category::Category;
 
+/// Id of an asset within its contract, a 256-bit unsigned integer
+///
+/// Its byte form is big-endian, as the contracts encode `uint256`, so leading zero bytes
+/// belong to small ids. Strings hold the decimal form, or the hex one with the `0x` prefix.
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct AssetId(U256);
 
@@ -29,6 +33,12 @@ impl std::fmt::Display for AssetId {
     }
 }
 
+impl std::fmt::LowerHex for AssetId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
 impl From<U256> for AssetId {
     fn from(value: U256) -> Self {
         Self(value)
@@ -47,6 +57,20 @@ impl From<AssetId> for U256 {
     }
 }
 
+impl From<[u8; 32]> for AssetId {
+    fn from(value: [u8; 32]) -> Self {
+        Self(U256::from_big_endian(&value))
+    }
+}
+
+impl From<AssetId> for [u8; 32] {
+    fn from(value: AssetId) -> Self {
+        let mut bytes = [0u8; 32];
+        value.0.to_big_endian(&mut bytes);
+        bytes
+    }
+}
+
 impl From<AssetId> for u64 {
     fn from(value: AssetId) -> Self {
         value.0.as_u64()
@@ -56,8 +80,12 @@ impl From<AssetId> for u64 {
 impl FromStr for AssetId {
     type Err = anyhow::Error;
 
+    /// Bare digits are decimal, hex needs the prefix to tell `10` from `0x10`
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(U256::from_dec_str(s)?))
+        match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => Ok(Self(U256::from_str_radix(hex, 16)?)),
+            None => Ok(Self(U256::from_dec_str(s)?)),
+        }
     }
 }
 
@@ -95,3 +123,51 @@ pub struct Asset {
     pub category: Category,
     pub confirmations: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_id_round_trips() {
+        let mut small = [0u8; 32];
+        small[31] = 0x10;
+
+        let mut large = [0u8; 32];
+        large[0] = 0x01;
+        large[31] = 0xff;
+
+        let cases = [
+            ([0u8; 32], "0", "0x0"),
+            (small, "16", "0x10"),
+            (
+                large,
+                "7237005577332262213973186563042994240829374041602535252466099000494570602751",
+                "0x10000000000000000000000000000000000000000000000000000000000000ff",
+            ),
+            (
+                [0xffu8; 32],
+                "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+                "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            ),
+        ];
+
+        for (bytes, decimal, hex) in cases {
+            let id = AssetId::from(bytes);
+
+            assert_eq!(id.to_string(), decimal);
+            assert_eq!(format!("{id:#x}"), hex);
+            assert_eq!(AssetId::from_str(decimal).unwrap(), id);
+            assert_eq!(AssetId::from_str(hex).unwrap(), id);
+            assert_eq!(<[u8; 32]>::from(id.clone()), bytes);
+            assert_eq!(AssetId::from(U256::from(id.clone())), id);
+        }
+
+        // Leading zeros are allowed in both forms
+        assert_eq!(AssetId::from_str("0x0010").unwrap(), AssetId::from(small));
+        assert_eq!(AssetId::from_str("0016").unwrap(), AssetId::from(small));
+
+        // Hex digits are not taken without the prefix
+        assert!(AssetId::from_str("ff").is_err());
+    }
+}
@@ -85,4 +85,7 @@ pub struct Case {
     pub url: String,
     pub status: CaseStatus,
     pub reporter_id: Uuid,
+    /// Cases linked to this one, only Solana keeps case links
+    #[serde(default)]
+    pub related_cases: Vec<Uuid>,
 }
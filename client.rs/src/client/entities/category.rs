@@ -136,7 +136,7 @@ impl TryFrom<u8> for Category {
             19 => Ok(Self::Hacker),
             20 => Ok(Self::HighRiskJurisdiction),
             _ => Err(ClientError::ContractData(format!(
-                "invalid case status: {value}",
+                "invalid category: {value}",
             ))),
         }
     }
@@ -19,9 +19,11 @@ pub enum EventName {
     Unstake,
     CreateCase,
     UpdateCase,
+    LinkCases,
     CreateAddress,
     UpdateAddress,
     ConfirmAddress,
+    InvalidateAddress,
     CreateAsset,
     UpdateAsset,
     ConfirmAsset,
@@ -47,6 +49,12 @@ impl EventName {
             14 => EventName::CreateAsset,
             15 => EventName::UpdateAsset,
             16 => EventName::ConfirmAsset,
+            // `set_reporter_url` on Solana
+            17 => EventName::UpdateReporter,
+            // `confirm_addresses` on Solana
+            18 => EventName::ConfirmAddress,
+            19 => EventName::InvalidateAddress,
+            20 => EventName::LinkCases,
             _ => bail!("Invalid instruction index: {}", index),
         };
 
@@ -81,9 +89,11 @@ impl Display for EventName {
             Self::Unstake => write!(f, "unstake"),
             Self::CreateCase => write!(f, "create_case"),
             Self::UpdateCase => write!(f, "update_case"),
+            Self::LinkCases => write!(f, "link_cases"),
             Self::CreateAddress => write!(f, "create_address"),
             Self::UpdateAddress => write!(f, "update_address"),
             Self::ConfirmAddress => write!(f, "confirm_address"),
+            Self::InvalidateAddress => write!(f, "invalidate_address"),
             Self::CreateAsset => write!(f, "create_asset"),
             Self::UpdateAsset => write!(f, "update_asset"),
             Self::ConfirmAsset => write!(f, "confirm_asset"),
@@ -112,9 +122,11 @@ impl FromStr for EventName {
             "unstake" | "Unstake" | "ReporterStakeWithdrawn" => Ok(Self::Unstake),
             "create_case" | "CaseCreated" => Ok(Self::CreateCase),
             "update_case" | "CaseUpdated" => Ok(Self::UpdateCase),
+            "link_cases" | "CasesLinked" => Ok(Self::LinkCases),
             "create_address" | "AddressCreated" => Ok(Self::CreateAddress),
             "update_address" | "AddressUpdated" => Ok(Self::UpdateAddress),
             "confirm_address" | "AddressConfirmed" => Ok(Self::ConfirmAddress),
+            "invalidate_address" | "AddressInvalidated" => Ok(Self::InvalidateAddress),
             "create_asset" | "AssetCreated" => Ok(Self::CreateAsset),
             "update_asset" | "AssetUpdated" => Ok(Self::UpdateAsset),
             "confirm_asset" | "AssetConfirmed" => Ok(Self::ConfirmAsset),
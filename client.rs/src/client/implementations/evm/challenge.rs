@@ -0,0 +1,201 @@
+use ethers::{
+    abi::{encode, Token},
+    types::{
+        transaction::eip712::{EIP712Domain, Eip712},
+        Address as EthAddress, RecoveryMessage, Signature, H256, U256,
+    },
+    utils::keccak256,
+};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::client::result::{ClientError, Result};
+
+const DOMAIN_NAME: &str = "HapiCore";
+const DOMAIN_VERSION: &str = "1";
+const CHALLENGE_TYPE: &str = "ReporterChallenge(uint128 reporterId,string action,uint256 nonce)";
+
+/// Off-chain challenge, that a reporter signs to prove the ownership of its account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReporterChallenge {
+    pub reporter_id: Uuid,
+    /// Action the reporter is authenticated for, i.e. `login`
+    pub action: String,
+    /// One-time value issued by the verifier, so that a signature can't be replayed
+    pub nonce: u64,
+}
+
+/// HAPI Core deployment the challenge is signed for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChallengeDomain {
+    pub chain_id: u64,
+    pub contract_address: EthAddress,
+}
+
+impl ChallengeDomain {
+    pub fn new(chain_id: u64, contract_address: &str) -> Result<Self> {
+        let contract_address = contract_address
+            .parse()
+            .map_err(|e| ClientError::EthAddressParse(format!("`contract-address`: {e}")))?;
+
+        Ok(Self {
+            chain_id,
+            contract_address,
+        })
+    }
+}
+
+/// Challenge bound to its domain, in the form of EIP-712 typed data
+pub(super) struct TypedChallenge<'a> {
+    pub challenge: &'a ReporterChallenge,
+    pub domain: ChallengeDomain,
+}
+
+impl Eip712 for TypedChallenge<'_> {
+    type Error = ClientError;
+
+    fn domain(&self) -> Result<EIP712Domain> {
+        Ok(EIP712Domain {
+            name: Some(DOMAIN_NAME.to_string()),
+            version: Some(DOMAIN_VERSION.to_string()),
+            chain_id: Some(self.domain.chain_id.into()),
+            verifying_contract: Some(self.domain.contract_address),
+            salt: None,
+        })
+    }
+
+    fn type_hash() -> Result<[u8; 32]> {
+        Ok(keccak256(CHALLENGE_TYPE))
+    }
+
+    fn struct_hash(&self) -> Result<[u8; 32]> {
+        Ok(keccak256(encode(&[
+            Token::FixedBytes(Self::type_hash()?.to_vec()),
+            Token::Uint(U256::from(self.challenge.reporter_id.as_u128())),
+            Token::FixedBytes(keccak256(&self.challenge.action).to_vec()),
+            Token::Uint(self.challenge.nonce.into()),
+        ])))
+    }
+}
+
+/// Checks that the challenge is signed by the reporter account
+///
+/// Doesn't require a connection to the node, so the signature can be verified by any
+/// service that knows the chain id and the contract address.
+pub fn verify_reporter_challenge(
+    challenge: &ReporterChallenge,
+    domain: ChallengeDomain,
+    signature: &str,
+    account: &str,
+) -> Result<()> {
+    let account: EthAddress = account
+        .parse()
+        .map_err(|e| ClientError::EthAddressParse(format!("`account`: {e}")))?;
+
+    let signature = Signature::from_str(signature)
+        .map_err(|e| ClientError::InvalidData(format!("invalid signature: {e}")))?;
+
+    let hash = TypedChallenge { challenge, domain }.encode_eip712()?;
+
+    let signer = signature
+        .recover(RecoveryMessage::Hash(H256::from(hash)))
+        .map_err(|e| ClientError::InvalidData(format!("invalid signature: {e}")))?;
+
+    if signer != account {
+        return Err(ClientError::InvalidData(format!(
+            "challenge is signed by {signer:?}, not by {account:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::interface::HapiCoreOptions, HapiCoreEvm, HapiCoreNetwork, RpcTimeouts};
+    use ethers::signers::{LocalWallet, Signer};
+
+    const CONTRACT_ADDRESS: &str = "0x2947F98C42597966a0ec25e92843c09ac18Fbab7";
+    const REPORTER_PK: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    const OTHER_PK: &str = "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d";
+
+    fn create_wallet(pk: &str) -> LocalWallet {
+        LocalWallet::from_str(pk).unwrap().with_chain_id(31337u64)
+    }
+
+    fn challenge() -> ReporterChallenge {
+        ReporterChallenge {
+            reporter_id: Uuid::from_u128(42),
+            action: "login".to_string(),
+            nonce: 7,
+        }
+    }
+
+    async fn sign(wallet: &LocalWallet, challenge: &ReporterChallenge) -> String {
+        let domain = ChallengeDomain::new(31337, CONTRACT_ADDRESS).unwrap();
+
+        let signature = wallet
+            .sign_typed_data(&TypedChallenge { challenge, domain })
+            .await
+            .unwrap();
+
+        format!("0x{signature}")
+    }
+
+    #[tokio::test]
+    async fn signed_challenge_is_verified() {
+        let client = HapiCoreEvm::new(HapiCoreOptions {
+            provider_url: "http://127.0.0.1:8545".to_string(),
+            fallback_provider_urls: vec![],
+            contract_address: CONTRACT_ADDRESS.to_string(),
+            private_key: Some(REPORTER_PK.to_string()),
+            chain_id: Some(31337),
+            account_id: None,
+            network: HapiCoreNetwork::Ethereum,
+            rpc_timeouts: RpcTimeouts::default(),
+        })
+        .unwrap();
+        let domain = ChallengeDomain::new(31337, CONTRACT_ADDRESS).unwrap();
+        let account = format!("{:?}", client.signer.address());
+
+        assert_eq!(client.challenge_domain(), domain);
+
+        let signature = client.sign_reporter_challenge(&challenge()).await.unwrap();
+        verify_reporter_challenge(&challenge(), domain, &signature, &account).unwrap();
+
+        // Signing doesn't depend on the client
+        assert_eq!(
+            sign(&create_wallet(REPORTER_PK), &challenge()).await,
+            signature
+        );
+    }
+
+    #[tokio::test]
+    async fn tampered_challenge_is_rejected() {
+        let wallet = create_wallet(REPORTER_PK);
+        let domain = ChallengeDomain::new(31337, CONTRACT_ADDRESS).unwrap();
+        let account = format!("{:?}", wallet.address());
+
+        let signature = sign(&wallet, &challenge()).await;
+
+        let mut tampered = challenge();
+        tampered.action = "withdraw".to_string();
+        assert!(verify_reporter_challenge(&tampered, domain, &signature, &account).is_err());
+
+        let mut replayed = challenge();
+        replayed.nonce += 1;
+        assert!(verify_reporter_challenge(&replayed, domain, &signature, &account).is_err());
+
+        // The same challenge is not valid for another deployment
+        let other_chain = ChallengeDomain::new(1, CONTRACT_ADDRESS).unwrap();
+        assert!(
+            verify_reporter_challenge(&challenge(), other_chain, &signature, &account).is_err()
+        );
+
+        // Nor is it valid for another account
+        let other = format!("{:?}", create_wallet(OTHER_PK).address());
+        assert!(verify_reporter_challenge(&challenge(), domain, &signature, &other).is_err());
+    }
+}
@@ -1,18 +1,21 @@
 use async_trait::async_trait;
 use ethers::{
-    abi::Token,
+    abi::{Abi, Detokenize, Token},
+    contract::{Contract, ContractCall},
     prelude::{abigen, SignerMiddleware},
-    providers::{Http, Provider as EthersProvider},
+    providers::{Middleware, PendingTransaction, Provider as EthersProvider, RpcError},
     signers::{LocalWallet, Signer as EthersSigner},
-    types::Address as EthAddress,
+    types::{Address as EthAddress, BlockNumber, TransactionRequest, TxHash, H256, U256},
 };
 use serde::{Deserialize, Serialize};
-use std::{str::FromStr, sync::Arc};
+use std::{path::Path, str::FromStr, sync::Arc};
 use uuid::Uuid;
 
 use crate::{
     client::{
-        configuration::{RewardConfiguration, StakeConfiguration},
+        configuration::{NetworkConfiguration, RewardConfiguration, StakeConfiguration},
+        confirmation::{poll_confirmation, ConfirmationOptions, TxStatus},
+        encoding::{category_code, checked_risk, role_code},
         entities::{
             address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
             asset::{Asset, AssetId, ConfirmAssetInput, CreateAssetInput, UpdateAssetInput},
@@ -25,16 +28,27 @@ use crate::{
     HapiCore,
 };
 
-use super::error::map_ethers_error;
+use super::{
+    challenge::{ChallengeDomain, ReporterChallenge, TypedChallenge},
+    error::{is_custom_error, map_ethers_error, revert_message},
+    nonce::NonceManager,
+    signatures::verify_event_signatures,
+    transport::FailoverHttp,
+};
 
 abigen!(
     HAPI_CORE_CONTRACT,
     "./src/client/implementations/evm/abi/HapiCore.json"
 );
 
-pub(super) type Provider = EthersProvider<Http>;
+pub(super) type Provider = EthersProvider<FailoverHttp>;
 pub(super) type Signer = SignerMiddleware<Provider, LocalWallet>;
 
+/// Storage slot of the OpenZeppelin `Initializable` state, the ERC-7201 namespace
+/// `openzeppelin.storage.Initializable`
+const INITIALIZABLE_STORAGE: &str =
+    "0xf0c57e16840df040f15088dc2f81fe391c3923bec73e23a9662efc9c229c6a00";
+
 const ZERO_PK: &str = "0000000000000000000000000000000000000000000000000000000000000001";
 pub(crate) const LOCAL_CHAIN_ID: u64 = 31337;
 
@@ -55,12 +69,16 @@ pub struct HapiCoreEvm {
     pub signer: LocalWallet,
     pub contract: HAPI_CORE_CONTRACT<Signer>,
     pub client: Arc<Signer>,
+    pub(super) nonce_manager: NonceManager,
 }
 
 impl HapiCoreEvm {
     pub fn new(options: HapiCoreOptions) -> Result<Self> {
-        let provider = Provider::try_from(options.provider_url.as_str())
-            .map_err(|e| ClientError::UrlParseError(format!("`provider-url`: {e}")))?;
+        let provider = Provider::new(FailoverHttp::new(
+            &options.provider_url,
+            &options.fallback_provider_urls,
+            options.rpc_timeouts,
+        )?);
 
         let signer =
             LocalWallet::from_str(options.private_key.unwrap_or(ZERO_PK.to_string()).as_str())
@@ -79,14 +97,41 @@ impl HapiCoreEvm {
         let contract: HAPI_CORE_CONTRACT<Signer> =
             HAPI_CORE_CONTRACT::new(contract_address, client.clone());
 
+        // The ABI is built in, a mismatch means it was regenerated for another contract version
+        verify_event_signatures(contract.abi())?;
+
         Ok(Self {
             provider,
             signer,
             contract,
             client,
+            nonce_manager: NonceManager::default(),
         })
     }
 
+    /// Uses the given contract ABI instead of the built-in one, i.e. of a newer contract build
+    ///
+    /// Takes either a bare ABI or a Hardhat artifact. Calls and events are encoded with it, so it
+    /// still has to expose the functions of the bindings and the expected event signatures.
+    pub fn with_abi(mut self, abi: &str) -> Result<Self> {
+        let abi = parse_abi(abi)?;
+        verify_event_signatures(&abi)?;
+
+        self.contract = Contract::new(self.contract.address(), abi, self.client.clone()).into();
+
+        Ok(self)
+    }
+
+    /// Reads the contract ABI from a file, resolved against the working directory if relative
+    pub fn with_abi_file(self, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let abi = std::fs::read_to_string(path).map_err(|e| {
+            ClientError::InvalidData(format!("failed to read ABI from {}: {e}", path.display()))
+        })?;
+
+        self.with_abi(&abi)
+    }
+
     pub fn decode_event(&self, log: &ethers::types::Log) -> Result<Option<LogHeader>> {
         let signature = log.topics.first().ok_or(ClientError::Ethers(format!(
             "failed to decode event: no topics in log: {log:?}",
@@ -111,20 +156,81 @@ impl HapiCoreEvm {
 
         Ok(None)
     }
-}
 
-macro_rules! handle_send {
-    ($call:expr, $method_name:expr) => {
-        $call
-            .send()
+    /// Deployment that reporter challenges of this client are signed for
+    pub fn challenge_domain(&self) -> ChallengeDomain {
+        ChallengeDomain {
+            chain_id: self.signer.chain_id(),
+            contract_address: self.contract.address(),
+        }
+    }
+
+    /// Signs the challenge as EIP-712 typed data with the client key
+    ///
+    /// Authenticates the reporter account without sending a transaction, the signature
+    /// is checked with `verify_reporter_challenge`.
+    pub async fn sign_reporter_challenge(&self, challenge: &ReporterChallenge) -> Result<String> {
+        let signature = self
+            .signer
+            .sign_typed_data(&TypedChallenge {
+                challenge,
+                domain: self.challenge_domain(),
+            })
             .await
-            .map_err(|e| map_ethers_error($method_name, e))?
+            .map_err(|e| ClientError::Ethers(format!("failed to sign challenge: {e}")))?;
+
+        Ok(format!("0x{signature}"))
+    }
+
+    async fn send_create_address(&self, input: CreateAddressInput) -> Result<TxHash> {
+        let case_id = input.case_id.as_u128();
+        let address = input.address.parse().map_err(|e| {
+            ClientError::Ethers(format!(
+                "failed to parse address `{}`: {}",
+                input.address, e
+            ))
+        })?;
+
+        let call = self.contract.create_address(
+            address,
+            case_id,
+            checked_risk(input.risk)?,
+            category_code(&input.category),
+        );
+
+        self.send_with_nonce(call, "create_address").await
+    }
+
+    /// Submits the transaction with a locally reserved nonce
+    ///
+    /// A failed submission may leave a gap or a conflict in the local nonce sequence,
+    /// so the nonce is resynced from the node before the next transaction.
+    async fn send_with_nonce<D: Detokenize>(
+        &self,
+        call: ContractCall<Signer, D>,
+        method_name: &str,
+    ) -> Result<TxHash> {
+        let nonce = self
+            .nonce_manager
+            .next(self.client.as_ref(), self.signer.address())
+            .await?;
+
+        match call.nonce(nonce).send().await {
+            Ok(pending) => Ok(pending.tx_hash()),
+            Err(e) => {
+                self.nonce_manager.reset().await;
+                Err(map_ethers_error(method_name, e))
+            }
+        }
+    }
+
+    async fn wait_for_receipt(&self, hash: TxHash, method_name: &str) -> Result<Tx> {
+        PendingTransaction::new(hash, self.client.provider())
             .await?
             .map_or_else(
                 || {
                     Err(ClientError::Ethers(format!(
-                        "`{}` failed: no receipt",
-                        $method_name
+                        "`{method_name}` failed: no receipt"
                     )))
                 },
                 |receipt| {
@@ -133,6 +239,49 @@ macro_rules! handle_send {
                     })
                 },
             )
+    }
+}
+
+impl HapiCoreEvm {
+    /// Replays the reverted transaction on the state before its block to get the reason
+    async fn revert_reason(&self, hash: TxHash, block: u64) -> String {
+        let tx = match self.provider.get_transaction(hash).await {
+            Ok(Some(tx)) => tx,
+            _ => return "unknown reason".to_string(),
+        };
+
+        let mut call = TransactionRequest::new()
+            .from(tx.from)
+            .data(tx.input)
+            .value(tx.value)
+            .gas(tx.gas);
+        if let Some(to) = tx.to {
+            call = call.to(to);
+        }
+
+        match self
+            .provider
+            .call(&call.into(), Some(block.saturating_sub(1).into()))
+            .await
+        {
+            Err(e) => e
+                .as_error_response()
+                .and_then(|response| response.as_revert_data())
+                .map_or_else(|| e.to_string(), |data| revert_message(&data)),
+            // The state has changed in the same block
+            Ok(_) => "unknown reason".to_string(),
+        }
+    }
+}
+
+macro_rules! handle_send {
+    ($self:ident, $call:expr, $method_name:expr) => {
+        $self
+            .wait_for_receipt(
+                $self.send_with_nonce($call, $method_name).await?,
+                $method_name,
+            )
+            .await
     };
 }
 
@@ -160,7 +309,11 @@ impl HapiCore for HapiCoreEvm {
             .parse()
             .map_err(|e| ClientError::EthAddressParse(format!("`address`: {e}")))?;
 
-        handle_send!(self.contract.set_authority(authority), "set_authority")
+        handle_send!(
+            self,
+            self.contract.set_authority(authority),
+            "set_authority"
+        )
     }
 
     async fn get_authority(&self) -> Result<String> {
@@ -174,6 +327,7 @@ impl HapiCore for HapiCoreEvm {
             .map_err(|e| ClientError::EthAddressParse(format!("`token`: {e}")))?;
 
         handle_send!(
+            self,
             self.contract.update_stake_configuration(
                 token,
                 configuration.unlock_duration.into(),
@@ -197,6 +351,7 @@ impl HapiCore for HapiCoreEvm {
             .map_err(|e| ClientError::EthAddressParse(format!("`token`: {e}")))?;
 
         handle_send!(
+            self,
             self.contract.update_reward_configuration(
                 token,
                 configuration.address_confirmation_reward.into(),
@@ -212,6 +367,34 @@ impl HapiCore for HapiCoreEvm {
         handle_call!(self.contract.reward_configuration(), "reward_configuration").map(|c| c.into())
     }
 
+    async fn get_network_configuration(&self) -> Result<NetworkConfiguration> {
+        let (authority, stake_configuration, reward_configuration) = tokio::try_join!(
+            self.get_authority(),
+            self.get_stake_configuration(),
+            self.get_reward_configuration()
+        )?;
+
+        Ok(NetworkConfiguration {
+            authority,
+            stake_configuration,
+            reward_configuration,
+        })
+    }
+
+    /// Version the contract was initialized or reinitialized with on upgrade
+    async fn get_contract_version(&self) -> Result<Option<u64>> {
+        let slot = H256::from_str(INITIALIZABLE_STORAGE).expect("Storage slot is valid");
+        let value = self
+            .provider
+            .get_storage_at(self.contract.address(), slot, None)
+            .await?;
+
+        // `_initialized` takes the lowest 8 bytes of the slot, the `_initializing` flag follows
+        let version = U256::from_big_endian(value.as_bytes()).low_u64();
+
+        Ok((version != 0).then_some(version))
+    }
+
     async fn create_reporter(&self, input: CreateReporterInput) -> Result<Tx> {
         let addr = input
             .account
@@ -219,10 +402,11 @@ impl HapiCore for HapiCoreEvm {
             .map_err(|e| ClientError::EthAddressParse(format!("`addr`: {e}")))?;
 
         handle_send!(
+            self,
             self.contract.create_reporter(
                 input.id.as_u128(),
                 addr,
-                input.role as u8,
+                role_code(&input.role),
                 input.name,
                 input.url,
             ),
@@ -237,10 +421,11 @@ impl HapiCore for HapiCoreEvm {
             .map_err(|e| ClientError::EthAddressParse(format!("`addr`: {e}")))?;
 
         handle_send!(
+            self,
             self.contract.update_reporter(
                 input.id.as_u128(),
                 addr,
-                input.role as u8,
+                role_code(&input.role),
                 input.name,
                 input.url,
             ),
@@ -251,7 +436,18 @@ impl HapiCore for HapiCoreEvm {
     async fn get_reporter(&self, id: &str) -> Result<Reporter> {
         let id = id.parse::<Uuid>()?.as_u128();
 
-        handle_call!(self.contract.get_reporter(id), "get_reporter").map(|c| c.try_into())?
+        self.contract
+            .get_reporter(id)
+            .call()
+            .await
+            .map_err(|e| {
+                if is_custom_error(&e, "ReporterNotFound(uint128)") {
+                    ClientError::InvalidReporter
+                } else {
+                    map_ethers_error("get_reporter", e)
+                }
+            })?
+            .try_into()
     }
 
     async fn get_reporter_count(&self) -> Result<u64> {
@@ -267,19 +463,34 @@ impl HapiCore for HapiCoreEvm {
     }
 
     async fn activate_reporter(&self) -> Result<Tx> {
-        handle_send!(self.contract.activate_reporter(), "activate_reporter")
+        handle_send!(self, self.contract.activate_reporter(), "activate_reporter")
     }
 
     async fn deactivate_reporter(&self) -> Result<Tx> {
-        handle_send!(self.contract.deactivate_reporter(), "deactivate_reporter")
+        handle_send!(
+            self,
+            self.contract.deactivate_reporter(),
+            "deactivate_reporter"
+        )
     }
 
     async fn unstake_reporter(&self) -> Result<Tx> {
-        handle_send!(self.contract.unstake(), "unstake")
+        handle_send!(self, self.contract.unstake(), "unstake")
+    }
+
+    async fn get_chain_time(&self) -> Result<u64> {
+        self.provider
+            .get_block(BlockNumber::Latest)
+            .await?
+            .map(|block| block.timestamp.as_u64())
+            .ok_or(ClientError::Ethers(
+                "`get_chain_time` failed: no latest block".to_string(),
+            ))
     }
 
     async fn create_case(&self, input: CreateCaseInput) -> Result<Tx> {
         handle_send!(
+            self,
             self.contract
                 .create_case(input.id.as_u128(), input.name, input.url),
             "create_case"
@@ -288,6 +499,7 @@ impl HapiCore for HapiCoreEvm {
 
     async fn update_case(&self, input: UpdateCaseInput) -> Result<Tx> {
         handle_send!(
+            self,
             self.contract.update_case(
                 input.id.as_u128(),
                 input.name,
@@ -328,12 +540,38 @@ impl HapiCore for HapiCoreEvm {
         })?;
 
         handle_send!(
-            self.contract
-                .create_address(address, case_id, input.risk, input.category as u8),
+            self,
+            self.contract.create_address(
+                address,
+                case_id,
+                checked_risk(input.risk)?,
+                category_code(&input.category)
+            ),
             "create_address"
         )
     }
 
+    async fn create_addresses(&self, inputs: Vec<CreateAddressInput>) -> Result<Vec<Result<Tx>>> {
+        // Every transaction is sent before waiting for any receipt,
+        // the nonce manager keeps their nonces sequential
+        let mut hashes = Vec::with_capacity(inputs.len());
+
+        for input in inputs {
+            hashes.push(self.send_create_address(input).await);
+        }
+
+        let mut results = Vec::with_capacity(hashes.len());
+
+        for hash in hashes {
+            results.push(match hash {
+                Ok(hash) => self.wait_for_receipt(hash, "create_address").await,
+                Err(error) => Err(error),
+            });
+        }
+
+        Ok(results)
+    }
+
     async fn update_address(&self, input: UpdateAddressInput) -> Result<Tx> {
         let case_id = input.case_id.as_u128();
         let address = input.address.parse().map_err(|e| {
@@ -344,8 +582,13 @@ impl HapiCore for HapiCoreEvm {
         })?;
 
         handle_send!(
-            self.contract
-                .update_address(address, input.risk, input.category as u8, case_id),
+            self,
+            self.contract.update_address(
+                address,
+                checked_risk(input.risk)?,
+                category_code(&input.category),
+                case_id
+            ),
             "update_address"
         )
     }
@@ -358,7 +601,11 @@ impl HapiCore for HapiCoreEvm {
             ))
         })?;
 
-        handle_send!(self.contract.confirm_address(address), "confirm_address")
+        handle_send!(
+            self,
+            self.contract.confirm_address(address),
+            "confirm_address"
+        )
     }
 
     async fn get_address(&self, address: &str) -> Result<Address> {
@@ -390,12 +637,13 @@ impl HapiCore for HapiCoreEvm {
         })?;
 
         handle_send!(
+            self,
             self.contract.create_asset(
                 address,
                 input.asset_id.into(),
                 input.case_id.as_u128(),
-                input.risk,
-                input.category as u8,
+                checked_risk(input.risk)?,
+                category_code(&input.category),
             ),
             "create_asset"
         )
@@ -410,11 +658,12 @@ impl HapiCore for HapiCoreEvm {
         })?;
 
         handle_send!(
+            self,
             self.contract.update_asset(
                 address,
                 input.asset_id.into(),
-                input.risk,
-                input.category as u8,
+                checked_risk(input.risk)?,
+                category_code(&input.category),
                 input.case_id.as_u128(),
             ),
             "update_asset"
@@ -430,6 +679,7 @@ impl HapiCore for HapiCoreEvm {
         })?;
 
         handle_send!(
+            self,
             self.contract.confirm_asset(address, input.asset_id.into(),),
             "confirm_asset"
         )
@@ -458,4 +708,108 @@ impl HapiCore for HapiCoreEvm {
         )
         .map(|c| c.into_iter().map(|r| r.try_into()).collect())?
     }
+
+    async fn wait_for_confirmation(
+        &self,
+        hash: &str,
+        options: ConfirmationOptions,
+    ) -> Result<TxStatus> {
+        let hash: TxHash = hash
+            .parse()
+            .map_err(|e| ClientError::InvalidData(format!("invalid transaction hash: {e}")))?;
+
+        poll_confirmation(&options, move || async move {
+            let Some(receipt) = self.provider.get_transaction_receipt(hash).await? else {
+                return Ok(None);
+            };
+            let Some(block) = receipt.block_number else {
+                return Ok(None);
+            };
+
+            let latest = self.provider.get_block_number().await?;
+            if latest.saturating_sub(block).as_u64() + 1 < options.confirmations {
+                return Ok(None);
+            }
+
+            if receipt.status == Some(0.into()) {
+                return Ok(Some(TxStatus::Reverted(
+                    self.revert_reason(hash, block.as_u64()).await,
+                )));
+            }
+
+            Ok(Some(TxStatus::Confirmed))
+        })
+        .await
+    }
+}
+
+/// Parses a bare ABI or the `abi` of a Hardhat artifact
+fn parse_abi(json: &str) -> Result<Abi> {
+    #[derive(Deserialize)]
+    struct Artifact {
+        abi: Abi,
+    }
+
+    serde_json::from_str::<Abi>(json)
+        .or_else(|_| serde_json::from_str::<Artifact>(json).map(|artifact| artifact.abi))
+        .map_err(|e| ClientError::InvalidData(format!("invalid ABI: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HapiCoreNetwork, RpcTimeouts};
+
+    const ARTIFACT: &str = include_str!("abi/HapiCore.json");
+
+    fn create_client() -> HapiCoreEvm {
+        HapiCoreEvm::new(HapiCoreOptions {
+            provider_url: "http://127.0.0.1:8545".to_string(),
+            fallback_provider_urls: vec![],
+            contract_address: "0x2947F98C42597966a0ec25e92843c09ac18Fbab7".to_string(),
+            private_key: None,
+            chain_id: None,
+            account_id: None,
+            network: HapiCoreNetwork::Ethereum,
+            rpc_timeouts: RpcTimeouts::default(),
+        })
+        .expect("Failed to initialize client")
+    }
+
+    #[test]
+    fn explicit_abi_is_used() {
+        let builtin = create_client();
+
+        let client = create_client().with_abi(ARTIFACT).unwrap();
+        assert_eq!(client.contract.address(), builtin.contract.address());
+        assert_eq!(client.contract.abi(), builtin.contract.abi());
+
+        // A bare ABI is taken as well as the artifact
+        let artifact: serde_json::Value = serde_json::from_str(ARTIFACT).unwrap();
+        let client = create_client()
+            .with_abi(&artifact["abi"].to_string())
+            .unwrap();
+        assert_eq!(client.contract.abi(), builtin.contract.abi());
+
+        let client = create_client()
+            .with_abi_file(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("src/client/implementations/evm/abi/HapiCore.json"),
+            )
+            .unwrap();
+        assert_eq!(client.contract.abi(), builtin.contract.abi());
+    }
+
+    #[test]
+    fn foreign_abi_is_rejected() {
+        // The token contract has none of the HAPI events
+        assert!(create_client()
+            .with_abi(include_str!("abi/Token.json"))
+            .is_err());
+
+        assert!(create_client().with_abi("{}").is_err());
+        assert!(create_client()
+            .with_abi_file("missing/HapiCore.json")
+            .is_err());
+    }
 }
@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::client::{
     configuration::{RewardConfiguration, StakeConfiguration},
+    encoding::{category_from_code, role_from_code},
     entities::{address::Address, asset::Asset, case::Case, reporter::Reporter},
     result::{ClientError, Result},
 };
@@ -43,7 +44,7 @@ impl TryFrom<hapi_core_contract::Reporter> for Reporter {
             account: to_checksum(&reporter.account, None),
             name: reporter.name.to_string(),
             url: reporter.url.to_string(),
-            role: reporter.role.try_into()?,
+            role: role_from_code(reporter.role)?,
             status: reporter.status.try_into()?,
             stake: reporter.stake.into(),
             unlock_timestamp: reporter.unlock_timestamp.as_u64(),
@@ -61,6 +62,7 @@ impl TryFrom<hapi_core_contract::Case> for Case {
             url: case.url.to_string(),
             status: case.status.try_into()?,
             reporter_id: Uuid::from_u128(case.reporter_id),
+            related_cases: vec![],
         })
     }
 }
@@ -74,7 +76,7 @@ impl TryFrom<hapi_core_contract::Address> for Address {
             case_id: Uuid::from_u128(address.case_id),
             reporter_id: Uuid::from_u128(address.reporter_id),
             risk: address.risk,
-            category: address.category.try_into()?,
+            category: category_from_code(address.category)?,
             confirmations: address.confirmations,
         })
     }
@@ -90,7 +92,7 @@ impl TryFrom<hapi_core_contract::Asset> for Asset {
             case_id: Uuid::from_u128(asset.case_id),
             reporter_id: Uuid::from_u128(asset.reporter_id),
             risk: asset.risk,
-            category: asset.category.try_into()?,
+            category: category_from_code(asset.category)?,
             confirmations: asset.confirmations,
         })
     }
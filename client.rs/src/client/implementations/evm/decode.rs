@@ -0,0 +1,203 @@
+use ethers::{
+    abi::Token,
+    providers::Middleware,
+    types::{Bytes, Log, TxHash, H256},
+    utils::hex,
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::str::FromStr;
+
+use super::client::HapiCoreEvm;
+use crate::client::{
+    events::EventName,
+    result::{ClientError, Result},
+};
+
+/// Contract event decoded from a log, with the parameters named after the contract ABI
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DecodedLog {
+    /// Event name in the contract ABI, i.e. `AddressCreated`
+    pub name: String,
+    /// HAPI event the log stands for, none for the events like `OwnershipTransferred`
+    pub event: Option<EventName>,
+    pub fields: Map<String, Value>,
+}
+
+impl HapiCoreEvm {
+    /// Decodes the log with the contract ABI, the same way the indexer does
+    ///
+    /// Doesn't require a connection to the node. Returns none if the log signature is not
+    /// one of the contract events.
+    pub fn decode_log(&self, log: &Log) -> Result<Option<DecodedLog>> {
+        let Some(header) = self.decode_event(log)? else {
+            return Ok(None);
+        };
+
+        let abi_event = self.contract.abi().event(&header.name).map_err(|e| {
+            ClientError::Ethers(format!("failed to decode event `{}`: {e}", header.name))
+        })?;
+
+        let fields = abi_event
+            .inputs
+            .iter()
+            .zip(header.tokens.iter())
+            .map(|(param, token)| (param.name.clone(), token_to_value(token)))
+            .collect();
+
+        Ok(Some(DecodedLog {
+            event: EventName::from_str(&header.name).ok(),
+            name: header.name,
+            fields,
+        }))
+    }
+
+    /// Decodes the log given by its hex encoded topics and data
+    pub fn decode_raw_log(&self, topics: &[String], data: &str) -> Result<Option<DecodedLog>> {
+        let topics = topics
+            .iter()
+            .map(|topic| H256::from_str(topic))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| ClientError::InvalidData(format!("invalid log topic: {e}")))?;
+
+        let data = Bytes::from_str(data)
+            .map_err(|e| ClientError::InvalidData(format!("invalid log data: {e}")))?;
+
+        self.decode_log(&Log {
+            address: self.contract.address(),
+            topics,
+            data,
+            ..Default::default()
+        })
+    }
+
+    /// Logs emitted by the contract in the transaction
+    pub async fn get_transaction_logs(&self, hash: &str) -> Result<Vec<Log>> {
+        let hash: TxHash = hash
+            .parse()
+            .map_err(|e| ClientError::InvalidData(format!("invalid transaction hash: {e}")))?;
+
+        let receipt = self
+            .provider
+            .get_transaction_receipt(hash)
+            .await?
+            .ok_or_else(|| {
+                ClientError::InvalidData(format!("transaction {hash:?} is not found"))
+            })?;
+
+        Ok(receipt
+            .logs
+            .into_iter()
+            .filter(|log| log.address == self.contract.address())
+            .collect())
+    }
+}
+
+fn token_to_value(token: &Token) -> Value {
+    match token {
+        Token::Address(address) => Value::String(format!("{address:?}")),
+        // Ids are `uint128`, so numbers are kept as strings to avoid precision loss
+        Token::Uint(value) | Token::Int(value) => Value::String(value.to_string()),
+        Token::Bool(value) => Value::Bool(*value),
+        Token::String(value) => Value::String(value.clone()),
+        Token::Bytes(bytes) | Token::FixedBytes(bytes) => {
+            Value::String(format!("0x{}", hex::encode(bytes)))
+        }
+        Token::Array(tokens) | Token::FixedArray(tokens) | Token::Tuple(tokens) => {
+            Value::Array(tokens.iter().map(token_to_value).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::interface::HapiCoreOptions, HapiCoreNetwork, RpcTimeouts};
+    use ethers::{abi::encode, types::Address as EthAddress};
+
+    fn client() -> HapiCoreEvm {
+        HapiCoreEvm::new(HapiCoreOptions {
+            provider_url: "http://127.0.0.1:8545".to_string(),
+            fallback_provider_urls: vec![],
+            contract_address: "0x2947F98C42597966a0ec25e92843c09ac18Fbab7".to_string(),
+            private_key: None,
+            chain_id: None,
+            account_id: None,
+            network: HapiCoreNetwork::Ethereum,
+            rpc_timeouts: RpcTimeouts::default(),
+        })
+        .unwrap()
+    }
+
+    fn topic(client: &HapiCoreEvm, name: &str) -> String {
+        format!(
+            "{:?}",
+            client.contract.abi().event(name).unwrap().signature()
+        )
+    }
+
+    #[test]
+    fn raw_log_is_decoded() {
+        let client = client();
+        let addr: EthAddress = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"
+            .parse()
+            .unwrap();
+
+        let decoded = client
+            .decode_raw_log(
+                &[
+                    topic(&client, "AddressCreated"),
+                    format!("{:?}", H256::from(addr)),
+                ],
+                &format!(
+                    "0x{}",
+                    hex::encode(encode(&[Token::Uint(5.into()), Token::Uint(3.into())]))
+                ),
+            )
+            .unwrap()
+            .expect("Log is expected to be decoded");
+
+        assert_eq!(decoded.name, "AddressCreated");
+        assert_eq!(decoded.event, Some(EventName::CreateAddress));
+        assert_eq!(
+            Value::Object(decoded.fields),
+            serde_json::json!({
+                "addr": format!("{addr:?}"),
+                "risk": "5",
+                "category": "3",
+            })
+        );
+    }
+
+    #[test]
+    fn foreign_log_is_skipped() {
+        let client = client();
+
+        assert_eq!(
+            client
+                .decode_raw_log(&[format!("{:?}", H256::repeat_byte(1))], "0x")
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn malformed_log_is_rejected() {
+        let client = client();
+
+        assert!(client
+            .decode_raw_log(&["0x1234".to_string()], "0x")
+            .is_err());
+
+        // Topics match the event, but the data is missing
+        assert!(client
+            .decode_raw_log(
+                &[
+                    topic(&client, "AddressCreated"),
+                    format!("{:?}", H256::zero())
+                ],
+                "0x"
+            )
+            .is_err());
+    }
+}
@@ -1,32 +1,56 @@
+use ethers::middleware::signer::SignerMiddlewareError;
 use ethers_contract::ContractError;
 
 use crate::client::result::ClientError;
 
-pub(super) fn map_ethers_error<M: ethers_providers::Middleware>(
-    caller: &str,
-    e: ContractError<M>,
-) -> ClientError {
+use super::client::Signer;
+
+/// Readable error of a contract call, failed requests keep the provider error, so that
+/// callers can tell a node failure from a rejected call
+pub(super) fn map_ethers_error(caller: &str, e: ContractError<Signer>) -> ClientError {
     match e {
-        ContractError::Revert(e) => match e {
-            _ if e.is_empty() => {
-                ClientError::Ethers(format!("`{caller}` reverted with empty message"))
-            }
-            // TODO: get rid of black magic parsing
-            _ if e.len() > 64 => ClientError::Ethers(format!(
-                "`{caller}` reverted with: {}",
-                String::from_utf8_lossy(&e[64..])
-                    .chars()
-                    .filter(|c| !c.is_control())
-                    .collect::<String>()
-            )),
-            e => ClientError::Ethers(format!(
-                "`{caller}` reverted with: {}",
-                String::from_utf8_lossy(&e)
-                    .chars()
-                    .filter(|c| !c.is_control())
-                    .collect::<String>()
-            )),
-        },
+        ContractError::ProviderError { e }
+        | ContractError::MiddlewareError {
+            e: SignerMiddlewareError::MiddlewareError(e),
+        } => ClientError::Provider(e),
+        ContractError::Revert(e) if e.is_empty() => {
+            ClientError::Ethers(format!("`{caller}` reverted with empty message"))
+        }
+        ContractError::Revert(e) => {
+            ClientError::Ethers(format!("`{caller}` reverted with: {}", revert_message(&e)))
+        }
         _ => ClientError::Ethers(format!("`{caller}` failed: {e}")),
     }
 }
+
+/// Whether the call reverted with the custom error of the signature, e.g. `ReporterNotFound(uint128)`
+pub(super) fn is_custom_error(e: &ContractError<Signer>, signature: &str) -> bool {
+    matches!(e, ContractError::Revert(data) if data.starts_with(&ethers::utils::id(signature)))
+}
+
+/// Readable message of the revert data
+pub(super) fn revert_message(data: &[u8]) -> String {
+    // TODO: get rid of black magic parsing
+    let message = if data.len() > 64 { &data[64..] } else { data };
+
+    String::from_utf8_lossy(message)
+        .chars()
+        .filter(|c| !c.is_control())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::{abi::AbiEncode, types::Bytes};
+
+    #[test]
+    fn custom_error_is_matched_by_selector() {
+        let mut data = ethers::utils::id("ReporterNotFound(uint128)").to_vec();
+        data.extend(42u128.encode());
+        let error = ContractError::<Signer>::Revert(Bytes::from(data));
+
+        assert!(is_custom_error(&error, "ReporterNotFound(uint128)"));
+        assert!(!is_custom_error(&error, "CaseNotFound(uint128)"));
+    }
+}
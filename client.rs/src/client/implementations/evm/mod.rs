@@ -1,7 +1,21 @@
+mod challenge;
 mod client;
 mod conversion;
+mod decode;
 mod error;
+mod nonce;
+mod relay;
+mod signatures;
 pub mod token;
+mod transport;
 
+pub use challenge::{verify_reporter_challenge, ChallengeDomain, ReporterChallenge};
 pub use client::{HapiCoreEvm, LogHeader};
+pub use decode::DecodedLog;
+pub use relay::{
+    verify_meta_transaction, EvmMetaTransaction, ForwardRequest, ForwarderDomain,
+    DEFAULT_FORWARDER_NAME, DEFAULT_RELAYED_GAS,
+};
+pub use signatures::{expected_event_topics, verify_event_signatures, HAPI_EVENT_SIGNATURES};
 pub use token::TokenContractEvm;
+pub use transport::{EndpointHealth, FailoverHttp, RpcTimeouts, PRIMARY_RETRY_INTERVAL};
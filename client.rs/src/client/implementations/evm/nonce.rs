@@ -0,0 +1,81 @@
+use ethers::{
+    providers::Middleware,
+    types::{Address as EthAddress, BlockNumber, U256},
+};
+use tokio::sync::Mutex;
+
+use crate::client::result::{ClientError, Result};
+
+/// Local nonce tracker for transactions sent from a single account
+///
+/// The pending nonce is fetched from the node once and then incremented locally for
+/// every submitted transaction, so that transactions sent in a quick succession don't
+/// reuse the same nonce. Transactions sent from the same account by other clients are
+/// not tracked: a failed submission drops the local value and the next one resyncs it.
+#[derive(Default)]
+pub(crate) struct NonceManager {
+    nonce: Mutex<Option<U256>>,
+}
+
+impl NonceManager {
+    /// Reserves the nonce for the next transaction
+    pub async fn next<M: Middleware>(&self, client: &M, account: EthAddress) -> Result<U256> {
+        let mut nonce = self.nonce.lock().await;
+
+        let next = match *nonce {
+            Some(nonce) => nonce,
+            None => client
+                .get_transaction_count(account, Some(BlockNumber::Pending.into()))
+                .await
+                .map_err(|e| ClientError::Ethers(format!("failed to get nonce: {e}")))?,
+        };
+
+        *nonce = Some(next + U256::one());
+
+        Ok(next)
+    }
+
+    /// Forgets the local nonce, the next reservation takes it from the node
+    pub async fn reset(&self) {
+        *self.nonce.lock().await = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::providers::Provider;
+
+    #[tokio::test]
+    async fn sequential_nonces() {
+        let (provider, mock) = Provider::mocked();
+        let manager = NonceManager::default();
+        let account = EthAddress::random();
+
+        // Only one response is available, so the node can be asked only once
+        mock.push(U256::from(5)).unwrap();
+
+        let mut nonces = vec![];
+        for _ in 0..3 {
+            nonces.push(manager.next(&provider, account).await.unwrap());
+        }
+
+        assert_eq!(nonces, vec![U256::from(5), U256::from(6), U256::from(7)]);
+    }
+
+    #[tokio::test]
+    async fn resync_after_reset() {
+        let (provider, mock) = Provider::mocked();
+        let manager = NonceManager::default();
+        let account = EthAddress::random();
+
+        mock.push(U256::from(5)).unwrap();
+        assert_eq!(manager.next(&provider, account).await.unwrap(), 5.into());
+
+        manager.reset().await;
+
+        mock.push(U256::from(9)).unwrap();
+        assert_eq!(manager.next(&provider, account).await.unwrap(), 9.into());
+        assert_eq!(manager.next(&provider, account).await.unwrap(), 10.into());
+    }
+}
@@ -0,0 +1,566 @@
+use ethers::{
+    abi::{decode, encode, ParamType, Token},
+    providers::Middleware,
+    signers::Signer as EthersSigner,
+    types::{
+        transaction::eip712::{EIP712Domain, Eip712},
+        Address as EthAddress, Bytes, RecoveryMessage, Signature, TransactionRequest, H256, U256,
+    },
+    utils::{id, keccak256},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::client::{
+    encoding::{category_code, checked_risk},
+    relay::RelayedCall,
+    result::{ClientError, Result, Tx},
+};
+
+use super::client::HapiCoreEvm;
+
+/// EIP-712 name of the forwarder deployed with `npx hardhat deploy-forwarder`
+pub const DEFAULT_FORWARDER_NAME: &str = "HapiCoreForwarder";
+
+/// Gas that the forwarder passes on to a relayed call, enough for any reporter call
+pub const DEFAULT_RELAYED_GAS: u64 = 500_000;
+
+const FORWARDER_VERSION: &str = "1";
+const FORWARD_REQUEST_TYPE: &str = "ForwardRequest(address from,address to,uint256 value,uint256 gas,uint256 nonce,uint48 deadline,bytes data)";
+const EXECUTE_SIGNATURE: &str = "execute((address,address,uint256,uint256,uint48,bytes,bytes))";
+const NONCES_SIGNATURE: &str = "nonces(address)";
+
+/// Deadlines are stored as `uint48` by the forwarder
+const MAX_DEADLINE: u64 = (1 << 48) - 1;
+
+/// ERC-2771 forwarder that the HAPI Core contract trusts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwarderDomain {
+    /// EIP-712 name the forwarder is deployed with
+    pub name: String,
+    pub chain_id: u64,
+    pub forwarder_address: EthAddress,
+}
+
+impl ForwarderDomain {
+    pub fn new(name: &str, chain_id: u64, forwarder_address: &str) -> Result<Self> {
+        let forwarder_address = forwarder_address
+            .parse()
+            .map_err(|e| ClientError::EthAddressParse(format!("`forwarder-address`: {e}")))?;
+
+        Ok(Self {
+            name: name.to_string(),
+            chain_id,
+            forwarder_address,
+        })
+    }
+}
+
+/// Call that the reporter signs for the forwarder, as the `ForwardRequest` type of ERC-2771
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForwardRequest {
+    /// Reporter account, the contract takes it as the caller
+    pub from: EthAddress,
+    /// HAPI Core contract
+    pub to: EthAddress,
+    pub value: U256,
+    pub gas: U256,
+    /// Forwarder nonce of the reporter, so that a request is executed once
+    pub nonce: U256,
+    /// Unix timestamp in seconds, the forwarder rejects the request after it
+    pub deadline: u64,
+    pub data: Bytes,
+}
+
+/// Forward request signed by the reporter, that any relayer can submit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvmMetaTransaction {
+    pub request: ForwardRequest,
+    pub signature: String,
+}
+
+/// Request bound to its forwarder, in the form of EIP-712 typed data
+struct TypedForwardRequest<'a> {
+    request: &'a ForwardRequest,
+    domain: &'a ForwarderDomain,
+}
+
+impl Eip712 for TypedForwardRequest<'_> {
+    type Error = ClientError;
+
+    fn domain(&self) -> Result<EIP712Domain> {
+        Ok(EIP712Domain {
+            name: Some(self.domain.name.clone()),
+            version: Some(FORWARDER_VERSION.to_string()),
+            chain_id: Some(self.domain.chain_id.into()),
+            verifying_contract: Some(self.domain.forwarder_address),
+            salt: None,
+        })
+    }
+
+    fn type_hash() -> Result<[u8; 32]> {
+        Ok(keccak256(FORWARD_REQUEST_TYPE))
+    }
+
+    fn struct_hash(&self) -> Result<[u8; 32]> {
+        let request = self.request;
+
+        Ok(keccak256(encode(&[
+            Token::FixedBytes(Self::type_hash()?.to_vec()),
+            Token::Address(request.from),
+            Token::Address(request.to),
+            Token::Uint(request.value),
+            Token::Uint(request.gas),
+            Token::Uint(request.nonce),
+            Token::Uint(request.deadline.into()),
+            Token::FixedBytes(keccak256(&request.data).to_vec()),
+        ])))
+    }
+}
+
+fn parse_signature(signature: &str) -> Result<Signature> {
+    Signature::from_str(signature)
+        .map_err(|e| ClientError::InvalidData(format!("invalid signature: {e}")))
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// Checks that the request is signed by its `from` account and is not expired at `now`
+///
+/// The forwarder checks the same before executing the request, so a relayer doesn't pay
+/// for a transaction that reverts.
+pub fn verify_meta_transaction(
+    meta: &EvmMetaTransaction,
+    domain: &ForwarderDomain,
+    now: u64,
+) -> Result<()> {
+    let request = &meta.request;
+
+    if request.deadline > MAX_DEADLINE {
+        return Err(ClientError::InvalidData(format!(
+            "deadline {} is out of range",
+            request.deadline
+        )));
+    }
+
+    if request.deadline < now {
+        return Err(ClientError::InvalidData(format!(
+            "request expired at {}",
+            request.deadline
+        )));
+    }
+
+    let hash = TypedForwardRequest { request, domain }.encode_eip712()?;
+
+    let signer = parse_signature(&meta.signature)?
+        .recover(RecoveryMessage::Hash(H256::from(hash)))
+        .map_err(|e| ClientError::InvalidData(format!("invalid signature: {e}")))?;
+
+    if signer != request.from {
+        return Err(ClientError::InvalidData(format!(
+            "request is signed by {signer:?}, not by {:?}",
+            request.from
+        )));
+    }
+
+    Ok(())
+}
+
+/// Call data of `ERC2771Forwarder.execute` with the signed request
+fn execute_call_data(meta: &EvmMetaTransaction) -> Result<Bytes> {
+    let request = &meta.request;
+
+    let mut data = id(EXECUTE_SIGNATURE).to_vec();
+    data.extend(encode(&[Token::Tuple(vec![
+        Token::Address(request.from),
+        Token::Address(request.to),
+        Token::Uint(request.value),
+        Token::Uint(request.gas),
+        Token::Uint(request.deadline.into()),
+        Token::Bytes(request.data.to_vec()),
+        Token::Bytes(parse_signature(&meta.signature)?.to_vec()),
+    ])]));
+
+    Ok(data.into())
+}
+
+fn parse_address(address: &str) -> Result<EthAddress> {
+    address
+        .parse()
+        .map_err(|e| ClientError::Ethers(format!("failed to parse address `{address}`: {e}")))
+}
+
+impl HapiCoreEvm {
+    /// Forwarder nonce of the client account, the next request is signed with it
+    pub async fn forwarder_nonce(&self, domain: &ForwarderDomain) -> Result<U256> {
+        let mut data = id(NONCES_SIGNATURE).to_vec();
+        data.extend(encode(&[Token::Address(self.signer.address())]));
+
+        let call = TransactionRequest::new()
+            .to(domain.forwarder_address)
+            .data(data);
+
+        let result = self.provider.call(&call.into(), None).await?;
+
+        decode(&[ParamType::Uint(256)], &result)
+            .ok()
+            .and_then(|tokens| tokens.into_iter().next())
+            .and_then(Token::into_uint)
+            .ok_or_else(|| ClientError::ContractData(format!("invalid forwarder nonce: {result}")))
+    }
+
+    /// Signs the call as an ERC-2771 forward request, to be submitted by a relayer
+    ///
+    /// The contract takes the client account as the caller of a relayed call, while the
+    /// relayer pays for the transaction with `relay_meta_transaction`.
+    pub async fn sign_meta_transaction(
+        &self,
+        call: RelayedCall,
+        domain: &ForwarderDomain,
+        deadline: u64,
+    ) -> Result<EvmMetaTransaction> {
+        let request = ForwardRequest {
+            from: self.signer.address(),
+            to: self.contract.address(),
+            value: U256::zero(),
+            gas: DEFAULT_RELAYED_GAS.into(),
+            nonce: self.forwarder_nonce(domain).await?,
+            deadline,
+            data: self.relayed_call_data(call)?,
+        };
+
+        let signature = self
+            .signer
+            .sign_typed_data(&TypedForwardRequest {
+                request: &request,
+                domain,
+            })
+            .await
+            .map_err(|e| ClientError::Ethers(format!("failed to sign request: {e}")))?;
+
+        Ok(EvmMetaTransaction {
+            request,
+            signature: format!("0x{signature}"),
+        })
+    }
+
+    /// Checks the signed request and submits it to the forwarder, paying with the client account
+    ///
+    /// Returns once the transaction is sent, it can be awaited with `wait_for_confirmation`.
+    pub async fn relay_meta_transaction(
+        &self,
+        meta: &EvmMetaTransaction,
+        domain: &ForwarderDomain,
+    ) -> Result<Tx> {
+        if meta.request.to != self.contract.address() {
+            return Err(ClientError::InvalidData(format!(
+                "request is sent to {:?}, not to the HAPI Core contract",
+                meta.request.to
+            )));
+        }
+
+        verify_meta_transaction(meta, domain, unix_now())?;
+
+        let nonce = self
+            .nonce_manager
+            .next(self.client.as_ref(), self.signer.address())
+            .await?;
+
+        let tx = TransactionRequest::new()
+            .to(domain.forwarder_address)
+            .data(execute_call_data(meta)?)
+            .nonce(nonce);
+
+        match self.client.send_transaction(tx, None).await {
+            Ok(pending) => Ok(Tx {
+                hash: format!("{:?}", pending.tx_hash()),
+            }),
+            Err(e) => {
+                self.nonce_manager.reset().await;
+                Err(ClientError::Ethers(format!("`relay` failed: {e}")))
+            }
+        }
+    }
+
+    fn relayed_call_data(&self, call: RelayedCall) -> Result<Bytes> {
+        let call = match call {
+            RelayedCall::CreateAddress(input) => self.contract.create_address(
+                parse_address(&input.address)?,
+                input.case_id.as_u128(),
+                checked_risk(input.risk)?,
+                category_code(&input.category),
+            ),
+            RelayedCall::UpdateAddress(input) => self.contract.update_address(
+                parse_address(&input.address)?,
+                checked_risk(input.risk)?,
+                category_code(&input.category),
+                input.case_id.as_u128(),
+            ),
+            RelayedCall::CreateAsset(input) => self.contract.create_asset(
+                parse_address(&input.address)?,
+                input.asset_id.into(),
+                input.case_id.as_u128(),
+                checked_risk(input.risk)?,
+                category_code(&input.category),
+            ),
+            RelayedCall::UpdateAsset(input) => self.contract.update_asset(
+                parse_address(&input.address)?,
+                input.asset_id.into(),
+                checked_risk(input.risk)?,
+                category_code(&input.category),
+                input.case_id.as_u128(),
+            ),
+        };
+
+        call.calldata()
+            .ok_or_else(|| ClientError::Ethers("relayed call has no data".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::entities::{address::CreateAddressInput, category::Category},
+        HapiCoreNetwork, HapiCoreOptions, RpcTimeouts,
+    };
+    use ethers::{signers::LocalWallet, types::Transaction, utils::rlp};
+    use serde_json::{json, Value};
+    use std::sync::{Arc, Mutex};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use uuid::Uuid;
+
+    const CONTRACT_ADDRESS: &str = "0x2947F98C42597966a0ec25e92843c09ac18Fbab7";
+    const FORWARDER_ADDRESS: &str = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+    const REPORTER_PK: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    const RELAYER_PK: &str = "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d";
+
+    /// Node that answers the calls of signing and relaying, keeping the sent raw transactions
+    async fn start_node() -> (String, Arc<Mutex<Vec<Bytes>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let sent = Arc::new(Mutex::new(vec![]));
+
+        let node_sent = sent.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let sent = node_sent.clone();
+
+                tokio::spawn(async move {
+                    let mut buffer = vec![];
+                    let mut chunk = [0u8; 4096];
+
+                    // Reads the headers, then the body of the announced length
+                    let body = loop {
+                        let read = stream.read(&mut chunk).await.unwrap_or(0);
+                        if read == 0 {
+                            return;
+                        }
+                        buffer.extend_from_slice(&chunk[..read]);
+
+                        let request = String::from_utf8_lossy(&buffer).to_string();
+                        let Some((head, body)) = request.split_once("\r\n\r\n") else {
+                            continue;
+                        };
+                        let length = head
+                            .lines()
+                            .find_map(|line| {
+                                let (name, value) = line.split_once(':')?;
+                                if name.eq_ignore_ascii_case("content-length") {
+                                    value.trim().parse::<usize>().ok()
+                                } else {
+                                    None
+                                }
+                            })
+                            .unwrap_or_default();
+
+                        if body.len() >= length {
+                            break body.to_string();
+                        }
+                    };
+
+                    let request: Value = serde_json::from_str(&body).unwrap();
+                    let result = match request["method"].as_str().unwrap() {
+                        "eth_call" => json!(format!("0x{}", "0".repeat(64))),
+                        "eth_getTransactionCount" => json!("0x0"),
+                        "eth_gasPrice" => json!("0x3b9aca00"),
+                        "eth_estimateGas" => json!("0x30d40"),
+                        "eth_chainId" => json!("0x7a69"),
+                        "eth_sendRawTransaction" => {
+                            let raw =
+                                Bytes::from_str(request["params"][0].as_str().unwrap()).unwrap();
+                            let hash = H256::from(keccak256(&raw));
+                            sent.lock().unwrap().push(raw);
+                            json!(format!("{hash:?}"))
+                        }
+                        method => panic!("Unexpected method `{method}`"),
+                    };
+
+                    let body = json!({ "jsonrpc": "2.0", "id": request["id"], "result": result })
+                        .to_string();
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        (url, sent)
+    }
+
+    fn create_client(url: &str, pk: &str) -> HapiCoreEvm {
+        HapiCoreEvm::new(HapiCoreOptions {
+            provider_url: url.to_string(),
+            fallback_provider_urls: vec![],
+            contract_address: CONTRACT_ADDRESS.to_string(),
+            private_key: Some(pk.to_string()),
+            chain_id: Some(31337),
+            account_id: None,
+            network: HapiCoreNetwork::Ethereum,
+            rpc_timeouts: RpcTimeouts::default(),
+        })
+        .unwrap()
+    }
+
+    fn domain() -> ForwarderDomain {
+        ForwarderDomain::new(DEFAULT_FORWARDER_NAME, 31337, FORWARDER_ADDRESS).unwrap()
+    }
+
+    fn create_address_call() -> RelayedCall {
+        RelayedCall::CreateAddress(CreateAddressInput {
+            address: "0x9DDE9F8b85e4c4278545549e4eDF2E3E9d2c890E".to_string(),
+            case_id: Uuid::from_u128(42),
+            risk: 5,
+            category: Category::Hacker,
+        })
+    }
+
+    #[tokio::test]
+    async fn meta_transaction_is_relayed() {
+        let (url, sent) = start_node().await;
+        let reporter = create_client(&url, REPORTER_PK);
+        let relayer = create_client(&url, RELAYER_PK);
+
+        let meta = reporter
+            .sign_meta_transaction(create_address_call(), &domain(), unix_now() + 3600)
+            .await
+            .unwrap();
+
+        assert_eq!(meta.request.from, reporter.signer.address());
+        assert_eq!(meta.request.to, reporter.contract.address());
+        assert_eq!(meta.request.nonce, U256::zero());
+
+        // The payload is passed to the relayer as JSON
+        let meta: EvmMetaTransaction =
+            serde_json::from_str(&serde_json::to_string(&meta).unwrap()).unwrap();
+
+        let tx = relayer
+            .relay_meta_transaction(&meta, &domain())
+            .await
+            .unwrap();
+
+        let raw = sent.lock().unwrap().pop().expect("No transaction is sent");
+        assert_eq!(tx.hash, format!("{:?}", H256::from(keccak256(&raw))));
+
+        // The relayer signs and pays for the forwarder call
+        let sent: Transaction = rlp::decode(&raw).unwrap();
+        assert_eq!(sent.recover_from().unwrap(), relayer.signer.address());
+        assert_eq!(sent.to, Some(domain().forwarder_address));
+        assert_eq!(&sent.input[..4], &id(EXECUTE_SIGNATURE));
+
+        let tokens = decode(
+            &[ParamType::Tuple(vec![
+                ParamType::Address,
+                ParamType::Address,
+                ParamType::Uint(256),
+                ParamType::Uint(256),
+                ParamType::Uint(48),
+                ParamType::Bytes,
+                ParamType::Bytes,
+            ])],
+            &sent.input[4..],
+        )
+        .unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![Token::Tuple(vec![
+                Token::Address(reporter.signer.address()),
+                Token::Address(reporter.contract.address()),
+                Token::Uint(U256::zero()),
+                Token::Uint(DEFAULT_RELAYED_GAS.into()),
+                Token::Uint(meta.request.deadline.into()),
+                Token::Bytes(meta.request.data.to_vec()),
+                Token::Bytes(parse_signature(&meta.signature).unwrap().to_vec()),
+            ])]
+        );
+    }
+
+    #[tokio::test]
+    async fn tampered_meta_transaction_is_rejected() {
+        let wallet = LocalWallet::from_str(REPORTER_PK)
+            .unwrap()
+            .with_chain_id(31337u64);
+        let now = unix_now();
+
+        let request = ForwardRequest {
+            from: wallet.address(),
+            to: CONTRACT_ADDRESS.parse().unwrap(),
+            value: U256::zero(),
+            gas: DEFAULT_RELAYED_GAS.into(),
+            nonce: U256::zero(),
+            deadline: now + 3600,
+            data: Bytes::from(vec![1, 2, 3]),
+        };
+        let signature = wallet
+            .sign_typed_data(&TypedForwardRequest {
+                request: &request,
+                domain: &domain(),
+            })
+            .await
+            .unwrap();
+        let meta = EvmMetaTransaction {
+            request,
+            signature: format!("0x{signature}"),
+        };
+
+        verify_meta_transaction(&meta, &domain(), now).unwrap();
+
+        let mut tampered = meta.clone();
+        tampered.request.data = Bytes::from(vec![1, 2, 4]);
+        assert!(verify_meta_transaction(&tampered, &domain(), now).is_err());
+
+        let mut replayed = meta.clone();
+        replayed.request.nonce = U256::one();
+        assert!(verify_meta_transaction(&replayed, &domain(), now).is_err());
+
+        // Expired requests are not relayed
+        assert!(verify_meta_transaction(&meta, &domain(), now + 3601).is_err());
+
+        // Nor are the ones signed for another forwarder
+        let other = ForwarderDomain::new("OtherForwarder", 31337, FORWARDER_ADDRESS).unwrap();
+        assert!(verify_meta_transaction(&meta, &other, now).is_err());
+
+        // The relayer only pays for calls of its contract
+        let relayer = create_client("http://127.0.0.1:8545", RELAYER_PK);
+        let mut foreign = meta.clone();
+        foreign.request.to = FORWARDER_ADDRESS.parse().unwrap();
+        assert!(relayer
+            .relay_meta_transaction(&foreign, &domain())
+            .await
+            .is_err());
+    }
+}
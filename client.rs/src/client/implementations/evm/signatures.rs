@@ -0,0 +1,114 @@
+use ethers::{abi::Abi, types::H256, utils::keccak256};
+use std::str::FromStr;
+
+use crate::client::{
+    events::EventName,
+    result::{ClientError, Result},
+};
+
+/// Signatures of the contract events that HAPI events are indexed from
+pub const HAPI_EVENT_SIGNATURES: &[&str] = &[
+    "Initialized(uint64)",
+    "StakeConfigurationChanged(address,uint256,uint256,uint256,uint256,uint256)",
+    "RewardConfigurationChanged(address,uint256,uint256,uint256,uint256)",
+    "ReporterCreated(uint128,address,uint8)",
+    "ReporterUpdated(uint128,address,uint8)",
+    "ReporterActivated(uint128)",
+    "ReporterDeactivated(uint128)",
+    "ReporterStakeWithdrawn(uint128)",
+    "CaseCreated(uint128)",
+    "CaseUpdated(uint128)",
+    "AddressCreated(address,uint8,uint8)",
+    "AddressUpdated(address,uint8,uint8)",
+    "AddressConfirmed(address)",
+    "AssetCreated(address,uint256,uint8,uint8)",
+    "AssetUpdated(address,uint256,uint8,uint8)",
+    "AssetConfirmed(address,uint256)",
+];
+
+/// Topics the expected events are logged with, along with their signatures
+pub fn expected_event_topics() -> Vec<(&'static str, H256)> {
+    HAPI_EVENT_SIGNATURES
+        .iter()
+        .map(|signature| (*signature, H256::from(keccak256(signature))))
+        .collect()
+}
+
+/// Checks that the ABI exposes every expected event with the expected signature
+///
+/// A HAPI event of the ABI with a signature other than the expected one would never
+/// match the logs of the contract, so it is reported as well.
+pub fn verify_event_signatures(abi: &Abi) -> Result<()> {
+    let expected = expected_event_topics();
+    let mut mismatches = vec![];
+
+    for (signature, topic) in &expected {
+        if !abi.events().any(|event| event.signature() == *topic) {
+            mismatches.push(format!("missing `{signature}`"));
+        }
+    }
+
+    for event in abi.events() {
+        let is_hapi_event = EventName::from_str(&event.name).is_ok();
+        let signature = event.signature();
+
+        if is_hapi_event && !expected.iter().any(|(_, topic)| *topic == signature) {
+            let params: Vec<_> = event
+                .inputs
+                .iter()
+                .map(|input| input.kind.to_string())
+                .collect();
+
+            mismatches.push(format!(
+                "unexpected `{}({})` with topic {signature:?}",
+                event.name,
+                params.join(",")
+            ));
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(ClientError::ContractData(format!(
+            "contract ABI doesn't match the HAPI events: {}",
+            mismatches.join(", ")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::client::HAPI_CORE_CONTRACT_ABI, *};
+
+    const CASE_CREATED_V0: &str = r#"[
+        {
+            "type": "event",
+            "name": "CaseCreated",
+            "anonymous": false,
+            "inputs": [{ "name": "id", "type": "uint256", "indexed": false }]
+        }
+    ]"#;
+
+    #[test]
+    fn contract_abi_has_expected_events() {
+        verify_event_signatures(&HAPI_CORE_CONTRACT_ABI).unwrap();
+    }
+
+    #[test]
+    fn mismatched_abi_is_flagged() {
+        let abi: Abi = serde_json::from_str(CASE_CREATED_V0).unwrap();
+
+        let error = verify_event_signatures(&abi).unwrap_err().to_string();
+
+        assert!(error.contains("missing `CaseCreated(uint128)`"), "{error}");
+        assert!(
+            error.contains("missing `AddressConfirmed(address)`"),
+            "{error}"
+        );
+        assert!(
+            error.contains("unexpected `CaseCreated(uint256)`"),
+            "{error}"
+        );
+    }
+}
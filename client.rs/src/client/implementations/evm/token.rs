@@ -18,6 +18,7 @@ use crate::{
 use super::{
     client::{Provider, Signer},
     error::map_ethers_error,
+    transport::FailoverHttp,
 };
 
 use super::client::LOCAL_CHAIN_ID;
@@ -33,8 +34,11 @@ pub struct TokenContractEvm {
 
 impl TokenContractEvm {
     pub fn new(options: HapiCoreOptions) -> Result<Self> {
-        let provider = Provider::try_from(options.provider_url.as_str())
-            .map_err(|e| ClientError::UrlParseError(format!("`provider_url`: {e}")))?;
+        let provider = Provider::new(FailoverHttp::new(
+            &options.provider_url,
+            &options.fallback_provider_urls,
+            options.rpc_timeouts,
+        )?);
 
         let signer = LocalWallet::from_str(options.private_key.unwrap_or_default().as_str())
             .map_err(|e| ClientError::Ethers(format!("`private_key`: {e}")))?
@@ -123,4 +127,12 @@ impl TokenContract for TokenContractEvm {
             .map_err(|e| map_ethers_error("balance", e))
             .map(|a| Amount::from_str(&a.to_string()).unwrap_or_default())
     }
+
+    async fn decimals(&self) -> Result<u8> {
+        self.contract
+            .decimals()
+            .call()
+            .await
+            .map_err(|e| map_ethers_error("decimals", e))
+    }
 }
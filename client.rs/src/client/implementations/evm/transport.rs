@@ -0,0 +1,435 @@
+use async_trait::async_trait;
+use ethers::providers::{
+    Http, HttpClientError, JsonRpcClient, JsonRpcError, ProviderError, RpcError,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+use crate::client::result::{ClientError, Result};
+
+/// Timeouts of JSON-RPC requests to an EVM node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcTimeouts {
+    /// Timeout of the methods that are not listed separately
+    pub default: Duration,
+    /// Timeouts by method name, i.e. `eth_getLogs`
+    pub methods: HashMap<String, Duration>,
+}
+
+impl Default for RpcTimeouts {
+    fn default() -> Self {
+        Self {
+            default: Duration::from_secs(30),
+            methods: HashMap::from([
+                // Scanning a wide block range may take a while
+                ("eth_getLogs".to_string(), Duration::from_secs(120)),
+                ("eth_blockNumber".to_string(), Duration::from_secs(5)),
+                ("eth_chainId".to_string(), Duration::from_secs(5)),
+            ]),
+        }
+    }
+}
+
+impl RpcTimeouts {
+    pub fn get(&self, method: &str) -> Duration {
+        self.methods.get(method).copied().unwrap_or(self.default)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TimeoutHttpError {
+    #[error("`{method}` request timed out after {} ms", .timeout.as_millis())]
+    Timeout { method: String, timeout: Duration },
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+}
+
+impl RpcError for TimeoutHttpError {
+    fn as_error_response(&self) -> Option<&JsonRpcError> {
+        match self {
+            TimeoutHttpError::Http(error) => error.as_error_response(),
+            TimeoutHttpError::Timeout { .. } => None,
+        }
+    }
+
+    fn as_serde_error(&self) -> Option<&serde_json::Error> {
+        match self {
+            TimeoutHttpError::Http(error) => error.as_serde_error(),
+            TimeoutHttpError::Timeout { .. } => None,
+        }
+    }
+}
+
+impl From<TimeoutHttpError> for ProviderError {
+    fn from(error: TimeoutHttpError) -> Self {
+        ProviderError::JsonRpcClientError(Box::new(error))
+    }
+}
+
+/// HTTP transport that limits every request by the timeout of its method
+#[derive(Debug, Clone)]
+pub struct TimeoutHttp {
+    inner: Http,
+    timeouts: Arc<RpcTimeouts>,
+}
+
+impl TimeoutHttp {
+    pub fn new(url: &str, timeouts: RpcTimeouts) -> Result<Self> {
+        let inner = Http::from_str(url)
+            .map_err(|e| ClientError::UrlParseError(format!("`provider-url`: {e}")))?;
+
+        Ok(Self {
+            inner,
+            timeouts: Arc::new(timeouts),
+        })
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for TimeoutHttp {
+    type Error = TimeoutHttpError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> std::result::Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let timeout = self.timeouts.get(method);
+
+        tokio::time::timeout(timeout, self.inner.request(method, params))
+            .await
+            .map_err(|_| TimeoutHttpError::Timeout {
+                method: method.to_string(),
+                timeout,
+            })?
+            .map_err(Into::into)
+    }
+}
+
+/// Time after which requests go back to the primary endpoint once it has failed
+pub const PRIMARY_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Health of an RPC endpoint as seen by the requests sent to it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EndpointHealth {
+    pub url: String,
+    /// Transport failures in a row, reset by the next answered request
+    pub failures: u64,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug)]
+struct Endpoint {
+    transport: TimeoutHttp,
+    health: Mutex<EndpointHealth>,
+}
+
+impl Endpoint {
+    fn lock_health(&self) -> MutexGuard<'_, EndpointHealth> {
+        self.health
+            .lock()
+            .expect("Endpoint health lock is poisoned")
+    }
+}
+
+#[derive(Debug)]
+struct ActiveEndpoint {
+    index: usize,
+    since: Instant,
+}
+
+/// Transport over several endpoints of the same node, i.e. providers behind a proxy or a load balancer
+///
+/// Requests go to the active endpoint, the first one initially. A transport error, i.e. a timeout,
+/// a refused connection or a response that is not JSON-RPC, moves the request on to the next endpoint,
+/// which stays active for the following ones. The primary endpoint is tried again once
+/// the retry interval passes. JSON-RPC errors are answers of a working node and are returned as is.
+#[derive(Debug, Clone)]
+pub struct FailoverHttp {
+    endpoints: Arc<Vec<Endpoint>>,
+    active: Arc<Mutex<ActiveEndpoint>>,
+    primary_retry_interval: Duration,
+}
+
+impl FailoverHttp {
+    pub fn new(primary_url: &str, fallback_urls: &[String], timeouts: RpcTimeouts) -> Result<Self> {
+        let endpoints = std::iter::once(primary_url)
+            .chain(fallback_urls.iter().map(String::as_str))
+            .map(|url| {
+                Ok(Endpoint {
+                    transport: TimeoutHttp::new(url, timeouts.clone())?,
+                    health: Mutex::new(EndpointHealth {
+                        url: url.to_string(),
+                        ..Default::default()
+                    }),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            endpoints: Arc::new(endpoints),
+            active: Arc::new(Mutex::new(ActiveEndpoint {
+                index: 0,
+                since: Instant::now(),
+            })),
+            primary_retry_interval: PRIMARY_RETRY_INTERVAL,
+        })
+    }
+
+    pub fn with_primary_retry_interval(mut self, interval: Duration) -> Self {
+        self.primary_retry_interval = interval;
+        self
+    }
+
+    /// Health of every endpoint, the primary one first
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.lock_health().clone())
+            .collect()
+    }
+
+    fn lock_active(&self) -> MutexGuard<'_, ActiveEndpoint> {
+        self.active
+            .lock()
+            .expect("Active endpoint lock is poisoned")
+    }
+
+    fn first_endpoint(&self) -> usize {
+        let mut active = self.lock_active();
+
+        if active.index != 0 && active.since.elapsed() >= self.primary_retry_interval {
+            // The primary is probed once per interval, a failed probe waits for the next one
+            active.since = Instant::now();
+            0
+        } else {
+            active.index
+        }
+    }
+
+    fn activate(&self, index: usize) {
+        let mut active = self.lock_active();
+
+        if active.index != index {
+            *active = ActiveEndpoint {
+                index,
+                since: Instant::now(),
+            };
+        }
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for FailoverHttp {
+    type Error = TimeoutHttpError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> std::result::Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let first = self.first_endpoint();
+        let mut last_error = None;
+
+        for offset in 0..self.endpoints.len() {
+            let index = (first + offset) % self.endpoints.len();
+            let endpoint = &self.endpoints[index];
+
+            let error = match endpoint.transport.request(method, &params).await {
+                Ok(result) => {
+                    endpoint.lock_health().failures = 0;
+                    self.activate(index);
+
+                    return Ok(result);
+                }
+                Err(error) => error,
+            };
+
+            if error.as_error_response().is_some() {
+                endpoint.lock_health().failures = 0;
+                self.activate(index);
+
+                return Err(error);
+            }
+
+            let mut health = endpoint.lock_health();
+            health.failures += 1;
+            health.last_error = Some(error.to_string());
+            drop(health);
+
+            last_error = Some(error);
+        }
+
+        Err(last_error.expect("There is at least one endpoint"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        time::Instant,
+    };
+
+    const RESPONSE_DELAY: Duration = Duration::from_millis(300);
+
+    const NODE_RESPONSE: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":\"0x1\"}";
+
+    // Proxy in front of a node that is down
+    const BAD_GATEWAY_RESPONSE: &str =
+        "HTTP/1.1 502 Bad Gateway\r\nContent-Type: text/html\r\n\r\n<html>502 Bad Gateway</html>";
+
+    // Endpoint that gives the response to every request after a delay
+    async fn start_node(delay: Duration, response: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buffer = [0u8; 4096];
+                    let _ = stream.read(&mut buffer).await;
+
+                    tokio::time::sleep(delay).await;
+
+                    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+                    let response = format!(
+                        "{head}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn timeouts_by_method() {
+        let url = start_node(RESPONSE_DELAY, NODE_RESPONSE).await;
+        let transport = TimeoutHttp::new(
+            &url,
+            RpcTimeouts {
+                default: Duration::from_secs(5),
+                methods: HashMap::from([
+                    ("eth_blockNumber".to_string(), Duration::from_millis(50)),
+                    ("eth_getLogs".to_string(), Duration::from_secs(2)),
+                ]),
+            },
+        )
+        .unwrap();
+
+        let started = Instant::now();
+        let error = transport
+            .request::<_, Value>("eth_blockNumber", ())
+            .await
+            .expect_err("Fast method must time out");
+
+        assert!(matches!(error, TimeoutHttpError::Timeout { .. }));
+        assert!(started.elapsed() < RESPONSE_DELAY);
+
+        let started = Instant::now();
+        let result = transport
+            .request::<_, Value>("eth_getLogs", ())
+            .await
+            .expect("Slow method must get its longer budget");
+
+        assert_eq!(result, Value::String("0x1".to_string()));
+        assert!(started.elapsed() >= RESPONSE_DELAY);
+    }
+
+    #[test]
+    fn unlisted_methods_use_default() {
+        let timeouts = RpcTimeouts::default();
+
+        assert_eq!(timeouts.get("eth_call"), timeouts.default);
+        assert!(timeouts.get("eth_blockNumber") < timeouts.get("eth_getLogs"));
+    }
+
+    #[tokio::test]
+    async fn failover_to_next_endpoint() {
+        let primary = start_node(Duration::ZERO, BAD_GATEWAY_RESPONSE).await;
+        let fallback = start_node(Duration::ZERO, NODE_RESPONSE).await;
+
+        let transport = FailoverHttp::new(&primary, &[fallback], RpcTimeouts::default()).unwrap();
+
+        let result = transport
+            .request::<_, Value>("eth_blockNumber", ())
+            .await
+            .expect("Fallback endpoint must answer");
+
+        assert_eq!(result, Value::String("0x1".to_string()));
+
+        let health = transport.health();
+        assert_eq!(health[0].failures, 1);
+        assert!(health[0].last_error.is_some());
+        assert_eq!(health[1].failures, 0);
+
+        // The fallback stays active until the primary is retried
+        transport
+            .request::<_, Value>("eth_blockNumber", ())
+            .await
+            .unwrap();
+        assert_eq!(transport.health()[0].failures, 1);
+
+        let transport = transport.with_primary_retry_interval(Duration::ZERO);
+        transport
+            .request::<_, Value>("eth_blockNumber", ())
+            .await
+            .unwrap();
+        assert_eq!(transport.health()[0].failures, 2);
+    }
+
+    #[tokio::test]
+    async fn primary_is_probed_once_per_interval() {
+        let primary = start_node(Duration::ZERO, BAD_GATEWAY_RESPONSE).await;
+        let fallback = start_node(Duration::ZERO, NODE_RESPONSE).await;
+
+        let interval = Duration::from_millis(200);
+        let transport = FailoverHttp::new(&primary, &[fallback], RpcTimeouts::default())
+            .unwrap()
+            .with_primary_retry_interval(interval);
+
+        transport
+            .request::<_, Value>("eth_blockNumber", ())
+            .await
+            .unwrap();
+        assert_eq!(transport.health()[0].failures, 1);
+
+        tokio::time::sleep(interval).await;
+
+        for _ in 0..3 {
+            transport
+                .request::<_, Value>("eth_blockNumber", ())
+                .await
+                .unwrap();
+        }
+        assert_eq!(transport.health()[0].failures, 2);
+    }
+
+    #[tokio::test]
+    async fn error_of_every_endpoint_is_returned() {
+        let primary = start_node(Duration::ZERO, BAD_GATEWAY_RESPONSE).await;
+        let fallback = start_node(Duration::ZERO, BAD_GATEWAY_RESPONSE).await;
+
+        let transport = FailoverHttp::new(&primary, &[fallback], RpcTimeouts::default()).unwrap();
+
+        transport
+            .request::<_, Value>("eth_blockNumber", ())
+            .await
+            .expect_err("No endpoint can answer");
+
+        assert!(transport.health().iter().all(|health| health.failures == 1));
+    }
+}
@@ -1,20 +1,23 @@
 use async_trait::async_trait;
 use near_crypto::{InMemorySigner, SecretKey};
 use near_jsonrpc_client::{
+    errors::{JsonRpcError, JsonRpcServerError},
     methods::{self, broadcast_tx_async::RpcBroadcastTxAsyncRequest, query::RpcQueryRequest},
     JsonRpcClient,
 };
 use near_jsonrpc_primitives::types::{
-    query::{QueryResponseKind, RpcQueryResponse},
+    query::{QueryResponseKind, RpcQueryError, RpcQueryResponse},
     transactions::TransactionInfo,
 };
 use near_primitives::{
+    hash::CryptoHash,
     transaction::{Action, FunctionCallAction, Transaction},
     types::{AccountId, BlockReference, Finality, FunctionArgs},
     views::{FinalExecutionStatus, QueryRequest},
 };
 use serde::Deserialize;
 use serde_json::{from_slice, json, Value};
+use std::str::FromStr;
 use tokio::{time, time::Duration};
 use uuid::Uuid;
 
@@ -26,9 +29,13 @@ pub const TRANSACTION_TIMEOUT: Duration = Duration::from_secs(60);
 pub const PERIOD_CHECK_TX_STATUS: Duration = Duration::from_secs(2);
 pub const DELAY_AFTER_TX_EXECUTION: Duration = Duration::from_secs(1);
 
+/// Panic message of the contract views of a missing reporter
+const ERROR_REPORTER_NOT_FOUND: &str = "Reporter not found";
+
 use crate::{
     client::{
-        configuration::{RewardConfiguration, StakeConfiguration},
+        configuration::{NetworkConfiguration, RewardConfiguration, StakeConfiguration},
+        confirmation::{poll_confirmation, ConfirmationOptions, TxStatus},
         entities::{
             address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
             asset::{Asset, AssetId, ConfirmAssetInput, CreateAssetInput, UpdateAssetInput},
@@ -217,6 +224,25 @@ impl HapiCore for HapiCoreNear {
         Ok(self.get_response::<RewardConfiguration>(request).await?)
     }
 
+    async fn get_network_configuration(&self) -> Result<NetworkConfiguration> {
+        let (authority, stake_configuration, reward_configuration) = tokio::try_join!(
+            self.get_authority(),
+            self.get_stake_configuration(),
+            self.get_reward_configuration()
+        )?;
+
+        Ok(NetworkConfiguration {
+            authority,
+            stake_configuration,
+            reward_configuration,
+        })
+    }
+
+    /// The contract has no version view
+    async fn get_contract_version(&self) -> Result<Option<u64>> {
+        Ok(None)
+    }
+
     async fn create_reporter(&self, input: CreateReporterInput) -> Result<Tx> {
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
@@ -262,9 +288,15 @@ impl HapiCore for HapiCoreNear {
     async fn get_reporter(&self, id: &str) -> Result<Reporter> {
         let request = self.view_request("get_reporter", Some(json!({ "id": uuid_to_u128!(id) })));
 
-        let reporter = self.get_response::<NearReporter>(request).await?;
-
-        reporter.try_into()
+        match self.get_response::<NearReporter>(request).await {
+            Err(ClientError::RpcQueryError(JsonRpcError::ServerError(
+                JsonRpcServerError::HandlerError(RpcQueryError::ContractExecutionError {
+                    vm_error,
+                    ..
+                }),
+            ))) if vm_error.contains(ERROR_REPORTER_NOT_FOUND) => Err(ClientError::InvalidReporter),
+            result => result?.try_into(),
+        }
     }
 
     async fn get_reporter_count(&self) -> Result<u64> {
@@ -359,6 +391,19 @@ impl HapiCore for HapiCoreNear {
         Ok(execute_transaction(transaction, signer, &self.client).await?)
     }
 
+    async fn get_chain_time(&self) -> Result<u64> {
+        let block = self
+            .client
+            .call(methods::block::RpcBlockRequest {
+                block_reference: BlockReference::Finality(Finality::Final),
+            })
+            .await
+            .map_err(|e| ClientError::InvalidResponse(format!("failed to get block: {e}")))?;
+
+        // Block timestamps are in nanoseconds
+        Ok(block.header.timestamp / 1_000_000_000)
+    }
+
     async fn create_case(&self, input: CreateCaseInput) -> Result<Tx> {
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
@@ -441,6 +486,18 @@ impl HapiCore for HapiCoreNear {
         Ok(execute_transaction(transaction, signer, &self.client).await?)
     }
 
+    async fn create_addresses(&self, inputs: Vec<CreateAddressInput>) -> Result<Vec<Result<Tx>>> {
+        // Actions of a single NEAR transaction are applied atomically, so addresses are
+        // submitted one by one to keep a failing item from reverting the rest
+        let mut results = Vec::with_capacity(inputs.len());
+
+        for input in inputs {
+            results.push(self.create_address(input).await);
+        }
+
+        Ok(results)
+    }
+
     async fn update_address(&self, input: UpdateAddressInput) -> Result<Tx> {
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
@@ -585,6 +642,50 @@ impl HapiCore for HapiCoreNear {
             .map(|asset| asset.try_into())
             .collect::<Result<Vec<Asset>>>()?)
     }
+
+    // NEAR has a single-block finality, so the status is final once the transaction is executed
+    async fn wait_for_confirmation(
+        &self,
+        hash: &str,
+        options: ConfirmationOptions,
+    ) -> Result<TxStatus> {
+        let tx_hash = CryptoHash::from_str(hash)
+            .map_err(|e| ClientError::InvalidData(format!("invalid transaction hash: {e}")))?;
+        let sender_account_id = self.get_signer()?.account_id;
+
+        poll_confirmation(&options, || {
+            let sender_account_id = sender_account_id.clone();
+
+            async move {
+                let response = self
+                    .client
+                    .call(methods::tx::RpcTransactionStatusRequest {
+                        transaction_info: TransactionInfo::TransactionId {
+                            tx_hash,
+                            sender_account_id,
+                        },
+                    })
+                    .await;
+
+                match response {
+                    Err(err) => match err.handler_error() {
+                        Some(methods::tx::RpcTransactionError::UnknownTransaction { .. }) => {
+                            Ok(None)
+                        }
+                        _ => Err(err.into()),
+                    },
+                    Ok(response) => match response.status {
+                        FinalExecutionStatus::SuccessValue(_) => Ok(Some(TxStatus::Confirmed)),
+                        FinalExecutionStatus::Failure(err) => {
+                            Ok(Some(TxStatus::Reverted(err.to_string())))
+                        }
+                        _ => Ok(None),
+                    },
+                }
+            }
+        })
+        .await
+    }
 }
 
 impl HapiCoreNear {
@@ -7,6 +7,7 @@ use near_sdk::{json_types::U64, AccountId};
 use uuid::Uuid;
 
 use crate::client::{
+    encoding::{category_from_code, role_from_code},
     entities::{
         address::Address,
         asset::Asset,
@@ -82,7 +83,7 @@ impl TryFrom<NearReporter> for Reporter {
         Ok(Reporter {
             id: Uuid::from_u128(reporter.id.0),
             account: reporter.account_id.to_string(),
-            role: (reporter.role as u8).try_into()?,
+            role: role_from_code(reporter.role as u8)?,
             status: (reporter.status as u8).try_into()?,
             name: reporter.name.to_string(),
             url: reporter.url.to_string(),
@@ -119,6 +120,7 @@ impl TryFrom<NearCase> for Case {
             name: case.name.to_string(),
             url: case.url.to_string(),
             reporter_id: Uuid::from_u128(case.reporter_id.0),
+            related_cases: vec![],
         })
     }
 }
@@ -143,7 +145,7 @@ impl TryFrom<NearAddress> for Address {
     fn try_from(address: NearAddress) -> Result<Self> {
         Ok(Address {
             address: address.address.to_string(),
-            category: (address.category as u8).try_into()?,
+            category: category_from_code(address.category as u8)?,
             risk: address.risk_score,
             case_id: Uuid::from_u128(address.case_id.0),
             reporter_id: Uuid::from_u128(address.reporter_id.0),
@@ -174,7 +176,7 @@ impl TryFrom<NearAsset> for Asset {
         Ok(Asset {
             address: asset.address.to_string(),
             asset_id: asset.id.0.into(),
-            category: (asset.category as u8).try_into()?,
+            category: category_from_code(asset.category as u8)?,
             risk: asset.risk_score,
             case_id: Uuid::from_u128(asset.case_id.0),
             reporter_id: Uuid::from_u128(asset.reporter_id.0),
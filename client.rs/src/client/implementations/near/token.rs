@@ -11,6 +11,7 @@ use near_primitives::{
     types::{AccountId, BlockReference, Finality, FunctionArgs},
     views::QueryRequest,
 };
+use serde::Deserialize;
 use serde_json::{from_slice, json};
 
 use super::client::execute_transaction;
@@ -23,6 +24,12 @@ use crate::{
     Amount, HapiCoreOptions,
 };
 
+/// Part of the NEP-148 metadata that is used by the client
+#[derive(Deserialize)]
+struct FungibleTokenMetadata {
+    decimals: u8,
+}
+
 pub struct TokenContractNear {
     client: JsonRpcClient,
     contract_address: AccountId,
@@ -117,4 +124,24 @@ impl TokenContract for TokenContractNear {
             ))
         }
     }
+
+    async fn decimals(&self) -> Result<u8> {
+        let request = RpcQueryRequest {
+            block_reference: BlockReference::Finality(Finality::Final),
+            request: QueryRequest::CallFunction {
+                account_id: self.contract_address.to_owned(),
+                method_name: "ft_metadata".to_string(),
+                args: FunctionArgs::from(json!({}).to_string().into_bytes()),
+            },
+        };
+
+        let result = self.client.call(request).await?;
+        if let QueryResponseKind::CallResult(result) = result.kind {
+            Ok(from_slice::<FungibleTokenMetadata>(&result.result)?.decimals)
+        } else {
+            Err(ClientError::InvalidResponse(
+                "failed to receive call result".into(),
+            ))
+        }
+    }
 }
@@ -11,17 +11,15 @@ macro_rules! get_solana_account {
 
 #[macro_export]
 macro_rules! get_solana_accounts {
-    ($self:expr, $account:ident) => {{
-        let data = $self
-            .get_accounts::<hapi_core_solana::$account>(hapi_core_solana::$account::LEN)
+    ($self:expr, $account:ident, $skip:expr, $take:expr) => {{
+        let mut accounts = $self
+            .scan_account_page::<hapi_core_solana::$account>($skip, $take)
             .await?;
 
         let mut result: Vec<$account> = vec![];
 
-        for (_, acc) in data {
-            if acc.network == $self.network {
-                result.push(<$account>::try_from(acc)?);
-            }
+        while let Some((_, acc)) = futures::TryStreamExt::try_next(&mut accounts).await? {
+            result.push(<$account>::try_from(acc)?);
         }
 
         Ok(result)
@@ -30,8 +28,7 @@ macro_rules! get_solana_accounts {
 
 #[macro_export]
 macro_rules! get_solana_account_count {
-    ($self:expr, $account:ident) => {{
-        let accounts: Result<Vec<$account>> = get_solana_accounts!($self, $account);
-        Ok(accounts?.len() as u64)
-    }};
+    ($self:expr, $account:ident) => {
+        $self.count_accounts::<hapi_core_solana::$account>().await
+    };
 }
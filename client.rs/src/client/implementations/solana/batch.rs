@@ -0,0 +1,105 @@
+use anchor_client::solana_sdk::{
+    instruction::Instruction, message::Message, packet::PACKET_DATA_SIZE, pubkey::Pubkey,
+    signature::SIGNATURE_BYTES,
+};
+
+/// Serialized size of a transaction signed by the fee payer only
+///
+/// One byte for the compact signature count, one signature, then the message itself
+fn transaction_size(instructions: &[Instruction], payer: &Pubkey) -> usize {
+    1 + SIGNATURE_BYTES + Message::new(instructions, Some(payer)).serialize().len()
+}
+
+/// Greedily groups instructions into transactions that fit into a single packet
+///
+/// Instruction order is preserved. An instruction that does not fit even on its own
+/// still gets a group of its own, so that the RPC node reports the error for it.
+pub(crate) fn pack_instructions(
+    instructions: Vec<Instruction>,
+    payer: &Pubkey,
+) -> Vec<Vec<Instruction>> {
+    let mut groups = vec![];
+    let mut current: Vec<Instruction> = vec![];
+
+    for instruction in instructions {
+        current.push(instruction);
+
+        if current.len() > 1 && transaction_size(&current, payer) > PACKET_DATA_SIZE {
+            let overflow = current.pop().expect("group is not empty");
+            groups.push(std::mem::replace(&mut current, vec![overflow]));
+        }
+    }
+
+    if !current.is_empty() {
+        groups.push(current);
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::solana_sdk::instruction::AccountMeta;
+
+    fn create_instruction(program_id: &Pubkey, payer: &Pubkey, data_len: usize) -> Instruction {
+        Instruction::new_with_bytes(
+            *program_id,
+            &vec![1u8; data_len],
+            vec![
+                AccountMeta::new(*payer, true),
+                AccountMeta::new(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ],
+        )
+    }
+
+    #[test]
+    fn pack_splits_by_packet_size() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let instructions: Vec<Instruction> = (0..20)
+            .map(|_| create_instruction(&program_id, &payer, 80))
+            .collect();
+
+        let groups = pack_instructions(instructions.clone(), &payer);
+
+        assert!(groups.len() > 1);
+        assert_eq!(
+            groups.iter().map(Vec::len).sum::<usize>(),
+            instructions.len()
+        );
+
+        for group in &groups {
+            assert!(transaction_size(group, &payer) <= PACKET_DATA_SIZE);
+        }
+
+        let flattened: Vec<Instruction> = groups.into_iter().flatten().collect();
+        assert_eq!(flattened, instructions);
+    }
+
+    #[test]
+    fn pack_keeps_oversized_instruction_alone() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let instructions = vec![
+            create_instruction(&program_id, &payer, 10),
+            create_instruction(&program_id, &payer, PACKET_DATA_SIZE),
+            create_instruction(&program_id, &payer, 10),
+        ];
+
+        let groups = pack_instructions(instructions, &payer);
+
+        assert_eq!(
+            groups.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn pack_empty() {
+        assert!(pack_instructions(vec![], &Pubkey::new_unique()).is_empty());
+    }
+}
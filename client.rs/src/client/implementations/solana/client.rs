@@ -4,20 +4,22 @@ use {
         solana_client::{
             nonblocking::rpc_client::RpcClient,
             rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
-            rpc_filter::{Memcmp, RpcFilterType},
         },
         solana_sdk::{
+            account::from_account,
             commitment_config::CommitmentConfig,
             pubkey::Pubkey,
-            signature::{Keypair, Signer},
+            signature::{Keypair, Signature, Signer},
             system_program,
+            sysvar::{self, clock::Clock},
             transaction::Transaction,
         },
         RequestBuilder,
     },
     async_trait::async_trait,
+    futures::TryStreamExt,
     hapi_core_solana::{accounts, instruction},
-    solana_account_decoder::UiAccountEncoding,
+    solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig},
     spl_associated_token_account::{
         get_associated_token_address, instruction::create_associated_token_account,
     },
@@ -28,7 +30,9 @@ use {
 
 use crate::{
     client::{
-        configuration::{RewardConfiguration, StakeConfiguration},
+        configuration::{NetworkConfiguration, RewardConfiguration, StakeConfiguration},
+        confirmation::{poll_confirmation, ConfirmationOptions, TxStatus},
+        encoding::checked_risk,
         entities::{
             address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
             asset::{Asset, AssetId, ConfirmAssetInput, CreateAssetInput, UpdateAssetInput},
@@ -42,7 +46,11 @@ use crate::{
 };
 
 use super::{
+    batch::pack_instructions,
     instruction_data::get_hapi_sighashes,
+    scan::{
+        account_filters, page_keys, scan_accounts, AccountStream, NetworkScoped, SCAN_PAGE_SIZE,
+    },
     utils::{
         byte_array_from_str, get_address_address, get_asset_address, get_case_address,
         get_confirmation_address, get_network_address, get_program_data_address,
@@ -56,7 +64,7 @@ pub struct HapiCoreSolana {
     pub rpc_client: RpcClient,
     pub program_id: Pubkey,
     network: Pubkey,
-    signer: Arc<Keypair>,
+    pub(super) signer: Arc<Keypair>,
     pub(crate) hashes: Vec<[u8; 8]>,
 }
 
@@ -115,20 +123,21 @@ impl HapiCoreSolana {
             .map_err(|e| ClientError::AccountDeserializationError(e.to_string()))
     }
 
-    async fn get_accounts<T>(&self, data_size: usize) -> Result<Vec<(Pubkey, T)>>
+    /// Lists keys of the program accounts of type `T` that belong to the current network
+    ///
+    /// Account data is sliced out of the response, so the listing stays small even for large communities
+    async fn get_account_keys<T>(&self) -> Result<Vec<Pubkey>>
     where
-        T: AccountDeserialize + Discriminator,
+        T: Discriminator + NetworkScoped,
     {
-        let account_type_filter =
-            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &T::discriminator()));
-
-        let account_size_filter =
-            RpcFilterType::DataSize((data_size + hapi_core_solana::ACCOUNT_RESERVE_SPACE) as u64);
-
         let config = RpcProgramAccountsConfig {
-            filters: Some(vec![account_type_filter, account_size_filter]),
+            filters: Some(account_filters::<T>(&self.network)),
             account_config: RpcAccountInfoConfig {
                 encoding: Some(UiAccountEncoding::Base64),
+                data_slice: Some(UiDataSliceConfig {
+                    offset: 0,
+                    length: 0,
+                }),
                 ..RpcAccountInfoConfig::default()
             },
             ..RpcProgramAccountsConfig::default()
@@ -139,16 +148,51 @@ impl HapiCoreSolana {
             .get_program_accounts_with_config(&self.program_id, config)
             .await?;
 
-        let mut result = vec![];
+        Ok(accounts.into_iter().map(|(pubkey, _)| pubkey).collect())
+    }
 
-        for (pubkey, acc) in accounts {
-            let account = T::try_deserialize(&mut (&acc.data as &[u8]))
-                .map_err(|e| ClientError::AccountDeserializationError(e.to_string()))?;
+    /// Streams program accounts of type `T` that belong to the current network
+    ///
+    /// Accounts are fetched with `getMultipleAccounts` in pages of `SCAN_PAGE_SIZE`
+    pub async fn scan_accounts<T>(&self) -> Result<AccountStream<'_, T>>
+    where
+        T: AccountDeserialize + Discriminator + NetworkScoped + Send,
+    {
+        let keys = self.get_account_keys::<T>().await?;
 
-            result.push((pubkey, account))
-        }
+        Ok(self.fetch_accounts(keys))
+    }
+
+    /// Streams a page of the program accounts of type `T` that belong to the current network
+    ///
+    /// Accounts are ordered by their keys, so that consecutive pages don't overlap
+    pub async fn scan_account_page<T>(&self, skip: u64, take: u64) -> Result<AccountStream<'_, T>>
+    where
+        T: AccountDeserialize + Discriminator + NetworkScoped + Send,
+    {
+        let keys = self.get_account_keys::<T>().await?;
+
+        Ok(self.fetch_accounts(page_keys(keys, skip, take)))
+    }
 
-        Ok(result)
+    /// Counts program accounts of type `T` that belong to the current network
+    pub async fn count_accounts<T>(&self) -> Result<u64>
+    where
+        T: Discriminator + NetworkScoped,
+    {
+        Ok(self.get_account_keys::<T>().await?.len() as u64)
+    }
+
+    fn fetch_accounts<T>(&self, keys: Vec<Pubkey>) -> AccountStream<'_, T>
+    where
+        T: AccountDeserialize + Send,
+    {
+        scan_accounts(keys, SCAN_PAGE_SIZE, move |page| async move {
+            self.rpc_client
+                .get_multiple_accounts(&page)
+                .await
+                .map_err(ClientError::from)
+        })
     }
 
     async fn call_contract(
@@ -169,17 +213,147 @@ impl HapiCoreSolana {
         self.send_transaction(&instructions).await
     }
 
-    async fn get_reporter(&self) -> Result<(Pubkey, hapi_core_solana::Reporter)> {
-        let data = self
-            .get_accounts::<hapi_core_solana::Reporter>(hapi_core_solana::Reporter::LEN)
-            .await?;
+    pub(super) fn create_address_instruction(
+        &self,
+        reporter: Pubkey,
+        input: CreateAddressInput,
+    ) -> Result<Instruction> {
+        let mut addr = [0u8; 64];
+        byte_array_from_str(&input.address, &mut addr)?;
+
+        let (address, bump) = get_address_address(&addr, &self.network, &self.program_id)?;
+        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: accounts::CreateAddress {
+                sender: self.signer.pubkey(),
+                network: self.network,
+                reporter,
+                case,
+                address,
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+            data: instruction::CreateAddress {
+                addr,
+                category: input.category.into(),
+                risk_score: checked_risk(input.risk)?,
+                bump,
+            }
+            .data(),
+        })
+    }
+
+    pub(super) fn update_address_instruction(
+        &self,
+        reporter: Pubkey,
+        input: UpdateAddressInput,
+    ) -> Result<Instruction> {
+        let mut addr = [0u8; 64];
+        byte_array_from_str(&input.address, &mut addr)?;
+
+        let (address, _) = get_address_address(&addr, &self.network, &self.program_id)?;
+        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: accounts::UpdateAddress {
+                sender: self.signer.pubkey(),
+                network: self.network,
+                reporter,
+                case,
+                address,
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+            data: instruction::UpdateAddress {
+                category: input.category.into(),
+                risk_score: checked_risk(input.risk)?,
+            }
+            .data(),
+        })
+    }
+
+    pub(super) fn create_asset_instruction(
+        &self,
+        reporter: Pubkey,
+        input: CreateAssetInput,
+    ) -> Result<Instruction> {
+        let mut addr = [0u8; 64];
+        byte_array_from_str(&input.address, &mut addr)?;
+
+        let mut asset_id = [0u8; 32];
+        byte_array_from_str(&input.asset_id.to_string(), &mut asset_id)?;
+
+        let (asset, bump) = get_asset_address(&addr, &asset_id, &self.network, &self.program_id)?;
+        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: accounts::CreateAsset {
+                sender: self.signer.pubkey(),
+                network: self.network,
+                reporter,
+                case,
+                asset,
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+            data: instruction::CreateAsset {
+                addr,
+                asset_id,
+                category: input.category.into(),
+                risk_score: checked_risk(input.risk)?,
+                bump,
+            }
+            .data(),
+        })
+    }
+
+    pub(super) fn update_asset_instruction(
+        &self,
+        reporter: Pubkey,
+        input: UpdateAssetInput,
+    ) -> Result<Instruction> {
+        let mut addr = [0u8; 64];
+        byte_array_from_str(&input.address, &mut addr)?;
+
+        let mut asset_id = [0u8; 32];
+        byte_array_from_str(&input.asset_id.to_string(), &mut asset_id)?;
+
+        let (asset, _) = get_asset_address(&addr, &asset_id, &self.network, &self.program_id)?;
+        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: accounts::UpdateAsset {
+                sender: self.signer.pubkey(),
+                network: self.network,
+                reporter,
+                case,
+                asset,
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+            data: instruction::UpdateAsset {
+                category: input.category.into(),
+                risk_score: checked_risk(input.risk)?,
+            }
+            .data(),
+        })
+    }
+
+    pub(super) async fn get_reporter(&self) -> Result<(Pubkey, hapi_core_solana::Reporter)> {
+        let mut reporters = self.scan_accounts::<hapi_core_solana::Reporter>().await?;
 
-        let reporter = data
-            .iter()
-            .find(|(_, reporter)| reporter.account == self.signer.pubkey())
-            .ok_or(ClientError::InvalidReporter)?;
+        while let Some((pubkey, reporter)) = reporters.try_next().await? {
+            if reporter.account == self.signer.pubkey() {
+                return Ok((pubkey, reporter));
+            }
+        }
 
-        Ok(reporter.to_owned())
+        Err(ClientError::InvalidReporter)
     }
 
     async fn create_network_ata(&self, token: &Pubkey) -> Result<()> {
@@ -301,11 +475,43 @@ impl HapiCore for HapiCoreSolana {
             .try_into()
     }
 
+    async fn get_network_configuration(&self) -> Result<NetworkConfiguration> {
+        // Everything is stored in the network account, so a single read is enough
+        let network = self
+            .get_account_data::<hapi_core_solana::Network>(&self.network)
+            .await?;
+
+        Ok(NetworkConfiguration {
+            authority: network.authority.to_string(),
+            stake_configuration: network.clone().try_into()?,
+            reward_configuration: network.try_into()?,
+        })
+    }
+
+    /// Layout version of the network account, the program keeps no version of its own
+    async fn get_contract_version(&self) -> Result<Option<u64>> {
+        let network = self
+            .get_account_data::<hapi_core_solana::Network>(&self.network)
+            .await?;
+
+        Ok(Some(network.version.into()))
+    }
+
     async fn create_reporter(&self, input: CreateReporterInput) -> Result<Tx> {
         let (reporter, bump) = get_reporter_address(input.id, &self.network, &self.program_id)?;
         let account = Pubkey::from_str(&input.account)
             .map_err(|e| ClientError::SolanaAddressParseError(format!("`account`: {e}")))?;
 
+        // The program would reject the taken reporter account with an opaque "already in use" error
+        match self
+            .get_account_data::<hapi_core_solana::Reporter>(&reporter)
+            .await
+        {
+            Err(ClientError::AccountNotFound) => {}
+            Ok(_) => return Err(ClientError::ReporterAlreadyExists(input.id.to_string())),
+            Err(e) => return Err(e),
+        }
+
         self.call_contract(
             accounts::CreateReporter {
                 authority: self.signer.pubkey(),
@@ -349,15 +555,21 @@ impl HapiCore for HapiCoreSolana {
     async fn get_reporter(&self, id: &str) -> Result<Reporter> {
         let (addr, _) = get_reporter_address(Uuid::from_str(id)?, &self.network, &self.program_id)?;
 
-        get_solana_account!(self, &addr, Reporter)
+        match self
+            .get_account_data::<hapi_core_solana::Reporter>(&addr)
+            .await
+        {
+            Err(ClientError::AccountNotFound) => Err(ClientError::InvalidReporter),
+            result => Reporter::try_from(result?),
+        }
     }
 
     async fn get_reporter_count(&self) -> Result<u64> {
         get_solana_account_count!(self, Reporter)
     }
 
-    async fn get_reporters(&self, _skip: u64, _take: u64) -> Result<Vec<Reporter>> {
-        get_solana_accounts!(self, Reporter)
+    async fn get_reporters(&self, skip: u64, take: u64) -> Result<Vec<Reporter>> {
+        get_solana_accounts!(self, Reporter, skip, take)
     }
 
     async fn activate_reporter(&self) -> Result<Tx> {
@@ -424,6 +636,16 @@ impl HapiCore for HapiCoreSolana {
         .await
     }
 
+    async fn get_chain_time(&self) -> Result<u64> {
+        // The program checks unlock timestamps against the clock sysvar
+        let account = self.rpc_client.get_account(&sysvar::clock::id()).await?;
+        let clock = from_account::<Clock, _>(&account).ok_or(ClientError::InvalidResponse(
+            "failed to decode clock sysvar".to_string(),
+        ))?;
+
+        Ok(clock.unix_timestamp as u64)
+    }
+
     async fn create_case(&self, input: CreateCaseInput) -> Result<Tx> {
         let (reporter, _) = self.get_reporter().await?;
         let (case, bump) = get_case_address(input.id, &self.network, &self.program_id)?;
@@ -477,60 +699,67 @@ impl HapiCore for HapiCoreSolana {
         get_solana_account_count!(self, Case)
     }
 
-    async fn get_cases(&self, _skip: u64, _take: u64) -> Result<Vec<Case>> {
-        get_solana_accounts!(self, Case)
+    async fn get_cases(&self, skip: u64, take: u64) -> Result<Vec<Case>> {
+        get_solana_accounts!(self, Case, skip, take)
     }
 
     async fn create_address(&self, input: CreateAddressInput) -> Result<Tx> {
-        let mut addr = [0u8; 64];
-        byte_array_from_str(&input.address, &mut addr)?;
+        let (reporter, _) = self.get_reporter().await?;
+        let instruction = self.create_address_instruction(reporter, input)?;
 
-        let (address, bump) = get_address_address(&addr, &self.network, &self.program_id)?;
+        self.send_transaction(&[instruction]).await
+    }
+
+    async fn create_addresses(&self, inputs: Vec<CreateAddressInput>) -> Result<Vec<Result<Tx>>> {
         let (reporter, _) = self.get_reporter().await?;
-        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
 
-        self.call_contract(
-            accounts::CreateAddress {
-                sender: self.signer.pubkey(),
-                network: self.network,
-                reporter,
-                case,
-                address,
-                system_program: system_program::id(),
-            },
-            instruction::CreateAddress {
-                addr,
-                category: input.category.into(),
-                risk_score: input.risk,
-                bump,
-            },
-        )
-        .await
+        let mut results: Vec<Option<Result<Tx>>> = inputs.iter().map(|_| None).collect();
+        let mut instructions = vec![];
+        let mut indices = vec![];
+
+        for (index, input) in inputs.into_iter().enumerate() {
+            match self.create_address_instruction(reporter, input) {
+                Ok(instruction) => {
+                    indices.push(index);
+                    instructions.push(instruction);
+                }
+                Err(error) => results[index] = Some(Err(error)),
+            }
+        }
+
+        let mut indices = indices.into_iter();
+
+        for group in pack_instructions(instructions, &self.signer.pubkey()) {
+            let group_indices: Vec<usize> = indices.by_ref().take(group.len()).collect();
+
+            match self.send_transaction(&group).await {
+                Ok(tx) => {
+                    for index in group_indices {
+                        results[index] = Some(Ok(tx.clone()));
+                    }
+                }
+                Err(error) => {
+                    let error = error.to_string();
+
+                    for index in group_indices {
+                        results[index] =
+                            Some(Err(ClientError::BatchTransactionFailed(error.clone())));
+                    }
+                }
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|result| result.expect("every input is either sent or rejected"))
+            .collect())
     }
 
     async fn update_address(&self, input: UpdateAddressInput) -> Result<Tx> {
-        let mut addr = [0u8; 64];
-        byte_array_from_str(&input.address, &mut addr)?;
-
-        let (address, _) = get_address_address(&addr, &self.network, &self.program_id)?;
         let (reporter, _) = self.get_reporter().await?;
-        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+        let instruction = self.update_address_instruction(reporter, input)?;
 
-        self.call_contract(
-            accounts::UpdateAddress {
-                sender: self.signer.pubkey(),
-                network: self.network,
-                reporter,
-                case,
-                address,
-                system_program: system_program::id(),
-            },
-            instruction::UpdateAddress {
-                category: input.category.into(),
-                risk_score: input.risk,
-            },
-        )
-        .await
+        self.send_transaction(&[instruction]).await
     }
 
     async fn confirm_address(&self, input: ConfirmAddressInput) -> Result<Tx> {
@@ -575,67 +804,22 @@ impl HapiCore for HapiCoreSolana {
         get_solana_account_count!(self, Address)
     }
 
-    async fn get_addresses(&self, _skip: u64, _take: u64) -> Result<Vec<Address>> {
-        get_solana_accounts!(self, Address)
+    async fn get_addresses(&self, skip: u64, take: u64) -> Result<Vec<Address>> {
+        get_solana_accounts!(self, Address, skip, take)
     }
 
     async fn create_asset(&self, input: CreateAssetInput) -> Result<Tx> {
-        let mut addr = [0u8; 64];
-        byte_array_from_str(&input.address, &mut addr)?;
-
-        let mut asset_id = [0u8; 32];
-        byte_array_from_str(&input.asset_id.to_string(), &mut asset_id)?;
-
-        let (asset, bump) = get_asset_address(&addr, &asset_id, &self.network, &self.program_id)?;
         let (reporter, _) = self.get_reporter().await?;
-        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+        let instruction = self.create_asset_instruction(reporter, input)?;
 
-        self.call_contract(
-            accounts::CreateAsset {
-                sender: self.signer.pubkey(),
-                network: self.network,
-                reporter,
-                case,
-                asset,
-                system_program: system_program::id(),
-            },
-            instruction::CreateAsset {
-                addr,
-                asset_id,
-                category: input.category.into(),
-                risk_score: input.risk,
-                bump,
-            },
-        )
-        .await
+        self.send_transaction(&[instruction]).await
     }
 
     async fn update_asset(&self, input: UpdateAssetInput) -> Result<Tx> {
-        let mut addr = [0u8; 64];
-        byte_array_from_str(&input.address, &mut addr)?;
-
-        let mut asset_id = [0u8; 32];
-        byte_array_from_str(&input.asset_id.to_string(), &mut asset_id)?;
-
-        let (asset, _) = get_asset_address(&addr, &asset_id, &self.network, &self.program_id)?;
         let (reporter, _) = self.get_reporter().await?;
-        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+        let instruction = self.update_asset_instruction(reporter, input)?;
 
-        self.call_contract(
-            accounts::UpdateAsset {
-                sender: self.signer.pubkey(),
-                network: self.network,
-                reporter,
-                case,
-                asset,
-                system_program: system_program::id(),
-            },
-            instruction::UpdateAsset {
-                category: input.category.into(),
-                risk_score: input.risk,
-            },
-        )
-        .await
+        self.send_transaction(&[instruction]).await
     }
 
     async fn confirm_asset(&self, input: ConfirmAssetInput) -> Result<Tx> {
@@ -685,7 +869,39 @@ impl HapiCore for HapiCoreSolana {
     async fn get_asset_count(&self) -> Result<u64> {
         get_solana_account_count!(self, Asset)
     }
-    async fn get_assets(&self, _skip: u64, _take: u64) -> Result<Vec<Asset>> {
-        get_solana_accounts!(self, Asset)
+    async fn get_assets(&self, skip: u64, take: u64) -> Result<Vec<Asset>> {
+        get_solana_accounts!(self, Asset, skip, take)
+    }
+
+    async fn wait_for_confirmation(
+        &self,
+        hash: &str,
+        options: ConfirmationOptions,
+    ) -> Result<TxStatus> {
+        let signature = Signature::from_str(hash)?;
+
+        poll_confirmation(&options, move || async move {
+            let statuses = self
+                .rpc_client
+                .get_signature_statuses(&[signature])
+                .await?
+                .value;
+
+            let Some(Some(status)) = statuses.into_iter().next() else {
+                return Ok(None);
+            };
+
+            if let Some(error) = status.err {
+                return Ok(Some(TxStatus::Reverted(error.to_string())));
+            }
+
+            // Confirmations are not counted once the block is rooted by the supermajority
+            let confirmed = status
+                .confirmations
+                .map_or(true, |count| count as u64 + 1 >= options.confirmations);
+
+            Ok(confirmed.then_some(TxStatus::Confirmed))
+        })
+        .await
     }
 }
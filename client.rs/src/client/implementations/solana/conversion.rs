@@ -1,5 +1,6 @@
 use crate::client::{
     configuration::{RewardConfiguration, StakeConfiguration},
+    encoding::{category_from_code, role_from_code},
     entities::{
         address::Address,
         asset::{Asset, AssetId},
@@ -147,7 +148,7 @@ impl TryFrom<SolanaReporter> for Reporter {
         Ok(Reporter {
             id: Uuid::from_u128(reporter.id),
             account: reporter.account.to_string(),
-            role: (reporter.role as u8).try_into()?,
+            role: role_from_code(reporter.role as u8)?,
             status: (reporter.status as u8).try_into()?,
             name: reporter.name.to_string(),
             url: reporter.url.to_string(),
@@ -167,6 +168,11 @@ impl TryFrom<SolanaCase> for Case {
             url: case.url.to_string(),
             status: (case.status as u8).try_into()?,
             reporter_id: Uuid::from_u128(case.reporter_id),
+            related_cases: case
+                .related_cases
+                .into_iter()
+                .map(Uuid::from_u128)
+                .collect(),
         })
     }
 }
@@ -180,7 +186,7 @@ impl TryFrom<SolanaAddress> for Address {
             case_id: Uuid::from_u128(addr.case_id),
             reporter_id: Uuid::from_u128(addr.reporter_id),
             risk: addr.risk_score,
-            category: (addr.category as u8).try_into()?,
+            category: category_from_code(addr.category as u8)?,
             confirmations: addr.confirmations,
         })
     }
@@ -202,7 +208,7 @@ impl TryFrom<SolanaAsset> for Asset {
             case_id: Uuid::from_u128(asset.case_id),
             reporter_id: Uuid::from_u128(asset.reporter_id),
             risk: asset.risk_score,
-            category: (asset.category as u8).try_into()?,
+            category: category_from_code(asset.category as u8)?,
             confirmations: asset.confirmations,
         })
     }
@@ -0,0 +1,269 @@
+use {
+    anchor_client::{
+        anchor_lang::{AccountDeserialize, Discriminator},
+        solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey},
+    },
+    hapi_core_solana::{
+        bytes_to_string, Address as SolanaAddress, Asset as SolanaAsset, Case as SolanaCase,
+        Confirmation as SolanaConfirmation, Network as SolanaNetwork, Reporter as SolanaReporter,
+    },
+    serde::Serialize,
+    serde_json::{json, Value},
+    std::str::FromStr,
+    uuid::Uuid,
+};
+
+use crate::client::{
+    configuration::{RewardConfiguration, StakeConfiguration},
+    entities::{address::Address, asset::Asset, case::Case, reporter::Reporter},
+    result::{ClientError, Result},
+};
+
+use super::{client::HapiCoreSolana, instruction_data::DISCRIMINATOR_SIZE};
+
+/// Program account decoded by its Anchor discriminator
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountDump {
+    /// `network`, `reporter`, `case`, `address`, `asset` or `confirmation`
+    pub account_type: String,
+    pub data: Value,
+    /// Hex encoded account data, if requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+}
+
+/// Identifies the program account type by the discriminator and deserializes it
+pub fn decode_account(data: &[u8]) -> Result<(&'static str, Value)> {
+    let discriminator = data
+        .get(..DISCRIMINATOR_SIZE)
+        .ok_or_else(|| ClientError::InvalidData("account data is too short".to_string()))?;
+
+    match discriminator {
+        d if d == SolanaNetwork::discriminator() => {
+            let network: SolanaNetwork = deserialize(data)?;
+
+            let name = bytes_to_string(&network.name)
+                .map_err(|e| ClientError::InvalidData(format!("network name: {e}")))?;
+            let stake_configuration = StakeConfiguration::try_from(network.clone())?;
+            let reward_configuration = RewardConfiguration::try_from(network.clone())?;
+
+            Ok((
+                "network",
+                json!({
+                    "version": network.version,
+                    "bump": network.bump,
+                    "authority": network.authority.to_string(),
+                    "name": name,
+                    "stake_configuration": to_value(stake_configuration)?,
+                    "reward_configuration": to_value(reward_configuration)?,
+                    "is_retired": network.is_retired,
+                }),
+            ))
+        }
+        d if d == SolanaReporter::discriminator() => {
+            let reporter: SolanaReporter = deserialize(data)?;
+            let (version, bump, network) = (reporter.version, reporter.bump, reporter.network);
+
+            Ok((
+                "reporter",
+                scoped(
+                    to_value(Reporter::try_from(reporter)?)?,
+                    version,
+                    bump,
+                    network,
+                ),
+            ))
+        }
+        d if d == SolanaCase::discriminator() => {
+            let case: SolanaCase = deserialize(data)?;
+            let (version, bump, network) = (case.version, case.bump, case.network);
+            let evidence: Vec<String> = case.evidence.iter().map(|hash| to_hex(hash)).collect();
+
+            let mut value = scoped(to_value(Case::try_from(case)?)?, version, bump, network);
+            value["evidence"] = evidence.into();
+
+            Ok(("case", value))
+        }
+        d if d == SolanaAddress::discriminator() => {
+            let address: SolanaAddress = deserialize(data)?;
+            let (version, bump, network) = (address.version, address.bump, address.network);
+            let is_invalidated = address.is_invalidated;
+
+            let mut value = scoped(
+                to_value(Address::try_from(address)?)?,
+                version,
+                bump,
+                network,
+            );
+            value["is_invalidated"] = is_invalidated.into();
+
+            Ok(("address", value))
+        }
+        d if d == SolanaAsset::discriminator() => {
+            let asset: SolanaAsset = deserialize(data)?;
+            let (version, bump, network) = (asset.version, asset.bump, asset.network);
+
+            Ok((
+                "asset",
+                scoped(to_value(Asset::try_from(asset)?)?, version, bump, network),
+            ))
+        }
+        d if d == SolanaConfirmation::discriminator() => {
+            let confirmation: SolanaConfirmation = deserialize(data)?;
+
+            Ok((
+                "confirmation",
+                json!({
+                    "version": confirmation.version,
+                    "bump": confirmation.bump,
+                    "network": confirmation.network.to_string(),
+                    "account": confirmation.account.to_string(),
+                    "reporter_id": Uuid::from_u128(confirmation.reporter_id),
+                }),
+            ))
+        }
+        _ => Err(ClientError::InvalidData(
+            "unknown account discriminator".to_string(),
+        )),
+    }
+}
+
+fn deserialize<T: AccountDeserialize>(mut data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut data)
+        .map_err(|e| ClientError::AccountDeserializationError(e.to_string()))
+}
+
+fn to_value<T: Serialize>(value: T) -> Result<Value> {
+    serde_json::to_value(value).map_err(|e| ClientError::InvalidData(e.to_string()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Adds the fields that the client entities leave out
+fn scoped(mut value: Value, version: u16, bump: u8, network: Pubkey) -> Value {
+    value["version"] = version.into();
+    value["bump"] = bump.into();
+    value["network"] = network.to_string().into();
+    value
+}
+
+impl HapiCoreSolana {
+    /// Fetches a program account and decodes it, for debugging
+    pub async fn dump_account(&self, address: &str, raw: bool) -> Result<AccountDump> {
+        let address = Pubkey::from_str(address)
+            .map_err(|e| ClientError::SolanaAddressParseError(format!("`account`: {e}")))?;
+
+        let account = self
+            .rpc_client
+            .get_account_with_commitment(&address, CommitmentConfig::processed())
+            .await?
+            .value
+            .ok_or(ClientError::AccountNotFound)?;
+
+        if account.owner != self.program_id {
+            return Err(ClientError::InvalidData(format!(
+                "account is owned by {}, not by the program",
+                account.owner
+            )));
+        }
+
+        let (account_type, data) = decode_account(&account.data)?;
+
+        Ok(AccountDump {
+            account_type: account_type.to_string(),
+            data,
+            raw: raw.then(|| to_hex(&account.data)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::anchor_lang::AccountSerialize;
+    use hapi_core_solana::CaseStatus;
+
+    fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = vec![];
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn case_account_is_dumped() {
+        let network = Pubkey::new_unique();
+        let data = serialize(&SolanaCase {
+            version: 1,
+            bump: 255,
+            id: 42,
+            network,
+            name: "big hack".to_string(),
+            reporter_id: 7,
+            status: CaseStatus::Open,
+            url: "https://big.hack".to_string(),
+            evidence: vec![[1; 32]],
+            related_cases: vec![43],
+        });
+
+        let (account_type, value) = decode_account(&data).unwrap();
+
+        assert_eq!(account_type, "case");
+        assert_eq!(value["id"], Uuid::from_u128(42).to_string());
+        assert_eq!(value["name"], "big hack");
+        assert_eq!(value["url"], "https://big.hack");
+        assert_eq!(value["reporter_id"], Uuid::from_u128(7).to_string());
+        assert_eq!(value["version"], 1);
+        assert_eq!(value["bump"], 255);
+        assert_eq!(value["network"], network.to_string());
+        assert_eq!(value["evidence"], json!(["01".repeat(32)]));
+        assert_eq!(
+            value["related_cases"],
+            json!([Uuid::from_u128(43).to_string()])
+        );
+    }
+
+    #[test]
+    fn invalidated_address_is_dumped() {
+        let mut address = [0u8; 64];
+        address[..4].copy_from_slice(b"addr");
+
+        let data = serialize(&SolanaAddress {
+            version: 1,
+            bump: 254,
+            network: Pubkey::new_unique(),
+            address,
+            category: Default::default(),
+            risk_score: 5,
+            case_id: 42,
+            reporter_id: 7,
+            confirmations: 0,
+            is_invalidated: true,
+        });
+
+        let (account_type, value) = decode_account(&data).unwrap();
+
+        assert_eq!(account_type, "address");
+        assert_eq!(value["address"], "addr");
+        assert_eq!(value["bump"], 254);
+        assert_eq!(value["is_invalidated"], true);
+    }
+
+    #[test]
+    fn unknown_account_is_rejected() {
+        let confirmation = serialize(&SolanaConfirmation {
+            version: 1,
+            bump: 255,
+            network: Pubkey::new_unique(),
+            account: Pubkey::new_unique(),
+            reporter_id: 7,
+        });
+        assert_eq!(decode_account(&confirmation).unwrap().0, "confirmation");
+
+        let mut unknown = confirmation.clone();
+        unknown[0] ^= 1;
+        assert!(decode_account(&unknown).is_err());
+        assert!(decode_account(&confirmation[..4]).is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use hapi_core_solana::ErrorCode;
+use serde::Serialize;
+
+use crate::client::result::{ClientError, Result};
+
+/// Program error that a custom error code of a failed transaction stands for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProgramErrorCode {
+    pub code: u32,
+    /// Name of the `ErrorCode` variant
+    pub name: String,
+    pub message: String,
+}
+
+/// Maps a custom error code of the HAPI Core program back to its `ErrorCode` variant
+///
+/// The variants are listed by the program build, so new errors are mapped without changes here.
+pub fn explain_error_code(code: u32) -> Option<ProgramErrorCode> {
+    ErrorCode::ALL
+        .iter()
+        .find(|error| u32::from(**error) == code)
+        .map(|error| ProgramErrorCode {
+            code,
+            name: error.name(),
+            message: error.to_string(),
+        })
+}
+
+/// Parses an error code given as a decimal number or as a hex one, as the
+/// `custom program error: 0x1770` transaction logs print it
+pub fn parse_error_code(value: &str) -> Result<u32> {
+    let value = value.trim();
+
+    let code = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+
+    code.map_err(|e| ClientError::InvalidData(format!("`code`: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_code_is_explained() {
+        let error = explain_error_code(6001).expect("Code must be known");
+
+        assert_eq!(error.name, "AuthorityMismatch");
+        assert_eq!(error.message, "Authority mismatched");
+
+        assert_eq!(parse_error_code("0x1770").unwrap(), 6000);
+        assert_eq!(
+            explain_error_code(6000).map(|error| error.name),
+            Some("InvalidToken".to_string())
+        );
+    }
+
+    #[test]
+    fn every_error_is_mapped() {
+        let first = u32::from(ErrorCode::ALL[0]);
+
+        for (offset, error) in ErrorCode::ALL.iter().enumerate() {
+            assert_eq!(u32::from(*error), first + offset as u32);
+        }
+
+        assert!(explain_error_code(first + ErrorCode::ALL.len() as u32).is_none());
+        assert!(explain_error_code(0).is_none());
+    }
+}
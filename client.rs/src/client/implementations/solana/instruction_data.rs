@@ -35,6 +35,10 @@ pub enum DecodedInstructionData {
     CreateAsset(CreateAssetData),
     UpdateAsset(UpdateAssetData),
     ConfirmAsset(u8),
+    SetReporterUrl(String),
+    ConfirmAddresses,
+    InvalidateAddress,
+    LinkCases,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Default, Debug)]
@@ -107,6 +111,18 @@ pub struct UpdateAssetData {
     pub risk_score: u8,
 }
 
+/// Position of `set_reporter_url` among the instruction hashes
+///
+/// The instruction changes the reporter account only, so it is indexed as a reporter update.
+#[cfg(feature = "decode")]
+pub(crate) const SET_REPORTER_URL_INDEX: usize = 17;
+
+/// Position of `confirm_addresses` among the instruction hashes
+///
+/// The instruction has no arguments and confirms every address of its remaining accounts.
+#[cfg(feature = "decode")]
+pub(crate) const CONFIRM_ADDRESSES_INDEX: usize = 18;
+
 /// Hashes instruction names to bytearray
 pub fn get_hapi_sighashes() -> Vec<[u8; 8]> {
     let names = [
@@ -127,6 +143,10 @@ pub fn get_hapi_sighashes() -> Vec<[u8; 8]> {
         "create_asset",
         "update_asset",
         "confirm_asset",
+        "set_reporter_url",
+        "confirm_addresses",
+        "invalidate_address",
+        "link_cases",
     ];
 
     names
@@ -15,6 +15,8 @@ use super::instruction_data::{
     DecodedInstructionData, InstructionData, UpdateAddressData, UpdateAssetData, UpdateCaseData,
     UpdateReporterData, DISCRIMINATOR_SIZE,
 };
+#[cfg(feature = "decode")]
+use super::instruction_data::{CONFIRM_ADDRESSES_INDEX, SET_REPORTER_URL_INDEX};
 use crate::{
     client::{events::EventName, result::ClientError},
     HapiCoreSolana,
@@ -120,20 +122,23 @@ impl HapiCoreSolana {
             let buf = &bs58::decode(&instruction.data).into_vec()?;
             let sighash = &buf[..DISCRIMINATOR_SIZE];
 
-            let name = if let Some(index) = self.hashes.iter().position(|hash| hash == sighash) {
-                EventName::from_index(index)?
-            } else {
+            let Some(index) = self.hashes.iter().position(|hash| hash == sighash) else {
                 return Ok(None);
             };
 
+            let name = EventName::from_index(index)?;
+
             #[cfg(not(feature = "decode"))]
             let data = InstructionData::Raw(instruction.data.clone());
 
             #[cfg(feature = "decode")]
-            let data = InstructionData::Decoded(decode_instruction_data(
-                &name,
-                &buf[DISCRIMINATOR_SIZE..],
-            )?);
+            let data = InstructionData::Decoded(match index {
+                SET_REPORTER_URL_INDEX => DecodedInstructionData::SetReporterUrl(
+                    String::try_from_slice(&buf[DISCRIMINATOR_SIZE..])?,
+                ),
+                CONFIRM_ADDRESSES_INDEX => DecodedInstructionData::ConfirmAddresses,
+                _ => decode_instruction_data(&name, &buf[DISCRIMINATOR_SIZE..])?,
+            });
 
             let account_keys = instruction
                 .accounts
@@ -196,6 +201,8 @@ fn decode_instruction_data(
         EventName::ConfirmAddress => {
             DecodedInstructionData::ConfirmAddress(u8::try_from_slice(data_slice)?)
         }
+        EventName::InvalidateAddress => DecodedInstructionData::InvalidateAddress,
+        EventName::LinkCases => DecodedInstructionData::LinkCases,
         EventName::CreateAsset => {
             DecodedInstructionData::CreateAsset(CreateAssetData::try_from_slice(data_slice)?)
         }
@@ -213,18 +220,20 @@ fn decode_instruction_data(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{client::solana::test_helpers::*, HapiCoreNetwork, HapiCoreOptions};
+    use crate::{client::solana::test_helpers::*, HapiCoreNetwork, HapiCoreOptions, RpcTimeouts};
 
     const PROGRAM_ID: &str = "39WzZqJgkK2QuQxV9jeguKRgHE65Q3HywqPwBzdrKn2B";
 
     fn get_cli(program_id: Option<String>) -> HapiCoreSolana {
         HapiCoreSolana::new(HapiCoreOptions {
             provider_url: String::default(),
+            fallback_provider_urls: vec![],
             contract_address: program_id.unwrap_or(PROGRAM_ID.to_string()),
             private_key: None,
             chain_id: None,
             account_id: None,
             network: HapiCoreNetwork::Solana,
+            rpc_timeouts: RpcTimeouts::default(),
         })
         .expect("Failed to initialize client")
     }
@@ -265,6 +274,9 @@ mod tests {
             "create_asset",
             "update_asset",
             "confirm_asset",
+            "set_reporter_url",
+            "confirm_addresses",
+            "invalidate_address",
         ]
         .iter()
         .map(|n| (*n, InstructionData::Raw(String::from("Some data"))))
@@ -385,6 +397,24 @@ mod tests {
                 "confirm_asset",
                 InstructionData::Decoded(DecodedInstructionData::ConfirmAsset(255)),
             ),
+            (
+                "set_reporter_url",
+                InstructionData::Decoded(DecodedInstructionData::SetReporterUrl(
+                    "https://reporter.profile".to_string(),
+                )),
+            ),
+            (
+                "confirm_addresses",
+                InstructionData::Decoded(DecodedInstructionData::ConfirmAddresses),
+            ),
+            (
+                "invalidate_address",
+                InstructionData::Decoded(DecodedInstructionData::InvalidateAddress),
+            ),
+            (
+                "link_cases",
+                InstructionData::Decoded(DecodedInstructionData::LinkCases),
+            ),
         ];
 
         let instructions = client
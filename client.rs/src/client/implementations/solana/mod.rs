@@ -1,8 +1,13 @@
 pub mod account_macro;
+mod batch;
 mod client;
 mod conversion;
+mod dump;
+mod error_code;
 mod instruction_data;
 pub mod instruction_decoder;
+mod relay;
+mod scan;
 pub mod token;
 mod utils;
 
@@ -10,8 +15,12 @@ pub mod test_helpers;
 pub use test_helpers::create_test_tx;
 
 pub use client::HapiCoreSolana;
+pub use dump::{decode_account, AccountDump};
+pub use error_code::{explain_error_code, parse_error_code, ProgramErrorCode};
+pub use relay::{cosign, partially_sign, SolanaMetaTransaction};
+pub use scan::{AccountStream, NetworkScoped, SCAN_PAGE_SIZE};
 pub use token::TokenContractSolana;
 
 pub use instruction_data::{DecodedInstructionData, InstructionData};
 pub use instruction_decoder::DecodedInstruction;
-pub use utils::{byte_array_from_str, get_network_address};
+pub use utils::{byte_array_from_str, get_network_address, get_reporter_account_address};
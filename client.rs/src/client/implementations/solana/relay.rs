@@ -0,0 +1,289 @@
+use {
+    anchor_client::solana_sdk::{
+        hash::Hash,
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+    serde::{Deserialize, Serialize},
+    solana_transaction_status::{EncodedTransaction, TransactionBinaryEncoding},
+    std::str::FromStr,
+};
+
+use crate::client::{
+    relay::RelayedCall,
+    result::{ClientError, Result, Tx},
+};
+
+use super::client::HapiCoreSolana;
+
+/// Transaction signed by the reporter, that the relayer completes as the fee payer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolanaMetaTransaction {
+    /// Base64 encoded transaction, without the signature of the fee payer
+    pub transaction: String,
+}
+
+impl SolanaMetaTransaction {
+    fn encode(tx: &Transaction) -> Result<Self> {
+        match EncodedTransaction::with_binary_encoding(
+            &tx.clone().into(),
+            TransactionBinaryEncoding::Base64,
+        ) {
+            EncodedTransaction::Binary(transaction, _) => Ok(Self { transaction }),
+            _ => Err(ClientError::InvalidData(
+                "failed to encode transaction".to_string(),
+            )),
+        }
+    }
+
+    fn decode(&self) -> Result<Transaction> {
+        EncodedTransaction::Binary(self.transaction.clone(), TransactionBinaryEncoding::Base64)
+            .decode()
+            .and_then(|tx| tx.into_legacy_transaction())
+            .ok_or_else(|| ClientError::InvalidData("invalid meta-transaction".to_string()))
+    }
+}
+
+/// Builds a transaction paid by the fee payer and signs it with the reporter key only
+pub fn partially_sign(
+    instructions: &[Instruction],
+    fee_payer: &Pubkey,
+    reporter: &Keypair,
+    blockhash: Hash,
+) -> Result<SolanaMetaTransaction> {
+    let mut tx = Transaction::new_with_payer(instructions, Some(fee_payer));
+
+    tx.try_partial_sign(&[reporter], blockhash)
+        .map_err(|e| ClientError::InvalidData(format!("failed to sign transaction: {e}")))?;
+
+    SolanaMetaTransaction::encode(&tx)
+}
+
+/// Checks the reporter transaction and signs it as the fee payer
+///
+/// The fee payer account must not be used by the instructions and only the program can be
+/// called, so that a reporter can't spend the relayer funds beyond the fees.
+pub fn cosign(
+    meta: &SolanaMetaTransaction,
+    fee_payer: &Keypair,
+    program_id: &Pubkey,
+) -> Result<Transaction> {
+    let mut tx = meta.decode()?;
+    let message = &tx.message;
+
+    if message.account_keys.first() != Some(&fee_payer.pubkey()) {
+        return Err(ClientError::InvalidData(format!(
+            "transaction is not paid by {}",
+            fee_payer.pubkey()
+        )));
+    }
+
+    for instruction in &message.instructions {
+        if message
+            .account_keys
+            .get(instruction.program_id_index as usize)
+            != Some(program_id)
+        {
+            return Err(ClientError::InvalidData(
+                "transaction calls a program other than HAPI Core".to_string(),
+            ));
+        }
+
+        // The fee payer is always the first account of the message
+        if instruction.accounts.contains(&0) {
+            return Err(ClientError::InvalidData(
+                "transaction uses the fee payer account".to_string(),
+            ));
+        }
+    }
+
+    let blockhash = message.recent_blockhash;
+    tx.try_partial_sign(&[fee_payer], blockhash)
+        .map_err(|e| ClientError::InvalidData(format!("failed to sign transaction: {e}")))?;
+
+    tx.verify()
+        .map_err(|e| ClientError::InvalidData(format!("invalid signature: {e}")))?;
+
+    Ok(tx)
+}
+
+impl HapiCoreSolana {
+    /// Signs the call with the reporter key, leaving the fees to the relayer account
+    ///
+    /// The relayer completes and sends the transaction with `relay_meta_transaction`, it has
+    /// to do so before the blockhash of the transaction expires.
+    pub async fn sign_meta_transaction(
+        &self,
+        call: RelayedCall,
+        fee_payer: &str,
+    ) -> Result<SolanaMetaTransaction> {
+        let fee_payer = Pubkey::from_str(fee_payer)
+            .map_err(|e| ClientError::SolanaAddressParseError(format!("`fee-payer`: {e}")))?;
+
+        let (reporter, _) = self.get_reporter().await?;
+        let instruction = self.relayed_call_instruction(reporter, call)?;
+        let blockhash = self.rpc_client.get_latest_blockhash().await?;
+
+        partially_sign(&[instruction], &fee_payer, &self.signer, blockhash)
+    }
+
+    /// Signs the reporter transaction as the fee payer and sends it
+    pub async fn relay_meta_transaction(&self, meta: &SolanaMetaTransaction) -> Result<Tx> {
+        let tx = cosign(meta, &self.signer, &self.program_id)?;
+
+        let hash = self
+            .rpc_client
+            .send_and_confirm_transaction(&tx)
+            .await?
+            .to_string();
+
+        Ok(Tx { hash })
+    }
+
+    fn relayed_call_instruction(&self, reporter: Pubkey, call: RelayedCall) -> Result<Instruction> {
+        match call {
+            RelayedCall::CreateAddress(input) => self.create_address_instruction(reporter, input),
+            RelayedCall::UpdateAddress(input) => self.update_address_instruction(reporter, input),
+            RelayedCall::CreateAsset(input) => self.create_asset_instruction(reporter, input),
+            RelayedCall::UpdateAsset(input) => self.update_asset_instruction(reporter, input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::entities::{address::CreateAddressInput, category::Category},
+        HapiCoreNetwork, HapiCoreOptions, RpcTimeouts,
+    };
+    use anchor_client::{
+        solana_client::nonblocking::rpc_client::RpcClient,
+        solana_sdk::{instruction::AccountMeta, system_instruction},
+    };
+    use uuid::Uuid;
+
+    const PROGRAM_ID: &str = "39WzZqJgkK2QuQxV9jeguKRgHE65Q3HywqPwBzdrKn2B";
+
+    fn create_client(keypair: &Keypair) -> HapiCoreSolana {
+        HapiCoreSolana::new(HapiCoreOptions {
+            provider_url: "http://127.0.0.1:8899".to_string(),
+            fallback_provider_urls: vec![],
+            contract_address: PROGRAM_ID.to_string(),
+            private_key: Some(keypair.to_base58_string()),
+            chain_id: None,
+            account_id: None,
+            network: HapiCoreNetwork::Solana,
+            rpc_timeouts: RpcTimeouts::default(),
+        })
+        .expect("Failed to initialize client")
+    }
+
+    fn create_address_call() -> RelayedCall {
+        RelayedCall::CreateAddress(CreateAddressInput {
+            address: "C7DNJUKfDVpL9ZZqLnVTG1adj4Yu46JgDB6hiTdMEktX".to_string(),
+            case_id: Uuid::from_u128(42),
+            risk: 5,
+            category: Category::Hacker,
+        })
+    }
+
+    #[tokio::test]
+    async fn meta_transaction_is_relayed() {
+        let reporter = Keypair::new();
+        let relayer = Keypair::new();
+        let reporter_account = Pubkey::new_unique();
+
+        let instruction = create_client(&reporter)
+            .relayed_call_instruction(reporter_account, create_address_call())
+            .unwrap();
+
+        let meta = partially_sign(
+            &[instruction],
+            &relayer.pubkey(),
+            &reporter,
+            Hash::new_unique(),
+        )
+        .unwrap();
+
+        // The payload is passed to the relayer as JSON
+        let meta: SolanaMetaTransaction =
+            serde_json::from_str(&serde_json::to_string(&meta).unwrap()).unwrap();
+
+        // The reporter signature alone is not enough to send the transaction
+        assert!(meta.decode().unwrap().verify().is_err());
+
+        let mut relayer_client = create_client(&relayer);
+        relayer_client.rpc_client = RpcClient::new_mock("succeeds".to_string());
+
+        let tx = relayer_client.relay_meta_transaction(&meta).await.unwrap();
+
+        let signed = cosign(&meta, &relayer, &relayer_client.program_id).unwrap();
+        assert_eq!(signed.message.account_keys[0], relayer.pubkey());
+        assert!(signed.message.account_keys.contains(&reporter.pubkey()));
+        assert_eq!(tx.hash, signed.signatures[0].to_string());
+    }
+
+    #[test]
+    fn foreign_meta_transaction_is_rejected() {
+        let reporter = Keypair::new();
+        let relayer = Keypair::new();
+        let program_id = Pubkey::from_str(PROGRAM_ID).unwrap();
+
+        let instruction = create_client(&reporter)
+            .relayed_call_instruction(Pubkey::new_unique(), create_address_call())
+            .unwrap();
+
+        // Paid by another account
+        let meta = partially_sign(
+            &[instruction.clone()],
+            &Pubkey::new_unique(),
+            &reporter,
+            Hash::new_unique(),
+        )
+        .unwrap();
+        assert!(cosign(&meta, &relayer, &program_id).is_err());
+
+        // Calls another program with the relayer funds
+        let transfer = system_instruction::transfer(&relayer.pubkey(), &reporter.pubkey(), 1);
+        let meta = partially_sign(
+            &[instruction.clone(), transfer],
+            &relayer.pubkey(),
+            &reporter,
+            Hash::new_unique(),
+        )
+        .unwrap();
+        assert!(cosign(&meta, &relayer, &program_id).is_err());
+
+        // Passes the relayer account to the program
+        let mut uses_relayer = instruction.clone();
+        uses_relayer
+            .accounts
+            .push(AccountMeta::new(relayer.pubkey(), false));
+        let meta = partially_sign(
+            &[uses_relayer],
+            &relayer.pubkey(),
+            &reporter,
+            Hash::new_unique(),
+        )
+        .unwrap();
+        assert!(cosign(&meta, &relayer, &program_id).is_err());
+
+        // Changed after the reporter has signed it
+        let meta = partially_sign(
+            &[instruction],
+            &relayer.pubkey(),
+            &reporter,
+            Hash::new_unique(),
+        )
+        .unwrap();
+        let mut tampered = meta.decode().unwrap();
+        tampered.message.instructions[0].data[8] ^= 1;
+        let tampered = SolanaMetaTransaction::encode(&tampered).unwrap();
+
+        cosign(&meta, &relayer, &program_id).unwrap();
+        assert!(cosign(&tampered, &relayer, &program_id).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use {
+    anchor_client::{
+        anchor_lang::{AccountDeserialize, Discriminator},
+        solana_client::rpc_filter::{Memcmp, RpcFilterType},
+        solana_sdk::{account::Account, pubkey::Pubkey},
+    },
+    futures::{stream, Future, Stream, StreamExt, TryStreamExt},
+    std::pin::Pin,
+};
+
+use crate::client::result::{ClientError, Result};
+
+/// Maximum number of accounts `getMultipleAccounts` accepts in a single request
+pub const SCAN_PAGE_SIZE: usize = 100;
+
+/// Stream of decoded program accounts, fetched page by page
+pub type AccountStream<'a, T> = Pin<Box<dyn Stream<Item = Result<(Pubkey, T)>> + Send + 'a>>;
+
+/// Offset of the `network` field inside of a program account
+///
+/// Used to narrow `getProgramAccounts` scans to a single network on the RPC side
+pub trait NetworkScoped {
+    const NETWORK_OFFSET: usize;
+}
+
+// discriminator(8) + version(2) + bump(1) + id(16)
+impl NetworkScoped for hapi_core_solana::Reporter {
+    const NETWORK_OFFSET: usize = 27;
+}
+
+// discriminator(8) + version(2) + bump(1) + id(16)
+impl NetworkScoped for hapi_core_solana::Case {
+    const NETWORK_OFFSET: usize = 27;
+}
+
+// discriminator(8) + version(2) + bump(1)
+impl NetworkScoped for hapi_core_solana::Address {
+    const NETWORK_OFFSET: usize = 11;
+}
+
+// discriminator(8) + version(2) + bump(1)
+impl NetworkScoped for hapi_core_solana::Asset {
+    const NETWORK_OFFSET: usize = 11;
+}
+
+/// `getProgramAccounts` filters for the accounts of type `T` that belong to `network`
+///
+/// Accounts grow into their reserved space with program upgrades, so they are told apart
+/// by the discriminator only and never by their data size
+pub(crate) fn account_filters<T: Discriminator + NetworkScoped>(
+    network: &Pubkey,
+) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &T::discriminator())),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            T::NETWORK_OFFSET,
+            network.as_ref(),
+        )),
+    ]
+}
+
+/// Picks a page out of the account keys
+///
+/// `getProgramAccounts` lists accounts in no particular order, so the keys are sorted
+/// to keep the pages of separate listings apart
+pub(crate) fn page_keys(mut keys: Vec<Pubkey>, skip: u64, take: u64) -> Vec<Pubkey> {
+    keys.sort_unstable();
+
+    keys.into_iter()
+        .skip(usize::try_from(skip).unwrap_or(usize::MAX))
+        .take(usize::try_from(take).unwrap_or(usize::MAX))
+        .collect()
+}
+
+/// Lazily fetches and decodes accounts in chunks of `page_size`
+///
+/// Accounts that were closed between listing and fetching are skipped
+pub(crate) fn scan_accounts<'a, T, F, Fut>(
+    keys: Vec<Pubkey>,
+    page_size: usize,
+    fetch_page: F,
+) -> AccountStream<'a, T>
+where
+    T: AccountDeserialize + Send + 'a,
+    F: Fn(Vec<Pubkey>) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<Vec<Option<Account>>>> + Send + 'a,
+{
+    let pages: Vec<Vec<Pubkey>> = keys
+        .chunks(page_size.max(1))
+        .map(|chunk| chunk.to_vec())
+        .collect();
+
+    let stream = stream::iter(pages)
+        .then(move |page| {
+            let response = fetch_page(page.clone());
+
+            async move {
+                let accounts = response.await?;
+
+                Ok::<_, ClientError>(stream::iter(
+                    page.into_iter()
+                        .zip(accounts)
+                        .filter_map(|(pubkey, account)| {
+                            account.map(|account| decode_account(pubkey, account))
+                        })
+                        .collect::<Vec<_>>(),
+                ))
+            }
+        })
+        .try_flatten();
+
+    Box::pin(stream)
+}
+
+fn decode_account<T: AccountDeserialize>(pubkey: Pubkey, account: Account) -> Result<(Pubkey, T)> {
+    T::try_deserialize(&mut (&account.data as &[u8]))
+        .map(|data| (pubkey, data))
+        .map_err(|e| ClientError::AccountDeserializationError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {
+        anchor_client::anchor_lang::AccountSerialize,
+        hapi_core_solana::{Address, Case, CaseStatus, Category, ACCOUNT_RESERVE_SPACE},
+        std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    fn create_case_account(id: u128) -> Account {
+        let case = Case {
+            version: Case::VERSION,
+            bump: 255,
+            id,
+            network: Pubkey::new_unique(),
+            name: format!("case {id}"),
+            reporter_id: 1,
+            status: CaseStatus::Open,
+            url: String::from("https://hapi.one"),
+            evidence: vec![],
+            related_cases: vec![],
+        };
+
+        let mut data = vec![];
+        case.try_serialize(&mut data)
+            .expect("Failed to serialize case");
+
+        Account {
+            data,
+            ..Account::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_multiple_pages() {
+        let total = 250;
+        let keys: Vec<Pubkey> = (0..total).map(|_| Pubkey::new_unique()).collect();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+
+        let stream = scan_accounts::<Case, _, _>(keys.clone(), SCAN_PAGE_SIZE, move |page| {
+            let offset = counter.fetch_add(1, Ordering::SeqCst) * SCAN_PAGE_SIZE;
+
+            async move {
+                assert!(page.len() <= SCAN_PAGE_SIZE);
+
+                // Every 10th account is closed before it gets fetched
+                Ok(page
+                    .iter()
+                    .enumerate()
+                    .map(|(i, _)| {
+                        let id = (offset + i) as u128;
+                        (id % 10 != 0).then(|| create_case_account(id))
+                    })
+                    .collect())
+            }
+        });
+
+        let result: Vec<(Pubkey, Case)> = stream.try_collect().await.expect("Scan failed");
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(result.len(), 225);
+
+        for (pubkey, case) in result {
+            assert_eq!(keys[case.id as usize], pubkey);
+            assert_ne!(case.id % 10, 0);
+        }
+    }
+
+    #[test]
+    fn pages_of_keys_do_not_overlap() {
+        let keys: Vec<Pubkey> = (0..25).map(|_| Pubkey::new_unique()).collect();
+
+        let mut shuffled = keys.clone();
+        shuffled.reverse();
+
+        let mut pages = vec![];
+        for skip in (0..30).step_by(10) {
+            // Every listing comes in its own order
+            shuffled.rotate_left(7);
+            pages.extend(page_keys(shuffled.clone(), skip, 10));
+        }
+
+        let mut expected = keys;
+        expected.sort_unstable();
+
+        assert_eq!(pages, expected);
+        assert!(page_keys(expected, 30, 10).is_empty());
+    }
+
+    #[tokio::test]
+    async fn scan_propagates_page_errors() {
+        let keys: Vec<Pubkey> = (0..150).map(|_| Pubkey::new_unique()).collect();
+
+        let mut stream = scan_accounts::<Case, _, _>(keys, SCAN_PAGE_SIZE, |page| async move {
+            if page.len() < SCAN_PAGE_SIZE {
+                return Err(ClientError::AccountNotFound);
+            }
+
+            Ok(page.iter().map(|_| Some(create_case_account(1))).collect())
+        });
+
+        for _ in 0..SCAN_PAGE_SIZE {
+            assert!(stream.next().await.expect("Stream ended early").is_ok());
+        }
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(ClientError::AccountNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn scan_addresses_created_before_invalidation() {
+        let address = Address {
+            version: Address::VERSION,
+            bump: 255,
+            network: Pubkey::new_unique(),
+            address: [1; 64],
+            category: Category::Scam,
+            risk_score: 7,
+            case_id: 1,
+            reporter_id: 2,
+            confirmations: 3,
+            is_invalidated: false,
+        };
+
+        let mut data = vec![];
+        address
+            .try_serialize(&mut data)
+            .expect("Failed to serialize address");
+
+        // Accounts created before `is_invalidated` were one byte shorter, followed by the reserve
+        data.truncate(Address::LEN - 1);
+        data.resize(Address::LEN - 1 + ACCOUNT_RESERVE_SPACE, 0);
+
+        let filters = account_filters::<Address>(&address.network);
+        assert!(!filters
+            .iter()
+            .any(|filter| matches!(filter, RpcFilterType::DataSize(_))));
+
+        let key = Pubkey::new_unique();
+        let stream = scan_accounts::<Address, _, _>(vec![key], SCAN_PAGE_SIZE, move |_| {
+            let data = data.clone();
+
+            async move {
+                Ok(vec![Some(Account {
+                    data,
+                    ..Account::default()
+                })])
+            }
+        });
+
+        let result: Vec<(Pubkey, Address)> = stream.try_collect().await.expect("Scan failed");
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0, key);
+        assert_eq!(result[0].1.address, address.address);
+        assert_eq!(result[0].1.confirmations, 3);
+        assert!(!result[0].1.is_invalidated);
+    }
+
+    #[tokio::test]
+    async fn scan_cases_created_before_evidence() {
+        let mut data = create_case_account(1).data;
+
+        // Accounts created before evidence had neither of the vectors, followed by the reserve
+        let old_len =
+            Case::LEN - (4 + 32 * Case::MAX_EVIDENCE) - (4 + 16 * Case::MAX_RELATED_CASES);
+        data.truncate(data.len() - 8);
+        data.resize(old_len + ACCOUNT_RESERVE_SPACE, 0);
+
+        let key = Pubkey::new_unique();
+        let stream = scan_accounts::<Case, _, _>(vec![key], SCAN_PAGE_SIZE, move |_| {
+            let data = data.clone();
+
+            async move {
+                Ok(vec![Some(Account {
+                    data,
+                    ..Account::default()
+                })])
+            }
+        });
+
+        let result: Vec<(Pubkey, Case)> = stream.try_collect().await.expect("Scan failed");
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].1.id, 1);
+        assert_eq!(result[0].1.url, "https://hapi.one");
+        assert!(result[0].1.evidence.is_empty());
+    }
+}
@@ -42,6 +42,7 @@ fn create_test_instruction(
             DecodedInstructionData::CreateAsset(data) => serialize(name, data),
             DecodedInstructionData::UpdateAsset(data) => serialize(name, data),
             DecodedInstructionData::ConfirmAsset(data) => serialize(name, data),
+            DecodedInstructionData::SetReporterUrl(data) => serialize(name, data),
             _ => get_instruction_sighash(name).to_vec(),
         },
         InstructionData::Raw(data) => serialize(name, data),
@@ -97,4 +97,8 @@ impl TokenContract for TokenContractSolana {
 
         Ok(res)
     }
+
+    async fn decimals(&self) -> Result<u8> {
+        Ok(self.cli.get_token_supply(&self.mint).await?.decimals)
+    }
 }
@@ -62,6 +62,21 @@ pub fn get_reporter_address(
     ))
 }
 
+/// Returns reporter PDA address of the program deployment on the named network
+pub fn get_reporter_account_address(
+    program_id: &str,
+    network_name: &str,
+    reporter_id: Uuid,
+) -> Result<String> {
+    let program_id = Pubkey::from_str(program_id)
+        .map_err(|e| ClientError::SolanaAddressParseError(format!("`program-id`: {e}")))?;
+
+    let (network, _) = get_network_address(network_name, &program_id)?;
+    let (reporter, _) = get_reporter_address(reporter_id, &network, &program_id)?;
+
+    Ok(reporter.to_string())
+}
+
 /// Returns case PDA address
 pub fn get_case_address(
     case_id: Uuid,
@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 
 use super::{
-    configuration::{RewardConfiguration, StakeConfiguration},
+    configuration::{NetworkConfiguration, RewardConfiguration, StakeConfiguration},
+    confirmation::{ConfirmationOptions, TxStatus},
     entities::{
         address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
         asset::{Asset, AssetId, ConfirmAssetInput, CreateAssetInput, UpdateAssetInput},
@@ -9,6 +10,7 @@ use super::{
         network::HapiCoreNetwork,
         reporter::{CreateReporterInput, Reporter, UpdateReporterInput},
     },
+    implementations::{evm::RpcTimeouts, HapiCoreEvm, HapiCoreNear, HapiCoreSolana},
     result::{Result, Tx},
 };
 
@@ -25,6 +27,12 @@ pub trait HapiCore {
     async fn update_reward_configuration(&self, configuration: RewardConfiguration) -> Result<Tx>;
     async fn get_reward_configuration(&self) -> Result<RewardConfiguration>;
 
+    /// Authority, stake and reward configurations read at once
+    async fn get_network_configuration(&self) -> Result<NetworkConfiguration>;
+
+    /// Version of the deployed contract, `None` if the contract doesn't keep one
+    async fn get_contract_version(&self) -> Result<Option<u64>>;
+
     async fn create_reporter(&self, input: CreateReporterInput) -> Result<Tx>;
     async fn update_reporter(&self, input: UpdateReporterInput) -> Result<Tx>;
     async fn get_reporter(&self, id: &str) -> Result<Reporter>;
@@ -35,6 +43,9 @@ pub trait HapiCore {
     async fn deactivate_reporter(&self) -> Result<Tx>;
     async fn unstake_reporter(&self) -> Result<Tx>;
 
+    /// Unix timestamp of the latest block in seconds
+    async fn get_chain_time(&self) -> Result<u64>;
+
     async fn create_case(&self, input: CreateCaseInput) -> Result<Tx>;
     async fn update_case(&self, input: UpdateCaseInput) -> Result<Tx>;
     async fn get_case(&self, id: &str) -> Result<Case>;
@@ -42,6 +53,8 @@ pub trait HapiCore {
     async fn get_cases(&self, skip: u64, take: u64) -> Result<Vec<Case>>;
 
     async fn create_address(&self, input: CreateAddressInput) -> Result<Tx>;
+    /// Submits several addresses at once, returning a result per input in the same order
+    async fn create_addresses(&self, inputs: Vec<CreateAddressInput>) -> Result<Vec<Result<Tx>>>;
     async fn update_address(&self, input: UpdateAddressInput) -> Result<Tx>;
     async fn confirm_address(&self, input: ConfirmAddressInput) -> Result<Tx>;
     async fn get_address(&self, addr: &str) -> Result<Address>;
@@ -54,14 +67,100 @@ pub trait HapiCore {
     async fn get_asset(&self, addr: &str, id: &AssetId) -> Result<Asset>;
     async fn get_asset_count(&self) -> Result<u64>;
     async fn get_assets(&self, skip: u64, take: u64) -> Result<Vec<Asset>>;
+
+    /// Polls the status of a submitted transaction until it reaches the required depth
+    async fn wait_for_confirmation(
+        &self,
+        hash: &str,
+        options: ConfirmationOptions,
+    ) -> Result<TxStatus>;
 }
 
 #[derive(Clone)]
 pub struct HapiCoreOptions {
     pub provider_url: String,
+    /// Endpoints that EVM clients fail over to when the provider is unreachable, in order
+    pub fallback_provider_urls: Vec<String>,
     pub contract_address: String,
     pub private_key: Option<String>,
     pub chain_id: Option<u64>,
     pub account_id: Option<String>,
     pub network: HapiCoreNetwork,
+    /// Request timeouts of EVM clients
+    pub rpc_timeouts: RpcTimeouts,
+}
+
+impl HapiCoreOptions {
+    /// Creates the client of the configured network, so that callers submit to any network alike
+    pub fn into_client(self) -> Result<Box<dyn HapiCore>> {
+        let client: Box<dyn HapiCore> = match self.network {
+            HapiCoreNetwork::Sepolia | HapiCoreNetwork::Ethereum | HapiCoreNetwork::Bsc => {
+                Box::new(HapiCoreEvm::new(self)?)
+            }
+            HapiCoreNetwork::Solana | HapiCoreNetwork::Bitcoin => {
+                Box::new(HapiCoreSolana::new(self)?)
+            }
+            HapiCoreNetwork::Near => Box::new(HapiCoreNear::new(self)?),
+        };
+
+        Ok(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_client::solana_sdk::signature::Keypair;
+
+    use super::*;
+
+    fn options(network: HapiCoreNetwork, contract_address: &str) -> HapiCoreOptions {
+        HapiCoreOptions {
+            provider_url: "http://127.0.0.1:8545".to_string(),
+            fallback_provider_urls: vec![],
+            contract_address: contract_address.to_string(),
+            private_key: None,
+            chain_id: None,
+            account_id: None,
+            network,
+            rpc_timeouts: RpcTimeouts::default(),
+        }
+    }
+
+    #[test]
+    fn client_of_network_is_created() {
+        let evm_address = "0x2947F98C42597966a0ec25e92843c09ac18Fbab7";
+        let solana_address = "FgE5ySSi6fbnfYGGRyaeW8y6p8A5KybXPyQ2DdxPCNRk";
+
+        let evm = options(HapiCoreNetwork::Ethereum, evm_address)
+            .into_client()
+            .expect("EVM client is created");
+        assert!(evm.is_valid_address(evm_address).is_ok());
+        assert!(evm.is_valid_address(solana_address).is_err());
+
+        let mut solana_options = options(HapiCoreNetwork::Solana, solana_address);
+        solana_options.private_key = Some(Keypair::new().to_base58_string());
+
+        let solana = solana_options
+            .into_client()
+            .expect("Solana client is created");
+        assert!(solana.is_valid_address(solana_address).is_ok());
+        assert!(solana.is_valid_address(evm_address).is_err());
+
+        // Solana program IDs are not EVM addresses
+        assert!(options(HapiCoreNetwork::Bsc, solana_address)
+            .into_client()
+            .is_err());
+    }
+
+    #[test]
+    fn tx_is_serialized_as_command_output() {
+        let tx = Tx {
+            hash: "0x01".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&tx).unwrap(),
+            serde_json::json!({ "tx": "0x01" })
+        );
+    }
 }
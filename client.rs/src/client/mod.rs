@@ -1,9 +1,12 @@
 pub mod amount;
 pub mod configuration;
+pub mod confirmation;
+pub mod encoding;
 pub mod entities;
 pub mod events;
 pub mod implementations;
 pub mod interface;
+pub mod relay;
 pub mod result;
 pub mod token;
 
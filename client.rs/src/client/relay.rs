@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use super::entities::{
+    address::{CreateAddressInput, UpdateAddressInput},
+    asset::{CreateAssetInput, UpdateAssetInput},
+};
+
+/// Reporter call that is signed by the reporter and submitted by a relayer, which pays the fees
+///
+/// EVM reporters sign an ERC-2771 forward request, Solana reporters partially sign a
+/// transaction that the relayer completes as the fee payer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "method", content = "input", rename_all = "snake_case")]
+pub enum RelayedCall {
+    CreateAddress(CreateAddressInput),
+    UpdateAddress(UpdateAddressInput),
+    CreateAsset(CreateAssetInput),
+    UpdateAsset(UpdateAssetInput),
+}
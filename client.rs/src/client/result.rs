@@ -1,6 +1,7 @@
 use anchor_client::solana_sdk::signature::ParseSignatureError;
 use near_jsonrpc_client::methods::broadcast_tx_async::RpcBroadcastTxAsyncError;
 use near_jsonrpc_primitives::types::{query::RpcQueryError, transactions::RpcTransactionError};
+use serde::Serialize;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -15,6 +16,10 @@ pub enum ClientError {
     FailedToParseBalance(String),
     #[error("The reporter does not exist")]
     InvalidReporter,
+    #[error("Reporter {0} already exists, use an update to change it")]
+    ReporterAlreadyExists(String),
+    #[error("Batch transaction failed: {0}")]
+    BatchTransactionFailed(String),
 
     // Ethereum client errors
     #[error("Invalid UUID: {0}")]
@@ -75,7 +80,9 @@ pub enum ClientError {
 
 pub type Result<T> = std::result::Result<T, ClientError>;
 
-#[derive(Default, Clone, Debug)]
+/// Submitted transaction, serialized in the `{"tx": hash}` shape of the command output
+#[derive(Default, Clone, Debug, Serialize)]
 pub struct Tx {
+    #[serde(rename = "tx")]
     pub hash: String,
 }
@@ -4,22 +4,67 @@ use serde_json::json;
 
 use hapi_core::{
     client::{
-        configuration::{RewardConfiguration, StakeConfiguration},
+        configuration::{NetworkConfiguration, RewardConfiguration, StakeConfiguration},
+        confirmation::TxStatus,
         entities::{
             address::{ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
             asset::{ConfirmAssetInput, CreateAssetInput, UpdateAssetInput},
-            case::{CreateCaseInput, UpdateCaseInput},
-            reporter::{CreateReporterInput, UpdateReporterInput},
+            case::{CaseStatus, CreateCaseInput, UpdateCaseInput},
+            reporter::{CreateReporterInput, ReporterStatus, UpdateReporterInput},
         },
+        implementations::solana::{explain_error_code, parse_error_code},
+        result::Tx,
     },
     Amount,
 };
 
+mod bootstrap;
 mod context;
 mod matcher;
+mod onboard;
 
-pub(crate) use context::{CommandOutput, HapiCoreCommandContext, TokenCommandContext};
+pub(crate) use bootstrap::{bootstrap, export_config};
+pub(crate) use context::{
+    CommandOutput, EvmCommandContext, HapiCoreCommandContext, SolanaCommandContext,
+    TokenCommandContext,
+};
 pub(crate) use matcher::matcher;
+pub(crate) use onboard::onboard_reporter;
+
+/// Prints the hash of the submitted transaction, and its status if it is awaited
+async fn print_tx(context: &HapiCoreCommandContext, tx: &Tx) -> anyhow::Result<()> {
+    let Some(options) = context.wait else {
+        match context.output {
+            CommandOutput::Json => println!("{}", json!(tx)),
+            CommandOutput::Plain => println!("{}", tx.hash),
+        }
+
+        return Ok(());
+    };
+
+    let status = context
+        .hapi_core
+        .wait_for_confirmation(&tx.hash, options)
+        .await?;
+
+    match (&context.output, &status) {
+        (CommandOutput::Json, _) => {
+            let mut output = serde_json::to_value(&status)?;
+            output["tx"] = json!(tx.hash);
+            println!("{output}");
+        }
+        (CommandOutput::Plain, TxStatus::Confirmed) => println!("{}\nconfirmed", tx.hash),
+        (CommandOutput::Plain, TxStatus::Reverted(reason)) => {
+            println!("{}\nreverted: {reason}", tx.hash)
+        }
+    }
+
+    if let TxStatus::Reverted(reason) = status {
+        anyhow::bail!("Transaction {} reverted: {reason}", tx.hash);
+    }
+
+    Ok(())
+}
 
 pub async fn get_authority(args: &ArgMatches) -> anyhow::Result<()> {
     let context = HapiCoreCommandContext::try_from(args)?;
@@ -48,12 +93,7 @@ pub async fn set_authority(args: &ArgMatches) -> anyhow::Result<()> {
 
     let tx = context.hapi_core.set_authority(authority).await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn update_stake_configuration(args: &ArgMatches) -> anyhow::Result<()> {
@@ -110,12 +150,7 @@ pub async fn update_stake_configuration(args: &ArgMatches) -> anyhow::Result<()>
 
     let tx = context.hapi_core.update_stake_configuration(cfg).await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn get_stake_configuration(args: &ArgMatches) -> anyhow::Result<()> {
@@ -180,18 +215,28 @@ pub async fn update_reward_configuration(args: &ArgMatches) -> anyhow::Result<()
 
     let tx = context.hapi_core.update_reward_configuration(cfg).await?;
 
+    print_tx(&context, &tx).await
+}
+
+pub async fn get_reward_configuration(args: &ArgMatches) -> anyhow::Result<()> {
+    let context = HapiCoreCommandContext::try_from(args)?;
+
+    let configuration = context.hapi_core.get_reward_configuration().await?;
+
     match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
+        CommandOutput::Json => println!("{}", json!({ "configuration": configuration })),
+        CommandOutput::Plain => {
+            println!("{configuration:#?}")
+        }
     }
 
     Ok(())
 }
 
-pub async fn get_reward_configuration(args: &ArgMatches) -> anyhow::Result<()> {
+pub async fn get_network_configuration(args: &ArgMatches) -> anyhow::Result<()> {
     let context = HapiCoreCommandContext::try_from(args)?;
 
-    let configuration = context.hapi_core.get_reward_configuration().await?;
+    let configuration = context.hapi_core.get_network_configuration().await?;
 
     match context.output {
         CommandOutput::Json => println!("{}", json!({ "configuration": configuration })),
@@ -203,6 +248,52 @@ pub async fn get_reward_configuration(args: &ArgMatches) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Compares the on-chain configuration with the expected one and fails on any difference
+pub async fn check_network_configuration(args: &ArgMatches) -> anyhow::Result<()> {
+    let context = HapiCoreCommandContext::try_from(args)?;
+
+    let file = args
+        .get_one::<String>("expected")
+        .ok_or(anyhow!("`expected` is required"))?;
+
+    let contents =
+        std::fs::read_to_string(file).map_err(|e| anyhow!("Failed to read `{file}`: {e}"))?;
+
+    let expected: NetworkConfiguration = serde_json::from_str(&contents)
+        .map_err(|e| anyhow!("Invalid configuration in `{file}`: {e}"))?;
+
+    let configuration = context.hapi_core.get_network_configuration().await?;
+    let diffs = configuration.diff(&expected);
+
+    match context.output {
+        CommandOutput::Json => println!(
+            "{}",
+            json!({ "matches": diffs.is_empty(), "differences": diffs })
+        ),
+        CommandOutput::Plain => {
+            if diffs.is_empty() {
+                println!("Configuration matches `{file}`");
+            }
+
+            for diff in &diffs {
+                println!(
+                    "{}: expected {}, actual {}",
+                    diff.field, diff.expected, diff.actual
+                );
+            }
+        }
+    }
+
+    if !diffs.is_empty() {
+        anyhow::bail!(
+            "Configuration differs from `{file}` in {} field(s)",
+            diffs.len()
+        );
+    }
+
+    Ok(())
+}
+
 pub async fn get_reporters(args: &ArgMatches) -> anyhow::Result<()> {
     let context = HapiCoreCommandContext::try_from(args)?;
 
@@ -313,12 +404,7 @@ pub async fn create_reporter(args: &ArgMatches) -> anyhow::Result<()> {
         })
         .await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn update_reporter(args: &ArgMatches) -> anyhow::Result<()> {
@@ -370,11 +456,7 @@ pub async fn update_reporter(args: &ArgMatches) -> anyhow::Result<()> {
         })
         .await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn activate_reporter(args: &ArgMatches) -> anyhow::Result<()> {
@@ -382,11 +464,7 @@ pub async fn activate_reporter(args: &ArgMatches) -> anyhow::Result<()> {
 
     let tx = context.hapi_core.activate_reporter().await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn deactivate_reporter(args: &ArgMatches) -> anyhow::Result<()> {
@@ -394,12 +472,7 @@ pub async fn deactivate_reporter(args: &ArgMatches) -> anyhow::Result<()> {
 
     let tx = context.hapi_core.deactivate_reporter().await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn unstake_reporter(args: &ArgMatches) -> anyhow::Result<()> {
@@ -407,9 +480,53 @@ pub async fn unstake_reporter(args: &ArgMatches) -> anyhow::Result<()> {
 
     let tx = context.hapi_core.unstake_reporter().await?;
 
+    print_tx(&context, &tx).await
+}
+
+pub async fn get_reporter_status(args: &ArgMatches) -> anyhow::Result<()> {
+    let context = HapiCoreCommandContext::try_from(args)?;
+
+    let reporter_id = args
+        .get_one::<String>("id")
+        .ok_or(anyhow!("`id` is required"))?;
+
+    let reporter = context.hapi_core.get_reporter(reporter_id).await?;
+    let chain_time = context.hapi_core.get_chain_time().await?;
+
+    // Mirrors the contract checks for unstaking
+    let seconds_remaining = reporter.unlock_timestamp.saturating_sub(chain_time);
+    let can_unstake = reporter.status == ReporterStatus::Unstaking && seconds_remaining == 0;
+
+    let summary = match reporter.status {
+        ReporterStatus::Unstaking if can_unstake => "Stake can be withdrawn now".to_string(),
+        ReporterStatus::Unstaking => {
+            format!("Stake is locked for another {seconds_remaining} seconds")
+        }
+        ReporterStatus::Active => {
+            "Reporter is active, deactivate it to start the unlock period".to_string()
+        }
+        ReporterStatus::Inactive => "Reporter has no stake to withdraw".to_string(),
+    };
+
     match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
+        CommandOutput::Json => println!(
+            "{}",
+            json!({
+                "status": reporter.status,
+                "stake": reporter.stake,
+                "unlock_timestamp": reporter.unlock_timestamp,
+                "chain_time": chain_time,
+                "can_unstake": can_unstake,
+                "seconds_remaining": seconds_remaining,
+            })
+        ),
+        CommandOutput::Plain => {
+            println!("Status: {}", reporter.status);
+            println!("Stake: {}", reporter.stake);
+            println!("Unlock timestamp: {}", reporter.unlock_timestamp);
+            println!("Chain time: {chain_time}");
+            println!("{summary}");
+        }
     }
 
     Ok(())
@@ -441,12 +558,7 @@ pub async fn create_case(args: &ArgMatches) -> anyhow::Result<()> {
         .create_case(CreateCaseInput { id, name, url })
         .await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn update_case(args: &ArgMatches) -> anyhow::Result<()> {
@@ -486,12 +598,7 @@ pub async fn update_case(args: &ArgMatches) -> anyhow::Result<()> {
         })
         .await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn get_case(args: &ArgMatches) -> anyhow::Result<()> {
@@ -531,19 +638,41 @@ pub async fn get_case_count(args: &ArgMatches) -> anyhow::Result<()> {
 pub async fn get_cases(args: &ArgMatches) -> anyhow::Result<()> {
     let context = HapiCoreCommandContext::try_from(args)?;
 
-    let skip = args
+    let skip: u64 = args
         .get_one::<String>("skip")
         .ok_or(anyhow!("`skip` is required"))?
         .parse()
         .map_err(|e| anyhow!("`skip`: {e}"))?;
 
-    let take = args
+    let take: u64 = args
         .get_one::<String>("take")
         .ok_or(anyhow!("`take` is required"))?
         .parse()
         .map_err(|e| anyhow!("`take`: {e}"))?;
 
-    let cases = context.hapi_core.get_cases(skip, take).await?;
+    let status = args
+        .get_one::<String>("status")
+        .map(|status| status.parse::<CaseStatus>())
+        .transpose()
+        .map_err(|e| anyhow!("`status`: {e}"))?;
+
+    let cases = match status {
+        // Cases can't be queried by status on chain, so all of them are fetched
+        Some(status) => {
+            let count = context.hapi_core.get_case_count().await?;
+
+            context
+                .hapi_core
+                .get_cases(0, count)
+                .await?
+                .into_iter()
+                .filter(|case| case.status == status)
+                .skip(skip as usize)
+                .take(take as usize)
+                .collect()
+        }
+        None => context.hapi_core.get_cases(skip, take).await?,
+    };
 
     match context.output {
         CommandOutput::Json => println!("{}", json!({ "cases": cases })),
@@ -594,14 +723,134 @@ pub async fn create_address(args: &ArgMatches) -> anyhow::Result<()> {
         })
         .await?;
 
+    print_tx(&context, &tx).await
+}
+
+pub async fn report_addresses(args: &ArgMatches) -> anyhow::Result<()> {
+    let context = HapiCoreCommandContext::try_from(args)?;
+
+    let file = args
+        .get_one::<String>("file")
+        .ok_or(anyhow!("`file` is required"))?;
+
+    let contents =
+        std::fs::read_to_string(file).map_err(|e| anyhow!("Failed to read `{file}`: {e}"))?;
+
+    let inputs = parse_address_list(&contents)?;
+
+    for (input, line) in &inputs {
+        context
+            .hapi_core
+            .is_valid_address(&input.address)
+            .map_err(|e| anyhow!("Invalid address on line {line}: {e}"))?;
+    }
+
+    let addresses: Vec<String> = inputs.iter().map(|(i, _)| i.address.clone()).collect();
+
+    let results = context
+        .hapi_core
+        .create_addresses(inputs.into_iter().map(|(input, _)| input).collect())
+        .await?;
+
+    let failed = results.iter().filter(|result| result.is_err()).count();
+
     match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
+        CommandOutput::Json => {
+            let results = addresses
+                .iter()
+                .zip(results.iter())
+                .map(|(address, result)| match result {
+                    Ok(tx) => json!({ "address": address, "tx": tx.hash }),
+                    Err(e) => json!({ "address": address, "error": e.to_string() }),
+                })
+                .collect::<Vec<_>>();
+
+            println!(
+                "{}",
+                json!({
+                    "results": results,
+                    "succeeded": addresses.len() - failed,
+                    "failed": failed,
+                })
+            )
+        }
+        CommandOutput::Plain => {
+            for (address, result) in addresses.iter().zip(results.iter()) {
+                match result {
+                    Ok(tx) => println!("{address}: {}", tx.hash),
+                    Err(e) => println!("{address}: FAILED: {e}"),
+                }
+            }
+
+            println!("{} succeeded, {failed} failed", addresses.len() - failed);
+        }
     }
 
     Ok(())
 }
 
+/// Parses `address,case_id,category,risk` rows, skipping blank lines, `#` comments and a header row
+///
+/// Every input is returned with its line number, so that validation errors can point at it
+fn parse_address_list(contents: &str) -> anyhow::Result<Vec<(CreateAddressInput, usize)>> {
+    let mut inputs = vec![];
+
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+
+        if inputs.is_empty() && fields.first() == Some(&"address") {
+            continue;
+        }
+
+        let [address, case_id, category, risk] = fields[..] else {
+            return Err(anyhow!(
+                "Line {line_number}: expected 4 fields (address,case_id,category,risk), got {}",
+                fields.len()
+            ));
+        };
+
+        let case_id = case_id
+            .parse()
+            .map_err(|e| anyhow!("Line {line_number}: `case_id`: {e}"))?;
+
+        let category = category
+            .parse()
+            .map_err(|e| anyhow!("Line {line_number}: `category`: {e}"))?;
+
+        let risk = match risk.parse::<u8>() {
+            Ok(risk) if risk <= 10 => risk,
+            _ => {
+                return Err(anyhow!(
+                    "Line {line_number}: risk must be an integer between 0 and 10: {risk}"
+                ))
+            }
+        };
+
+        inputs.push((
+            CreateAddressInput {
+                address: address.to_owned(),
+                case_id,
+                risk,
+                category,
+            },
+            line_number,
+        ));
+    }
+
+    if inputs.is_empty() {
+        return Err(anyhow!("No addresses found in the file"));
+    }
+
+    Ok(inputs)
+}
+
 pub async fn update_address(args: &ArgMatches) -> anyhow::Result<()> {
     let context = HapiCoreCommandContext::try_from(args)?;
 
@@ -641,12 +890,7 @@ pub async fn update_address(args: &ArgMatches) -> anyhow::Result<()> {
         })
         .await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn confirm_address(args: &ArgMatches) -> anyhow::Result<()> {
@@ -667,12 +911,7 @@ pub async fn confirm_address(args: &ArgMatches) -> anyhow::Result<()> {
         .confirm_address(ConfirmAddressInput { address })
         .await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn get_address(args: &ArgMatches) -> anyhow::Result<()> {
@@ -787,12 +1026,7 @@ pub async fn create_asset(args: &ArgMatches) -> anyhow::Result<()> {
         })
         .await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn update_asset(args: &ArgMatches) -> anyhow::Result<()> {
@@ -841,12 +1075,7 @@ pub async fn update_asset(args: &ArgMatches) -> anyhow::Result<()> {
         })
         .await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn confirm_asset(args: &ArgMatches) -> anyhow::Result<()> {
@@ -873,12 +1102,7 @@ pub async fn confirm_asset(args: &ArgMatches) -> anyhow::Result<()> {
         .confirm_asset(ConfirmAssetInput { address, asset_id })
         .await?;
 
-    match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
-        CommandOutput::Plain => println!("{}", tx.hash),
-    }
-
-    Ok(())
+    print_tx(&context, &tx).await
 }
 
 pub async fn get_asset(args: &ArgMatches) -> anyhow::Result<()> {
@@ -953,6 +1177,105 @@ pub async fn get_assets(args: &ArgMatches) -> anyhow::Result<()> {
     Ok(())
 }
 
+pub async fn decode_log(args: &ArgMatches) -> anyhow::Result<()> {
+    let context = EvmCommandContext::try_from(args)?;
+
+    let logs = if let Some(hash) = args.get_one::<String>("tx-hash") {
+        if !context.is_connected {
+            anyhow::bail!("`provider-url` is required to fetch the transaction");
+        }
+
+        let mut logs = vec![];
+        for log in context.hapi_core.get_transaction_logs(hash).await? {
+            logs.extend(context.hapi_core.decode_log(&log)?);
+        }
+
+        logs
+    } else {
+        let topics: Vec<String> = args
+            .get_many::<String>("topics")
+            .ok_or(anyhow!("`topics` is required"))?
+            .cloned()
+            .collect();
+
+        let data = args.get_one::<String>("data").map_or("0x", String::as_str);
+
+        let log = context
+            .hapi_core
+            .decode_raw_log(&topics, data)?
+            .ok_or(anyhow!("The log is not a HAPI Core contract event"))?;
+
+        vec![log]
+    };
+
+    match context.output {
+        CommandOutput::Json => println!("{}", json!({ "logs": logs })),
+        CommandOutput::Plain => {
+            for log in logs {
+                println!("{}", log.name);
+                for (name, value) in log.fields {
+                    match value.as_str() {
+                        Some(value) => println!("  {name}: {value}"),
+                        None => println!("  {name}: {value}"),
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn dump_account(args: &ArgMatches) -> anyhow::Result<()> {
+    let context = SolanaCommandContext::try_from(args)?;
+
+    let account = args
+        .get_one::<String>("account")
+        .ok_or(anyhow!("`account` is required"))?;
+
+    let dump = context
+        .hapi_core
+        .dump_account(account, args.get_flag("raw"))
+        .await?;
+
+    match context.output {
+        CommandOutput::Json => println!("{}", json!(dump)),
+        CommandOutput::Plain => {
+            println!("{}", dump.account_type);
+            println!("{}", serde_json::to_string_pretty(&dump.data)?);
+            if let Some(raw) = dump.raw {
+                println!("{raw}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn explain_error(args: &ArgMatches) -> anyhow::Result<()> {
+    let output: CommandOutput = args
+        .get_one::<String>("output")
+        .unwrap_or(&"plain".to_string())
+        .parse()
+        .map_err(|e| anyhow!("Failed to parse `output`: {:?}", e))?;
+
+    let code = parse_error_code(
+        args.get_one::<String>("code")
+            .ok_or(anyhow!("`code` is required"))?,
+    )?;
+
+    let error = explain_error_code(code).ok_or(anyhow!(
+        "{code} is not an error code of the HAPI Core program"
+    ))?;
+
+    match output {
+        CommandOutput::Json => println!("{}", json!(error)),
+        CommandOutput::Plain => println!("{}: {}", error.name, error.message),
+    }
+
+    Ok(())
+}
+
 pub async fn transfer_token(args: &ArgMatches) -> anyhow::Result<()> {
     let context = TokenCommandContext::try_from(args)?;
 
@@ -960,16 +1283,12 @@ pub async fn transfer_token(args: &ArgMatches) -> anyhow::Result<()> {
         .get_one::<String>("to")
         .ok_or(anyhow!("`to` is required"))?;
 
-    let amount: Amount = args
-        .get_one::<String>("amount")
-        .ok_or(anyhow!("`amount` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`amount`: {}", e))?;
+    let amount = token_amount(args, &context).await?;
 
     let tx = context.token.transfer(to, amount).await?;
 
     match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
+        CommandOutput::Json => println!("{}", json!(tx)),
         CommandOutput::Plain => println!("{}", tx.hash),
     }
 
@@ -983,16 +1302,12 @@ pub async fn approve_token(args: &ArgMatches) -> anyhow::Result<()> {
         .get_one::<String>("spender")
         .ok_or(anyhow!("`spender` is required"))?;
 
-    let amount: Amount = args
-        .get_one::<String>("amount")
-        .ok_or(anyhow!("`amount` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`amount`: {}", e))?;
+    let amount = token_amount(args, &context).await?;
 
     let tx = context.token.approve(spender, amount).await?;
 
     match context.output {
-        CommandOutput::Json => println!("{}", json!({ "tx": tx.hash })),
+        CommandOutput::Json => println!("{}", json!(tx)),
         CommandOutput::Plain => println!("{}", tx.hash),
     }
 
@@ -1008,6 +1323,12 @@ pub async fn balance_token(args: &ArgMatches) -> anyhow::Result<()> {
 
     let balance = context.token.balance(address).await?;
 
+    let balance = if args.get_flag("decimal") {
+        balance.format_units(context.token.decimals().await?)?
+    } else {
+        balance.to_string()
+    };
+
     match context.output {
         CommandOutput::Json => println!("{}", json!({ "balance": balance })),
         CommandOutput::Plain => println!("{}", balance),
@@ -1015,3 +1336,18 @@ pub async fn balance_token(args: &ArgMatches) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Amount argument in base units, converted from whole tokens with `--decimal`
+async fn token_amount(args: &ArgMatches, context: &TokenCommandContext) -> anyhow::Result<Amount> {
+    let amount = args
+        .get_one::<String>("amount")
+        .ok_or(anyhow!("`amount` is required"))?;
+
+    if args.get_flag("decimal") {
+        let decimals = context.token.decimals().await?;
+
+        return Amount::parse_units(amount, decimals).map_err(|e| anyhow!("`amount`: {}", e));
+    }
+
+    amount.parse().map_err(|e| anyhow!("`amount`: {}", e))
+}
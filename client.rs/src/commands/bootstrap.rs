@@ -0,0 +1,435 @@
+use anyhow::{anyhow, bail};
+use clap::ArgMatches;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use hapi_core::{
+    client::{
+        configuration::{RewardConfiguration, StakeConfiguration},
+        entities::reporter::{CreateReporterInput, Reporter, UpdateReporterInput},
+        result::ClientError,
+    },
+    HapiCore,
+};
+
+use super::{CommandOutput, HapiCoreCommandContext};
+
+/// The number of reporters read by a single request of `export-config`
+const EXPORT_PAGE_SIZE: u64 = 100;
+
+/// Deployment settings applied by the `bootstrap` command, every section is optional
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BootstrapConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stake_configuration: Option<StakeConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reward_configuration: Option<RewardConfiguration>,
+    /// Reporters to create, or to update if they exist with other settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reporters: Option<Vec<CreateReporterInput>>,
+    /// Authority to hand the contract over to once it is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authority: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepStatus {
+    /// The contract state already matches the config
+    Skipped,
+    /// The step would be applied without `--dry-run`
+    Pending,
+    Applied,
+}
+
+impl StepStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            StepStatus::Skipped => "skipped",
+            StepStatus::Pending => "pending",
+            StepStatus::Applied => "applied",
+        }
+    }
+}
+
+struct StepReport {
+    name: String,
+    status: StepStatus,
+    tx: Option<String>,
+}
+
+impl StepReport {
+    fn skipped(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: StepStatus::Skipped,
+            tx: None,
+        }
+    }
+
+    fn pending(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: StepStatus::Pending,
+            tx: None,
+        }
+    }
+
+    fn applied(name: impl Into<String>, tx: String) -> Self {
+        Self {
+            name: name.into(),
+            status: StepStatus::Applied,
+            tx: Some(tx),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "step": self.name,
+            "status": self.status.as_str(),
+            "tx": self.tx,
+        })
+    }
+}
+
+/// EVM addresses may come back with a different letter case than configured
+fn same_address(a: &str, b: &str) -> bool {
+    if a.starts_with("0x") && b.starts_with("0x") {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
+fn parse_config(contents: &str) -> anyhow::Result<BootstrapConfig> {
+    let config: BootstrapConfig =
+        serde_json::from_str(contents).map_err(|e| anyhow!("Invalid bootstrap config: {e}"))?;
+
+    if config.stake_configuration.is_none()
+        && config.reward_configuration.is_none()
+        && config.reporters.is_none()
+        && config.authority.is_none()
+    {
+        bail!("Bootstrap config has nothing to apply");
+    }
+
+    Ok(config)
+}
+
+/// Whether the existing reporter has the settings of the config
+fn same_reporter(current: &Reporter, input: &CreateReporterInput) -> bool {
+    same_address(&current.account, &input.account)
+        && current.role == input.role
+        && current.name == input.name
+        && current.url == input.url
+}
+
+/// Config that brings another contract to the state of the exported one
+///
+/// Reporter stakes and statuses are left out, as reporters stake on their own.
+fn exported_config(
+    authority: String,
+    stake_configuration: Option<StakeConfiguration>,
+    reward_configuration: Option<RewardConfiguration>,
+    reporters: Vec<Reporter>,
+) -> BootstrapConfig {
+    BootstrapConfig {
+        stake_configuration,
+        reward_configuration,
+        reporters: Some(
+            reporters
+                .into_iter()
+                .map(|reporter| CreateReporterInput {
+                    id: reporter.id,
+                    account: reporter.account,
+                    role: reporter.role,
+                    name: reporter.name,
+                    url: reporter.url,
+                })
+                .collect(),
+        ),
+        authority: Some(authority),
+    }
+}
+
+async fn get_all_reporters(hapi_core: &dyn HapiCore) -> anyhow::Result<Vec<Reporter>> {
+    let count = hapi_core.get_reporter_count().await?;
+    let mut reporters = Vec::with_capacity(count as usize);
+
+    while (reporters.len() as u64) < count {
+        let page = hapi_core
+            .get_reporters(reporters.len() as u64, EXPORT_PAGE_SIZE)
+            .await?;
+
+        if page.is_empty() {
+            break;
+        }
+
+        reporters.extend(page);
+    }
+
+    Ok(reporters)
+}
+
+/// Brings a freshly deployed contract to the state described by the config file
+///
+/// Steps run in order: stake configuration, reward configuration, then the authority, as
+/// handing the contract over takes configuration rights away from the signer. A step is
+/// skipped if the contract already matches the config, so an interrupted run can be
+/// restarted with the same file. Every applied step is read back before the next one.
+pub async fn bootstrap(args: &ArgMatches) -> anyhow::Result<()> {
+    let context = HapiCoreCommandContext::try_from(args)?;
+
+    let file = args
+        .get_one::<String>("config")
+        .ok_or(anyhow!("`config` is required"))?;
+
+    let contract_address = args
+        .get_one::<String>("contract-address")
+        .ok_or(anyhow!("`contract-address` is required"))?;
+
+    let dry_run = args.get_flag("dry-run");
+
+    let contents =
+        std::fs::read_to_string(file).map_err(|e| anyhow!("Failed to read `{file}`: {e}"))?;
+
+    let config = parse_config(&contents)?;
+
+    let hapi_core = &context.hapi_core;
+
+    for (field, value) in [
+        (
+            "stake_configuration.token",
+            config.stake_configuration.as_ref().map(|c| &c.token),
+        ),
+        (
+            "reward_configuration.token",
+            config.reward_configuration.as_ref().map(|c| &c.token),
+        ),
+        ("authority", config.authority.as_ref()),
+    ] {
+        if let Some(address) = value {
+            hapi_core
+                .is_valid_address(address)
+                .map_err(|e| anyhow!("Invalid address in `{field}`: {e}"))?;
+        }
+    }
+
+    let mut steps = vec![];
+
+    // Getters fail while a configuration is not set yet, which is treated as a mismatch
+    if let Some(cfg) = config.stake_configuration {
+        let name = "stake_configuration";
+        let current = hapi_core.get_stake_configuration().await.ok();
+
+        steps.push(if current.is_some_and(|c| c.diff(&cfg).is_empty()) {
+            StepReport::skipped(name)
+        } else if dry_run {
+            StepReport::pending(name)
+        } else {
+            let tx = hapi_core.update_stake_configuration(cfg.clone()).await?;
+
+            let applied = hapi_core.get_stake_configuration().await?;
+            if !applied.diff(&cfg).is_empty() {
+                bail!("Stake configuration is not applied after {}", tx.hash);
+            }
+
+            StepReport::applied(name, tx.hash)
+        });
+    }
+
+    if let Some(cfg) = config.reward_configuration {
+        let name = "reward_configuration";
+        let current = hapi_core.get_reward_configuration().await.ok();
+
+        steps.push(if current.is_some_and(|c| c.diff(&cfg).is_empty()) {
+            StepReport::skipped(name)
+        } else if dry_run {
+            StepReport::pending(name)
+        } else {
+            let tx = hapi_core.update_reward_configuration(cfg.clone()).await?;
+
+            let applied = hapi_core.get_reward_configuration().await?;
+            if !applied.diff(&cfg).is_empty() {
+                bail!("Reward configuration is not applied after {}", tx.hash);
+            }
+
+            StepReport::applied(name, tx.hash)
+        });
+    }
+
+    // Reporters are created while the signer still holds the authority
+    for input in config.reporters.unwrap_or_default() {
+        let name = format!("reporter {}", input.id);
+        let current = match hapi_core.get_reporter(&input.id.to_string()).await {
+            Ok(reporter) => Some(reporter),
+            Err(ClientError::InvalidReporter) => None,
+            Err(e) => bail!("Failed to get reporter {}: {e}", input.id),
+        };
+
+        steps.push(match current {
+            Some(current) if same_reporter(&current, &input) => StepReport::skipped(name),
+            _ if dry_run => StepReport::pending(name),
+            Some(_) => {
+                let tx = hapi_core
+                    .update_reporter(UpdateReporterInput {
+                        id: input.id,
+                        account: input.account.clone(),
+                        role: input.role.clone(),
+                        name: input.name.clone(),
+                        url: input.url.clone(),
+                    })
+                    .await?;
+
+                StepReport::applied(name, tx.hash)
+            }
+            None => {
+                let tx = hapi_core.create_reporter(input.clone()).await?;
+
+                StepReport::applied(name, tx.hash)
+            }
+        });
+    }
+
+    if let Some(authority) = &config.authority {
+        let name = "authority";
+        let current = hapi_core.get_authority().await?;
+
+        steps.push(if same_address(&current, authority) {
+            StepReport::skipped(name)
+        } else if dry_run {
+            StepReport::pending(name)
+        } else {
+            let tx = hapi_core.set_authority(authority).await?;
+
+            if !same_address(&hapi_core.get_authority().await?, authority) {
+                bail!("Authority is not changed after {}", tx.hash);
+            }
+
+            StepReport::applied(name, tx.hash)
+        });
+    }
+
+    let authority = hapi_core.get_authority().await?;
+
+    match context.output {
+        CommandOutput::Json => println!(
+            "{}",
+            json!({
+                "contract_address": contract_address,
+                "authority": authority,
+                "dry_run": dry_run,
+                "steps": steps.iter().map(StepReport::to_json).collect::<Vec<_>>(),
+            })
+        ),
+        CommandOutput::Plain => {
+            for step in &steps {
+                match &step.tx {
+                    Some(tx) => println!("{}: {} ({tx})", step.name, step.status.as_str()),
+                    None => println!("{}: {}", step.name, step.status.as_str()),
+                }
+            }
+
+            println!("Contract: {contract_address}");
+            println!("Authority: {authority}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the authority, configurations and reporters of the contract to a `bootstrap` config
+///
+/// Getters of configurations that are not set yet fail, such sections are left out.
+pub async fn export_config(args: &ArgMatches) -> anyhow::Result<()> {
+    let context = HapiCoreCommandContext::try_from(args)?;
+
+    let file = args
+        .get_one::<String>("output")
+        .ok_or(anyhow!("`output` is required"))?;
+
+    let hapi_core = &context.hapi_core;
+
+    let config = exported_config(
+        hapi_core.get_authority().await?,
+        hapi_core.get_stake_configuration().await.ok(),
+        hapi_core.get_reward_configuration().await.ok(),
+        get_all_reporters(hapi_core.as_ref()).await?,
+    );
+
+    std::fs::write(file, serde_json::to_string_pretty(&config)?)
+        .map_err(|e| anyhow!("Failed to write `{file}`: {e}"))?;
+
+    let reporters = config.reporters.as_ref().map_or(0, Vec::len);
+
+    match context.output {
+        CommandOutput::Json => println!(
+            "{}",
+            json!({
+                "file": file,
+                "authority": config.authority,
+                "stake_configuration": config.stake_configuration.is_some(),
+                "reward_configuration": config.reward_configuration.is_some(),
+                "reporters": reporters,
+            })
+        ),
+        CommandOutput::Plain => println!("Exported {reporters} reporters to {file}"),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hapi_core::client::entities::reporter::{ReporterRole, ReporterStatus};
+    use uuid::Uuid;
+
+    #[test]
+    fn exported_config_round_trips() {
+        let reporter = Reporter {
+            id: Uuid::new_v4(),
+            account: "0x922ffdfcb57de5dd6f641f275e98b684ce5576a3".to_string(),
+            role: ReporterRole::Publisher,
+            status: ReporterStatus::Active,
+            name: "Publisher".to_string(),
+            url: "https://publisher.com".to_string(),
+            stake: 3000.into(),
+            unlock_timestamp: 12345,
+        };
+
+        let stake_configuration = StakeConfiguration {
+            token: "0x2947F98C42597966a0ec25e92843c09ac18Fbab7".to_string(),
+            unlock_duration: 86400,
+            validator_stake: 1000.into(),
+            tracer_stake: 2000.into(),
+            publisher_stake: 3000.into(),
+            authority_stake: 4000.into(),
+        };
+
+        let exported = exported_config(
+            "0x922ffdfcb57de5dd6f641f275e98b684ce5576a3".to_string(),
+            Some(stake_configuration.clone()),
+            None,
+            vec![reporter.clone()],
+        );
+
+        let contents = serde_json::to_string_pretty(&exported).unwrap();
+
+        // Stakes are not part of the config
+        assert!(!contents.contains("unlock_timestamp"));
+        assert!(!contents.contains("reward_configuration"));
+
+        let parsed = parse_config(&contents).expect("Failed to parse exported config");
+
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&exported).unwrap()
+        );
+        assert!(parsed
+            .stake_configuration
+            .is_some_and(|c| c.diff(&stake_configuration).is_empty()));
+        assert!(same_reporter(&reporter, &parsed.reporters.unwrap()[0]));
+        assert_eq!(parsed.authority, exported.authority);
+    }
+}
@@ -1,12 +1,18 @@
 use clap::ArgMatches;
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use hapi_core::{
-    client::{implementations::TokenContractSolana, token::TokenContract},
-    HapiCore, HapiCoreEvm, HapiCoreNear, HapiCoreNetwork, HapiCoreOptions, HapiCoreSolana,
+    client::{
+        confirmation::ConfirmationOptions, implementations::TokenContractSolana,
+        token::TokenContract,
+    },
+    HapiCore, HapiCoreEvm, HapiCoreNetwork, HapiCoreOptions, HapiCoreSolana, RpcTimeouts,
     TokenContractEvm, TokenContractNear,
 };
 
+/// Placeholder for the commands that can run without a node, it is never requested
+const OFFLINE_PROVIDER_URL: &str = "http://127.0.0.1:8545";
+
 #[derive(Default)]
 pub enum CommandOutput {
     #[default]
@@ -29,6 +35,22 @@ impl FromStr for CommandOutput {
 pub(crate) struct HapiCoreCommandContext {
     pub hapi_core: Box<dyn HapiCore>,
     pub output: CommandOutput,
+    /// Set if submitted transactions should be awaited
+    pub wait: Option<ConfirmationOptions>,
+}
+
+/// Context of the commands that are specific to EVM networks
+pub(crate) struct EvmCommandContext {
+    pub hapi_core: HapiCoreEvm,
+    pub output: CommandOutput,
+    /// Set if the provider URL is given, logs are decoded without it
+    pub is_connected: bool,
+}
+
+/// Context of the commands that are specific to Solana networks
+pub(crate) struct SolanaCommandContext {
+    pub hapi_core: HapiCoreSolana,
+    pub output: CommandOutput,
 }
 
 pub(crate) struct TokenCommandContext {
@@ -66,11 +88,13 @@ impl TryFrom<&ArgMatches> for TokenCommandContext {
 
         let options = HapiCoreOptions {
             provider_url,
+            fallback_provider_urls: fallback_provider_urls(matches),
             contract_address,
             private_key,
             chain_id: None,
             account_id: None,
             network: network.clone(),
+            rpc_timeouts: RpcTimeouts::default(),
         };
 
         let token: Box<dyn TokenContract> = match network {
@@ -87,6 +111,57 @@ impl TryFrom<&ArgMatches> for TokenCommandContext {
     }
 }
 
+impl TryFrom<&ArgMatches> for EvmCommandContext {
+    type Error = anyhow::Error;
+
+    fn try_from(matches: &ArgMatches) -> Result<Self, Self::Error> {
+        let network: HapiCoreNetwork = matches
+            .get_one::<String>("network")
+            .ok_or(anyhow::anyhow!("`network` is required"))?
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Failed to parse `network`: {:?}", e))?;
+
+        if !matches!(
+            network,
+            HapiCoreNetwork::Sepolia | HapiCoreNetwork::Ethereum | HapiCoreNetwork::Bsc
+        ) {
+            anyhow::bail!("The command is supported on EVM networks only");
+        }
+
+        let provider_url = matches.get_one::<String>("provider-url").cloned();
+
+        let contract_address = matches
+            .get_one::<String>("contract-address")
+            .ok_or(anyhow::anyhow!("`contract-address` is required"))?
+            .to_owned();
+
+        let output: CommandOutput = matches
+            .get_one::<String>("output")
+            .unwrap_or(&"plain".to_string())
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Failed to parse `output`: {:?}", e))?;
+
+        let hapi_core = HapiCoreEvm::new(HapiCoreOptions {
+            provider_url: provider_url
+                .clone()
+                .unwrap_or(OFFLINE_PROVIDER_URL.to_string()),
+            fallback_provider_urls: fallback_provider_urls(matches),
+            contract_address,
+            private_key: None,
+            chain_id: None,
+            account_id: None,
+            network,
+            rpc_timeouts: RpcTimeouts::default(),
+        })?;
+
+        Ok(Self {
+            hapi_core,
+            output,
+            is_connected: provider_url.is_some(),
+        })
+    }
+}
+
 impl TryFrom<&ArgMatches> for HapiCoreCommandContext {
     type Error = anyhow::Error;
 
@@ -131,25 +206,98 @@ impl TryFrom<&ArgMatches> for HapiCoreCommandContext {
             })
             .transpose()?;
 
+        let wait = matches
+            .get_flag("wait")
+            .then(|| confirmation_options(matches))
+            .transpose()?;
+
         let options = HapiCoreOptions {
             provider_url,
+            fallback_provider_urls: fallback_provider_urls(matches),
             contract_address,
             private_key,
             chain_id,
             account_id,
             network: network.clone(),
+            rpc_timeouts: RpcTimeouts::default(),
         };
 
-        let hapi_core: Box<dyn HapiCore> = match network {
-            HapiCoreNetwork::Sepolia | HapiCoreNetwork::Ethereum | HapiCoreNetwork::Bsc => {
-                Box::new(HapiCoreEvm::new(options)?)
-            }
-            HapiCoreNetwork::Solana | HapiCoreNetwork::Bitcoin => {
-                Box::new(HapiCoreSolana::new(options)?)
-            }
-            HapiCoreNetwork::Near => Box::new(HapiCoreNear::new(options)?),
-        };
+        Ok(Self {
+            hapi_core: options.into_client()?,
+            output,
+            wait,
+        })
+    }
+}
+
+impl TryFrom<&ArgMatches> for SolanaCommandContext {
+    type Error = anyhow::Error;
+
+    fn try_from(matches: &ArgMatches) -> Result<Self, Self::Error> {
+        let network: HapiCoreNetwork = matches
+            .get_one::<String>("network")
+            .ok_or(anyhow::anyhow!("`network` is required"))?
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Failed to parse `network`: {:?}", e))?;
+
+        if !matches!(network, HapiCoreNetwork::Solana | HapiCoreNetwork::Bitcoin) {
+            anyhow::bail!("The command is supported on Solana networks only");
+        }
+
+        let provider_url = matches
+            .get_one::<String>("provider-url")
+            .ok_or(anyhow::anyhow!("`provider-url` is required"))?
+            .to_owned();
+
+        let contract_address = matches
+            .get_one::<String>("contract-address")
+            .ok_or(anyhow::anyhow!("`contract-address` is required"))?
+            .to_owned();
+
+        let output: CommandOutput = matches
+            .get_one::<String>("output")
+            .unwrap_or(&"plain".to_string())
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Failed to parse `output`: {:?}", e))?;
+
+        let hapi_core = HapiCoreSolana::new(HapiCoreOptions {
+            provider_url,
+            fallback_provider_urls: vec![],
+            contract_address,
+            private_key: None,
+            chain_id: None,
+            account_id: None,
+            network,
+            rpc_timeouts: RpcTimeouts::default(),
+        })?;
 
         Ok(Self { hapi_core, output })
     }
 }
+
+fn confirmation_options(matches: &ArgMatches) -> anyhow::Result<ConfirmationOptions> {
+    let mut options = ConfirmationOptions::default();
+
+    if let Some(confirmations) = matches.get_one::<String>("confirmations") {
+        options.confirmations = confirmations
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("`confirmations`: {e}"))?
+            .max(1);
+    }
+
+    if let Some(timeout) = matches.get_one::<String>("wait-timeout") {
+        options.timeout = timeout
+            .parse::<u64>()
+            .map(Duration::from_secs)
+            .map_err(|e| anyhow::anyhow!("`wait-timeout`: {e}"))?;
+    }
+
+    Ok(options)
+}
+
+fn fallback_provider_urls(matches: &ArgMatches) -> Vec<String> {
+    matches
+        .get_many::<String>("fallback-provider-urls")
+        .map(|urls| urls.cloned().collect())
+        .unwrap_or_default()
+}
@@ -1,4 +1,4 @@
-use clap::{Arg, ArgGroup, ArgMatches, Command};
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command};
 use std::process::exit;
 
 pub(crate) fn matcher() -> ArgMatches {
@@ -25,6 +25,16 @@ pub(crate) fn matcher() -> ArgMatches {
                 .env("PROVIDER_URL")
                 .help("Network-specific provider URL (e.g. RPC node URL)"),
         )
+        .arg(
+            Arg::new("fallback-provider-urls")
+                .global(true)
+                .long("fallback-provider-urls")
+                .value_name("FALLBACK_PROVIDER_URLS")
+                .env("FALLBACK_PROVIDER_URLS")
+                .value_delimiter(',')
+                .num_args(1..)
+                .help("Comma-separated EVM provider URLs to fail over to when the provider is unreachable"),
+        )
         .arg(
             Arg::new("contract-address")
                 .global(true)
@@ -72,6 +82,31 @@ pub(crate) fn matcher() -> ArgMatches {
                 .value_parser(["json", "text"])
                 .help("[OPTIONAL] Command output format"),
         )
+        .arg(
+            Arg::new("wait")
+                .global(true)
+                .long("wait")
+                .action(ArgAction::SetTrue)
+                .help("[OPTIONAL] Wait for the transaction confirmation and report its status"),
+        )
+        .arg(
+            Arg::new("confirmations")
+                .global(true)
+                .long("confirmations")
+                .value_name("CONFIRMATIONS")
+                .env("CONFIRMATIONS")
+                .requires("wait")
+                .help("[OPTIONAL] Number of blocks to wait for, including the transaction one (default 1)"),
+        )
+        .arg(
+            Arg::new("wait-timeout")
+                .global(true)
+                .long("wait-timeout")
+                .value_name("SECONDS")
+                .env("WAIT_TIMEOUT")
+                .requires("wait")
+                .help("[OPTIONAL] Maximum time to wait for the confirmation in seconds (default 120)"),
+        )
         .subcommand_required(true)
         .subcommand(
             Command::new("authority")
@@ -95,6 +130,21 @@ pub(crate) fn matcher() -> ArgMatches {
                 .alias("cfg")
                 .about("Configuration commands")
                 .subcommand_required(true)
+                .subcommand(
+                    Command::new("get")
+                        .about("Get authority, stake and reward configuration at once"),
+                )
+                .subcommand(
+                    Command::new("check")
+                        .about("Compare the configuration with the expected one from a JSON file")
+                        .arg(
+                            Arg::new("expected")
+                                .long("expected")
+                                .value_name("FILE")
+                                .required(true)
+                                .help("JSON file with the expected authority, stake and reward configuration"),
+                        ),
+                )
                 .subcommand(Command::new("get-stake").about("Get stake configuration"))
                 .subcommand(
                     Command::new("update-stake")
@@ -319,7 +369,18 @@ pub(crate) fn matcher() -> ArgMatches {
                 )
                 .subcommand(Command::new("activate").about("Activate reporter"))
                 .subcommand(Command::new("deactivate").about("Deactivate reporter"))
-                .subcommand(Command::new("unstake").about("Unstake reporter")),
+                .subcommand(Command::new("unstake").about("Unstake reporter"))
+                .subcommand(
+                    Command::new("status")
+                        .about("Show whether the reporter stake can be withdrawn")
+                        .arg(
+                            Arg::new("id")
+                                .value_name("ID")
+                                .index(1)
+                                .required(true)
+                                .help("Reporter UUID"),
+                        ),
+                ),
         )
         .subcommand(
             Command::new("case")
@@ -413,6 +474,13 @@ pub(crate) fn matcher() -> ArgMatches {
                                 .value_name("TAKE")
                                 .default_value("10")
                                 .help("Return N items"),
+                        )
+                        .arg(
+                            Arg::new("status")
+                                .long("status")
+                                .value_name("STATUS")
+                                .help("List only cases with the status, pagination applies to the matching cases")
+                                .value_parser(["Closed", "Open", "closed", "open"]),
                         ),
                 ),
         )
@@ -617,6 +685,151 @@ pub(crate) fn matcher() -> ArgMatches {
                         ),
                 ),
         )
+        .subcommand(
+            Command::new("report-addresses")
+                .about("Create addresses in batches from a CSV file")
+                .arg(
+                    Arg::new("file")
+                        .long("file")
+                        .short('f')
+                        .value_name("FILE")
+                        .required(true)
+                        .help("CSV file with `address,case_id,category,risk` rows"),
+                ),
+        )
+        .subcommand(
+            Command::new("onboard-reporter")
+                .about("Generate a keypair for a new Solana reporter and create the reporter with it")
+                .arg(
+                    Arg::new("name")
+                        .long("name")
+                        .value_name("NAME")
+                        .required(true)
+                        .help("Reporter display name"),
+                )
+                .arg(
+                    Arg::new("role")
+                        .long("role")
+                        .value_name("ROLE")
+                        .required(true)
+                        .help("Reporter role")
+                        .value_parser([
+                            "Validator",
+                            "Tracer",
+                            "Publisher",
+                            "Authority",
+                            "validator",
+                            "tracer",
+                            "publisher",
+                            "authority",
+                        ]),
+                )
+                .arg(
+                    Arg::new("url")
+                        .long("url")
+                        .value_name("URL")
+                        .help("[OPTIONAL] Reporter URL"),
+                )
+                .arg(
+                    Arg::new("id")
+                        .long("id")
+                        .value_name("ID")
+                        .help("[OPTIONAL] Reporter UUID (default is a random one)"),
+                )
+                .arg(
+                    Arg::new("keypair-file")
+                        .long("keypair-file")
+                        .value_name("FILE")
+                        .help("[OPTIONAL] New file to write the keypair to (default reporter-<ID>.json)"),
+                ),
+        )
+        .subcommand(
+            Command::new("bootstrap")
+                .about("Apply stake, reward and authority settings from a JSON file")
+                .arg(
+                    Arg::new("config")
+                        .long("config")
+                        .value_name("CONFIG")
+                        .required(true)
+                        .help("JSON file with the settings to apply"),
+                )
+                .arg(
+                    Arg::new("dry-run")
+                        .long("dry-run")
+                        .action(ArgAction::SetTrue)
+                        .help("Only report the steps that would be applied"),
+                ),
+        )
+        .subcommand(
+            Command::new("export-config")
+                .about("Write authority, stake, reward and reporter settings to a bootstrap file")
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .value_name("FILE")
+                        .required(true)
+                        .help("JSON file to write the settings to"),
+                ),
+        )
+        .subcommand(
+            Command::new("decode-log")
+                .about("Decode HAPI Core contract events from a raw EVM log or a transaction")
+                .group(
+                    ArgGroup::new("source")
+                        .args(["topics", "tx-hash"])
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("topics")
+                        .long("topics")
+                        .value_name("TOPICS")
+                        .value_delimiter(',')
+                        .num_args(1..)
+                        .help("Comma-separated hex encoded log topics, the event signature first"),
+                )
+                .arg(
+                    Arg::new("data")
+                        .long("data")
+                        .value_name("DATA")
+                        .requires("topics")
+                        .help("[OPTIONAL] Hex encoded log data (default 0x)"),
+                )
+                .arg(
+                    Arg::new("tx-hash")
+                        .long("tx-hash")
+                        .value_name("TX_HASH")
+                        .help("Transaction to decode the contract logs of, fetched from the provider"),
+                ),
+        )
+        .subcommand(
+            Command::new("dump-account")
+                .about("Fetch a HAPI Core Solana program account and print it decoded by its type")
+                .arg(
+                    Arg::new("account")
+                        .value_name("PUBKEY")
+                        .index(1)
+                        .required(true)
+                        .help("Account address"),
+                )
+                .arg(
+                    Arg::new("raw")
+                        .long("raw")
+                        .action(ArgAction::SetTrue)
+                        .help("[OPTIONAL] Print the hex encoded account data as well"),
+                ),
+        )
+        .subcommand(
+            Command::new("explain-error")
+                .about("Explain a custom error code of a failed HAPI Core Solana transaction")
+                .arg(
+                    Arg::new("code")
+                        .value_name("CODE")
+                        .index(1)
+                        .required(true)
+                        .help("Error code, decimal or hex (e.g. 6001 or 0x1771)"),
+                ),
+        )
         .subcommand(
             Command::new("asset")
                 .about("Asset commands")
@@ -636,7 +849,7 @@ pub(crate) fn matcher() -> ArgMatches {
                                 .value_name("ASSET_ID")
                                 .index(2)
                                 .required(true)
-                                .help("Asset ID"),
+                                .help("Asset ID, decimal or 0x-prefixed hex"),
                         )
                         .arg(
                             Arg::new("case-id")
@@ -720,7 +933,7 @@ pub(crate) fn matcher() -> ArgMatches {
                                 .value_name("ASSET_ID")
                                 .index(2)
                                 .required(true)
-                                .help("Asset ID"),
+                                .help("Asset ID, decimal or 0x-prefixed hex"),
                         )
                         .arg(
                             Arg::new("case-id")
@@ -804,7 +1017,7 @@ pub(crate) fn matcher() -> ArgMatches {
                                 .value_name("ASSET_ID")
                                 .index(2)
                                 .required(true)
-                                .help("Asset ID"),
+                                .help("Asset ID, decimal or 0x-prefixed hex"),
                         ),
                 )
                 .subcommand(
@@ -822,7 +1035,7 @@ pub(crate) fn matcher() -> ArgMatches {
                                 .value_name("ASSET_ID")
                                 .index(2)
                                 .required(true)
-                                .help("Asset ID"),
+                                .help("Asset ID, decimal or 0x-prefixed hex"),
                         ),
                 )
                 .subcommand(Command::new("count").about("Get asset count"))
@@ -876,6 +1089,12 @@ pub(crate) fn matcher() -> ArgMatches {
                                 .index(3)
                                 .required(true)
                                 .help("Amount to transfer"),
+                        )
+                        .arg(
+                            Arg::new("decimal")
+                                .long("decimal")
+                                .action(ArgAction::SetTrue)
+                                .help("Amount is given in whole tokens, i.e. 100.5, using the token decimals"),
                         ),
                 )
                 .subcommand(
@@ -901,6 +1120,12 @@ pub(crate) fn matcher() -> ArgMatches {
                                 .index(3)
                                 .required(true)
                                 .help("Amount to approve"),
+                        )
+                        .arg(
+                            Arg::new("decimal")
+                                .long("decimal")
+                                .action(ArgAction::SetTrue)
+                                .help("Amount is given in whole tokens, i.e. 100.5, using the token decimals"),
                         ),
                 )
                 .subcommand(
@@ -919,6 +1144,12 @@ pub(crate) fn matcher() -> ArgMatches {
                                 .index(2)
                                 .required(true)
                                 .help("Address to get balance for"),
+                        )
+                        .arg(
+                            Arg::new("decimal")
+                                .long("decimal")
+                                .action(ArgAction::SetTrue)
+                                .help("Show the balance in whole tokens using the token decimals"),
                         ),
                 ),
         )
@@ -0,0 +1,167 @@
+use anchor_client::solana_sdk::signature::{Keypair, Signer};
+use anyhow::{anyhow, bail};
+use clap::ArgMatches;
+use serde_json::json;
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+};
+use uuid::Uuid;
+
+use hapi_core::{
+    client::{
+        entities::reporter::CreateReporterInput,
+        implementations::solana::get_reporter_account_address,
+    },
+    HapiCoreNetwork,
+};
+
+use super::{CommandOutput, HapiCoreCommandContext};
+
+const KEY_STORAGE_WARNING: &str = "The keypair file holds the private key of the reporter \
+    in plain text. Move it to an encrypted storage or a hardware wallet, keep a backup, and \
+    never share or commit it: the key can't be recovered, and whoever holds it reports on \
+    behalf of the reporter.";
+
+/// Generates a keypair and writes it to a new file, in the Solana CLI format
+///
+/// The file is readable by its owner only, an existing file is never overwritten.
+fn write_new_keypair(path: &Path) -> anyhow::Result<Keypair> {
+    let keypair = Keypair::new();
+    let contents = serde_json::to_string(&keypair.to_bytes().to_vec())?;
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options
+        .open(path)
+        .map_err(|e| anyhow!("Failed to create keypair file {}: {e}", path.display()))?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+
+    Ok(keypair)
+}
+
+/// Creates a reporter for a freshly generated keypair, signed by the network authority
+pub async fn onboard_reporter(args: &ArgMatches) -> anyhow::Result<()> {
+    let context = HapiCoreCommandContext::try_from(args)?;
+
+    let network: HapiCoreNetwork = args
+        .get_one::<String>("network")
+        .ok_or(anyhow!("`network` is required"))?
+        .parse()
+        .map_err(|e| anyhow!("`network`: {e}"))?;
+
+    if !matches!(network, HapiCoreNetwork::Solana | HapiCoreNetwork::Bitcoin) {
+        bail!("The command is supported on Solana networks only");
+    }
+
+    let contract_address = args
+        .get_one::<String>("contract-address")
+        .ok_or(anyhow!("`contract-address` is required"))?;
+
+    let id = match args.get_one::<String>("id") {
+        Some(id) => id.parse().map_err(|e| anyhow!("`id`: {e}"))?,
+        None => Uuid::new_v4(),
+    };
+
+    let role = args
+        .get_one::<String>("role")
+        .ok_or(anyhow!("`role` is required"))?
+        .parse()
+        .map_err(|e| anyhow!("`role`: {e}"))?;
+
+    let name = args
+        .get_one::<String>("name")
+        .ok_or(anyhow!("`name` is required"))?
+        .to_owned();
+
+    let url = args.get_one::<String>("url").cloned().unwrap_or_default();
+
+    let keypair_file = args
+        .get_one::<String>("keypair-file")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(format!("reporter-{id}.json")));
+
+    let reporter_address =
+        get_reporter_account_address(contract_address, &network.to_string(), id)?;
+
+    // The key is stored before the reporter is created, so that it is never lost
+    let keypair = write_new_keypair(&keypair_file)?;
+    let account = keypair.pubkey().to_string();
+
+    eprintln!("WARNING: {KEY_STORAGE_WARNING}");
+
+    let tx = context
+        .hapi_core
+        .create_reporter(CreateReporterInput {
+            id,
+            account: account.clone(),
+            role,
+            name,
+            url,
+        })
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "Failed to create reporter, the keypair is kept in {}: {e}",
+                keypair_file.display()
+            )
+        })?;
+
+    match context.output {
+        CommandOutput::Json => println!(
+            "{}",
+            json!({
+                "tx": tx.hash,
+                "id": id,
+                "account": account,
+                "reporter_address": reporter_address,
+                "keypair_file": keypair_file,
+            })
+        ),
+        CommandOutput::Plain => {
+            println!("{}", tx.hash);
+            println!("id: {id}");
+            println!("account: {account}");
+            println!("reporter address: {reporter_address}");
+            println!("keypair file: {}", keypair_file.display());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::solana_sdk::signature::read_keypair_file;
+
+    #[test]
+    fn keypair_is_written_to_new_file() {
+        let path = std::env::temp_dir().join(format!("hapi-onboard-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let keypair = write_new_keypair(&path).expect("Failed to write keypair");
+
+        let stored = read_keypair_file(&path).expect("Failed to read keypair");
+        assert_eq!(stored.pubkey(), keypair.pubkey());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // an existing key is never overwritten
+        assert!(write_new_keypair(&path).is_err());
+        assert_eq!(read_keypair_file(&path).unwrap().pubkey(), keypair.pubkey());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
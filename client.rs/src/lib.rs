@@ -4,8 +4,8 @@ pub use client::{
     amount::Amount,
     entities::network::HapiCoreNetwork,
     implementations::{
-        HapiCoreEvm, HapiCoreNear, HapiCoreSolana, TokenContractEvm, TokenContractNear,
-        TokenContractSolana,
+        evm::RpcTimeouts, HapiCoreEvm, HapiCoreNear, HapiCoreSolana, TokenContractEvm,
+        TokenContractNear, TokenContractSolana,
     },
     interface::{HapiCore, HapiCoreOptions},
     token::TokenContract,
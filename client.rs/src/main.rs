@@ -9,6 +9,8 @@ async fn main() -> anyhow::Result<()> {
             _ => unreachable!(),
         },
         Some(("configuration", matches)) => match matches.subcommand() {
+            Some(("get", matches)) => commands::get_network_configuration(matches).await?,
+            Some(("check", matches)) => commands::check_network_configuration(matches).await?,
             Some(("get-stake", matches)) => commands::get_stake_configuration(matches).await?,
             Some(("update-stake", matches)) => {
                 commands::update_stake_configuration(matches).await?
@@ -28,6 +30,7 @@ async fn main() -> anyhow::Result<()> {
             Some(("activate", matches)) => commands::activate_reporter(matches).await?,
             Some(("deactivate", matches)) => commands::deactivate_reporter(matches).await?,
             Some(("unstake", matches)) => commands::unstake_reporter(matches).await?,
+            Some(("status", matches)) => commands::get_reporter_status(matches).await?,
             _ => unreachable!(),
         },
         Some(("case", matches)) => match matches.subcommand() {
@@ -47,6 +50,13 @@ async fn main() -> anyhow::Result<()> {
             Some(("list", matches)) => commands::get_addresses(matches).await?,
             _ => unreachable!(),
         },
+        Some(("report-addresses", matches)) => commands::report_addresses(matches).await?,
+        Some(("onboard-reporter", matches)) => commands::onboard_reporter(matches).await?,
+        Some(("bootstrap", matches)) => commands::bootstrap(matches).await?,
+        Some(("export-config", matches)) => commands::export_config(matches).await?,
+        Some(("decode-log", matches)) => commands::decode_log(matches).await?,
+        Some(("dump-account", matches)) => commands::dump_account(matches).await?,
+        Some(("explain-error", matches)) => commands::explain_error(matches)?,
         Some(("asset", matches)) => match matches.subcommand() {
             Some(("create", matches)) => commands::create_asset(matches).await?,
             Some(("update", matches)) => commands::update_asset(matches).await?,
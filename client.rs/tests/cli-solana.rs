@@ -9,7 +9,7 @@ mod solana;
 use solana::setup::Setup;
 
 use common_fixtures::*;
-use solana::fixtures::{ADDRESS_ADDR_1, ASSET_ADDR_1};
+use solana::fixtures::{ADDRESS_ADDR_1, ASSET_ADDR_1, CONTRACT_ADDRESS, NETWORK, REPORTER_UUID_3};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn solana_works() {
@@ -145,6 +145,31 @@ async fn solana_works() {
         })
     );
 
+    t.print("Read the whole network configuration at once");
+    assert_json_output!(
+        t.exec(["configuration", "get"]),
+        json!({
+            "configuration": {
+                "authority": &authority_pubkey,
+                "stake_configuration": {
+                    "token": stake_mint,
+                    "unlock_duration": unlock_duration,
+                    "validator_stake": validator_stake.to_string(),
+                    "tracer_stake": tracer_stake.to_string(),
+                    "publisher_stake": publisher_stake.to_string(),
+                    "authority_stake": authority_stake.to_string()
+                },
+                "reward_configuration": {
+                    "token": reward_mint,
+                    "address_confirmation_reward": address_confirmation_reward.to_string(),
+                    "address_tracer_reward": address_tracer_reward.to_string(),
+                    "asset_confirmation_reward": asset_confirmation_reward.to_string(),
+                    "asset_tracer_reward": asset_tracer_reward.to_string()
+                }
+            }
+        })
+    );
+
     t.print("Make sure that the reporter 1 does not exist yet");
     assert_error_output!(
         t.exec(["reporter", "get", REPORTER_UUID_1]),
@@ -180,6 +205,20 @@ async fn solana_works() {
     t.print("Make sure that reporter counter has increased");
     assert_json_output!(t.exec(["reporter", "count"]), json!({ "count": 1 }));
 
+    t.print("Make sure that the same reporter can't be created twice");
+    assert_error_output!(
+        t.exec([
+            "reporter",
+            "create",
+            REPORTER_UUID_1,
+            &authority_pubkey,
+            "authority",
+            "HAPI Authority",
+            "https://hapi.one/reporter/authority",
+        ]),
+        format!("Error: Reporter {REPORTER_UUID_1} already exists, use an update to change it")
+    );
+
     t.print("Check authority's token balance");
     let json = assert_json_output!(
         t.exec(["token", "balance", &stake_mint, &authority_pubkey]),
@@ -571,4 +610,84 @@ async fn solana_works() {
             "unlock_timestamp": 0
         }})
     );
+
+    t.print("Onboard a reporter with a freshly generated keypair");
+    let keypair_file =
+        std::env::temp_dir().join(format!("hapi-onboard-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&keypair_file);
+
+    let output = t
+        .exec([
+            "onboard-reporter",
+            "--id",
+            REPORTER_UUID_3,
+            "--name",
+            "HAPI Tracer",
+            "--role",
+            "tracer",
+            "--url",
+            "https://hapi.one/reporter/tracer",
+            "--keypair-file",
+            keypair_file.to_str().unwrap(),
+        ])
+        .expect("Failed to onboard reporter");
+    assert!(output.success, "{output:?}");
+    assert!(output.stderr.contains("WARNING"), "{output:?}");
+
+    let onboarded: serde_json::Value =
+        serde_json::from_str(&output.stdout).expect("json parse error");
+    assert!(Setup::is_tx_match(&onboarded), "transaction hash expected");
+
+    t.print("Verify that the keypair is stored and the reporter is created for it");
+    let keypair = anchor_client::solana_sdk::signature::read_keypair_file(&keypair_file)
+        .expect("Failed to read keypair");
+    let tracer_pubkey = keypair.pubkey().to_string();
+
+    assert_eq!(onboarded["id"], REPORTER_UUID_3);
+    assert_eq!(onboarded["account"], tracer_pubkey.as_str());
+    assert_eq!(
+        onboarded["reporter_address"],
+        hapi_core::client::implementations::solana::get_reporter_account_address(
+            CONTRACT_ADDRESS,
+            NETWORK,
+            REPORTER_UUID_3.parse().unwrap()
+        )
+        .unwrap()
+    );
+
+    assert_json_output!(
+        t.exec(["reporter", "get", REPORTER_UUID_3]),
+        json!({ "reporter": {
+            "id": REPORTER_UUID_3,
+            "account": tracer_pubkey,
+            "role": "Tracer",
+            "name": "HAPI Tracer",
+            "url": "https://hapi.one/reporter/tracer",
+            "stake": "0",
+            "status": "Inactive",
+            "unlock_timestamp": 0
+        }})
+    );
+
+    t.print("Make sure that an existing keypair file is not overwritten");
+    let output = t
+        .exec([
+            "onboard-reporter",
+            "--name",
+            "HAPI Tracer",
+            "--role",
+            "tracer",
+            "--keypair-file",
+            keypair_file.to_str().unwrap(),
+        ])
+        .expect("Failed to run command");
+    assert!(!output.success);
+    assert_eq!(
+        anchor_client::solana_sdk::signature::read_keypair_file(&keypair_file)
+            .unwrap()
+            .pubkey(),
+        keypair.pubkey()
+    );
+
+    std::fs::remove_file(&keypair_file).unwrap();
 }
@@ -117,6 +117,99 @@ async fn evm_works() {
         })
     );
 
+    t.print("Read the whole network configuration at once");
+    assert_json_output!(
+        t.exec(["configuration", "get"]),
+        json!({
+            "configuration": {
+                "authority": PUBLIC_KEY_1,
+                "stake_configuration": {
+                    "token": t.token_contract,
+                    "unlock_duration": unlock_duration,
+                    "validator_stake": validator_stake.to_string(),
+                    "tracer_stake": tracer_stake.to_string(),
+                    "publisher_stake": publisher_stake.to_string(),
+                    "authority_stake": authority_stake.to_string()
+                },
+                "reward_configuration": {
+                    "token": t.token_contract,
+                    "address_confirmation_reward": address_confirmation_reward.to_string(),
+                    "address_tracer_reward": address_tracer_reward.to_string(),
+                    "asset_confirmation_reward": asset_confirmation_reward.to_string(),
+                    "asset_tracer_reward": asset_tracer_reward.to_string()
+                }
+            }
+        })
+    );
+
+    t.print("Check that bootstrap with the applied settings has nothing to do");
+    let bootstrap_file =
+        std::env::temp_dir().join(format!("hapi-bootstrap-{}.json", std::process::id()));
+    std::fs::write(
+        &bootstrap_file,
+        json!({
+            "stake_configuration": {
+                "token": t.token_contract,
+                "unlock_duration": unlock_duration,
+                "validator_stake": validator_stake.to_string(),
+                "tracer_stake": tracer_stake.to_string(),
+                "publisher_stake": publisher_stake.to_string(),
+                "authority_stake": authority_stake.to_string()
+            },
+            "reward_configuration": {
+                "token": t.token_contract,
+                "address_confirmation_reward": address_confirmation_reward.to_string(),
+                "address_tracer_reward": address_tracer_reward.to_string(),
+                "asset_confirmation_reward": asset_confirmation_reward.to_string(),
+                "asset_tracer_reward": asset_tracer_reward.to_string()
+            },
+            "authority": PUBLIC_KEY_1
+        })
+        .to_string(),
+    )
+    .expect("Failed to write bootstrap file");
+
+    let output = t
+        .exec([
+            "bootstrap",
+            "--config",
+            bootstrap_file.to_str().unwrap(),
+            "--dry-run",
+        ])
+        .unwrap_or_else(|e| panic!("{}", e));
+    std::fs::remove_file(&bootstrap_file).ok();
+
+    assert!(output.success, "Expected command success: {:?}", output);
+    let report: serde_json::Value = serde_json::from_str(&output.stdout).expect("json parse error");
+    assert_eq!(report["authority"], PUBLIC_KEY_1);
+    for step in report["steps"].as_array().expect("steps are missing") {
+        assert_eq!(step["status"], "skipped", "Unexpected step: {step}");
+    }
+
+    t.print("Check that the exported settings bootstrap to the same state");
+    let export_file =
+        std::env::temp_dir().join(format!("hapi-export-{}.json", std::process::id()));
+    let output = t
+        .exec(["export-config", "--output", export_file.to_str().unwrap()])
+        .unwrap_or_else(|e| panic!("{}", e));
+    assert!(output.success, "Expected command success: {:?}", output);
+
+    let output = t
+        .exec([
+            "bootstrap",
+            "--config",
+            export_file.to_str().unwrap(),
+            "--dry-run",
+        ])
+        .unwrap_or_else(|e| panic!("{}", e));
+    std::fs::remove_file(&export_file).ok();
+
+    assert!(output.success, "Expected command success: {:?}", output);
+    let report: serde_json::Value = serde_json::from_str(&output.stdout).expect("json parse error");
+    for step in report["steps"].as_array().expect("steps are missing") {
+        assert_eq!(step["status"], "skipped", "Unexpected step: {step}");
+    }
+
     t.print("Make sure that the reporter 1 does not exist yet");
     assert_error_output!(
         t.exec(["reporter", "get", REPORTER_UUID_1]),
@@ -295,8 +388,24 @@ async fn evm_works() {
     t.print("Verify the case count has increased");
     assert_json_output!(t.exec(["case", "count"]), json!({ "count": 1 }));
 
+    t.print("Verify that the case is listed among open cases only");
+    assert_json_output!(
+        t.exec(["case", "list", "--status", "open"]),
+        json!({ "cases": [{
+            "id": CASE_UUID_1,
+            "name": CASE_NAME_1,
+            "url": CASE_URL_1,
+            "status": "Open",
+            "reporter_id": REPORTER_UUID_1,
+        }]})
+    );
+    assert_json_output!(
+        t.exec(["case", "list", "--status", "closed"]),
+        json!({ "cases": [] })
+    );
+
     t.print("Create an address by authority");
-    assert_tx_output!(t.exec([
+    let tx = assert_tx_output!(t.exec([
         "address",
         "create",
         ADDRESS_ADDR_1,
@@ -305,6 +414,22 @@ async fn evm_works() {
         ADDRESS_RISK_1,
     ]));
 
+    t.print("Decode the event emitted by the address creation");
+    assert_json_output!(
+        t.exec(["decode-log", "--tx-hash", &tx]),
+        json!({ "logs": [
+            {
+                "name": "AddressCreated",
+                "event": "create_address",
+                "fields": {
+                    "addr": ADDRESS_ADDR_1,
+                    "risk": ADDRESS_RISK_1,
+                    "category": "13",
+                }
+            }
+        ]})
+    );
+
     t.print("Verify that the address has been created");
     assert_json_output!(
         t.exec(["address", "get", ADDRESS_ADDR_1]),
@@ -381,6 +506,46 @@ async fn evm_works() {
         }})
     );
 
+    t.print("Report a batch of addresses from a file");
+    let batch_file = std::env::temp_dir().join(format!("hapi-batch-{}.csv", std::process::id()));
+    std::fs::write(
+        &batch_file,
+        format!(
+            "address,case_id,category,risk\n\
+             {ADDRESS_ADDR_2},{CASE_UUID_1},Scam,4\n\
+             {ADDRESS_ADDR_1},{CASE_UUID_1},Scam,4\n\
+             {ADDRESS_ADDR_3},{CASE_UUID_1},Mixer,8\n"
+        ),
+    )
+    .expect("Failed to write batch file");
+
+    let output = t
+        .exec(["report-addresses", "--file", batch_file.to_str().unwrap()])
+        .unwrap_or_else(|e| panic!("{}", e));
+    std::fs::remove_file(&batch_file).ok();
+
+    assert!(output.success, "Expected command success: {:?}", output);
+    let report: serde_json::Value = serde_json::from_str(&output.stdout).expect("json parse error");
+    assert_eq!(report["succeeded"], 2);
+    assert_eq!(report["failed"], 1);
+    assert!(Setup::is_tx_match(&report["results"][0]));
+    assert!(report["results"][1]["error"].is_string());
+    assert!(Setup::is_tx_match(&report["results"][2]));
+
+    t.print("Verify that the batch addresses have been created");
+    assert_json_output!(t.exec(["address", "count"]), json!({ "count": 3 }));
+    assert_json_output!(
+        t.exec(["address", "get", ADDRESS_ADDR_3]),
+        json!({ "address": {
+            "address": to_checksum(ADDRESS_ADDR_3),
+            "case_id": CASE_UUID_1,
+            "reporter_id": REPORTER_UUID_1,
+            "risk": 8,
+            "category": "Mixer",
+            "confirmations": 0,
+        }})
+    );
+
     t.print("Create an asset by authority");
     assert_tx_output!(t.exec([
         "asset",
@@ -460,15 +625,28 @@ async fn evm_works() {
         }})
     );
 
-    t.print("Close the case by authority");
-    assert_tx_output!(t.exec([
-        "case",
-        "update",
-        CASE_UUID_1,
-        "closed case",
-        "https://hapi.one/case/closed",
-        "Closed"
-    ]));
+    t.print("Close the case by authority and wait for the confirmation");
+    {
+        let output = t
+            .exec([
+                "case",
+                "update",
+                CASE_UUID_1,
+                "closed case",
+                "https://hapi.one/case/closed",
+                "Closed",
+                "--wait",
+                "--confirmations",
+                "1",
+            ])
+            .expect("failed to update case");
+        assert!(output.success, "Expected command success: {:?}", output);
+
+        let value =
+            serde_json::from_str::<serde_json::Value>(&output.stdout).expect("json parse error");
+        assert!(Setup::is_tx_match(&value), "transaction hash expected");
+        assert_eq!(value["status"], "confirmed");
+    }
 
     t.print("Verify that the case has been closed");
     assert_json_output!(
@@ -482,6 +660,12 @@ async fn evm_works() {
         }})
     );
 
+    t.print("Verify that the closed case is not listed among open cases");
+    assert_json_output!(
+        t.exec(["case", "list", "--status", "open"]),
+        json!({ "cases": [] })
+    );
+
     t.print("Deactivate authority reporter");
     let unlock_timestamp = {
         let tx_hash = assert_tx_output!(t.exec(["reporter", "deactivate"]));
@@ -504,6 +688,22 @@ async fn evm_works() {
         }})
     );
 
+    t.print("Verify that the authority stake is still locked");
+    {
+        let output = t
+            .exec(["reporter", "status", REPORTER_UUID_1])
+            .expect("failed to get reporter status");
+        assert!(output.success, "Expected command success: {:?}", output);
+
+        let status =
+            serde_json::from_str::<serde_json::Value>(&output.stdout).expect("json parse error");
+        assert_eq!(status["status"], "Unstaking");
+        assert_eq!(status["stake"], authority_stake.to_string());
+        assert_eq!(status["unlock_timestamp"], unlock_timestamp);
+        assert_eq!(status["can_unstake"], false);
+        assert!(status["seconds_remaining"].as_u64().unwrap_or_default() > 0);
+    }
+
     sleep(Duration::from_secs(unlock_duration));
 
     t.print("Unstake authority reporter");
@@ -5,3 +5,6 @@ pub const PRIVATE_KEY_1: &str =
 pub const PUBLIC_KEY_2: &str = "0x70997970c51812dc3a010c7d01b50e0d17dc79c8";
 pub const PRIVATE_KEY_2: &str =
     "0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d";
+
+pub const ADDRESS_ADDR_2: &str = "0x2e6a1b2ed1f1c2b0a3b9bbd1a1f0c7c0b1a7d5e3";
+pub const ADDRESS_ADDR_3: &str = "0x5b9a4b3f0d6c8e2a1f7d3c9b0e4a6d8c2f1b3e5a";
@@ -7,6 +7,8 @@ pub const PROGRAM_NAME: &str = "hapi_core_solana";
 pub const HAPI_CORE_KEYPAIR: &str = "tests/test_keypair.json";
 pub const CONTRACT_ADDRESS: &str = "FgE5ySSi6fbnfYGGRyaeW8y6p8A5KybXPyQ2DdxPCNRk";
 
+pub const REPORTER_UUID_3: &str = "6f4a1c2e-8b3d-4e5f-9a7b-1c2d3e4f5a6b";
+
 pub const KEYS_DIR: &str = "tests/solana/keys";
 
 pub const STAKE_MINT_KEYPAIR: &str = "token_1.json";
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.22;
+
+import "@openzeppelin/contracts/metatx/ERC2771Forwarder.sol";
+
+contract Forwarder is ERC2771Forwarder {
+    constructor() ERC2771Forwarder("HapiCoreForwarder") {}
+}
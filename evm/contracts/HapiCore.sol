@@ -3,6 +3,7 @@ pragma solidity ^0.8.22;
 
 import "@openzeppelin/contracts-upgradeable/access/OwnableUpgradeable.sol";
 import "@openzeppelin/contracts-upgradeable/access/AccessControlUpgradeable.sol";
+import "@openzeppelin/contracts-upgradeable/metatx/ERC2771ContextUpgradeable.sol";
 import "@openzeppelin/contracts/token/ERC20/IERC20.sol";
 
 /**
@@ -10,8 +11,15 @@ import "@openzeppelin/contracts/token/ERC20/IERC20.sol";
  * @author HAPI Protocol development team
  *
  * Core contract for the HAPI protocol
+ *
+ * Calls relayed by the trusted ERC-2771 forwarder are made on behalf of their signer,
+ * so that reporters can submit without paying the gas themselves.
  */
-contract HapiCore is OwnableUpgradeable, AccessControlUpgradeable {
+contract HapiCore is
+    OwnableUpgradeable,
+    AccessControlUpgradeable,
+    ERC2771ContextUpgradeable
+{
     error AddressAlreadyConfirmed(address addr, uint128 reporter_id);
     error AddressNotFound(address addr);
     error AssetAlreadyConfirmed(
@@ -43,6 +51,12 @@ contract HapiCore is OwnableUpgradeable, AccessControlUpgradeable {
 
     bytes32 public constant AUTHORITY_ROLE = keccak256("AUTHORITY_ROLE");
 
+    /// @param trustedForwarder ERC-2771 forwarder of relayed calls, zero address disables relaying
+    /// @custom:oz-upgrades-unsafe-allow constructor
+    constructor(
+        address trustedForwarder
+    ) ERC2771ContextUpgradeable(trustedForwarder) {}
+
     /// Initializes the contract
     function initialize() public initializer {
         __Ownable_init(_msgSender());
@@ -1309,4 +1323,32 @@ contract HapiCore is OwnableUpgradeable, AccessControlUpgradeable {
         authority = _authority;
         _grantRole(AUTHORITY_ROLE, _authority);
     }
+
+    /// Sender of the call, the signer of the request if it is relayed by the forwarder
+    function _msgSender()
+        internal
+        view
+        override(ContextUpgradeable, ERC2771ContextUpgradeable)
+        returns (address)
+    {
+        return ERC2771ContextUpgradeable._msgSender();
+    }
+
+    function _msgData()
+        internal
+        view
+        override(ContextUpgradeable, ERC2771ContextUpgradeable)
+        returns (bytes calldata)
+    {
+        return ERC2771ContextUpgradeable._msgData();
+    }
+
+    function _contextSuffixLength()
+        internal
+        view
+        override(ContextUpgradeable, ERC2771ContextUpgradeable)
+        returns (uint256)
+    {
+        return ERC2771ContextUpgradeable._contextSuffixLength();
+    }
 }
@@ -11,6 +11,10 @@ const HARDHAT_NETWORK = process.env.HARDHAT_NETWORK || "hardhat";
 const HARDHAT_LOCALHOST_URL =
   process.env.HARDHAT_LOCALHOST_URL || "http://127.0.0.1:8545";
 
+// ERC-2771 forwarder that the contract accepts relayed calls from, none by default
+const TRUSTED_FORWARDER =
+  process.env.TRUSTED_FORWARDER || "0x0000000000000000000000000000000000000000";
+
 const config: HardhatUserConfig = {
   defaultNetwork: HARDHAT_NETWORK,
   typechain: {
@@ -84,8 +88,11 @@ task("deploy", "Deploys the HAPI Core contract").setAction(async (_, hre) => {
       forceSignerGasPrice(hre, await hre.ethers.provider.getSigner(), gasPrice);
     }
 
+    console.log(`Trusted forwarder: ${TRUSTED_FORWARDER}`);
+
     const contract = (await hre.upgrades.deployProxy(HapiCore as any, [], {
       initializer: "initialize",
+      constructorArgs: [TRUSTED_FORWARDER],
       pollingInterval: Number(process.env.DEPLOY_POLLING_INTERVAL) || 10000,
       timeout: Number(process.env.DEPLOY_TIMEOUT) || 3600000,
     })) as Contract;
@@ -150,6 +157,44 @@ task("deploy-test-token", "Deploys the HAPI Test Token contract").setAction(
   }
 );
 
+task(
+  "deploy-forwarder",
+  "Deploys the ERC-2771 forwarder for relayed HAPI Core calls"
+).setAction(async (_, hre) => {
+  try {
+    const [signer] = await hre.ethers.getSigners();
+
+    if (!signer) {
+      throw new Error("No signer found");
+    } else {
+      console.log(`Using wallet: ${signer.address}`);
+    }
+
+    const network = await hre.ethers.provider.getNetwork();
+
+    console.log(`Deploying to '${network.name}' (${network.chainId})`);
+
+    const Forwarder = await hre.ethers.getContractFactory("Forwarder", signer);
+
+    const gasPrice = process.env.GAS_PRICE_GWEI;
+    if (gasPrice) {
+      console.log(`Enforcing gas price: ${gasPrice} Gwei`);
+      forceSignerGasPrice(hre, signer, gasPrice);
+    }
+
+    const contract = await Forwarder.deploy();
+
+    await contract.waitForDeployment();
+
+    console.log(`HAPI Core forwarder deployed`, {
+      contract: await contract.getAddress(),
+    });
+  } catch (error) {
+    console.error(`${error}`);
+    process.exit(1);
+  }
+});
+
 task("upgrade", "Upgrades the HAPI Core contract").setAction(
   async (args, hre) => {
     try {
@@ -184,9 +229,12 @@ task("upgrade", "Upgrades the HAPI Core contract").setAction(
         await hre.upgrades.forceImport(contractAddress, HapiCore as any);
       }
 
+      console.log(`Trusted forwarder: ${TRUSTED_FORWARDER}`);
+
       const contract = await hre.upgrades.upgradeProxy(
         contractAddress,
-        HapiCore as any
+        HapiCore as any,
+        { constructorArgs: [TRUSTED_FORWARDER] }
       );
 
       const adminAddress = await hre.upgrades.erc1967.getAdminAddress(
@@ -0,0 +1,103 @@
+import { loadFixture, time } from "@nomicfoundation/hardhat-network-helpers";
+import { expect } from "chai";
+import { ethers } from "hardhat";
+
+import { fixtureWithReporters } from "../setup";
+import { Category, randomId } from "../util";
+
+const FORWARD_REQUEST_TYPES = {
+  ForwardRequest: [
+    { name: "from", type: "address" },
+    { name: "to", type: "address" },
+    { name: "value", type: "uint256" },
+    { name: "gas", type: "uint256" },
+    { name: "nonce", type: "uint256" },
+    { name: "deadline", type: "uint48" },
+    { name: "data", type: "bytes" },
+  ],
+};
+
+describe("HapiCore: Meta-transactions", function () {
+  it("Should create an address on behalf of the relayed reporter", async function () {
+    const { hapiCore, forwarder, wallets, reporters } = await loadFixture(
+      fixtureWithReporters
+    );
+
+    const caseId = randomId();
+
+    await hapiCore
+      .connect(wallets.authority)
+      .createCase(caseId, "big hack 2023", "https://big.hack");
+
+    const address = {
+      addr: "0x9DDE9F8b85e4c4278545549e4eDF2E3E9d2c890E",
+      risk: 5,
+      category: Category.Hacker,
+    };
+
+    const request = {
+      from: wallets.publisher.address,
+      to: await hapiCore.getAddress(),
+      value: 0,
+      gas: 500000,
+      nonce: await forwarder.nonces(wallets.publisher.address),
+      deadline: (await time.latest()) + 3600,
+      data: hapiCore.interface.encodeFunctionData("createAddress", [
+        address.addr,
+        caseId,
+        address.risk,
+        address.category,
+      ]),
+    };
+
+    const { chainId } = await ethers.provider.getNetwork();
+
+    const signature = await wallets.publisher.signTypedData(
+      {
+        name: "HapiCoreForwarder",
+        version: "1",
+        chainId,
+        verifyingContract: await forwarder.getAddress(),
+      },
+      FORWARD_REQUEST_TYPES,
+      request
+    );
+
+    // The relayer pays for the transaction
+    await expect(
+      await forwarder.connect(wallets.nobody).execute({ ...request, signature })
+    )
+      .to.emit(hapiCore, "AddressCreated")
+      .withArgs(address.addr, address.risk, address.category);
+
+    expect(
+      await hapiCore.getFunction("getAddress")(address.addr)
+    ).to.deep.equal([
+      address.addr,
+      caseId,
+      reporters.publisher.id,
+      0,
+      address.risk,
+      address.category,
+    ]);
+
+    // A request can't be relayed twice
+    await expect(
+      forwarder.connect(wallets.nobody).execute({ ...request, signature })
+    ).to.be.reverted;
+  });
+
+  it("Should not accept calls relayed by an untrusted forwarder", async function () {
+    const { hapiCore, wallets } = await loadFixture(fixtureWithReporters);
+
+    const untrusted = await ethers.deployContract("Forwarder");
+    await untrusted.waitForDeployment();
+
+    expect(await hapiCore.isTrustedForwarder(await untrusted.getAddress())).to
+      .be.false;
+
+    expect(
+      await hapiCore.isTrustedForwarder(wallets.nobody.address)
+    ).to.be.false;
+  });
+});
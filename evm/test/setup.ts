@@ -1,21 +1,33 @@
 import { ethers, upgrades } from "hardhat";
 import { BaseContract } from "ethers";
 
-import { HapiCore, Token } from "../typechain-types";
+import { Forwarder, HapiCore, Token } from "../typechain-types";
 import { ReporterRole, randomId } from "./util";
 
-export async function setupContract(): Promise<{ hapiCore: HapiCore, contractAddress: string }> {
+export async function setupContract(): Promise<{
+  hapiCore: HapiCore;
+  contractAddress: string;
+  forwarder: Forwarder;
+}> {
+  const forwarder = (await ethers.deployContract("Forwarder")) as Forwarder;
+  await forwarder.waitForDeployment();
+
   const HapiCoreFactory = await ethers.getContractFactory("HapiCore");
 
   const contract = await upgrades.deployProxy(HapiCoreFactory as any, [], {
     initializer: "initialize",
+    constructorArgs: [forwarder.target.toString()],
   });
 
   await contract.waitForDeployment();
 
   const contractAddress = contract.target.toString();
 
-  return { hapiCore: contract as unknown as BaseContract & HapiCore, contractAddress };
+  return {
+    hapiCore: contract as unknown as BaseContract & HapiCore,
+    contractAddress,
+    forwarder,
+  };
 }
 
 export async function basicFixture() {
@@ -1,11 +1,12 @@
 use {
     anyhow::{anyhow, bail, Result},
+    hapi_core::{HapiCoreNetwork, HapiCoreOptions, RpcTimeouts},
     jsonwebtoken::{encode, EncodingKey, Header},
     sea_orm::{Database, DatabaseConnection},
     sea_orm_cli::MigrateSubcommands,
     sea_orm_migration::MigratorTrait,
     secrecy::{ExposeSecret, SecretString},
-    std::net::SocketAddr,
+    std::{net::SocketAddr, sync::Arc},
     tokio::{sync::oneshot, task::JoinHandle},
     tracing::info,
     tracing::instrument,
@@ -13,12 +14,21 @@ use {
 };
 
 use crate::{
-    configuration::Configuration,
-    entity::{network, types::NetworkBackend},
+    cache::{CachedEntity, ResponseCache},
+    chain_head::ChainHeadProbe,
+    configuration::{
+        Configuration, ConfirmationConfiguration, HealthConfiguration, NetworkConfiguration,
+    },
+    entity::{
+        address, network,
+        types::{AddressStatus, NetworkBackend},
+    },
     migrations::Migrator,
-    observability::{update_network_metrics, MetricOp},
+    observability::{update_address_metrics, update_network_metrics, MetricOp},
+    report_rate::ReportRateTracker,
     server::handlers::TokenClaims,
     service::{EntityMutation, EntityQuery},
+    verify::{verify, VerifyOptions, VerifyReport},
 };
 
 const JWT_VALIDITY_DAYS: i64 = 365;
@@ -26,7 +36,19 @@ const JWT_VALIDITY_DAYS: i64 = 365;
 #[derive(Clone)]
 pub struct AppState {
     pub database_conn: DatabaseConnection,
+    /// Connection of the read-only queries: to the replica if configured, to the primary otherwise
+    ///
+    /// A replica may lag behind, so writes and the reads that must see them, like indexer
+    /// cursors and data freshness, stay on the primary.
+    pub read_conn: DatabaseConnection,
     pub jwt_secret: SecretString,
+    pub cache: ResponseCache,
+    pub chain_heads: ChainHeadProbe,
+    /// Configured networks, with the settings the database doesn't keep
+    pub networks: Arc<Vec<NetworkConfiguration>>,
+    pub health: HealthConfiguration,
+    pub confirmations: ConfirmationConfiguration,
+    pub report_rate: ReportRateTracker,
 }
 
 pub struct Application {
@@ -41,9 +63,24 @@ impl Application {
     pub async fn from_configuration(configuration: &Configuration) -> Result<Self> {
         let database_conn = Database::connect(configuration.database_url.as_str()).await?;
 
+        let read_conn = match &configuration.replica_database_url {
+            Some(url) => {
+                info!("Read-only queries are served by the replica");
+                Database::connect(url.as_str()).await?
+            }
+            None => database_conn.clone(),
+        };
+
         let state = AppState {
             database_conn,
+            read_conn,
             jwt_secret: configuration.jwt_secret.to_owned(),
+            cache: ResponseCache::new(configuration.cache.to_owned()),
+            chain_heads: ChainHeadProbe::new(&configuration.health, &configuration.networks),
+            networks: Arc::new(configuration.networks.to_owned()),
+            health: configuration.health.to_owned(),
+            confirmations: configuration.confirmations.to_owned(),
+            report_rate: ReportRateTracker::new(configuration.report_rate.to_owned()),
         };
 
         info!("Application initialized");
@@ -100,6 +137,7 @@ impl Application {
         )
         .await?;
 
+        self.state.cache.invalidate(CachedEntity::Network);
         update_network_metrics(network, MetricOp::Increment);
 
         Ok(())
@@ -129,12 +167,101 @@ impl Application {
         )
         .await?;
 
+        self.state.cache.invalidate(CachedEntity::Network);
         update_network_metrics(old, MetricOp::Decrement);
         update_network_metrics(new, MetricOp::Increment);
 
         Ok(())
     }
 
+    /// Creates the configured networks that are missing and updates the changed ones
+    ///
+    /// Backend and chain id of a registered network can't be changed, as its indexer
+    /// and the stored entities are bound to them.
+    #[instrument(level = "info", skip(self))]
+    pub async fn register_networks(&self, networks: &[NetworkConfiguration]) -> Result<()> {
+        for network in networks {
+            let existing = EntityQuery::find_entity_by_id::<network::Entity, _>(
+                &self.state.database_conn,
+                network.id.clone(),
+            )
+            .await?;
+
+            match existing {
+                None => {
+                    self.create_network(
+                        network.id.clone(),
+                        network.name.clone(),
+                        network.backend,
+                        network.chain_id.clone(),
+                        network.authority.clone(),
+                        network.stake_token.clone(),
+                    )
+                    .await?
+                }
+                Some(old) if old.backend != network.backend || old.chain_id != network.chain_id => {
+                    bail!(
+                        "Network `{}` is registered with {} backend and chain id {:?}",
+                        old.id,
+                        old.backend,
+                        old.chain_id
+                    );
+                }
+                Some(old)
+                    if old.name != network.name
+                        || old.authority != network.authority
+                        || old.stake_token != network.stake_token =>
+                {
+                    self.update_network(
+                        network.id.clone(),
+                        Some(network.name.clone()),
+                        Some(network.authority.clone()),
+                        Some(network.stake_token.clone()),
+                    )
+                    .await?
+                }
+                Some(_) => {}
+            }
+        }
+
+        info!(count = networks.len(), "Networks registered");
+
+        Ok(())
+    }
+
+    #[instrument(level = "info", skip(self))]
+    pub async fn withdraw_address(
+        &self,
+        network_id: String,
+        address: String,
+        reason: String,
+    ) -> Result<()> {
+        let old = EntityQuery::find_entity_by_id::<address::Entity, _>(
+            &self.state.database_conn,
+            (network_id.clone(), address.clone()),
+        )
+        .await?
+        .ok_or(anyhow!("This address does not exist"))?;
+
+        if old.status == AddressStatus::Withdrawn {
+            bail!("This address is already withdrawn");
+        }
+
+        EntityMutation::withdraw_address(
+            &self.state.database_conn,
+            network_id,
+            address,
+            reason,
+            chrono::Utc::now().timestamp() as u64,
+        )
+        .await?;
+
+        self.state.cache.invalidate(CachedEntity::Address);
+        update_address_metrics(old, MetricOp::Decrement);
+
+        Ok(())
+    }
+
     #[instrument(level = "info", skip(self))]
     pub async fn create_indexer(
         &self,
@@ -172,11 +299,62 @@ impl Application {
         Ok(token)
     }
 
-    pub async fn shutdown(&mut self) -> Result<()> {
-        // Close database connection
-        self.state.database_conn.clone().close().await?;
+    /// Compares the stored entities of a network to its contract state
+    ///
+    /// The RPC url of the network configuration is used unless another one is given.
+    #[instrument(level = "info", skip(self, networks))]
+    pub async fn verify(
+        &self,
+        networks: &[NetworkConfiguration],
+        network_id: String,
+        network: HapiCoreNetwork,
+        contract_address: String,
+        rpc_url: Option<String>,
+        options: VerifyOptions,
+    ) -> Result<VerifyReport> {
+        let model = EntityQuery::find_entity_by_id::<network::Entity, _>(
+            &self.state.read_conn,
+            network_id.clone(),
+        )
+        .await?
+        .ok_or(anyhow!("This network does not exist"))?;
 
-        // Send shutdown signal
+        if model.backend != NetworkBackend::from(network.clone()) {
+            bail!("Network {network_id} has {} backend", model.backend);
+        }
+
+        let rpc_url = rpc_url
+            .or_else(|| {
+                networks
+                    .iter()
+                    .find(|configured| configured.id == network_id)
+                    .and_then(|configured| configured.rpc_url.clone())
+            })
+            .ok_or(anyhow!("No RPC url is configured for network {network_id}"))?;
+
+        let client = HapiCoreOptions {
+            provider_url: rpc_url,
+            fallback_provider_urls: vec![],
+            contract_address,
+            private_key: None,
+            chain_id: model.chain_id.as_deref().map(str::parse).transpose()?,
+            account_id: None,
+            network,
+            rpc_timeouts: RpcTimeouts::default(),
+        }
+        .into_client()?;
+
+        verify(
+            &self.state.read_conn,
+            &network_id,
+            client.as_ref(),
+            &options,
+        )
+        .await
+    }
+
+    pub async fn shutdown(&mut self) -> Result<()> {
+        // Stop accepting requests first, so that the running ones finish with the database
         if let Some(sender) = self.shutdown_sender.take() {
             let _ = sender.send(());
         }
@@ -186,6 +364,10 @@ impl Application {
             handle.await??;
         }
 
+        // Close database connections, closing the primary twice is harmless
+        self.state.read_conn.clone().close().await?;
+        self.state.database_conn.clone().close().await?;
+
         info!("Application shutdown");
         Ok(())
     }
@@ -0,0 +1,150 @@
+use {
+    hapi_indexer::PushData,
+    serde_json::Value,
+    std::{
+        collections::HashMap,
+        sync::{Arc, RwLock},
+        time::{Duration, Instant},
+    },
+};
+
+use crate::{configuration::CacheConfiguration, observability::track_cache_lookup};
+
+/// Entity types that cached responses are built from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CachedEntity {
+    Address,
+    Asset,
+    Case,
+    Reporter,
+    Network,
+}
+
+impl From<&PushData> for CachedEntity {
+    fn from(data: &PushData) -> Self {
+        match data {
+            PushData::Address(_) => CachedEntity::Address,
+            PushData::Asset(_) => CachedEntity::Asset,
+            PushData::Case(_) => CachedEntity::Case,
+            PushData::Reporter(_) => CachedEntity::Reporter,
+        }
+    }
+}
+
+/// Endpoints whose responses can be cached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CachedEndpoint {
+    Search,
+    GraphQL,
+}
+
+impl CachedEndpoint {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CachedEndpoint::Search => "search",
+            CachedEndpoint::GraphQL => "graphql",
+        }
+    }
+
+    fn ttl(&self, configuration: &CacheConfiguration) -> Duration {
+        match self {
+            CachedEndpoint::Search => configuration.search_ttl_ms,
+            CachedEndpoint::GraphQL => configuration.graphql_ttl_ms,
+        }
+    }
+
+    /// Entities that may appear in the endpoint responses
+    fn dependencies(&self) -> &'static [CachedEntity] {
+        match self {
+            CachedEndpoint::Search => &[CachedEntity::Reporter, CachedEntity::Case],
+            // Any query may select any entity, including the statistics
+            CachedEndpoint::GraphQL => &[
+                CachedEntity::Address,
+                CachedEntity::Asset,
+                CachedEntity::Case,
+                CachedEntity::Reporter,
+                CachedEntity::Network,
+            ],
+        }
+    }
+}
+
+struct CacheEntry {
+    endpoint: CachedEndpoint,
+    value: Value,
+    expires_at: Instant,
+}
+
+/// In-process cache of serialized responses, keyed by endpoint and request parameters
+#[derive(Clone)]
+pub struct ResponseCache {
+    configuration: CacheConfiguration,
+    entries: Arc<RwLock<HashMap<(&'static str, String), CacheEntry>>>,
+}
+
+impl ResponseCache {
+    pub fn new(configuration: CacheConfiguration) -> Self {
+        Self {
+            configuration,
+            entries: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    fn is_enabled(&self, endpoint: CachedEndpoint) -> bool {
+        self.configuration.max_entries > 0 && !endpoint.ttl(&self.configuration).is_zero()
+    }
+
+    /// Returns a fresh cached response, recording the lookup in metrics
+    pub fn get(&self, endpoint: CachedEndpoint, key: &str) -> Option<Value> {
+        if !self.is_enabled(endpoint) {
+            return None;
+        }
+
+        let value = self
+            .entries
+            .read()
+            .expect("cache lock is poisoned")
+            .get(&(endpoint.as_str(), key.to_owned()))
+            .filter(|entry| entry.expires_at > Instant::now())
+            .map(|entry| entry.value.clone());
+
+        track_cache_lookup(endpoint.as_str(), value.is_some());
+
+        value
+    }
+
+    pub fn insert(&self, endpoint: CachedEndpoint, key: String, value: Value) {
+        if !self.is_enabled(endpoint) {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut entries = self.entries.write().expect("cache lock is poisoned");
+
+        if entries.len() >= self.configuration.max_entries {
+            entries.retain(|_, entry| entry.expires_at > now);
+        }
+
+        // Still full of live entries, the response will be cached once some of them expire
+        if entries.len() >= self.configuration.max_entries {
+            return;
+        }
+
+        entries.insert(
+            (endpoint.as_str(), key),
+            CacheEntry {
+                endpoint,
+                value,
+                expires_at: now + endpoint.ttl(&self.configuration),
+            },
+        );
+    }
+
+    /// Drops every response that may contain the given entity
+    pub fn invalidate(&self, entity: CachedEntity) {
+        self.entries
+            .write()
+            .expect("cache lock is poisoned")
+            .retain(|_, entry| !entry.endpoint.dependencies().contains(&entity));
+    }
+}
@@ -0,0 +1,158 @@
+use {
+    anyhow::{anyhow, Result},
+    serde::Serialize,
+    serde_json::{json, Value},
+    std::{sync::Arc, time::Duration},
+    tokio::{task::JoinSet, time::timeout},
+};
+
+use crate::{
+    configuration::{HealthConfiguration, NetworkConfiguration},
+    entity::types::NetworkBackend,
+};
+
+/// Network whose chain head is fetched from its RPC node
+#[derive(Debug, Clone)]
+struct ProbedNetwork {
+    id: String,
+    backend: NetworkBackend,
+    rpc_url: String,
+}
+
+/// Whether the RPC node of a network answered in time
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcStatus {
+    Ok,
+    Degraded,
+}
+
+/// Result of a single chain head probe
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ChainHead {
+    pub network_id: String,
+    pub status: RpcStatus,
+    /// Latest block, or slot on Solana, if the node answered
+    pub chain_head: Option<u64>,
+    /// Why the node is considered degraded
+    pub error: Option<String>,
+}
+
+/// Fetches the chain heads of the networks with a configured RPC node
+///
+/// Every request is bounded by the probe timeout, so a hanging node shows up as degraded
+/// instead of holding the caller.
+#[derive(Clone)]
+pub struct ChainHeadProbe {
+    client: reqwest::Client,
+    timeout: Duration,
+    networks: Arc<Vec<ProbedNetwork>>,
+}
+
+impl ChainHeadProbe {
+    pub fn new(configuration: &HealthConfiguration, networks: &[NetworkConfiguration]) -> Self {
+        let networks = networks
+            .iter()
+            .filter_map(|network| {
+                network.rpc_url.as_ref().map(|rpc_url| ProbedNetwork {
+                    id: network.id.clone(),
+                    backend: network.backend,
+                    rpc_url: rpc_url.clone(),
+                })
+            })
+            .collect();
+
+        Self {
+            client: reqwest::Client::new(),
+            timeout: configuration.rpc_timeout_ms,
+            networks: Arc::new(networks),
+        }
+    }
+
+    /// Probes all networks at once, so the slowest node bounds the total time
+    pub async fn probe(&self) -> Vec<ChainHead> {
+        let mut probes = JoinSet::new();
+
+        for network in self.networks.iter().cloned() {
+            let probe = self.clone();
+            probes.spawn(async move { probe.probe_network(&network).await });
+        }
+
+        let mut heads = Vec::with_capacity(self.networks.len());
+        while let Some(head) = probes.join_next().await {
+            match head {
+                Ok(head) => heads.push(head),
+                Err(error) => tracing::error!(?error, "Chain head probe task failed"),
+            }
+        }
+
+        heads.sort_by(|a, b| a.network_id.cmp(&b.network_id));
+        heads
+    }
+
+    async fn probe_network(&self, network: &ProbedNetwork) -> ChainHead {
+        let result = match timeout(self.timeout, self.fetch_chain_head(network)).await {
+            Ok(result) => result,
+            Err(_) => Err(anyhow!(
+                "RPC node did not respond within {} ms",
+                self.timeout.as_millis()
+            )),
+        };
+
+        match result {
+            Ok(height) => ChainHead {
+                network_id: network.id.clone(),
+                status: RpcStatus::Ok,
+                chain_head: Some(height),
+                error: None,
+            },
+            Err(error) => {
+                tracing::warn!(network = network.id, ?error, "Chain head probe failed");
+
+                ChainHead {
+                    network_id: network.id.clone(),
+                    status: RpcStatus::Degraded,
+                    chain_head: None,
+                    error: Some(error.to_string()),
+                }
+            }
+        }
+    }
+
+    async fn fetch_chain_head(&self, network: &ProbedNetwork) -> Result<u64> {
+        let (method, params) = match network.backend {
+            NetworkBackend::Evm => ("eth_blockNumber", json!([])),
+            NetworkBackend::Solana => ("getSlot", json!([])),
+            NetworkBackend::Near => ("block", json!({ "finality": "final" })),
+        };
+
+        let response: Value = self
+            .client
+            .post(&network.rpc_url)
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params,
+            }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let result = response
+            .get("result")
+            .ok_or_else(|| anyhow!("RPC response has no result: {response}"))?;
+
+        let height = match network.backend {
+            NetworkBackend::Evm => result
+                .as_str()
+                .and_then(|hex| u64::from_str_radix(hex.trim_start_matches("0x"), 16).ok()),
+            NetworkBackend::Solana => result.as_u64(),
+            NetworkBackend::Near => result.pointer("/header/height").and_then(Value::as_u64),
+        };
+
+        height.ok_or_else(|| anyhow!("Unexpected chain head in RPC response: {result}"))
+    }
+}
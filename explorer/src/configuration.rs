@@ -2,10 +2,12 @@ use {
     config::{Config, ConfigError, File, FileFormat},
     secrecy::SecretString,
     serde::{Deserialize, Deserializer},
-    serde_with::serde_as,
-    std::env,
+    serde_with::{serde_as, DisplayFromStr, DurationMilliSeconds},
+    std::{collections::HashMap, env, net::SocketAddr, path::PathBuf, time::Duration},
 };
 
+use crate::entity::types::NetworkBackend;
+
 const CONFIG_PATH: &str = "configuration.toml";
 const SECRET_PATH: &str = "secret.toml";
 
@@ -20,23 +22,228 @@ pub struct Configuration {
     #[serde(default = "default_is_json_logging")]
     pub is_json_logging: bool,
 
+    /// Rolling log files, written along with stdout or instead of it
+    pub log_file: Option<LogFileConfiguration>,
+
     /// Whether to enable metrics
     #[serde(default = "default_enable_metrics")]
     pub enable_metrics: bool,
 
-    /// The address to listen on
+    /// The address to listen on: `0.0.0.0:<port>` accepts connections on all interfaces,
+    /// `127.0.0.1:<port>` on the loopback only
     #[serde(default = "default_listener")]
-    pub listener: String,
+    pub listener: SocketAddr,
 
     /// The database url
     pub database_url: String,
 
+    /// Url of a read replica of the database, serving the read-only endpoints
+    #[serde(default)]
+    pub replica_database_url: Option<String>,
+
     /// Secret for JWT
     #[serde(deserialize_with = "deserialize_secret_string")]
     pub jwt_secret: SecretString,
 
     /// Origins to allow for CORS
     pub cors_origins: Option<Vec<String>>,
+
+    /// In-process response cache settings
+    #[serde(default)]
+    pub cache: CacheConfiguration,
+
+    /// Networks registered on startup, each one is served by its own indexer
+    #[serde(default)]
+    pub networks: Vec<NetworkConfiguration>,
+
+    /// Health check settings
+    #[serde(default)]
+    pub health: HealthConfiguration,
+
+    /// Confirmations that make an entity confirmed
+    #[serde(default)]
+    pub confirmations: ConfirmationConfiguration,
+
+    /// Report rate that flags a reporter as suspicious
+    #[serde(default)]
+    pub report_rate: ReportRateConfiguration,
+}
+
+#[serde_as]
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NetworkConfiguration {
+    /// Network string identifier
+    pub id: String,
+
+    /// Network display name
+    pub name: String,
+
+    /// Network backend type
+    #[serde_as(as = "DisplayFromStr")]
+    pub backend: NetworkBackend,
+
+    /// Network chain id, the indexer of the network is authenticated by it along with the backend
+    pub chain_id: Option<String>,
+
+    /// Network authority address
+    pub authority: String,
+
+    /// Stake token contract address
+    pub stake_token: String,
+
+    /// RPC node that the chain head is fetched from by the health check
+    #[serde(default)]
+    pub rpc_url: Option<String>,
+
+    /// HAPI Core contract address, listed by the network status
+    #[serde(default)]
+    pub contract_address: Option<String>,
+}
+
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+pub struct HealthConfiguration {
+    /// Time to wait for the chain head of a network, a slower RPC node is reported as degraded
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_health_rpc_timeout")]
+    pub rpc_timeout_ms: Duration,
+
+    /// Time without a heartbeat after which the indexer of a network is reported as halted
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_indexer_halted_after")]
+    pub indexer_halted_after_ms: Duration,
+
+    /// Blocks the indexer of a network may stay behind the chain head before it is degraded
+    #[serde(default = "default_max_indexer_lag")]
+    pub max_indexer_lag: u64,
+}
+
+impl Default for HealthConfiguration {
+    fn default() -> Self {
+        Self {
+            rpc_timeout_ms: default_health_rpc_timeout(),
+            indexer_halted_after_ms: default_indexer_halted_after(),
+            max_indexer_lag: default_max_indexer_lag(),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LogFileConfiguration {
+    /// Directory of the log files
+    pub directory: PathBuf,
+
+    /// File name, rotated files are suffixed with the date and time of their period
+    #[serde(default = "default_log_file_prefix")]
+    pub prefix: String,
+
+    /// How often a new file is started
+    #[serde(default)]
+    pub rotation: LogRotation,
+
+    /// The number of latest files kept, older ones are removed on rotation
+    pub max_files: Option<usize>,
+
+    /// Whether logs are written to stdout as well
+    #[serde(default = "default_log_file_stdout")]
+    pub stdout: bool,
+}
+
+/// Period of a log file
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogRotation {
+    Minutely,
+    Hourly,
+    #[default]
+    Daily,
+    Never,
+}
+
+/// Confirmation thresholds of the networks
+///
+/// The threshold is set on-chain by the community of every network and is not part of the indexed
+/// events, so the explorer takes it from the configuration. A threshold of a network overrides the
+/// default one.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ConfirmationConfiguration {
+    /// Threshold of the networks that are not listed
+    #[serde(default = "default_confirmation_threshold")]
+    pub threshold: u64,
+
+    /// Thresholds by network id
+    #[serde(default)]
+    pub networks: HashMap<String, u64>,
+}
+
+impl ConfirmationConfiguration {
+    /// Number of confirmations that an entity of the network needs to be confirmed
+    pub fn threshold(&self, network_id: &str) -> u64 {
+        self.networks
+            .get(network_id)
+            .copied()
+            .unwrap_or(self.threshold)
+    }
+}
+
+impl Default for ConfirmationConfiguration {
+    fn default() -> Self {
+        Self {
+            threshold: default_confirmation_threshold(),
+            networks: HashMap::new(),
+        }
+    }
+}
+
+/// Limit of reports by a single reporter, a reporter exceeding it is flagged but not blocked
+#[serde_as]
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReportRateConfiguration {
+    /// Reports of a reporter within the window that are still considered normal, zero disables flagging
+    #[serde(default = "default_report_rate_max_reports")]
+    pub max_reports: usize,
+
+    /// Window of block time in which the reports are counted
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_report_rate_window")]
+    pub window_ms: Duration,
+}
+
+impl Default for ReportRateConfiguration {
+    fn default() -> Self {
+        Self {
+            max_reports: default_report_rate_max_reports(),
+            window_ms: default_report_rate_window(),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+pub struct CacheConfiguration {
+    /// Maximum number of cached responses
+    #[serde(default = "default_cache_max_entries")]
+    pub max_entries: usize,
+
+    /// Time to live of `/search` responses, zero disables caching
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_search_ttl")]
+    pub search_ttl_ms: Duration,
+
+    /// Time to live of `/graphql` responses, zero disables caching
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_graphql_ttl")]
+    pub graphql_ttl_ms: Duration,
+}
+
+impl Default for CacheConfiguration {
+    fn default() -> Self {
+        Self {
+            max_entries: default_cache_max_entries(),
+            search_ttl_ms: default_search_ttl(),
+            graphql_ttl_ms: default_graphql_ttl(),
+        }
+    }
 }
 
 impl Default for Configuration {
@@ -44,11 +251,18 @@ impl Default for Configuration {
         Self {
             log_level: default_loglevel(),
             is_json_logging: default_is_json_logging(),
+            log_file: None,
             enable_metrics: false,
             listener: default_listener(),
             database_url: String::new(),
+            replica_database_url: None,
             jwt_secret: default_jwt_secret(),
             cors_origins: None,
+            cache: CacheConfiguration::default(),
+            networks: vec![],
+            health: HealthConfiguration::default(),
+            confirmations: ConfirmationConfiguration::default(),
+            report_rate: ReportRateConfiguration::default(),
         }
     }
 }
@@ -70,25 +284,115 @@ pub fn get_configuration() -> Result<Configuration, ConfigError> {
         )
         .build()?;
 
-    settings.try_deserialize::<Configuration>()
+    parse_configuration(settings)
+}
+
+/// Deserializes the configuration and validates the declared networks
+pub fn parse_configuration(settings: Config) -> Result<Configuration, ConfigError> {
+    let mut configuration = settings.try_deserialize::<Configuration>()?;
+
+    configuration.networks = validate_networks(configuration.networks)?;
+
+    Ok(configuration)
+}
+
+/// Drops repeated network entries and rejects the ones that conflict
+///
+/// Webhook payloads are matched to a network by its backend and chain id, so the pair must
+/// be unique along with the network id.
+fn validate_networks(
+    networks: Vec<NetworkConfiguration>,
+) -> Result<Vec<NetworkConfiguration>, ConfigError> {
+    let mut unique: Vec<NetworkConfiguration> = Vec::with_capacity(networks.len());
+
+    for network in networks {
+        if unique.contains(&network) {
+            continue;
+        }
+
+        if let Some(other) = unique.iter().find(|other| other.id == network.id) {
+            return Err(ConfigError::Message(format!(
+                "Network `{}` is declared more than once with different settings",
+                other.id
+            )));
+        }
+
+        if let Some(other) = unique
+            .iter()
+            .find(|other| other.backend == network.backend && other.chain_id == network.chain_id)
+        {
+            return Err(ConfigError::Message(format!(
+                "Networks `{}` and `{}` have the same backend and chain id",
+                other.id, network.id
+            )));
+        }
+
+        unique.push(network);
+    }
+
+    Ok(unique)
 }
 
 fn default_loglevel() -> String {
     String::from("info")
 }
 
+fn default_log_file_prefix() -> String {
+    String::from("explorer.log")
+}
+
+fn default_log_file_stdout() -> bool {
+    true
+}
+
 fn default_is_json_logging() -> bool {
     true
 }
 
-fn default_listener() -> String {
-    String::from("0.0.0.0:3000")
+fn default_listener() -> SocketAddr {
+    SocketAddr::from(([0, 0, 0, 0], 3000))
 }
 
 fn default_enable_metrics() -> bool {
     true
 }
 
+fn default_cache_max_entries() -> usize {
+    10_000
+}
+
+fn default_search_ttl() -> Duration {
+    Duration::from_secs(30)
+}
+
+fn default_graphql_ttl() -> Duration {
+    Duration::from_secs(5)
+}
+
+fn default_health_rpc_timeout() -> Duration {
+    Duration::from_secs(2)
+}
+
+fn default_indexer_halted_after() -> Duration {
+    Duration::from_secs(300)
+}
+
+fn default_max_indexer_lag() -> u64 {
+    100
+}
+
+fn default_confirmation_threshold() -> u64 {
+    1
+}
+
+fn default_report_rate_max_reports() -> usize {
+    1000
+}
+
+fn default_report_rate_window() -> Duration {
+    Duration::from_millis(3_600_000)
+}
+
 fn default_jwt_secret() -> SecretString {
     SecretString::new("my_ultra_secure_secret".to_string())
 }
@@ -5,7 +5,11 @@ use {
 };
 
 use super::query_utils::{AddressCondition, AddressFilter};
-use crate::entity::{case, reporter, types::Category, EntityFilter, FromPayload};
+use crate::entity::{
+    case, reporter,
+    types::{AddressStatus, Category},
+    EntityFilter, FromPayload,
+};
 
 // Note: risk and confirmations do not correspond to the types of contracts (due to Postgresql restrictions)
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
@@ -23,6 +27,16 @@ pub struct Model {
     pub confirmations: String,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub status: AddressStatus,
+    pub withdrawal_reason: Option<String>,
+    pub withdrawn_at: Option<DateTime>,
+}
+
+impl Entity {
+    /// Addresses that are not withdrawn
+    pub fn find_active() -> Select<Entity> {
+        Self::find().filter(Column::Status.eq(AddressStatus::Active))
+    }
 }
 
 impl EntityFilter for Entity {
@@ -57,9 +71,19 @@ impl EntityFilter for Entity {
             query = query.filter(Column::Confirmations.eq(confirmations));
         }
 
+        // Withdrawn reports are listed only on explicit request
+        let status = filter_options.status.unwrap_or(AddressStatus::Active);
+        query = query.filter(Column::Status.eq(status));
+
+        query = filter_options.created.filter(query, Column::CreatedAt);
+
         query
     }
 
+    fn validate(filter_options: &AddressFilter) -> Result<(), DbErr> {
+        filter_options.created.validate()
+    }
+
     /// Columns for search
     fn columns_for_search() -> Vec<String> {
         vec![
@@ -124,6 +148,10 @@ impl FromPayload<AddressPayload> for ActiveModel {
             confirmations: Set(payload.confirmations.to_string()),
             created_at,
             updated_at,
+            // Any event from the chain means that the report is in effect
+            status: Set(AddressStatus::Active),
+            withdrawal_reason: Set(None),
+            withdrawn_at: Set(None),
         }
     }
 }
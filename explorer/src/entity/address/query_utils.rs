@@ -4,7 +4,10 @@ use {
 };
 
 use super::model::Column;
-use crate::entity::types::Category;
+use crate::entity::{
+    pagination::TimeRange,
+    types::{AddressStatus, Category},
+};
 
 /// Conditions to filter address listings by
 #[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
@@ -15,6 +18,11 @@ pub struct AddressFilter {
     pub category: Option<Category>,
     pub risk: Option<u8>,
     pub confirmations: Option<String>,
+    /// Active addresses are listed by default
+    pub status: Option<AddressStatus>,
+    /// Time of the creating event
+    #[graphql(flatten)]
+    pub created: TimeRange,
 }
 
 /// Available ordering values for address
@@ -34,6 +42,8 @@ pub enum AddressCondition {
     Risk,
     /// Order by confirmation count
     Confirmations,
+    /// Order by status
+    Status,
     /// Order by the time when entity was created
     CreatedAt,
     /// Order by the time when entity was updated
@@ -51,6 +61,7 @@ impl From<AddressCondition> for Column {
             AddressCondition::Category => Column::Category,
             AddressCondition::Risk => Column::Risk,
             AddressCondition::Confirmations => Column::Confirmations,
+            AddressCondition::Status => Column::Status,
             AddressCondition::CreatedAt => Column::CreatedAt,
             AddressCondition::UpdatedAt => Column::UpdatedAt,
         }
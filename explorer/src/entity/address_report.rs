@@ -0,0 +1,39 @@
+use sea_orm::{entity::prelude::*, Set};
+
+use super::{address, types::Category};
+
+/// Classification of an address by one of its reporters
+///
+/// The address itself keeps the latest report only, while every reporter's last
+/// classification stays here.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "address_report")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub network_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub address: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub reporter_id: Uuid,
+    pub category: Category,
+    pub risk: i16,
+    pub reported_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<&address::Model> for ActiveModel {
+    fn from(address: &address::Model) -> Self {
+        Self {
+            network_id: Set(address.network_id.clone()),
+            address: Set(address.address.clone()),
+            reporter_id: Set(address.reporter_id),
+            category: Set(address.category),
+            risk: Set(address.risk),
+            reported_at: Set(address.updated_at),
+        }
+    }
+}
@@ -63,9 +63,15 @@ impl EntityFilter for Entity {
             query = query.filter(Column::Confirmations.eq(confirmations));
         }
 
+        query = filter_options.created.filter(query, Column::CreatedAt);
+
         query
     }
 
+    fn validate(filter_options: &AssetFilter) -> Result<(), DbErr> {
+        filter_options.created.validate()
+    }
+
     /// Columns for search
     fn columns_for_search() -> Vec<String> {
         vec![
@@ -4,7 +4,7 @@ use {
 };
 
 use super::model::Column;
-use crate::entity::types::Category;
+use crate::entity::{pagination::TimeRange, types::Category};
 
 /// Conditions to filter address listings by
 #[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
@@ -16,6 +16,9 @@ pub struct AssetFilter {
     pub category: Option<Category>,
     pub risk: Option<u8>,
     pub confirmations: Option<String>,
+    /// Time of the creating event
+    #[graphql(flatten)]
+    pub created: TimeRange,
 }
 
 /// Available ordering values for asset
@@ -8,9 +8,9 @@ use {
 
 use super::query_utils::{CaseCondition, CaseFilter};
 use crate::entity::{
-    address, asset,
+    address, asset, case_relation,
     pagination::{order_by_column, Ordering},
-    reporter,
+    reporter, sanitize_name,
     types::CaseStatus,
     EntityFilter, FromPayload,
 };
@@ -59,9 +59,15 @@ impl EntityFilter for Entity {
             query = query.filter(Column::ReporterId.eq(reporter_id));
         }
 
+        query = filter_options.created.filter(query, Column::CreatedAt);
+
         query
     }
 
+    fn validate(filter_options: &CaseFilter) -> Result<(), DbErr> {
+        filter_options.created.validate()
+    }
+
     // Ordering query
     fn order(
         selected: Select<Entity>,
@@ -122,6 +128,8 @@ pub enum Relation {
     Address,
     #[sea_orm(has_many = "asset::Entity")]
     Asset,
+    #[sea_orm(has_many = "case_relation::Entity")]
+    CaseRelation,
 }
 
 impl Related<reporter::Entity> for Entity {
@@ -142,6 +150,12 @@ impl Related<asset::Entity> for Entity {
     }
 }
 
+impl Related<case_relation::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CaseRelation.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
 
 impl FromPayload<CasePayload> for ActiveModel {
@@ -157,7 +171,7 @@ impl FromPayload<CasePayload> for ActiveModel {
         Self {
             network_id: Set(network_id),
             id: Set(payload.id.to_owned()),
-            name: Set(payload.name.to_owned()),
+            name: Set(sanitize_name(&payload.name)),
             url: Set(payload.url.to_owned()),
             status: Set(payload.status.into()),
             reporter_id: Set(payload.reporter_id.to_owned()),
@@ -4,7 +4,7 @@ use {
 };
 
 use super::model::Column;
-use crate::entity::types::CaseStatus;
+use crate::entity::{pagination::TimeRange, types::CaseStatus};
 
 /// Conditions to filter address listings by
 #[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
@@ -14,6 +14,9 @@ pub struct CaseFilter {
     pub url: Option<String>,
     pub status: Option<CaseStatus>,
     pub reporter_id: Option<Uuid>,
+    /// Time of the creating event
+    #[graphql(flatten)]
+    pub created: TimeRange,
 }
 
 /// Available ordering values for asset
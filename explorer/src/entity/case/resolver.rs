@@ -49,4 +49,18 @@ impl CaseQuery {
 
         Ok(page)
     }
+
+    /// Get the cases linked to a case
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_related_cases(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Case id")] id: Uuid,
+        #[graphql(desc = "Case network")] network_id: String,
+    ) -> Result<Vec<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let cases = EntityQuery::find_related_cases(db, network_id, id).await?;
+
+        Ok(cases)
+    }
 }
@@ -0,0 +1,45 @@
+use sea_orm::{entity::prelude::*, Set};
+
+use super::case;
+
+/// Link between two cases of a network
+///
+/// Links are mutual, so each of them is kept for both of its cases.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "case_relation")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub network_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub case_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub related_case_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "case::Entity",
+        from = "Column::CaseId",
+        to = "case::model::Column::Id"
+    )]
+    Case,
+}
+
+impl Related<case::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Case.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl ActiveModel {
+    pub fn new(network_id: String, case_id: Uuid, related_case_id: Uuid) -> Self {
+        Self {
+            network_id: Set(network_id),
+            case_id: Set(case_id),
+            related_case_id: Set(related_case_id),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use {sea_orm::entity::prelude::*, serde::Serialize};
+
+/// Number of events of a kind processed for a network within an hour
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
+#[sea_orm(table_name = "event_count")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub network_id: String,
+    /// Event name, i.e. `confirm_address`
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub event: String,
+    /// Start of the hour the events were processed in
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub hour: DateTime,
+    pub count: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
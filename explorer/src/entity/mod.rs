@@ -1,15 +1,24 @@
 pub mod address;
+pub mod address_report;
 pub mod asset;
 pub mod case;
+pub mod case_relation;
+pub mod event_count;
 pub mod indexer;
 pub mod network;
 pub mod pagination;
 pub mod reporter;
+pub mod reporter_stake_change;
 pub mod statistics;
 pub mod types;
 
 use self::pagination::{order_by_column, Ordering};
-use sea_orm::{prelude::DateTime, EntityTrait, Select};
+use sea_orm::{prelude::DateTime, DbErr, EntityTrait, Select};
+
+/// Names decoded from fixed-size byte arrays may carry NUL padding, which Postgres text rejects
+pub(crate) fn sanitize_name(name: &str) -> String {
+    name.replace('\0', "")
+}
 
 pub trait FromPayload<T>: Sized {
     fn from(
@@ -27,6 +36,11 @@ pub trait EntityFilter: Sized + EntityTrait {
 
     fn filter(selected: Select<Self>, filter_options: &Self::Filter) -> Select<Self>;
 
+    /// Rejects filters that can't match anything, i.e. a reversed time range
+    fn validate(_filter_options: &Self::Filter) -> Result<(), DbErr> {
+        Ok(())
+    }
+
     fn columns_for_search() -> Vec<String>;
 
     fn order(
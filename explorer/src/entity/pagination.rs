@@ -1,6 +1,8 @@
 use {
     async_graphql::{Enum, InputObject, InputType, OutputType, SimpleObject},
-    sea_orm::{EntityTrait, QueryOrder, Select},
+    sea_orm::{
+        prelude::DateTime, ColumnTrait, DbErr, EntityTrait, QueryFilter, QueryOrder, Select,
+    },
 };
 
 use super::{
@@ -53,6 +55,46 @@ pub enum Ordering {
     Desc,
 }
 
+/// A convenience wrapper for filtering by the event time
+///
+/// Both ends are inclusive and either may be omitted for an open-ended range.
+#[derive(Clone, Copy, Eq, PartialEq, InputObject, Debug, Default)]
+pub struct TimeRange {
+    /// The earliest event time
+    pub from: Option<DateTime>,
+    /// The latest event time
+    pub to: Option<DateTime>,
+}
+
+impl TimeRange {
+    pub fn validate(&self) -> Result<(), DbErr> {
+        match (self.from, self.to) {
+            (Some(from), Some(to)) if from > to => Err(DbErr::Custom(
+                "Time range `from` must not be later than `to`".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Method for query filtering by a time column
+    pub fn filter<M>(&self, query: Select<M>, column: M::Column) -> Select<M>
+    where
+        M: EntityTrait,
+    {
+        let mut query = query;
+
+        if let Some(from) = self.from {
+            query = query.filter(column.gte(from));
+        }
+
+        if let Some(to) = self.to {
+            query = query.filter(column.lte(to));
+        }
+
+        query
+    }
+}
+
 /// Method for query ordering by column
 pub fn order_by_column<M, C>(
     query: Select<M>,
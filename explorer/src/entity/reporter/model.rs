@@ -6,7 +6,7 @@ use {
 
 use super::query_utils::{ReporterCondition, ReporterFilter};
 use crate::entity::{
-    address, asset, case,
+    address, asset, case, sanitize_name,
     types::{ReporterRole, ReporterStatus},
     EntityFilter, FromPayload,
 };
@@ -29,6 +29,8 @@ pub struct Model {
     pub unlock_timestamp: String,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    /// Block time when the report rate of the reporter exceeded the configured limit
+    pub flagged_at: Option<DateTime>,
 }
 
 impl EntityFilter for Entity {
@@ -63,9 +65,15 @@ impl EntityFilter for Entity {
             query = query.filter(Column::Url.eq(url));
         }
 
+        query = filter_options.created.filter(query, Column::CreatedAt);
+
         query
     }
 
+    fn validate(filter_options: &ReporterFilter) -> Result<(), DbErr> {
+        filter_options.created.validate()
+    }
+
     /// Columns for search
     fn columns_for_search() -> Vec<String> {
         vec![
@@ -130,12 +138,14 @@ impl FromPayload<ReporterPayload> for ActiveModel {
             account: Set(payload.account.to_owned()),
             role: Set(payload.role.into()),
             status: Set(payload.status.into()),
-            name: Set(payload.name.to_owned()),
+            name: Set(sanitize_name(&payload.name)),
             url: Set(payload.url.to_owned()),
             stake: Set(payload.stake.to_string()),
             unlock_timestamp: Set(payload.unlock_timestamp.to_string()),
             created_at,
             updated_at,
+            // Flags are set by the explorer, not by the events
+            flagged_at: NotSet,
         }
     }
 }
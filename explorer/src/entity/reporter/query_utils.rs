@@ -1,7 +1,10 @@
 use async_graphql::{Enum, InputObject};
 
 use super::model::Column;
-use crate::entity::types::{ReporterRole, ReporterStatus};
+use crate::entity::{
+    pagination::TimeRange,
+    types::{ReporterRole, ReporterStatus},
+};
 
 /// Conditions to filter address listings by
 #[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
@@ -12,6 +15,9 @@ pub struct ReporterFilter {
     pub status: Option<ReporterStatus>,
     pub name: Option<String>,
     pub url: Option<String>,
+    /// Time of the creating event
+    #[graphql(flatten)]
+    pub created: TimeRange,
 }
 
 /// Available ordering values for asset
@@ -0,0 +1,57 @@
+use {
+    sea_orm::{entity::prelude::*, NotSet, Set},
+    serde::Serialize,
+};
+
+use super::reporter;
+
+/// Change of a reporter stake, i.e. a deposit on activation or a release on unstaking
+///
+/// Stakes are kept as decimal strings, as they exceed the Postgres integer types.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
+#[sea_orm(table_name = "reporter_stake_change")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    #[serde(skip)]
+    pub id: i64,
+    pub network_id: String,
+    pub reporter_id: Uuid,
+    /// Name of the event that changed the stake, i.e. `activate_reporter`
+    pub event: String,
+    /// Signed difference to the previous balance
+    pub amount: String,
+    /// Stake after the change
+    pub balance: String,
+    pub changed_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl ActiveModel {
+    /// Change between the stakes of the reporter, none if the stake is the same
+    ///
+    /// Stakes that are not decimal numbers can't be compared, so they give no change as well.
+    pub fn between(previous: &str, reporter: &reporter::Model, event: String) -> Option<Self> {
+        let previous: u128 = previous.parse().ok()?;
+        let balance: u128 = reporter.stake.parse().ok()?;
+
+        let amount = match balance.cmp(&previous) {
+            std::cmp::Ordering::Equal => return None,
+            std::cmp::Ordering::Greater => (balance - previous).to_string(),
+            std::cmp::Ordering::Less => format!("-{}", previous - balance),
+        };
+
+        Some(Self {
+            id: NotSet,
+            network_id: Set(reporter.network_id.clone()),
+            reporter_id: Set(reporter.id),
+            event: Set(event),
+            amount: Set(amount),
+            balance: Set(reporter.stake.clone()),
+            changed_at: Set(reporter.updated_at),
+        })
+    }
+}
@@ -104,7 +104,7 @@ impl StatisticsQuery {
             labels.push(label);
 
             let addresses_count =
-                count_rows_per_week(db, address::Entity::find(), year, week).await?;
+                count_rows_per_week(db, address::Entity::find_active(), year, week).await?;
             addresses.push(addresses_count);
 
             let assets_count = count_rows_per_week(db, asset::Entity::find(), year, week).await?;
@@ -207,7 +207,7 @@ async fn get_address_dashboard(
     year: i32,
     week: u32,
 ) -> Result<(u64, u64, Vec<address::Model>)> {
-    let query = address::Entity::find();
+    let query = address::Entity::find_active();
 
     let total_addresses_count = query.clone().count(db).await?;
     let new_weekly_address_count = count_rows_per_week(db, query.clone(), year, week).await?;
@@ -16,7 +16,7 @@ use {
     std::{fmt, str::FromStr},
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Enum, Serialize)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "network_backend")]
 pub enum NetworkBackend {
     #[sea_orm(string_value = "evm")]
@@ -170,6 +170,56 @@ impl fmt::Display for Category {
     }
 }
 
+impl Category {
+    /// Human readable name of the category
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::WalletService => "Wallet service",
+            Self::MerchantService => "Merchant service",
+            Self::MiningPool => "Mining pool",
+            Self::Exchange => "Exchange",
+            Self::DeFi => "DeFi application",
+            Self::OTCBroker => "OTC broker",
+            Self::ATM => "Cryptocurrency ATM",
+            Self::Gambling => "Gambling",
+            Self::IllicitOrganization => "Illicit organization",
+            Self::Mixer => "Mixer",
+            Self::DarknetService => "Darknet market or service",
+            Self::Scam => "Scam",
+            Self::Ransomware => "Ransomware",
+            Self::Theft => "Theft",
+            Self::Counterfeit => "Counterfeit",
+            Self::TerroristFinancing => "Terrorist financing",
+            Self::Sanctions => "Sanctions",
+            Self::ChildAbuse => "Child abuse",
+            Self::Hacker => "Hacker",
+            Self::HighRiskJurisdiction => "High risk jurisdiction",
+        }
+    }
+
+    /// Risk tier of the category as the contracts group them, from 0 (none) to 4 (severe)
+    pub fn tier(&self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::WalletService | Self::MerchantService | Self::MiningPool => 1,
+            Self::Exchange | Self::DeFi | Self::OTCBroker | Self::ATM | Self::Gambling => 2,
+            Self::IllicitOrganization
+            | Self::Mixer
+            | Self::DarknetService
+            | Self::Scam
+            | Self::Ransomware
+            | Self::Theft
+            | Self::Counterfeit => 3,
+            Self::TerroristFinancing
+            | Self::Sanctions
+            | Self::ChildAbuse
+            | Self::Hacker
+            | Self::HighRiskJurisdiction => 4,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "reporter_role")]
 pub enum ReporterRole {
@@ -262,3 +312,21 @@ impl fmt::Display for CaseStatus {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "address_status")]
+pub enum AddressStatus {
+    #[sea_orm(string_value = "active")]
+    Active,
+    #[sea_orm(string_value = "withdrawn")]
+    Withdrawn,
+}
+
+impl fmt::Display for AddressStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressStatus::Active => write!(f, "active"),
+            AddressStatus::Withdrawn => write!(f, "withdrawn"),
+        }
+    }
+}
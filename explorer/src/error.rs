@@ -3,7 +3,11 @@ use {
         http::StatusCode,
         response::{IntoResponse, Response},
     },
-    std::fmt::Display,
+    std::{
+        fmt::{self, Display},
+        io,
+        net::SocketAddr,
+    },
 };
 
 #[derive(Debug)]
@@ -37,3 +41,37 @@ where
         Self::new(StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
     }
 }
+
+/// Failure to start the explorer server
+#[derive(Debug)]
+pub enum StartupError {
+    /// The listener address can't be bound
+    Bind {
+        address: SocketAddr,
+        source: io::Error,
+    },
+}
+
+impl Display for StartupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StartupError::Bind { address, source } if source.kind() == io::ErrorKind::AddrInUse => {
+                write!(
+                    f,
+                    "Failed to bind to {address}: {source}. The port is already in use, stop the other process or change `listener` in the configuration"
+                )
+            }
+            StartupError::Bind { address, source } => {
+                write!(f, "Failed to bind to {address}: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StartupError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StartupError::Bind { source, .. } => Some(source),
+        }
+    }
+}
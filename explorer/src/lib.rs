@@ -1,8 +1,12 @@
 pub mod application;
+pub mod cache;
+pub mod chain_head;
 pub mod configuration;
 pub mod entity;
 pub mod error;
 pub mod migrations;
 pub mod observability;
+pub mod report_rate;
 pub mod server;
 pub mod service;
+pub mod verify;
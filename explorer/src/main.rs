@@ -1,12 +1,12 @@
 use {
     anyhow::Result,
     clap::{command, Parser, Subcommand},
+    hapi_core::HapiCoreNetwork,
     hapi_explorer::{
         application::Application, configuration::get_configuration, entity::types::NetworkBackend,
-        observability::setup_tracing,
+        observability::setup_tracing, verify::VerifyOptions,
     },
     sea_orm_cli::MigrateSubcommands,
-    tokio::net::TcpListener,
 };
 
 #[derive(Subcommand, PartialEq, Eq, Debug, Clone)]
@@ -47,6 +47,21 @@ pub enum NetworkSubcommands {
     },
 }
 
+#[derive(Subcommand, PartialEq, Eq, Debug, Clone)]
+pub enum AddressSubcommands {
+    #[command(about = "Withdraw address report, it is kept for audit but hidden from listings")]
+    Withdraw {
+        #[arg(long, help = "Network string identifier")]
+        network_id: String,
+
+        #[arg(long, help = "Reported address")]
+        address: String,
+
+        #[arg(long, help = "Reason of the withdrawal")]
+        reason: String,
+    },
+}
+
 #[derive(Parser)]
 enum ExplorerCli {
     #[command(about = "Run explorer server")]
@@ -56,6 +71,11 @@ enum ExplorerCli {
         #[command(subcommand)]
         subcommand: NetworkSubcommands,
     },
+    #[command(about = "Run commands related to addresses")]
+    Address {
+        #[command(subcommand)]
+        subcommand: AddressSubcommands,
+    },
     #[command(about = "Run migrations")]
     Migrate {
         #[command(subcommand)]
@@ -67,20 +87,59 @@ enum ExplorerCli {
         #[arg(long, default_value = None, help = "Network chain id (optional)")]
         chain_id: Option<String>,
     },
+    #[command(about = "Compare stored entities to the contract state, print mismatches as JSON")]
+    Verify {
+        #[arg(long, help = "Network string identifier")]
+        network_id: String,
+
+        #[arg(long, help = "Network of the contract, i.e. ethereum or solana")]
+        network: HapiCoreNetwork,
+
+        #[arg(long, help = "HAPI Core contract address")]
+        contract_address: String,
+
+        #[arg(
+            long,
+            default_value = None,
+            help = "RPC url, the one of the network configuration by default"
+        )]
+        rpc_url: Option<String>,
+
+        #[arg(long, default_value_t = 4, help = "The number of batches read at once")]
+        concurrency: usize,
+
+        #[arg(
+            long,
+            default_value_t = 100,
+            help = "The number of entities read per request"
+        )]
+        batch_size: u64,
+
+        #[arg(
+            long,
+            default_value_t = 1.0,
+            help = "Share of the batches to check, up to 1"
+        )]
+        sample_rate: f64,
+    },
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let configuration = get_configuration()?;
-    setup_tracing(&configuration.log_level, configuration.is_json_logging)?;
+    setup_tracing(
+        &configuration.log_level,
+        configuration.is_json_logging,
+        configuration.log_file.as_ref(),
+    )?;
 
     let mut app = Application::from_configuration(&configuration).await?;
 
     match ExplorerCli::parse() {
         ExplorerCli::Server => {
-            let listener = TcpListener::bind(&configuration.listener)
-                .await?
-                .local_addr()?;
+            app.register_networks(&configuration.networks).await?;
+
+            let listener = Application::bind(configuration.listener)?;
 
             app.run_server(listener, &configuration.cors_origins)
                 .await?;
@@ -107,9 +166,46 @@ async fn main() -> Result<()> {
                 authority,
             } => app.update_network(id, name, authority, stake_token).await,
         },
+        ExplorerCli::Address { subcommand } => match subcommand {
+            AddressSubcommands::Withdraw {
+                network_id,
+                address,
+                reason,
+            } => app.withdraw_address(network_id, address, reason).await,
+        },
         ExplorerCli::CreateIndexer { backend, chain_id } => {
             app.create_indexer(backend, chain_id).await?;
 
+            Ok(())
+        }
+        ExplorerCli::Verify {
+            network_id,
+            network,
+            contract_address,
+            rpc_url,
+            concurrency,
+            batch_size,
+            sample_rate,
+        } => {
+            let options = VerifyOptions {
+                concurrency,
+                batch_size,
+                sample_rate,
+            };
+
+            let report = app
+                .verify(
+                    &configuration.networks,
+                    network_id,
+                    network,
+                    contract_address,
+                    rpc_url,
+                    options,
+                )
+                .await?;
+
+            println!("{}", serde_json::to_string_pretty(&report)?);
+
             Ok(())
         }
     }?;
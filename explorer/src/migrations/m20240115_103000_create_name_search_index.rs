@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Trigram indexes allow case-insensitive substring search (ILIKE '%...%') without full scans
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("CREATE EXTENSION IF NOT EXISTS pg_trgm")
+            .await?;
+
+        db.execute_unprepared(
+            r#"CREATE INDEX IF NOT EXISTS "idx-reporter_name_trgm" ON "reporter" USING GIN ("name" gin_trgm_ops)"#,
+        )
+        .await?;
+
+        db.execute_unprepared(
+            r#"CREATE INDEX IF NOT EXISTS "idx-case_name_trgm" ON "case" USING GIN ("name" gin_trgm_ops)"#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(r#"DROP INDEX IF EXISTS "idx-reporter_name_trgm""#)
+            .await?;
+
+        db.execute_unprepared(r#"DROP INDEX IF EXISTS "idx-case_name_trgm""#)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use {
+    sea_orm::{EnumIter, Iterable},
+    sea_orm_migration::{prelude::*, sea_query::extension::postgres::Type},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Withdrawn addresses are kept for audit, so the reports are marked instead of deleted
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(AddressStatus::Type)
+                    .values(AddressStatus::iter().skip(1))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Address::Table)
+                    .add_column(
+                        ColumnDef::new(Address::Status)
+                            .enumeration(AddressStatus::Type, AddressStatus::iter().skip(1))
+                            .not_null()
+                            .default("active"),
+                    )
+                    .add_column(ColumnDef::new(Address::WithdrawalReason).string().null())
+                    .add_column(ColumnDef::new(Address::WithdrawnAt).timestamp().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Address::Table)
+                    .drop_column(Address::Status)
+                    .drop_column(Address::WithdrawalReason)
+                    .drop_column(Address::WithdrawnAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(AddressStatus::Type).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden, EnumIter)]
+pub enum AddressStatus {
+    #[iden = "address_status"]
+    Type,
+    Active,
+    Withdrawn,
+}
+
+#[derive(DeriveIden)]
+enum Address {
+    Table,
+    Status,
+    WithdrawalReason,
+    WithdrawnAt,
+}
@@ -0,0 +1,106 @@
+use super::{Address, Category, Reporter};
+use {sea_orm::Iterable, sea_orm_migration::prelude::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// The address table keeps the latest report only, so every reporter's classification
+// is kept separately. Existing addresses give the reports of their current reporters.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AddressReport::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(AddressReport::NetworkId).string().not_null())
+                    .col(ColumnDef::new(AddressReport::Address).string().not_null())
+                    .col(ColumnDef::new(AddressReport::ReporterId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(AddressReport::Category)
+                            .enumeration(Category::Type, Category::iter().skip(1))
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AddressReport::Risk)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AddressReport::ReportedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("address_report_id")
+                            .col(AddressReport::NetworkId)
+                            .col(AddressReport::Address)
+                            .col(AddressReport::ReporterId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-address_report_address")
+                            .from(
+                                AddressReport::Table,
+                                (AddressReport::NetworkId, AddressReport::Address),
+                            )
+                            .to(Address::Table, (Address::NetworkId, Address::Address))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-address_report_reporter_id")
+                            .from(
+                                AddressReport::Table,
+                                (AddressReport::NetworkId, AddressReport::ReporterId),
+                            )
+                            .to(Reporter::Table, (Reporter::NetworkId, Reporter::Id))
+                            .on_delete(ForeignKeyAction::NoAction)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-address_report_address")
+                    .table(AddressReport::Table)
+                    .col(AddressReport::Address)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"INSERT INTO address_report (network_id, address, reporter_id, category, risk, reported_at)
+                SELECT network_id, address, reporter_id, category, risk, updated_at FROM address"#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AddressReport::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AddressReport {
+    // Composite key: network_id + address + reporter_id
+    Table,
+    NetworkId,
+    Address,
+    ReporterId,
+    Category,
+    Risk,
+    ReportedAt,
+}
@@ -0,0 +1,34 @@
+use super::Reporter;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Reporter listings are filtered by role alone or together with the status
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-reporter_role_status")
+                    .table(Reporter::Table)
+                    .col(Reporter::Role)
+                    .col(Reporter::Status)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-reporter_role_status")
+                    .table(Reporter::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,101 @@
+use super::Reporter;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// The reporter table keeps the current stake only, every change of it is kept separately.
+// Stakes are not backfilled, as the earlier balances are unknown.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReporterStakeChange::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReporterStakeChange::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ReporterStakeChange::NetworkId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ReporterStakeChange::ReporterId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ReporterStakeChange::Event)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ReporterStakeChange::Amount)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ReporterStakeChange::Balance)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ReporterStakeChange::ChangedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-reporter_stake_change_reporter_id")
+                            .from(
+                                ReporterStakeChange::Table,
+                                (
+                                    ReporterStakeChange::NetworkId,
+                                    ReporterStakeChange::ReporterId,
+                                ),
+                            )
+                            .to(Reporter::Table, (Reporter::NetworkId, Reporter::Id))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-reporter_stake_change_reporter")
+                    .table(ReporterStakeChange::Table)
+                    .col(ReporterStakeChange::ReporterId)
+                    .col(ReporterStakeChange::ChangedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReporterStakeChange::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ReporterStakeChange {
+    Table,
+    Id,
+    NetworkId,
+    ReporterId,
+    Event,
+    Amount,
+    Balance,
+    ChangedAt,
+}
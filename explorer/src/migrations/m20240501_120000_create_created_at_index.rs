@@ -0,0 +1,61 @@
+use super::{Address, Asset, Case, Reporter};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Listings are filtered by the time of the creating event
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for (name, table, column) in indexes() {
+            manager
+                .create_index(
+                    Index::create()
+                        .if_not_exists()
+                        .name(name)
+                        .table(table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for (name, table, _) in indexes() {
+            manager
+                .drop_index(Index::drop().name(name).table(table).to_owned())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn indexes() -> Vec<(&'static str, DynIden, DynIden)> {
+    vec![
+        (
+            "idx-address_created_at",
+            Address::Table.into_iden(),
+            Address::CreatedAt.into_iden(),
+        ),
+        (
+            "idx-asset_created_at",
+            Asset::Table.into_iden(),
+            Asset::CreatedAt.into_iden(),
+        ),
+        (
+            "idx-case_created_at",
+            Case::Table.into_iden(),
+            Case::CreatedAt.into_iden(),
+        ),
+        (
+            "idx-reporter_created_at",
+            Reporter::Table.into_iden(),
+            Reporter::CreatedAt.into_iden(),
+        ),
+    ]
+}
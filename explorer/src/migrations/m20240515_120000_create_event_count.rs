@@ -0,0 +1,70 @@
+use super::Network;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Events are counted by the hour they are processed in, per network and event name.
+// Counts are not backfilled, as the explorer keeps entities rather than events.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventCount::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(EventCount::NetworkId).string().not_null())
+                    .col(ColumnDef::new(EventCount::Event).string().not_null())
+                    .col(ColumnDef::new(EventCount::Hour).timestamp().not_null())
+                    .col(
+                        ColumnDef::new(EventCount::Count)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("event_count_id")
+                            .col(EventCount::NetworkId)
+                            .col(EventCount::Event)
+                            .col(EventCount::Hour),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-event_count_network_id")
+                            .from(EventCount::Table, EventCount::NetworkId)
+                            .to(Network::Table, Network::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-event_count_hour")
+                    .table(EventCount::Table)
+                    .col(EventCount::Hour)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EventCount::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum EventCount {
+    Table,
+    NetworkId,
+    Event,
+    Hour,
+    Count,
+}
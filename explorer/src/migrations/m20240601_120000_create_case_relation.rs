@@ -0,0 +1,72 @@
+use super::Case;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Case links are mutual, so a link is kept as a row for each of its cases.
+// Links were not indexed before, they are filled by the next case updates.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CaseRelation::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(CaseRelation::NetworkId).string().not_null())
+                    .col(ColumnDef::new(CaseRelation::CaseId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(CaseRelation::RelatedCaseId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("case_relation_id")
+                            .col(CaseRelation::NetworkId)
+                            .col(CaseRelation::CaseId)
+                            .col(CaseRelation::RelatedCaseId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-case_relation_case_id")
+                            .from(
+                                CaseRelation::Table,
+                                (CaseRelation::NetworkId, CaseRelation::CaseId),
+                            )
+                            .to(Case::Table, (Case::NetworkId, Case::Id))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-case_relation_related_case_id")
+                            .from(
+                                CaseRelation::Table,
+                                (CaseRelation::NetworkId, CaseRelation::RelatedCaseId),
+                            )
+                            .to(Case::Table, (Case::NetworkId, Case::Id))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CaseRelation::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CaseRelation {
+    // Composite key: network_id + case_id + related_case_id
+    Table,
+    NetworkId,
+    CaseId,
+    RelatedCaseId,
+}
@@ -9,6 +9,15 @@ mod m20231127_170630_create_case_status_type;
 mod m20231205_131413_create_indexer;
 mod m20231205_131413_create_network;
 mod m20231211_164133_create_network_backend;
+mod m20240115_103000_create_name_search_index;
+mod m20240201_120000_add_address_withdrawal;
+mod m20240301_120000_create_address_report;
+mod m20240315_120000_create_reporter_role_index;
+mod m20240401_120000_create_reporter_stake_change;
+mod m20240415_120000_add_reporter_flagged_at;
+mod m20240501_120000_create_created_at_index;
+mod m20240515_120000_create_event_count;
+mod m20240601_120000_create_case_relation;
 
 pub(super) use m20231127_162603_create_category_type::Category;
 pub(super) use m20231127_165849_create_reporter_role_type::ReporterRole;
@@ -17,6 +26,8 @@ pub(super) use m20231127_170630_create_case_status_type::CaseStatus;
 pub(super) use m20231205_131413_create_network::Network;
 pub(super) use m20231211_164133_create_network_backend::NetworkBackend;
 
+pub(super) use m20231127_140636_create_address::Address;
+pub(super) use m20231127_160838_create_asset::Asset;
 pub(super) use m20231127_161317_create_reporter::Reporter;
 pub(super) use m20231127_162130_create_case::Case;
 
@@ -39,6 +50,15 @@ impl MigratorTrait for Migrator {
             Box::new(m20231127_162130_create_case::Migration),
             Box::new(m20231127_140636_create_address::Migration),
             Box::new(m20231127_160838_create_asset::Migration),
+            Box::new(m20240115_103000_create_name_search_index::Migration),
+            Box::new(m20240201_120000_add_address_withdrawal::Migration),
+            Box::new(m20240301_120000_create_address_report::Migration),
+            Box::new(m20240315_120000_create_reporter_role_index::Migration),
+            Box::new(m20240401_120000_create_reporter_stake_change::Migration),
+            Box::new(m20240415_120000_add_reporter_flagged_at::Migration),
+            Box::new(m20240501_120000_create_created_at_index::Migration),
+            Box::new(m20240515_120000_create_event_count::Migration),
+            Box::new(m20240601_120000_create_case_relation::Migration),
         ]
     }
 }
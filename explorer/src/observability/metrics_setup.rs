@@ -15,6 +15,8 @@ use crate::{
 
 const REQUEST_DURATION_METRIC: &str = "http_requests_duration_seconds";
 const REQUEST_DURATION_TOTAL: &str = "http_requests_total";
+const CACHE_HITS_TOTAL: &str = "cache_hits_total";
+const CACHE_MISSES_TOTAL: &str = "cache_misses_total";
 
 const REPORTER_METRIC: &str = "reporter";
 const CASE_METRIC: &str = "case";
@@ -68,6 +70,17 @@ pub(crate) async fn track_metrics<B>(req: Request<B>, next: Next<B>) -> impl Int
     response
 }
 
+/// Counts response cache lookups, hit ratio is `hits / (hits + misses)` per endpoint
+pub(crate) fn track_cache_lookup(endpoint: &'static str, hit: bool) {
+    let labels = [("endpoint", endpoint)];
+
+    if hit {
+        metrics::increment_counter!(CACHE_HITS_TOTAL, &labels);
+    } else {
+        metrics::increment_counter!(CACHE_MISSES_TOTAL, &labels);
+    }
+}
+
 /// Gauge metric operation
 pub enum MetricOp {
     Increment,
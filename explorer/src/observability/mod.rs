@@ -2,7 +2,7 @@ mod metrics_setup;
 mod tracing_setup;
 
 pub(crate) use metrics_setup::{
-    setup_metrics, track_metrics, update_address_metrics, update_asset_metrics,
+    setup_metrics, track_cache_lookup, track_metrics, update_address_metrics, update_asset_metrics,
     update_case_metrics, update_network_metrics, update_reporter_metrics, MetricOp,
 };
-pub use tracing_setup::setup_tracing;
+pub use tracing_setup::{build_subscriber, setup_tracing};
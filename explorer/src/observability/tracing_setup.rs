@@ -1,23 +1,78 @@
 use {
     anyhow::{anyhow, Result},
-    tracing::subscriber,
-    tracing_subscriber::{fmt::Subscriber, EnvFilter},
+    tracing::{subscriber, Subscriber},
+    tracing_appender::rolling::{RollingFileAppender, Rotation},
+    tracing_subscriber::{
+        fmt::{
+            self,
+            writer::{BoxMakeWriter, MakeWriterExt},
+        },
+        EnvFilter,
+    },
 };
 
-pub fn setup_tracing(log_level: &str, is_json_logging: bool) -> Result<()> {
-    let builder = Subscriber::builder()
+use crate::configuration::{LogFileConfiguration, LogRotation};
+
+pub fn setup_tracing(
+    log_level: &str,
+    is_json_logging: bool,
+    log_file: Option<&LogFileConfiguration>,
+) -> Result<()> {
+    subscriber::set_global_default(build_subscriber(log_level, is_json_logging, log_file)?)
+        .map_err(|e| anyhow!("Failed to set up tracing subscriber: {:?}", e))
+}
+
+/// Subscriber writing to stdout, to the rolling log files or to both
+pub fn build_subscriber(
+    log_level: &str,
+    is_json_logging: bool,
+    log_file: Option<&LogFileConfiguration>,
+) -> Result<Box<dyn Subscriber + Send + Sync>> {
+    let writer = match log_file {
+        None => BoxMakeWriter::new(std::io::stdout),
+        Some(log_file) if log_file.stdout => {
+            BoxMakeWriter::new(std::io::stdout.and(file_appender(log_file)?))
+        }
+        Some(log_file) => BoxMakeWriter::new(file_appender(log_file)?),
+    };
+
+    let builder = fmt::Subscriber::builder()
         .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| {
             format!("{}={log_level}", to_snake_case(env!("CARGO_PKG_NAME"))).into()
         }))
-        .with_writer(std::io::stdout);
+        // Color codes would end up in the files
+        .with_ansi(log_file.is_none())
+        .with_writer(writer);
 
-    let result = if is_json_logging {
-        subscriber::set_global_default(builder.json().flatten_event(true).finish())
+    Ok(if is_json_logging {
+        Box::new(builder.json().flatten_event(true).finish())
     } else {
-        subscriber::set_global_default(builder.finish())
+        Box::new(builder.finish())
+    })
+}
+
+fn file_appender(log_file: &LogFileConfiguration) -> Result<RollingFileAppender> {
+    let rotation = match log_file.rotation {
+        LogRotation::Minutely => Rotation::MINUTELY,
+        LogRotation::Hourly => Rotation::HOURLY,
+        LogRotation::Daily => Rotation::DAILY,
+        LogRotation::Never => Rotation::NEVER,
     };
 
-    result.map_err(|e| anyhow!("Failed to set up tracing subscriber: {:?}", e))
+    let mut builder = RollingFileAppender::builder()
+        .rotation(rotation)
+        .filename_prefix(&log_file.prefix);
+
+    if let Some(max_files) = log_file.max_files {
+        builder = builder.max_log_files(max_files);
+    }
+
+    builder.build(&log_file.directory).map_err(|e| {
+        anyhow!(
+            "Failed to open log file in {}: {e}",
+            log_file.directory.display()
+        )
+    })
 }
 
 fn to_snake_case(s: &str) -> String {
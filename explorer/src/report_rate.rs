@@ -0,0 +1,124 @@
+use {
+    hapi_core::client::events::EventName,
+    hapi_indexer::{PushData, PushPayload},
+    std::{
+        collections::{BTreeSet, HashMap},
+        sync::{Arc, Mutex},
+    },
+    uuid::Uuid,
+};
+
+use crate::configuration::ReportRateConfiguration;
+
+/// Reporter of a network as the indexers identify it: backend, chain id and reporter id
+type ReporterKey = (String, Option<String>, Uuid);
+
+/// Report as the block timestamp, transaction hash and index of its event
+type Report = (u64, String, u64);
+
+#[derive(Default)]
+struct ReporterReports {
+    /// Reports within the window before the latest one, ordered by block time
+    reports: BTreeSet<Report>,
+    flagged: bool,
+}
+
+/// Counts the reports of every reporter within a sliding window of block time
+///
+/// The window follows the block timestamps of the events rather than their arrival, so an
+/// indexer catching up with a backlog doesn't make its reporters look anomalous. Events are
+/// counted once, however many times they are delivered.
+#[derive(Clone)]
+pub struct ReportRateTracker {
+    configuration: ReportRateConfiguration,
+    reporters: Arc<Mutex<HashMap<ReporterKey, ReporterReports>>>,
+}
+
+impl ReportRateTracker {
+    pub fn new(configuration: ReportRateConfiguration) -> Self {
+        Self {
+            configuration,
+            reporters: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Counts the report of a processed payload, returns its reporter while the reports exceed the limit
+    ///
+    /// The reporter is returned until it is marked as flagged, so a flag that failed to be
+    /// stored is tried again with the next report.
+    pub fn record(&self, payload: &PushPayload) -> Option<Uuid> {
+        if self.configuration.max_reports == 0 || payload.event.removed {
+            return None;
+        }
+
+        let (key, reporter_id) = reporter_key(payload)?;
+        let window = self.configuration.window_ms.as_secs().max(1);
+        let report = (
+            payload.event.timestamp,
+            payload.event.tx_hash.clone(),
+            payload.event.tx_index,
+        );
+
+        let mut reporters = self.reporters.lock().expect("report rate lock is poisoned");
+        let reports = reporters.entry(key).or_default();
+
+        if reports.flagged {
+            return None;
+        }
+
+        reports.reports.insert(report);
+
+        // Reports that fell out of the window before the latest one, whatever order they came in
+        let latest = reports
+            .reports
+            .last()
+            .map_or(0, |(timestamp, _, _)| *timestamp);
+        while reports
+            .reports
+            .first()
+            .is_some_and(|(timestamp, _, _)| timestamp + window <= latest)
+        {
+            reports.reports.pop_first();
+        }
+
+        if reports.reports.len() <= self.configuration.max_reports {
+            return None;
+        }
+
+        Some(reporter_id)
+    }
+
+    /// Stops counting the reports of the payload reporter, once its flag is stored
+    pub fn mark_flagged(&self, payload: &PushPayload) {
+        let Some((key, _)) = reporter_key(payload) else {
+            return;
+        };
+
+        let mut reporters = self.reporters.lock().expect("report rate lock is poisoned");
+        let reports = reporters.entry(key).or_default();
+
+        reports.flagged = true;
+        reports.reports.clear();
+    }
+}
+
+fn reporter_key(payload: &PushPayload) -> Option<(ReporterKey, Uuid)> {
+    let reporter_id = match (&payload.event.name, &payload.data) {
+        (EventName::CreateAddress | EventName::UpdateAddress, PushData::Address(address)) => {
+            address.reporter_id
+        }
+        (EventName::CreateAsset | EventName::UpdateAsset, PushData::Asset(asset)) => {
+            asset.reporter_id
+        }
+        (EventName::CreateCase | EventName::UpdateCase, PushData::Case(case)) => case.reporter_id,
+        _ => return None,
+    };
+
+    let key = (
+        payload.network_data.network.to_string(),
+        payload.network_data.chain_id.clone(),
+        reporter_id,
+    );
+
+    Some((key, reporter_id))
+}
@@ -6,7 +6,10 @@ use {
         routing::{get, post, put},
         Extension, Router, Server,
     },
-    std::{future::ready, net::SocketAddr},
+    std::{
+        future::ready,
+        net::{SocketAddr, TcpListener},
+    },
     tokio::{signal, sync::oneshot},
     tower_http::cors::{AllowOrigin, Any, CorsLayer},
     tracing::info,
@@ -14,14 +17,19 @@ use {
 
 use super::{
     handlers::{
-        auth_handler, event_handler, graphiql_playground, graphql_handler, health_handler,
-        indexer_handler, indexer_heartbeat_handler, stats_handler,
+        address_distribution_handler, asset_handler, auth_handler, bulk_event_handler,
+        case_confirmations_handler, categories_handler, cross_case_handler, event_handler,
+        freshness_handler, graphiql_playground, graphql_handler, health_handler, indexer_handler,
+        indexer_heartbeat_handler, network_status_handler, recent_handler,
+        reporter_activity_handler, reporter_networks_handler, reporter_stake_history_handler,
+        search_handler, stats_handler,
     },
     schema::create_graphql_schema,
 };
 
 use crate::{
     application::Application,
+    error::StartupError,
     observability::{setup_metrics, track_metrics},
 };
 
@@ -42,7 +50,7 @@ impl Application {
     }
 
     async fn create_router(&self, origins: &Option<Vec<String>>) -> Result<Router> {
-        let schema = create_graphql_schema(self.state.database_conn.clone())?;
+        let schema = create_graphql_schema(self.state.read_conn.clone())?;
         let cors = self.create_cors_layer(origins)?;
 
         let router = Router::new()
@@ -54,7 +62,35 @@ impl Application {
                     auth_handler,
                 )),
             )
+            .route(
+                "/events/bulk",
+                post(bulk_event_handler).route_layer(middleware::from_fn_with_state(
+                    self.state.clone(),
+                    auth_handler,
+                )),
+            )
             .route("/stats", get(stats_handler))
+            .route("/search", get(search_handler))
+            .route("/asset", get(asset_handler))
+            .route("/categories", get(categories_handler))
+            .route(
+                "/case/:network/:id/confirmations",
+                get(case_confirmations_handler),
+            )
+            .route("/addresses/cross-case", get(cross_case_handler))
+            .route(
+                "/addresses/:address/distribution",
+                get(address_distribution_handler),
+            )
+            .route("/reporter/:id/activity", get(reporter_activity_handler))
+            .route("/reporter/:id/networks", get(reporter_networks_handler))
+            .route(
+                "/reporter/:id/stake-history",
+                get(reporter_stake_history_handler),
+            )
+            .route("/freshness", get(freshness_handler))
+            .route("/recent", get(recent_handler))
+            .route("/networks/status", get(network_status_handler))
             .route("/graphql", get(graphiql_playground).post(graphql_handler))
             .route("/indexer", get(indexer_handler))
             .route("/indexer/:id/heartbeat", put(indexer_heartbeat_handler))
@@ -75,25 +111,33 @@ impl Application {
         Ok(router)
     }
 
+    /// Binds the listener address, so that a taken port is reported before the server starts
+    pub fn bind(address: SocketAddr) -> Result<TcpListener, StartupError> {
+        TcpListener::bind(address).map_err(|source| StartupError::Bind { address, source })
+    }
+
     pub async fn run_server(
         &mut self,
-        listener: SocketAddr,
+        listener: TcpListener,
         origins: &Option<Vec<String>>,
     ) -> Result<()> {
-        self.socket = Some(listener);
-
-        self.start_server(origins).await?;
+        self.start_server(listener, origins).await?;
         self.handle_shutdown_signal().await
     }
 
-    pub async fn start_server(&mut self, origins: &Option<Vec<String>>) -> Result<()> {
+    pub async fn start_server(
+        &mut self,
+        listener: TcpListener,
+        origins: &Option<Vec<String>>,
+    ) -> Result<()> {
+        self.socket = Some(listener.local_addr()?);
         tracing::info!(address = ?self.socket, "Start server");
 
         let (tx, rx) = oneshot::channel::<()>();
         self.shutdown_sender = Some(tx);
 
         let router = self.create_router(origins).await?.into_make_service();
-        let server = Server::bind(&self.socket.ok_or_else(|| anyhow!("Socket not set"))?)
+        let server = Server::from_tcp(listener)?
             .serve(router)
             .with_graceful_shutdown(async {
                 rx.await.ok();
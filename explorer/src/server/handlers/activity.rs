@@ -0,0 +1,165 @@
+use {
+    axum::{
+        extract::{Path, Query, State},
+        http::{HeaderMap, StatusCode},
+        response::{IntoResponse, Response},
+        Json,
+    },
+    sea_orm::{
+        prelude::DateTime, ColumnTrait, DbBackend, EntityTrait, FromQueryResult, PaginatorTrait,
+        QueryFilter, Statement,
+    },
+    serde::{Deserialize, Serialize},
+    uuid::Uuid,
+};
+
+use super::ndjson::{accepts_ndjson, ndjson_response};
+use crate::{
+    application::AppState,
+    entity::{pagination::DEFAULT_PAGE_SIZE, reporter},
+    error::AppError,
+};
+
+const MAX_ACTIVITY_PAGE_SIZE: u64 = 100;
+
+/// Entities keep their creation and last update time only, so every entity gives
+/// a creation entry and, if it was changed afterwards, an entry of the latest update
+const ACTIVITY_QUERY: &str = r#"
+    SELECT network_id, 'case' AS entity, id::text AS id, NULL AS asset_id,
+        'create_case' AS event, created_at AS "timestamp"
+    FROM "case" WHERE reporter_id = $1
+    UNION ALL
+    SELECT network_id, 'case', id::text, NULL, 'update_case', updated_at
+    FROM "case" WHERE reporter_id = $1 AND updated_at > created_at
+    UNION ALL
+    SELECT network_id, 'address', address, NULL, 'create_address', created_at
+    FROM address WHERE reporter_id = $1
+    UNION ALL
+    SELECT network_id, 'address', address, NULL, 'update_address', updated_at
+    FROM address WHERE reporter_id = $1 AND updated_at > created_at
+    UNION ALL
+    SELECT network_id, 'asset', address, id, 'create_asset', created_at
+    FROM asset WHERE reporter_id = $1
+    UNION ALL
+    SELECT network_id, 'asset', address, id, 'update_asset', updated_at
+    FROM asset WHERE reporter_id = $1 AND updated_at > created_at
+"#;
+
+const ACTIVITY_ORDER: &str = r#""timestamp", entity, network_id, id, asset_id, event"#;
+
+#[derive(Deserialize)]
+pub struct ActivityParams {
+    page: Option<u64>,
+    page_size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, FromQueryResult)]
+struct ActivityEntry {
+    network_id: String,
+    /// Entity type: `case`, `address` or `asset`
+    entity: String,
+    /// Case id or the address
+    id: String,
+    asset_id: Option<String>,
+    /// Name of the event, i.e. `create_address`
+    event: String,
+    timestamp: DateTime,
+}
+
+#[derive(FromQueryResult)]
+struct ActivityCount {
+    total: i64,
+}
+
+/// Handle requests for the cases, addresses and assets of a reporter in time order
+///
+/// A reporter id may be registered on several networks, entries of all of them are
+/// listed. Confirmations are not attributed to the confirming reporter, as the stored
+/// entities keep the reporter that created them.
+///
+/// With `Accept: application/x-ndjson` every entry is streamed, one per line, without pages.
+pub(crate) async fn reporter_activity_handler(
+    state: State<AppState>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    params: Query<ActivityParams>,
+) -> Result<Response, AppError> {
+    tracing::info!(%id, "Received reporter activity request");
+    let db = &state.read_conn;
+
+    let page = params.page.unwrap_or(1);
+    let page_size = params.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+
+    if page == 0 {
+        return Err(AppError::invalid_request("Page numbers start from 1"));
+    }
+
+    if page_size == 0 || page_size > MAX_ACTIVITY_PAGE_SIZE {
+        return Err(AppError::invalid_request(&format!(
+            "Page size must be between 1 and {MAX_ACTIVITY_PAGE_SIZE}"
+        )));
+    }
+
+    let reporter_count = reporter::Entity::find()
+        .filter(reporter::Column::Id.eq(id))
+        .count(db)
+        .await?;
+
+    if reporter_count == 0 {
+        return Err(AppError::new(
+            StatusCode::NOT_FOUND,
+            "This reporter does not exist".to_string(),
+        ));
+    }
+
+    if accepts_ndjson(&headers) {
+        let (sender, response) = ndjson_response();
+        let db = state.read_conn.clone();
+
+        tokio::spawn(async move {
+            let rows = ActivityEntry::find_by_statement(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                format!("{ACTIVITY_QUERY} ORDER BY {ACTIVITY_ORDER}"),
+                [id.into()],
+            ))
+            .stream(&db)
+            .await;
+
+            sender.forward(rows).await;
+        });
+
+        return Ok(response);
+    }
+
+    let total = ActivityCount::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        format!("SELECT COUNT(*) AS total FROM ({ACTIVITY_QUERY}) AS activity"),
+        [id.into()],
+    ))
+    .one(db)
+    .await?
+    .map_or(0, |count| count.total as u64);
+
+    let data = ActivityEntry::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        format!("{ACTIVITY_QUERY} ORDER BY {ACTIVITY_ORDER} LIMIT $2 OFFSET $3"),
+        [
+            id.into(),
+            (page_size as i64).into(),
+            (((page - 1) * page_size) as i64).into(),
+        ],
+    ))
+    .all(db)
+    .await?;
+
+    Ok(Json(serde_json::json!({
+        "data": data,
+        "meta": {
+            "total": total,
+            "page": page,
+            "page_size": page_size,
+            "page_count": total / page_size + u64::from(total % page_size != 0),
+        }
+    }))
+    .into_response())
+}
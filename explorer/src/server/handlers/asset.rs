@@ -0,0 +1,108 @@
+use {
+    axum::{
+        extract::{Query, State},
+        http::StatusCode,
+        response::IntoResponse,
+        Json,
+    },
+    hapi_core::client::entities::asset::AssetId,
+    sea_orm::{prelude::DateTime, ActiveEnum, EntityTrait},
+    serde::{Deserialize, Serialize},
+    std::str::FromStr,
+    uuid::Uuid,
+};
+
+use crate::{application::AppState, entity::asset, error::AppError};
+
+#[derive(Deserialize)]
+pub struct AssetParams {
+    network: String,
+    /// Mint on Solana, token contract address on EVM
+    address: String,
+    asset_id: String,
+}
+
+#[derive(Serialize)]
+struct AssetRecord {
+    network_id: String,
+    address: String,
+    id: String,
+    case_id: Uuid,
+    reporter_id: Uuid,
+    risk: i16,
+    category: String,
+    confirmations: String,
+    /// Confirmations that the network requires, see `ConfirmationConfiguration`
+    confirmation_threshold: u64,
+    /// Whether the asset has reached the confirmation threshold of its network
+    confirmed: bool,
+    created_at: DateTime,
+    updated_at: DateTime,
+}
+
+impl AssetRecord {
+    fn new(model: asset::Model, confirmation_threshold: u64) -> Self {
+        // Confirmation counts are stored as decimal strings of u64
+        let confirmations: u64 = model.confirmations.parse().unwrap_or_default();
+
+        Self {
+            confirmation_threshold,
+            confirmed: confirmations >= confirmation_threshold,
+            network_id: model.network_id,
+            address: model.address,
+            id: model.id,
+            case_id: model.case_id,
+            reporter_id: model.reporter_id,
+            risk: model.risk,
+            category: model.category.to_value(),
+            confirmations: model.confirmations,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+/// Handle requests for a single asset by its network, address and asset id
+pub(crate) async fn asset_handler(
+    state: State<AppState>,
+    params: Query<AssetParams>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(
+        network = %params.network,
+        address = %params.address,
+        asset_id = %params.asset_id,
+        "Received asset request"
+    );
+    let db = &state.read_conn;
+
+    if params.network.is_empty() || params.address.is_empty() {
+        return Err(AppError::invalid_request(
+            "Network and address must not be empty",
+        ));
+    }
+
+    // Asset ids are stored in their canonical decimal form
+    let asset_id = AssetId::from_str(&params.asset_id).map_err(|_| {
+        AppError::invalid_request("Asset id must be a decimal or 0x-prefixed hex number")
+    })?;
+
+    let model = asset::Entity::find_by_id((
+        params.network.clone(),
+        params.address.clone(),
+        asset_id.to_string(),
+    ))
+    .one(db)
+    .await?
+    .ok_or_else(|| {
+        AppError::new(
+            StatusCode::NOT_FOUND,
+            "This asset does not exist".to_string(),
+        )
+    })?;
+
+    let threshold = state.confirmations.threshold(&model.network_id);
+
+    Ok(Json(
+        serde_json::json!({ "data": AssetRecord::new(model, threshold) }),
+    ))
+}
@@ -0,0 +1,182 @@
+use {
+    axum::{
+        extract::{Path, Query, State},
+        http::StatusCode,
+        response::IntoResponse,
+        Json,
+    },
+    sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder},
+    serde::{Deserialize, Serialize},
+    uuid::Uuid,
+};
+
+use crate::{
+    application::AppState,
+    entity::{address, asset, case},
+    error::AppError,
+};
+
+#[derive(Deserialize)]
+pub struct CaseConfirmationsParams {
+    /// List only the items that haven't reached the threshold yet
+    #[serde(default)]
+    unconfirmed: bool,
+}
+
+#[derive(Default, Serialize)]
+struct QuorumCount {
+    confirmed: u64,
+    pending: u64,
+}
+
+impl QuorumCount {
+    fn add(&mut self, confirmed: bool) {
+        if confirmed {
+            self.confirmed += 1;
+        } else {
+            self.pending += 1;
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct QuorumItem {
+    /// `address` or `asset`
+    kind: &'static str,
+    address: String,
+    /// Asset id, for assets only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    asset_id: Option<String>,
+    confirmations: u64,
+    confirmed: bool,
+}
+
+impl QuorumItem {
+    fn new(
+        kind: &'static str,
+        address: String,
+        asset_id: Option<String>,
+        confirmations: &str,
+        threshold: u64,
+    ) -> Self {
+        // Confirmation counts are stored as decimal strings of u64
+        let confirmations: u64 = confirmations.parse().unwrap_or_default();
+
+        Self {
+            kind,
+            address,
+            asset_id,
+            confirmations,
+            confirmed: confirmations >= threshold,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CaseConfirmations {
+    network_id: String,
+    case_id: Uuid,
+    /// Confirmations that the network requires, see `ConfirmationConfiguration`
+    confirmation_threshold: u64,
+    addresses: QuorumCount,
+    assets: QuorumCount,
+    /// Share of the confirmed addresses and assets, `null` if the case has none
+    progress: Option<f64>,
+    items: Vec<QuorumItem>,
+}
+
+/// Handle requests for the confirmation progress of the addresses and assets of a case
+///
+/// Withdrawn addresses are no longer reported, so they don't take part. The counts cover
+/// every item of the case, whatever the items are filtered to.
+pub(crate) async fn case_confirmations_handler(
+    state: State<AppState>,
+    Path((network_id, case_id)): Path<(String, Uuid)>,
+    params: Query<CaseConfirmationsParams>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(%network_id, %case_id, "Received case confirmations request");
+    let db = &state.read_conn;
+
+    case::Entity::find_by_id((network_id.clone(), case_id))
+        .one(db)
+        .await?
+        .ok_or_else(|| {
+            AppError::new(
+                StatusCode::NOT_FOUND,
+                "This case does not exist".to_string(),
+            )
+        })?;
+
+    let threshold = state.confirmations.threshold(&network_id);
+
+    let addresses = address::Entity::find_active()
+        .filter(address::Column::NetworkId.eq(network_id.as_str()))
+        .filter(address::Column::CaseId.eq(case_id))
+        .order_by_asc(address::Column::Address)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            QuorumItem::new(
+                "address",
+                model.address,
+                None,
+                &model.confirmations,
+                threshold,
+            )
+        });
+
+    let assets = asset::Entity::find()
+        .filter(asset::Column::NetworkId.eq(network_id.as_str()))
+        .filter(asset::Column::CaseId.eq(case_id))
+        .order_by_asc(asset::Column::Address)
+        .order_by_asc(asset::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            QuorumItem::new(
+                "asset",
+                model.address,
+                Some(model.id),
+                &model.confirmations,
+                threshold,
+            )
+        });
+
+    let mut address_count = QuorumCount::default();
+    let mut asset_count = QuorumCount::default();
+    let mut items = vec![];
+
+    for item in addresses.chain(assets) {
+        match item.kind {
+            "address" => address_count.add(item.confirmed),
+            _ => asset_count.add(item.confirmed),
+        }
+
+        if !params.unconfirmed || !item.confirmed {
+            items.push(item);
+        }
+    }
+
+    let confirmed = address_count.confirmed + asset_count.confirmed;
+    let total = confirmed + address_count.pending + asset_count.pending;
+
+    // Percentage with two decimal places
+    let progress = (total > 0).then(|| {
+        let share = confirmed as f64 / total as f64;
+        (share * 10000.0).round() / 100.0
+    });
+
+    Ok(Json(serde_json::json!({
+        "data": CaseConfirmations {
+            network_id,
+            case_id,
+            confirmation_threshold: threshold,
+            addresses: address_count,
+            assets: asset_count,
+            progress,
+            items,
+        }
+    })))
+}
@@ -0,0 +1,36 @@
+use {
+    axum::{response::IntoResponse, Json},
+    sea_orm::{ActiveEnum, Iterable},
+    serde::Serialize,
+};
+
+use crate::entity::types::Category;
+
+#[derive(Serialize)]
+struct CategoryRecord {
+    /// Numeric value of the category in the contracts
+    code: u8,
+    /// Value of the category in the explorer API and filters
+    value: String,
+    name: String,
+    label: &'static str,
+    tier: u8,
+}
+
+/// Handle requests for the list of address and asset categories
+///
+/// The categories are declared in the order of their on-chain codes, so the code is the position.
+pub(crate) async fn categories_handler() -> impl IntoResponse {
+    let categories: Vec<CategoryRecord> = Category::iter()
+        .enumerate()
+        .map(|(code, category)| CategoryRecord {
+            code: code as u8,
+            value: category.to_value(),
+            name: category.to_string(),
+            label: category.label(),
+            tier: category.tier(),
+        })
+        .collect();
+
+    Json(serde_json::json!({ "data": categories }))
+}
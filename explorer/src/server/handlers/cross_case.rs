@@ -0,0 +1,111 @@
+use {
+    axum::{
+        extract::{Query, State},
+        response::IntoResponse,
+        Json,
+    },
+    sea_orm::{sea_query::Expr, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect},
+    serde::{Deserialize, Serialize},
+    std::collections::HashSet,
+    uuid::Uuid,
+};
+
+use crate::{application::AppState, entity::address, error::AppError};
+
+const DEFAULT_MIN_CASES: u64 = 2;
+const DEFAULT_CROSS_CASE_LIMIT: u64 = 25;
+
+#[derive(Deserialize)]
+pub struct CrossCaseParams {
+    min_cases: Option<u64>,
+    limit: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct CaseReference {
+    network_id: String,
+    case_id: Uuid,
+    risk: i16,
+}
+
+#[derive(Serialize)]
+struct CrossCaseAddress {
+    address: String,
+    case_count: usize,
+    cases: Vec<CaseReference>,
+}
+
+/// Handle requests for addresses reported in several distinct cases
+///
+/// Addresses are unique within a network, so the cases are linked by the same address
+/// being reported on different networks. Withdrawn reports are not taken into account.
+pub(crate) async fn cross_case_handler(
+    state: State<AppState>,
+    params: Query<CrossCaseParams>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(min_cases = ?params.min_cases, "Received cross-case address request");
+    let db = &state.read_conn;
+
+    let min_cases = params.min_cases.unwrap_or(DEFAULT_MIN_CASES);
+    if min_cases < 2 {
+        return Err(AppError::invalid_request(
+            "Minimum case count must be at least 2",
+        ));
+    }
+
+    let limit = params.limit.unwrap_or(DEFAULT_CROSS_CASE_LIMIT);
+
+    // The most linked addresses go first
+    let shared: Vec<String> = address::Entity::find_active()
+        .select_only()
+        .column(address::Column::Address)
+        .group_by(address::Column::Address)
+        .having(Expr::expr(Expr::cust("COUNT(DISTINCT case_id)")).gte(min_cases))
+        .order_by_desc(Expr::cust("COUNT(DISTINCT case_id)"))
+        .order_by_asc(address::Column::Address)
+        .limit(limit)
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    let reports = address::Entity::find_active()
+        .filter(address::Column::Address.is_in(shared.clone()))
+        .order_by_asc(address::Column::NetworkId)
+        .all(db)
+        .await?;
+
+    let data: Vec<CrossCaseAddress> = shared
+        .into_iter()
+        .map(|address| {
+            let cases: Vec<CaseReference> = reports
+                .iter()
+                .filter(|report| report.address == address)
+                .map(|report| CaseReference {
+                    network_id: report.network_id.clone(),
+                    case_id: report.case_id,
+                    risk: report.risk,
+                })
+                .collect();
+
+            let case_count = cases
+                .iter()
+                .map(|case| case.case_id)
+                .collect::<HashSet<_>>()
+                .len();
+
+            CrossCaseAddress {
+                address,
+                case_count,
+                cases,
+            }
+        })
+        .collect();
+
+    Ok(Json(serde_json::json!({
+        "data": data,
+        "meta": {
+            "total": data.len(),
+            "min_cases": min_cases,
+        }
+    })))
+}
@@ -0,0 +1,149 @@
+use {
+    axum::{
+        extract::{Path, State},
+        http::StatusCode,
+        response::IntoResponse,
+        Json,
+    },
+    sea_orm::{prelude::DateTime, ActiveEnum, DbBackend, FromQueryResult, Statement},
+    serde::Serialize,
+    std::collections::BTreeMap,
+    uuid::Uuid,
+};
+
+use crate::{application::AppState, entity::types::Category, error::AppError};
+
+/// Reports of withdrawn addresses are not in effect, so they don't take part
+const REPORTS_QUERY: &str = r#"
+    SELECT report.network_id, report.reporter_id, report.category::text AS category,
+        report.risk, report.reported_at
+    FROM address_report AS report
+    JOIN address ON address.network_id = report.network_id AND address.address = report.address
+    WHERE report.address = $1 AND address.status = 'active'
+    ORDER BY report.network_id, report.reporter_id
+"#;
+
+#[derive(FromQueryResult)]
+struct Report {
+    network_id: String,
+    reporter_id: Uuid,
+    category: Category,
+    risk: i16,
+    reported_at: DateTime,
+}
+
+#[derive(Serialize)]
+struct ReporterOpinion {
+    network_id: String,
+    reporter_id: Uuid,
+    category: String,
+    risk: i16,
+    reported_at: DateTime,
+}
+
+#[derive(Serialize)]
+struct RiskDistribution {
+    /// Number of reports per risk score
+    distribution: BTreeMap<i16, usize>,
+    median: f64,
+    min: i16,
+    max: i16,
+}
+
+#[derive(Serialize)]
+struct CategoryDistribution {
+    /// Number of reports per category
+    distribution: BTreeMap<String, usize>,
+    /// The most reported categories, several of them on a tie
+    modal: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct AddressDistribution {
+    address: String,
+    report_count: usize,
+    reports: Vec<ReporterOpinion>,
+    risk: RiskDistribution,
+    category: CategoryDistribution,
+}
+
+/// Handle requests for the risk and category reported for an address by each reporter
+///
+/// Every reporter's latest classification on every network is counted once, so the
+/// distribution shows whether the reporters agree on the address.
+pub(crate) async fn address_distribution_handler(
+    state: State<AppState>,
+    Path(address): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(%address, "Received address distribution request");
+    let db = &state.read_conn;
+
+    let reports = Report::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        REPORTS_QUERY,
+        [address.clone().into()],
+    ))
+    .all(db)
+    .await?;
+
+    if reports.is_empty() {
+        return Err(AppError::new(
+            StatusCode::NOT_FOUND,
+            "This address is not reported".to_string(),
+        ));
+    }
+
+    let mut risks: Vec<i16> = reports.iter().map(|report| report.risk).collect();
+    risks.sort_unstable();
+
+    let middle = risks.len() / 2;
+    let median = if risks.len() % 2 == 0 {
+        f64::from(risks[middle - 1] + risks[middle]) / 2.0
+    } else {
+        f64::from(risks[middle])
+    };
+
+    let mut risk_distribution = BTreeMap::new();
+    let mut category_distribution = BTreeMap::new();
+
+    for report in &reports {
+        *risk_distribution.entry(report.risk).or_insert(0) += 1;
+        *category_distribution
+            .entry(report.category.to_value())
+            .or_insert(0) += 1;
+    }
+
+    let top = category_distribution.values().copied().max().unwrap_or(0);
+    let modal = category_distribution
+        .iter()
+        .filter(|(_, count)| **count == top)
+        .map(|(category, _)| category.clone())
+        .collect();
+
+    let data = AddressDistribution {
+        address,
+        report_count: reports.len(),
+        risk: RiskDistribution {
+            distribution: risk_distribution,
+            median,
+            min: risks[0],
+            max: risks[risks.len() - 1],
+        },
+        category: CategoryDistribution {
+            distribution: category_distribution,
+            modal,
+        },
+        reports: reports
+            .into_iter()
+            .map(|report| ReporterOpinion {
+                network_id: report.network_id,
+                reporter_id: report.reporter_id,
+                category: report.category.to_value(),
+                risk: report.risk,
+                reported_at: report.reported_at,
+            })
+            .collect(),
+    };
+
+    Ok(Json(serde_json::json!({ "data": data })))
+}
@@ -3,21 +3,34 @@ use {
         extract::{Json, State},
         http::StatusCode,
     },
+    chrono::{Duration, DurationRound, Utc},
     hapi_core::client::{
         entities::{
-            address::Address as AddressPayload, asset::Asset as AssetPayload,
-            case::Case as CasePayload, reporter::Reporter as ReporterPayload,
+            address::Address as AddressPayload,
+            asset::Asset as AssetPayload,
+            case::Case as CasePayload,
+            reporter::{Reporter as ReporterPayload, ReporterStatus},
         },
         events::EventName,
     },
     hapi_indexer::{PushData, PushPayload},
-    sea_orm::DatabaseConnection,
+    sea_orm::{
+        ActiveModelBehavior, ActiveModelTrait, DatabaseConnection, EntityTrait, TryIntoModel,
+    },
+    serde::Serialize,
+    std::collections::{HashMap, HashSet},
     tracing::instrument,
+    uuid::Uuid,
 };
 
+use super::push_body::PushBody;
 use crate::{
     application::AppState,
-    entity::{address, asset, case, reporter},
+    cache::CachedEntity,
+    entity::{
+        address, asset, case, reporter, reporter_stake_change,
+        types::{AddressStatus, NetworkBackend},
+    },
     error::AppError,
     observability::{
         update_address_metrics, update_asset_metrics, update_case_metrics, update_reporter_metrics,
@@ -26,16 +39,201 @@ use crate::{
     service::{get_network_id, EntityMutation, EntityQuery},
 };
 
+/// Reason recorded for addresses, whose reports were dropped by a chain reorganization
+pub(crate) const REORG_WITHDRAWAL_REASON: &str = "Report was removed by a chain reorganization";
+pub(crate) const INVALIDATION_WITHDRAWAL_REASON: &str =
+    "Report was invalidated by the network authority";
+
+/// The largest batch accepted by the bulk endpoint, bigger ones are left for the sender to split
+pub(crate) const MAX_BULK_PAYLOADS: usize = 1000;
+
+/// Rows per multi-row INSERT, keeps the statement well below the Postgres bind parameter limit
+const BULK_INSERT_CHUNK: usize = 500;
+
+/// Outcome of a bulk push
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct BulkResult {
+    /// Entities created by multi-row inserts
+    inserted: usize,
+    /// Payloads applied one by one
+    processed: usize,
+}
+
 /// Handle events Requests
 #[instrument(level = "info", skip(state))]
 pub(crate) async fn event_handler(
     state: State<AppState>,
-    Json(payload): Json<PushPayload>,
+    PushBody(payload): PushBody<PushPayload>,
 ) -> Result<StatusCode, AppError> {
     tracing::info!(event = ?payload.event, "Received event");
+    let entity = CachedEntity::from(&payload.data);
+
+    let mut counts = EventCounts::default();
+    counts.add(&payload);
+
+    let result = process_payload(&state.database_conn, payload.clone()).await?;
+    track_report_rate(&state, &payload).await;
+
+    state.cache.invalidate(entity);
+    counts.record(&state.database_conn).await;
+
+    Ok(result)
+}
+
+/// Handle batches of events, pushed in chain order
+///
+/// New entities are created with multi-row inserts first, then the rest of the payloads
+/// are processed one by one in their original order, as the single event handler does.
+/// If a chunk conflicts with the stored entities, its payloads fall back to the single
+/// path as well, where a known entity is updated instead.
+#[instrument(level = "info", skip_all, fields(count = payloads.len()))]
+pub(crate) async fn bulk_event_handler(
+    state: State<AppState>,
+    PushBody(payloads): PushBody<Vec<PushPayload>>,
+) -> Result<Json<BulkResult>, AppError> {
+    if payloads.len() > MAX_BULK_PAYLOADS {
+        return Err(AppError::new(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "Batch holds {} events, the limit is {MAX_BULK_PAYLOADS}",
+                payloads.len()
+            ),
+        ));
+    }
+
+    let db = &state.database_conn;
+
+    // Reverted events withdraw reports, so they have to keep their order against creations
+    let inserted = if payloads.iter().any(|payload| payload.event.removed) {
+        HashSet::new()
+    } else {
+        bulk_insert(db, &payloads).await?
+    };
+
+    let mut result = BulkResult {
+        inserted: inserted.len(),
+        processed: 0,
+    };
+
+    let mut entities = vec![];
+    let mut counts = EventCounts::default();
+
+    for (index, payload) in payloads.into_iter().enumerate() {
+        let entity = CachedEntity::from(&payload.data);
+        if !entities.contains(&entity) {
+            entities.push(entity);
+        }
+
+        counts.add(&payload);
+
+        if !inserted.contains(&index) {
+            process_payload(db, payload.clone()).await?;
+            result.processed += 1;
+        }
+
+        track_report_rate(&state, &payload).await;
+    }
+
+    for entity in entities {
+        state.cache.invalidate(entity);
+    }
+
+    counts.record(db).await;
+
+    tracing::info!(?result, "Batch is processed");
+
+    Ok(Json(result))
+}
+
+/// Processed events by their network backend, chain id and event name
+#[derive(Default)]
+struct EventCounts(HashMap<(NetworkBackend, Option<String>, String), i64>);
+
+impl EventCounts {
+    /// Reverted events undo an earlier one rather than happen again, so they are not counted
+    fn add(&mut self, payload: &PushPayload) {
+        if payload.event.removed {
+            return;
+        }
+
+        let key = (
+            payload.network_data.network.clone().into(),
+            payload.network_data.chain_id.clone(),
+            payload.event.name.to_string(),
+        );
+
+        *self.0.entry(key).or_default() += 1;
+    }
+
+    /// Adds the counts to the current hour
+    ///
+    /// Counts only serve the statistics, so a failure to store them is logged rather than
+    /// failing the events that are already processed.
+    async fn record(self, db: &DatabaseConnection) {
+        let now = Utc::now().naive_utc();
+        let hour = now.duration_trunc(Duration::hours(1)).unwrap_or(now);
+
+        for ((backend, chain_id, event), count) in self.0 {
+            let result = match get_network_id(db, backend, chain_id).await {
+                Ok(network_id) => {
+                    EntityMutation::count_events(db, network_id, event.clone(), hour, count).await
+                }
+                Err(error) => Err(error),
+            };
+
+            if let Err(error) = result {
+                tracing::error!(?error, %event, "Failed to count events");
+            }
+        }
+    }
+}
+
+/// Flags the reporter of a processed payload once its reports come faster than the configured rate
+///
+/// Reports are stored either way, the flag only draws the attention of analysts, so a failure
+/// to store it is logged rather than failing the event. The flag is tried again with the next
+/// report of the reporter until it is stored.
+async fn track_report_rate(state: &AppState, payload: &PushPayload) {
+    let Some(reporter_id) = state.report_rate.record(payload) else {
+        return;
+    };
+
+    match flag_reporter(state, payload, reporter_id).await {
+        Ok(true) => state.report_rate.mark_flagged(payload),
+        Ok(false) => tracing::warn!(%reporter_id, "Reporter to flag is not found"),
+        Err(error) => tracing::error!(?error, %reporter_id, "Failed to flag reporter"),
+    }
+}
+
+async fn flag_reporter(
+    state: &AppState,
+    payload: &PushPayload,
+    reporter_id: Uuid,
+) -> Result<bool, AppError> {
+    let db = &state.database_conn;
+    let network_id = get_network_id(
+        db,
+        payload.network_data.network.clone().into(),
+        payload.network_data.chain_id.clone(),
+    )
+    .await?;
+
+    tracing::warn!(%network_id, %reporter_id, "Reporter exceeds the report rate and is flagged");
+
+    let flagged =
+        EntityMutation::flag_reporter(db, network_id, reporter_id, payload.event.timestamp).await?;
+    state.cache.invalidate(CachedEntity::Reporter);
+
+    Ok(flagged)
+}
+
+async fn process_payload(
+    db: &DatabaseConnection,
+    payload: PushPayload,
+) -> Result<StatusCode, AppError> {
     let event_name = payload.event.name;
     let timestamp = payload.event.timestamp;
-    let db = &state.database_conn;
+    let removed = payload.event.removed;
 
     let network_id = get_network_id(
         db,
@@ -46,54 +244,229 @@ pub(crate) async fn event_handler(
 
     match payload.data {
         PushData::Address(address) => {
-            process_address_payload(address, event_name, db, network_id, timestamp).await
+            process_address_payload(address, event_name, removed, db, network_id, timestamp).await
         }
         PushData::Asset(asset) => {
-            process_asset_payload(asset, event_name, db, network_id, timestamp).await
+            process_asset_payload(asset, event_name, removed, db, network_id, timestamp).await
         }
         PushData::Case(case) => {
-            process_case_payload(case, event_name, db, network_id, timestamp).await
+            process_case_payload(case, event_name, removed, db, network_id, timestamp).await
         }
         PushData::Reporter(reporter) => {
-            process_reporter_payload(reporter, event_name, db, network_id, timestamp).await
+            process_reporter_payload(reporter, event_name, removed, db, network_id, timestamp).await
+        }
+    }
+}
+
+/// Inserts the entities created in the batch, returns indexes of the inserted payloads
+///
+/// Reporters and cases go first, as addresses and assets refer to them.
+async fn bulk_insert(
+    db: &DatabaseConnection,
+    payloads: &[PushPayload],
+) -> Result<HashSet<usize>, AppError> {
+    let mut network_ids: Vec<((NetworkBackend, Option<String>), String)> = vec![];
+    let mut reporters = vec![];
+    let mut cases = vec![];
+    let mut addresses = vec![];
+    let mut assets = vec![];
+
+    for (index, payload) in payloads.iter().enumerate() {
+        let network = (
+            NetworkBackend::from(payload.network_data.network.clone()),
+            payload.network_data.chain_id.clone(),
+        );
+
+        let network_id = match network_ids.iter().find(|(key, _)| key == &network) {
+            Some((_, id)) => id.clone(),
+            None => {
+                let id = get_network_id(db, network.0, network.1.clone()).await?;
+                network_ids.push((network, id.clone()));
+                id
+            }
+        };
+
+        let timestamp = payload.event.timestamp;
+
+        match (&payload.event.name, &payload.data) {
+            (EventName::CreateReporter, PushData::Reporter(reporter)) => reporters.push((
+                index,
+                EntityMutation::new_entity::<reporter::ActiveModel, _>(
+                    reporter.clone(),
+                    network_id,
+                    timestamp,
+                )?,
+            )),
+            (EventName::CreateCase, PushData::Case(case)) => cases.push((
+                index,
+                EntityMutation::new_entity::<case::ActiveModel, _>(
+                    case.clone(),
+                    network_id,
+                    timestamp,
+                )?,
+            )),
+            (EventName::CreateAddress, PushData::Address(address)) => addresses.push((
+                index,
+                EntityMutation::new_entity::<address::ActiveModel, _>(
+                    address.clone(),
+                    network_id,
+                    timestamp,
+                )?,
+            )),
+            (EventName::CreateAsset, PushData::Asset(asset)) => assets.push((
+                index,
+                EntityMutation::new_entity::<asset::ActiveModel, _>(
+                    asset.clone(),
+                    network_id,
+                    timestamp,
+                )?,
+            )),
+            _ => {}
+        }
+    }
+
+    let mut inserted = HashSet::new();
+
+    inserted.extend(insert_chunks(db, reporters, update_reporter_metrics).await?);
+    inserted.extend(insert_chunks(db, cases, update_case_metrics).await?);
+
+    // Inserted addresses are the first reports of their reporters
+    let address_rows = addresses.clone();
+    inserted.extend(insert_chunks(db, addresses, update_address_metrics).await?);
+
+    let reports = address_rows
+        .into_iter()
+        .filter(|(index, _)| inserted.contains(index))
+        .map(|(_, model)| model.try_into_model())
+        .collect::<Result<Vec<_>, _>>()?;
+    EntityMutation::record_address_reports(db, &reports).await?;
+
+    inserted.extend(insert_chunks(db, assets, update_asset_metrics).await?);
+
+    Ok(inserted)
+}
+
+async fn insert_chunks<M>(
+    db: &DatabaseConnection,
+    rows: Vec<(usize, M)>,
+    update_metrics: fn(<M::Entity as EntityTrait>::Model, MetricOp),
+) -> Result<Vec<usize>, AppError>
+where
+    M: ActiveModelTrait
+        + ActiveModelBehavior
+        + TryIntoModel<<M::Entity as EntityTrait>::Model>
+        + Send,
+{
+    let mut inserted = vec![];
+
+    for chunk in rows.chunks(BULK_INSERT_CHUNK) {
+        let models = chunk.iter().map(|(_, model)| model.clone()).collect();
+
+        if let Err(error) = EntityMutation::create_entities(db, models).await {
+            tracing::warn!(
+                ?error,
+                rows = chunk.len(),
+                "Bulk insert failed, payloads are processed one by one"
+            );
+            continue;
+        }
+
+        for (index, model) in chunk {
+            update_metrics(model.clone().try_into_model()?, MetricOp::Increment);
+            inserted.push(*index);
         }
     }
+
+    Ok(inserted)
 }
 
 #[instrument(level = "trace", skip(db))]
 async fn process_address_payload(
     address: AddressPayload,
     event_name: EventName,
+    removed: bool,
     db: &DatabaseConnection,
     network_id: String,
     timestamp: u64,
 ) -> Result<StatusCode, AppError> {
-    tracing::info!(address = ?address, "Received address");
+    tracing::info!(address = ?address, removed, "Received address");
 
-    let address = match event_name {
-        EventName::CreateAddress => {
-            EntityMutation::create_entity::<address::ActiveModel, _>(
-                db, address, network_id, timestamp,
+    let old = EntityQuery::find_entity_by_id::<address::Entity, _>(
+        db,
+        (network_id.clone(), address.address.clone()),
+    )
+    .await?;
+
+    let address = match (&event_name, old) {
+        // The report never made it to the canonical chain
+        (EventName::CreateAddress, Some(old)) if removed => {
+            EntityMutation::withdraw_address(
+                db,
+                network_id,
+                address.address,
+                REORG_WITHDRAWAL_REASON.to_string(),
+                timestamp,
             )
-            .await?
+            .await?;
+
+            if old.status == AddressStatus::Active {
+                update_address_metrics(old, MetricOp::Decrement);
+            }
+
+            return Ok(StatusCode::OK);
         }
-        EventName::UpdateAddress => {
-            let old = EntityQuery::find_entity_by_id::<address::Entity, _>(
+        (EventName::CreateAddress, None) if removed => {
+            tracing::warn!("Reverted address is not indexed, nothing to withdraw");
+
+            return Ok(StatusCode::OK);
+        }
+        // The authority has invalidated a report made in error, it is kept for audit
+        (EventName::InvalidateAddress, Some(old)) => {
+            EntityMutation::withdraw_address(
                 db,
-                (network_id.clone(), address.address.clone()),
+                network_id,
+                address.address,
+                INVALIDATION_WITHDRAWAL_REASON.to_string(),
+                timestamp,
             )
-            .await?
-            .ok_or(AppError::invalid_request("This address does not exist"))?;
+            .await?;
+
+            if old.status == AddressStatus::Active {
+                update_address_metrics(old, MetricOp::Decrement);
+            }
 
+            return Ok(StatusCode::OK);
+        }
+        (EventName::CreateAddress, None) => {
+            EntityMutation::create_entity::<address::ActiveModel, _>(
+                db, address, network_id, timestamp,
+            )
+            .await?
+        }
+        // A known address is created again by a repeated push or a restored withdrawn report.
+        // Reverted updates are applied as usual: the payload holds the current on-chain state.
+        // Confirmations carry the on-chain state too, the confirming reporter doesn't classify
+        (
+            EventName::CreateAddress | EventName::UpdateAddress | EventName::ConfirmAddress,
+            Some(old),
+        ) => {
             let new = EntityMutation::update_entity::<address::ActiveModel, _>(
                 db, address, network_id, timestamp,
             )
             .await?;
 
-            update_address_metrics(old, MetricOp::Decrement);
+            if old.status == AddressStatus::Active {
+                update_address_metrics(old, MetricOp::Decrement);
+            }
 
             new
         }
+        (
+            EventName::UpdateAddress | EventName::ConfirmAddress | EventName::InvalidateAddress,
+            None,
+        ) => {
+            return Err(AppError::invalid_request("This address does not exist"));
+        }
         _ => {
             return Err(AppError::invalid_request(&format!(
                 "Received unexpected event with address payload: {event_name}",
@@ -101,6 +474,7 @@ async fn process_address_payload(
         }
     };
 
+    EntityMutation::record_address_reports(db, std::slice::from_ref(&address)).await?;
     update_address_metrics(address, MetricOp::Increment);
 
     Ok(StatusCode::OK)
@@ -110,29 +484,43 @@ async fn process_address_payload(
 async fn process_asset_payload(
     asset: AssetPayload,
     event_name: EventName,
+    removed: bool,
     db: &DatabaseConnection,
     network_id: String,
     timestamp: u64,
 ) -> Result<StatusCode, AppError> {
-    tracing::info!(asset = ?asset, "Received asset");
+    tracing::info!(asset = ?asset, removed, "Received asset");
 
-    let asset = match event_name {
-        EventName::CreateAsset => {
+    let old = EntityQuery::find_entity_by_id::<asset::Entity, _>(
+        db,
+        (
+            network_id.clone(),
+            asset.address.clone(),
+            asset.asset_id.to_string(),
+        ),
+    )
+    .await?;
+
+    // Payloads may be pushed again, so the creation of a known asset is applied as an update
+    let asset = match (&event_name, old) {
+        // The asset never made it to the canonical chain
+        (EventName::CreateAsset, Some(old)) if removed => {
+            EntityMutation::delete_asset(db, network_id, old.address.clone(), old.id.clone())
+                .await?;
+            update_asset_metrics(old, MetricOp::Decrement);
+
+            return Ok(StatusCode::OK);
+        }
+        (EventName::CreateAsset, None) if removed => {
+            tracing::warn!("Reverted asset is not indexed, nothing to delete");
+
+            return Ok(StatusCode::OK);
+        }
+        (EventName::CreateAsset, None) => {
             EntityMutation::create_entity::<asset::ActiveModel, _>(db, asset, network_id, timestamp)
                 .await?
         }
-        EventName::UpdateAsset => {
-            let old = EntityQuery::find_entity_by_id::<asset::Entity, _>(
-                db,
-                (
-                    network_id.clone(),
-                    asset.address.clone(),
-                    asset.asset_id.to_string(),
-                ),
-            )
-            .await?
-            .ok_or(AppError::invalid_request("This asset does not exist"))?;
-
+        (EventName::CreateAsset | EventName::UpdateAsset | EventName::ConfirmAsset, Some(old)) => {
             let new = EntityMutation::update_entity::<asset::ActiveModel, _>(
                 db, asset, network_id, timestamp,
             )
@@ -142,6 +530,9 @@ async fn process_asset_payload(
 
             new
         }
+        (EventName::UpdateAsset | EventName::ConfirmAsset, None) => {
+            return Err(AppError::invalid_request("This asset does not exist"));
+        }
         _ => {
             return Err(AppError::invalid_request(&format!(
                 "Received unexpected event with asset payload: {event_name}"
@@ -158,34 +549,56 @@ async fn process_asset_payload(
 async fn process_case_payload(
     case: CasePayload,
     event_name: EventName,
+    removed: bool,
     db: &DatabaseConnection,
     network_id: String,
     timestamp: u64,
 ) -> Result<StatusCode, AppError> {
-    tracing::info!(case = ?case, "Received case");
+    tracing::info!(case = ?case, removed, "Received case");
+
+    let old = EntityQuery::find_entity_by_id::<case::Entity, _>(db, (network_id.clone(), case.id))
+        .await?;
 
-    let case = match event_name {
-        EventName::CreateCase => {
+    // Payloads may be pushed again, so the creation of a known case is applied as an update
+    let case = match (&event_name, old) {
+        // The case never made it to the canonical chain
+        (EventName::CreateCase, Some(old)) if removed => {
+            let closed = EntityMutation::revert_case(db, network_id, case.id, timestamp).await?;
+            update_case_metrics(old, MetricOp::Decrement);
+
+            match closed {
+                Some(closed) => closed,
+                None => return Ok(StatusCode::OK),
+            }
+        }
+        (EventName::CreateCase, None) if removed => {
+            tracing::warn!("Reverted case is not indexed, nothing to delete");
+
+            return Ok(StatusCode::OK);
+        }
+        (EventName::CreateCase, None) => {
             EntityMutation::create_entity::<case::ActiveModel, _>(db, case, network_id, timestamp)
                 .await?
         }
-        EventName::UpdateCase => {
-            let old = EntityQuery::find_entity_by_id::<case::Entity, _>(
-                db,
-                (network_id.clone(), case.id),
-            )
-            .await?
-            .ok_or(AppError::invalid_request("This case does not exist"))?;
-
+        (EventName::CreateCase | EventName::UpdateCase | EventName::LinkCases, Some(old)) => {
+            let related_cases = case.related_cases.clone();
             let new = EntityMutation::update_entity::<case::ActiveModel, _>(
-                db, case, network_id, timestamp,
+                db,
+                case,
+                network_id.clone(),
+                timestamp,
             )
             .await?;
 
+            EntityMutation::record_case_relations(db, network_id, new.id, &related_cases).await?;
+
             update_case_metrics(old, MetricOp::Decrement);
 
             new
         }
+        (EventName::UpdateCase | EventName::LinkCases, None) => {
+            return Err(AppError::invalid_request("This case does not exist"));
+        }
         _ => {
             return Err(AppError::invalid_request(&format!(
                 "Received unexpected event with case payload: {event_name}",
@@ -202,30 +615,63 @@ async fn process_case_payload(
 async fn process_reporter_payload(
     reporter: ReporterPayload,
     event_name: EventName,
+    removed: bool,
     db: &DatabaseConnection,
     network_id: String,
     timestamp: u64,
 ) -> Result<StatusCode, AppError> {
-    tracing::info!(reporter = ?reporter, "Received reporter");
+    tracing::info!(reporter = ?reporter, removed, "Received reporter");
+
+    // A reverted unstake doesn't release the stake, its payload holds the current state
+    let reporter = if event_name == EventName::Unstake && !removed {
+        released(reporter)
+    } else {
+        reporter
+    };
 
-    let reporter = match event_name {
-        EventName::CreateReporter => {
+    let old = EntityQuery::find_entity_by_id::<reporter::Entity, _>(
+        db,
+        (network_id.clone(), reporter.id),
+    )
+    .await?;
+
+    // Reporters are created unstaked, so a missing one starts from zero
+    let previous_stake = old
+        .as_ref()
+        .map_or_else(|| "0".to_string(), |old| old.stake.clone());
+
+    // Payloads may be pushed again, so the creation of a known reporter is applied as an update
+    let reporter = match (&event_name, old) {
+        // The reporter never made it to the canonical chain
+        (EventName::CreateReporter, Some(old)) if removed => {
+            let deactivated =
+                EntityMutation::revert_reporter(db, network_id, reporter.id, timestamp).await?;
+            update_reporter_metrics(old, MetricOp::Decrement);
+
+            match deactivated {
+                Some(deactivated) => deactivated,
+                None => return Ok(StatusCode::OK),
+            }
+        }
+        (EventName::CreateReporter, None) if removed => {
+            tracing::warn!("Reverted reporter is not indexed, nothing to delete");
+
+            return Ok(StatusCode::OK);
+        }
+        (EventName::CreateReporter, None) => {
             EntityMutation::create_entity::<reporter::ActiveModel, _>(
                 db, reporter, network_id, timestamp,
             )
             .await?
         }
-        EventName::UpdateReporter
-        | EventName::ActivateReporter
-        | EventName::DeactivateReporter
-        | EventName::Unstake => {
-            let old = EntityQuery::find_entity_by_id::<reporter::Entity, _>(
-                db,
-                (network_id.clone(), reporter.id),
-            )
-            .await?
-            .ok_or(AppError::invalid_request("This reporter does not exist"))?;
-
+        (
+            EventName::CreateReporter
+            | EventName::UpdateReporter
+            | EventName::ActivateReporter
+            | EventName::DeactivateReporter
+            | EventName::Unstake,
+            Some(old),
+        ) => {
             let new = EntityMutation::update_entity::<reporter::ActiveModel, _>(
                 db, reporter, network_id, timestamp,
             )
@@ -235,6 +681,15 @@ async fn process_reporter_payload(
 
             new
         }
+        (
+            EventName::UpdateReporter
+            | EventName::ActivateReporter
+            | EventName::DeactivateReporter
+            | EventName::Unstake,
+            None,
+        ) => {
+            return Err(AppError::invalid_request("This reporter does not exist"));
+        }
         _ => {
             return Err(AppError::invalid_request(&format!(
                 "Received unexpected event with reporter payload: {event_name}"
@@ -242,7 +697,30 @@ async fn process_reporter_payload(
         }
     };
 
+    // A pushed again payload holds the stake that is already stored, so it gives no change
+    if let Some(change) = reporter_stake_change::ActiveModel::between(
+        &previous_stake,
+        &reporter,
+        event_name.to_string(),
+    ) {
+        EntityMutation::record_stake_change(db, change).await?;
+    }
+
     update_reporter_metrics(reporter, MetricOp::Increment);
 
     Ok(StatusCode::OK)
 }
+
+/// The state that unstaking leaves a reporter in: inactive, with the whole stake withdrawn
+///
+/// Indexers read the reporter from the chain when they get to the event, so the payload
+/// may hold a later state, e.g. of a reporter that has staked again. The later state
+/// arrives with its own event, so the stake history keeps both the release and the deposit.
+fn released(reporter: ReporterPayload) -> ReporterPayload {
+    ReporterPayload {
+        status: ReporterStatus::Inactive,
+        stake: 0.into(),
+        unlock_timestamp: 0,
+        ..reporter
+    }
+}
@@ -0,0 +1,95 @@
+use {
+    axum::{
+        extract::{Query, State},
+        http::StatusCode,
+        response::IntoResponse,
+        Json,
+    },
+    chrono::Utc,
+    sea_orm::{prelude::DateTime, DbBackend, FromQueryResult, Statement},
+    serde::{Deserialize, Serialize},
+};
+
+use crate::{application::AppState, error::AppError};
+
+/// Entities are stored with the block timestamps of their events, withdrawn addresses
+/// keep the timestamp of the reverted event separately
+const FRESHNESS_QUERY: &str = r#"
+    SELECT network.id AS network_id, MAX(events."timestamp") AS latest_event_at
+    FROM network LEFT JOIN (
+        SELECT network_id, updated_at AS "timestamp" FROM reporter
+        UNION ALL
+        SELECT network_id, updated_at FROM "case"
+        UNION ALL
+        SELECT network_id, updated_at FROM address
+        UNION ALL
+        SELECT network_id, withdrawn_at FROM address WHERE withdrawn_at IS NOT NULL
+        UNION ALL
+        SELECT network_id, updated_at FROM asset
+    ) AS events ON events.network_id = network.id
+"#;
+
+#[derive(Deserialize)]
+pub struct FreshnessParams {
+    network: Option<String>,
+}
+
+#[derive(FromQueryResult)]
+struct LatestEvent {
+    network_id: String,
+    latest_event_at: Option<DateTime>,
+}
+
+#[derive(Serialize)]
+struct NetworkFreshness {
+    network_id: String,
+    /// Block timestamp of the latest indexed event, none if nothing is indexed yet
+    latest_event_at: Option<DateTime>,
+    /// Seconds passed since the latest indexed event
+    stale_seconds: Option<i64>,
+}
+
+/// Handle requests for the time of the latest indexed event per network
+pub(crate) async fn freshness_handler(
+    state: State<AppState>,
+    params: Query<FreshnessParams>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(network = ?params.network, "Received freshness request");
+    let db = &state.database_conn;
+
+    let statement = match &params.network {
+        Some(network) => Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!("{FRESHNESS_QUERY} WHERE network.id = $1 GROUP BY network.id"),
+            [network.into()],
+        ),
+        None => Statement::from_string(
+            DbBackend::Postgres,
+            format!("{FRESHNESS_QUERY} GROUP BY network.id ORDER BY network.id"),
+        ),
+    };
+
+    let latest = LatestEvent::find_by_statement(statement).all(db).await?;
+
+    if params.network.is_some() && latest.is_empty() {
+        return Err(AppError::new(
+            StatusCode::NOT_FOUND,
+            "This network does not exist".to_string(),
+        ));
+    }
+
+    let now = Utc::now().naive_utc();
+
+    let data: Vec<NetworkFreshness> = latest
+        .into_iter()
+        .map(|event| NetworkFreshness {
+            network_id: event.network_id,
+            latest_event_at: event.latest_event_at,
+            stale_seconds: event
+                .latest_event_at
+                .map(|timestamp| (now - timestamp).num_seconds()),
+        })
+        .collect();
+
+    Ok(Json(serde_json::json!({ "data": data })))
+}
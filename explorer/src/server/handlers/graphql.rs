@@ -1,12 +1,13 @@
 use {
     async_graphql_axum::{GraphQLRequest, GraphQLResponse},
     axum::{
-        response::{Html, IntoResponse},
-        Extension,
+        extract::State,
+        response::{Html, IntoResponse, Response},
+        Extension, Json,
     },
 };
 
-use crate::server::schema::AppSchema;
+use crate::{application::AppState, cache::CachedEndpoint, server::schema::AppSchema};
 
 /// Handle GraphQL playground
 pub(crate) async fn graphiql_playground() -> impl IntoResponse {
@@ -17,8 +18,32 @@ pub(crate) async fn graphiql_playground() -> impl IntoResponse {
 
 /// Handle GraphQL Requests
 pub(crate) async fn graphql_handler(
+    state: State<AppState>,
     schema: Extension<AppSchema>,
     req: GraphQLRequest,
-) -> GraphQLResponse {
-    schema.execute(req.into_inner()).await.into()
+) -> Response {
+    let request = req.into_inner();
+    let cache_key = format!(
+        "{}:{}:{}",
+        request.operation_name.as_deref().unwrap_or_default(),
+        serde_json::to_string(&request.variables).unwrap_or_default(),
+        request.query
+    );
+
+    if let Some(cached) = state.cache.get(CachedEndpoint::GraphQL, &cache_key) {
+        return Json(cached).into_response();
+    }
+
+    let response = schema.execute(request).await;
+
+    // Only complete responses are cached, errors may be caused by a transient DB failure
+    if response.is_ok() {
+        if let Ok(value) = serde_json::to_value(&response) {
+            state
+                .cache
+                .insert(CachedEndpoint::GraphQL, cache_key, value);
+        }
+    }
+
+    GraphQLResponse::from(response).into_response()
 }
@@ -1,6 +1,28 @@
-use axum::{http::StatusCode, response::IntoResponse};
+use {
+    axum::{extract::State, response::IntoResponse, Json},
+    serde_json::json,
+};
+
+use crate::{application::AppState, chain_head::RpcStatus};
 
 /// Handle health Requests
-pub(crate) async fn health_handler() -> impl IntoResponse {
-    StatusCode::OK
+///
+/// Always answers with 200: a slow or failing RPC node of a network makes the explorer
+/// `degraded`, but it keeps serving the indexed data.
+pub(crate) async fn health_handler(state: State<AppState>) -> impl IntoResponse {
+    let networks = state.chain_heads.probe().await;
+
+    let status = if networks
+        .iter()
+        .all(|network| network.status == RpcStatus::Ok)
+    {
+        RpcStatus::Ok
+    } else {
+        RpcStatus::Degraded
+    };
+
+    Json(json!({
+        "status": status,
+        "networks": networks,
+    }))
 }
@@ -1,15 +1,41 @@
+mod activity;
+mod asset;
+mod case_confirmations;
+mod categories;
+mod cross_case;
+mod distribution;
 mod events;
+mod freshness;
 mod graphql;
 mod health;
 mod indexer;
 mod jwt_auth;
+mod ndjson;
+mod network_status;
+mod push_body;
+mod recent;
+mod reporter_networks;
+mod search;
+mod stake_history;
 mod stats;
 
-pub(crate) use events::event_handler;
+pub(crate) use activity::reporter_activity_handler;
+pub(crate) use asset::asset_handler;
+pub(crate) use case_confirmations::case_confirmations_handler;
+pub(crate) use categories::categories_handler;
+pub(crate) use cross_case::cross_case_handler;
+pub(crate) use distribution::address_distribution_handler;
+pub(crate) use events::{bulk_event_handler, event_handler};
+pub(crate) use freshness::freshness_handler;
 pub(crate) use graphql::{graphiql_playground, graphql_handler};
 pub(crate) use health::health_handler;
 pub(crate) use indexer::{indexer_handler, indexer_heartbeat_handler};
 pub(crate) use jwt_auth::auth_handler;
+pub(crate) use network_status::network_status_handler;
+pub(crate) use recent::recent_handler;
+pub(crate) use reporter_networks::reporter_networks_handler;
+pub(crate) use search::search_handler;
+pub(crate) use stake_history::reporter_stake_history_handler;
 pub(crate) use stats::stats_handler;
 
 pub use jwt_auth::TokenClaims;
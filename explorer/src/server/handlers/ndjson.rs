@@ -0,0 +1,94 @@
+use {
+    axum::{
+        body::StreamBody,
+        http::{header, HeaderMap, HeaderValue},
+        response::{IntoResponse, Response},
+    },
+    futures::{stream, Stream, StreamExt},
+    sea_orm::DbErr,
+    serde::Serialize,
+    tokio::sync::mpsc,
+};
+
+/// Media type of newline-delimited JSON
+pub(crate) const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// The number of rows read from the database ahead of the client
+const NDJSON_BUFFER_SIZE: usize = 32;
+
+/// Whether the client asks for a stream of rows instead of a single JSON document
+pub(crate) fn accepts_ndjson(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|media_type| media_type.split(';').next())
+        .any(|media_type| media_type.trim() == NDJSON_CONTENT_TYPE)
+}
+
+/// Writes the rows it is given to an NDJSON response, one JSON object per line
+pub(crate) struct NdjsonSender {
+    sender: mpsc::Sender<Result<String, DbErr>>,
+}
+
+impl NdjsonSender {
+    /// Forwards the rows of a database cursor as the client reads them
+    ///
+    /// The status of the response is already sent, so a failure aborts the response
+    /// and leaves the client with an incomplete last line.
+    pub async fn forward<T, S>(self, rows: Result<S, DbErr>)
+    where
+        T: Serialize,
+        S: Stream<Item = Result<T, DbErr>>,
+    {
+        let rows = match rows {
+            Ok(rows) => rows,
+            Err(error) => {
+                tracing::error!(?error, "Failed to query rows for the stream");
+                let _ = self.sender.send(Err(error)).await;
+                return;
+            }
+        };
+
+        futures::pin_mut!(rows);
+
+        while let Some(row) = rows.next().await {
+            let line = row.and_then(|row| {
+                serde_json::to_string(&row)
+                    .map(|json| json + "\n")
+                    .map_err(|e| DbErr::Json(e.to_string()))
+            });
+
+            let failed = line.is_err();
+            if let Err(error) = &line {
+                tracing::error!(?error, "Failed to stream a row");
+            }
+
+            // The receiver is dropped when the client is gone
+            if self.sender.send(line).await.is_err() || failed {
+                return;
+            }
+        }
+    }
+}
+
+/// Creates a streamed NDJSON response along with the sender of its rows
+pub(crate) fn ndjson_response() -> (NdjsonSender, Response) {
+    let (sender, receiver) = mpsc::channel(NDJSON_BUFFER_SIZE);
+
+    let body = stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|line| (line, receiver))
+    });
+
+    let response = (
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(NDJSON_CONTENT_TYPE),
+        )],
+        StreamBody::new(body),
+    )
+        .into_response();
+
+    (NdjsonSender { sender }, response)
+}
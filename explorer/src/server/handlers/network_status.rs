@@ -0,0 +1,133 @@
+use {
+    axum::{extract::State, response::IntoResponse, Json},
+    chrono::Utc,
+    sea_orm::{prelude::DateTime, EntityTrait, QueryOrder},
+    serde::Serialize,
+    serde_json::Value,
+    std::collections::HashMap,
+};
+
+use crate::{
+    application::AppState,
+    chain_head::RpcStatus,
+    entity::{indexer, network},
+    error::AppError,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum IndexerStatus {
+    Healthy,
+    /// The RPC node fails or the indexer is too far behind the chain head
+    Degraded,
+    /// No heartbeat was received lately, or ever
+    Halted,
+}
+
+#[derive(Serialize)]
+struct NetworkStatus {
+    network_id: String,
+    contract_address: Option<String>,
+    /// Block of the latest indexer heartbeat, none for networks indexed by transaction
+    cursor: Option<u64>,
+    chain_head: Option<u64>,
+    /// Blocks between the cursor and the chain head
+    lag: Option<u64>,
+    /// Time of the latest heartbeat, it is sent after every successful iteration
+    last_heartbeat: Option<DateTime>,
+    status: IndexerStatus,
+}
+
+/// Indexers send their cursor as JSON, i.e. `{"Block":123}`
+fn cursor_block(cursor: &str) -> Option<u64> {
+    serde_json::from_str::<Value>(cursor)
+        .ok()?
+        .get("Block")?
+        .as_u64()
+}
+
+/// Handle requests for the sync status of every network
+///
+/// The status is aggregated from the heartbeats of the network indexers and from the
+/// chain heads of the configured RPC nodes.
+pub(crate) async fn network_status_handler(
+    state: State<AppState>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!("Received network status request");
+    let db = &state.database_conn;
+
+    let networks = network::Entity::find()
+        .order_by_asc(network::Column::Id)
+        .all(db)
+        .await?;
+
+    // A network may have several indexers, the one with the latest heartbeat counts
+    let mut heartbeats: HashMap<String, indexer::Model> = HashMap::new();
+    for indexer in indexer::Entity::find().all(db).await? {
+        match heartbeats.get(&indexer.network_id) {
+            Some(latest) if latest.last_heartbeat >= indexer.last_heartbeat => {}
+            _ => {
+                heartbeats.insert(indexer.network_id.clone(), indexer);
+            }
+        }
+    }
+
+    let heads: HashMap<_, _> = state
+        .chain_heads
+        .probe()
+        .await
+        .into_iter()
+        .map(|head| (head.network_id.clone(), head))
+        .collect();
+
+    let now = Utc::now().naive_utc();
+    let halted_after =
+        chrono::Duration::milliseconds(state.health.indexer_halted_after_ms.as_millis() as i64);
+
+    let data: Vec<NetworkStatus> = networks
+        .into_iter()
+        .map(|network| {
+            let indexer = heartbeats.get(&network.id);
+            let head = heads.get(&network.id);
+
+            // Indexers that never sent a heartbeat keep the default timestamp
+            let last_heartbeat = indexer
+                .map(|indexer| indexer.last_heartbeat)
+                .filter(|heartbeat| *heartbeat != DateTime::default());
+            let cursor = indexer.and_then(|indexer| cursor_block(&indexer.cursor));
+            let chain_head = head.and_then(|head| head.chain_head);
+            let lag = cursor
+                .zip(chain_head)
+                .map(|(cursor, chain_head)| chain_head.saturating_sub(cursor));
+
+            let status = if !last_heartbeat.is_some_and(|heartbeat| now - heartbeat <= halted_after)
+            {
+                IndexerStatus::Halted
+            } else if head.is_some_and(|head| head.status == RpcStatus::Degraded)
+                || lag.is_some_and(|lag| lag > state.health.max_indexer_lag)
+            {
+                IndexerStatus::Degraded
+            } else {
+                IndexerStatus::Healthy
+            };
+
+            let contract_address = state
+                .networks
+                .iter()
+                .find(|configured| configured.id == network.id)
+                .and_then(|configured| configured.contract_address.clone());
+
+            NetworkStatus {
+                network_id: network.id,
+                contract_address,
+                cursor,
+                chain_head,
+                lag,
+                last_heartbeat,
+                status,
+            }
+        })
+        .collect();
+
+    Ok(Json(serde_json::json!({ "data": data })))
+}
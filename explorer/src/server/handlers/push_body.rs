@@ -0,0 +1,59 @@
+use {
+    axum::{
+        async_trait,
+        body::{Bytes, HttpBody},
+        extract::FromRequest,
+        http::{header, Request, StatusCode},
+        response::{IntoResponse, Response},
+        BoxError, Json,
+    },
+    hapi_indexer::PushFormat,
+    serde::de::DeserializeOwned,
+};
+
+use crate::error::AppError;
+
+/// Indexer payloads, decoded by the `Content-Type` of the request
+///
+/// MessagePack bodies are decoded as such, anything else is left to the JSON extractor,
+/// which keeps rejecting bodies of other media types.
+pub(crate) struct PushBody<T>(pub T);
+
+#[async_trait]
+impl<T, S, B> FromRequest<S, B> for PushBody<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let format = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(PushFormat::from_content_type);
+
+        if format != Some(PushFormat::Msgpack) {
+            return Json::<T>::from_request(req, state)
+                .await
+                .map(|Json(value)| Self(value))
+                .map_err(IntoResponse::into_response);
+        }
+
+        let bytes = Bytes::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        PushFormat::Msgpack.decode(&bytes).map(Self).map_err(|e| {
+            AppError::new(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("Failed to deserialize the MessagePack body: {e}"),
+            )
+            .into_response()
+        })
+    }
+}
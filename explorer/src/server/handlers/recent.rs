@@ -0,0 +1,108 @@
+use {
+    axum::{
+        extract::{Query, State},
+        http::StatusCode,
+        response::IntoResponse,
+        Json,
+    },
+    sea_orm::{prelude::DateTime, DbBackend, EntityTrait, FromQueryResult, Statement},
+    serde::{Deserialize, Serialize},
+};
+
+use crate::{application::AppState, entity::network, error::AppError};
+
+const DEFAULT_RECENT_LIMIT: u64 = 20;
+const MAX_RECENT_LIMIT: u64 = 100;
+
+/// Every entity is listed once, at its latest change. Each type is limited on its own
+/// first, so that the merge only sorts the candidates rather than every stored entity.
+const RECENT_QUERY: &str = r#"
+    (SELECT network_id, 'reporter' AS entity, id::text AS id, NULL AS asset_id,
+        CASE WHEN updated_at > created_at THEN 'update_reporter' ELSE 'create_reporter' END
+            AS event,
+        updated_at AS "timestamp"
+    FROM reporter WHERE $1::text IS NULL OR network_id = $1
+    ORDER BY updated_at DESC LIMIT $2)
+    UNION ALL
+    (SELECT network_id, 'case', id::text, NULL,
+        CASE WHEN updated_at > created_at THEN 'update_case' ELSE 'create_case' END,
+        updated_at
+    FROM "case" WHERE $1::text IS NULL OR network_id = $1
+    ORDER BY updated_at DESC LIMIT $2)
+    UNION ALL
+    (SELECT network_id, 'address', address, NULL,
+        CASE WHEN updated_at > created_at THEN 'update_address' ELSE 'create_address' END,
+        updated_at
+    FROM address WHERE $1::text IS NULL OR network_id = $1
+    ORDER BY updated_at DESC LIMIT $2)
+    UNION ALL
+    (SELECT network_id, 'asset', address, id,
+        CASE WHEN updated_at > created_at THEN 'update_asset' ELSE 'create_asset' END,
+        updated_at
+    FROM asset WHERE $1::text IS NULL OR network_id = $1
+    ORDER BY updated_at DESC LIMIT $2)
+    ORDER BY "timestamp" DESC, entity, network_id, id, asset_id
+    LIMIT $2
+"#;
+
+#[derive(Deserialize)]
+pub struct RecentParams {
+    network: Option<String>,
+    limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, FromQueryResult)]
+struct RecentEntry {
+    network_id: String,
+    /// Entity type: `reporter`, `case`, `address` or `asset`
+    entity: String,
+    /// Reporter or case id, or the address
+    id: String,
+    asset_id: Option<String>,
+    /// Name of the latest event, i.e. `update_address`
+    event: String,
+    timestamp: DateTime,
+}
+
+/// Handle requests for the latest changed reporters, cases, addresses and assets, newest first
+///
+/// Entities keep the block timestamp of their latest event only, so an entity changed
+/// several times is listed once.
+pub(crate) async fn recent_handler(
+    state: State<AppState>,
+    params: Query<RecentParams>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(network = ?params.network, limit = ?params.limit, "Received recent request");
+    let db = &state.read_conn;
+
+    let limit = params.limit.unwrap_or(DEFAULT_RECENT_LIMIT);
+
+    if limit == 0 || limit > MAX_RECENT_LIMIT {
+        return Err(AppError::invalid_request(&format!(
+            "Limit must be between 1 and {MAX_RECENT_LIMIT}"
+        )));
+    }
+
+    if let Some(network) = &params.network {
+        if network::Entity::find_by_id(network.clone())
+            .one(db)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::new(
+                StatusCode::NOT_FOUND,
+                "This network does not exist".to_string(),
+            ));
+        }
+    }
+
+    let data = RecentEntry::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        RECENT_QUERY,
+        [params.network.clone().into(), (limit as i64).into()],
+    ))
+    .all(db)
+    .await?;
+
+    Ok(Json(serde_json::json!({ "data": data })))
+}
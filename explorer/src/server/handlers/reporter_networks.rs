@@ -0,0 +1,140 @@
+use {
+    axum::{
+        extract::{Path, State},
+        http::StatusCode,
+        response::IntoResponse,
+        Json,
+    },
+    hapi_core::{client::solana::get_reporter_account_address, HapiCoreNetwork},
+    sea_orm::{prelude::DateTime, ColumnTrait, EntityTrait, QueryFilter, QueryOrder},
+    serde::Serialize,
+    std::{collections::HashMap, str::FromStr},
+    uuid::Uuid,
+};
+
+use crate::{
+    application::AppState,
+    entity::{network, reporter, types::NetworkBackend},
+    error::AppError,
+};
+
+#[derive(Serialize)]
+struct NetworkReporter {
+    network_id: String,
+    backend: Option<NetworkBackend>,
+    id: Uuid,
+    /// Account the reporter signs with on the network
+    account: String,
+    /// Reporter PDA on Solana networks with a configured contract address
+    reporter_address: Option<String>,
+    role: String,
+    status: String,
+    name: String,
+    url: String,
+    stake: String,
+    unlock_timestamp: String,
+    updated_at: DateTime,
+}
+
+/// Handle requests for the reporter records of every network, by reporter id or name
+///
+/// A reporter keeps the same id on every network it is registered on, so the id links its
+/// records. A name that is not an id is matched exactly, it may match several reporters.
+pub(crate) async fn reporter_networks_handler(
+    state: State<AppState>,
+    Path(key): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(%key, "Received reporter networks request");
+    let db = &state.read_conn;
+
+    let condition = match Uuid::from_str(&key) {
+        Ok(id) => reporter::Column::Id.eq(id),
+        Err(_) => reporter::Column::Name.eq(key.as_str()),
+    };
+
+    let reporters = reporter::Entity::find()
+        .filter(condition)
+        .order_by_asc(reporter::Column::NetworkId)
+        .order_by_asc(reporter::Column::Id)
+        .all(db)
+        .await?;
+
+    if reporters.is_empty() {
+        return Err(AppError::new(
+            StatusCode::NOT_FOUND,
+            "This reporter does not exist".to_string(),
+        ));
+    }
+
+    let networks: HashMap<_, _> = network::Entity::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|network| (network.id.clone(), network))
+        .collect();
+
+    let data: Vec<NetworkReporter> = reporters
+        .into_iter()
+        .map(|reporter| {
+            let network = networks.get(&reporter.network_id);
+            let backend = network.map(|network| network.backend);
+
+            NetworkReporter {
+                reporter_address: network
+                    .and_then(|network| reporter_address(&state, &reporter, network)),
+                network_id: reporter.network_id,
+                backend,
+                id: reporter.id,
+                account: reporter.account,
+                role: reporter.role.to_string(),
+                status: reporter.status.to_string(),
+                name: reporter.name,
+                url: reporter.url,
+                stake: reporter.stake,
+                unlock_timestamp: reporter.unlock_timestamp,
+                updated_at: reporter.updated_at,
+            }
+        })
+        .collect();
+
+    Ok(Json(serde_json::json!({ "data": data })))
+}
+
+/// Solana programs keep reporters in PDAs of the network account, named after the HAPI network
+fn reporter_address(
+    state: &AppState,
+    reporter: &reporter::Model,
+    network: &network::Model,
+) -> Option<String> {
+    if network.backend != NetworkBackend::Solana {
+        return None;
+    }
+
+    let network_name = program_network(network);
+    let program_id = state
+        .networks
+        .iter()
+        .find(|configured| configured.id == reporter.network_id)?
+        .contract_address
+        .as_deref()?;
+
+    match get_reporter_account_address(program_id, &network_name.to_string(), reporter.id) {
+        Ok(address) => Some(address),
+        Err(error) => {
+            tracing::warn!(?error, network_id = %reporter.network_id, "Invalid program id");
+            None
+        }
+    }
+}
+
+/// HAPI network that the Solana program serves for the registered network
+///
+/// Network ids and names are free-form, so a network that names none of the Solana backed
+/// networks is taken for Solana itself.
+fn program_network(network: &network::Model) -> HapiCoreNetwork {
+    [&network.name, &network.id]
+        .into_iter()
+        .filter_map(|name| HapiCoreNetwork::from_str(name).ok())
+        .find(|hapi_network| NetworkBackend::from(hapi_network.clone()) == NetworkBackend::Solana)
+        .unwrap_or(HapiCoreNetwork::Solana)
+}
@@ -0,0 +1,114 @@
+use {
+    axum::{
+        extract::{Query, State},
+        response::IntoResponse,
+        Json,
+    },
+    sea_orm::{
+        sea_query::{extension::postgres::PgExpr, Expr},
+        EntityTrait, QueryFilter, QuerySelect,
+    },
+    serde::{Deserialize, Serialize},
+    uuid::Uuid,
+};
+
+use crate::{
+    application::AppState,
+    cache::CachedEndpoint,
+    entity::{case, reporter},
+    error::AppError,
+};
+
+const DEFAULT_SEARCH_LIMIT: u64 = 25;
+
+#[derive(Deserialize)]
+pub struct SearchParams {
+    q: String,
+    limit: Option<u64>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum SearchResult {
+    Reporter {
+        network_id: String,
+        id: Uuid,
+        name: String,
+    },
+    Case {
+        network_id: String,
+        id: Uuid,
+        name: String,
+    },
+}
+
+/// Handle case-insensitive search over reporter and case names
+pub(crate) async fn search_handler(
+    state: State<AppState>,
+    params: Query<SearchParams>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(query = ?params.q, "Received search request");
+    let db = &state.read_conn;
+
+    let query = params.q.trim();
+    if query.is_empty() {
+        return Err(AppError::invalid_request("Search query must not be empty"));
+    }
+
+    let limit = params.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+    let cache_key = format!("{limit}:{query}");
+
+    if let Some(cached) = state.cache.get(CachedEndpoint::Search, &cache_key) {
+        return Ok(Json(cached));
+    }
+
+    let pattern = format!("%{}%", escape_like(query));
+
+    let reporters = reporter::Entity::find()
+        .filter(Expr::col(reporter::Column::Name).ilike(&pattern))
+        .limit(limit)
+        .all(db)
+        .await?;
+
+    let cases = case::Entity::find()
+        .filter(Expr::col(case::Column::Name).ilike(&pattern))
+        .limit(limit)
+        .all(db)
+        .await?;
+
+    let data: Vec<SearchResult> = reporters
+        .into_iter()
+        .map(|reporter| SearchResult::Reporter {
+            network_id: reporter.network_id,
+            id: reporter.id,
+            name: reporter.name,
+        })
+        .chain(cases.into_iter().map(|case| SearchResult::Case {
+            network_id: case.network_id,
+            id: case.id,
+            name: case.name,
+        }))
+        .collect();
+
+    let json_response = serde_json::json!({
+        "data": data,
+        "meta": {
+            "total": data.len(),
+            "query": query,
+        }
+    });
+
+    state
+        .cache
+        .insert(CachedEndpoint::Search, cache_key, json_response.clone());
+
+    Ok(Json(json_response))
+}
+
+// Wildcards typed by the user are matched literally
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
@@ -0,0 +1,64 @@
+use {
+    axum::{
+        extract::{Path, State},
+        http::{HeaderMap, StatusCode},
+        response::{IntoResponse, Response},
+        Json,
+    },
+    sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder},
+    uuid::Uuid,
+};
+
+use super::ndjson::{accepts_ndjson, ndjson_response};
+use crate::{
+    application::AppState,
+    entity::{reporter, reporter_stake_change},
+    error::AppError,
+};
+
+/// Handle requests for the stake deposits and releases of a reporter in time order
+///
+/// Every entry holds the signed amount of the change along with the resulting balance.
+/// A reporter id may be registered on several networks, changes of all of them are listed.
+/// With `Accept: application/x-ndjson` the changes are streamed one per line.
+pub(crate) async fn reporter_stake_history_handler(
+    state: State<AppState>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    tracing::info!(%id, "Received reporter stake history request");
+    let db = &state.read_conn;
+
+    let reporter_count = reporter::Entity::find()
+        .filter(reporter::Column::Id.eq(id))
+        .count(db)
+        .await?;
+
+    if reporter_count == 0 {
+        return Err(AppError::new(
+            StatusCode::NOT_FOUND,
+            "This reporter does not exist".to_string(),
+        ));
+    }
+
+    let changes = reporter_stake_change::Entity::find()
+        .filter(reporter_stake_change::Column::ReporterId.eq(id))
+        .order_by_asc(reporter_stake_change::Column::ChangedAt)
+        .order_by_asc(reporter_stake_change::Column::Id);
+
+    if accepts_ndjson(&headers) {
+        let (sender, response) = ndjson_response();
+        let db = state.read_conn.clone();
+
+        tokio::spawn(async move {
+            let rows = changes.stream(&db).await;
+            sender.forward(rows).await;
+        });
+
+        return Ok(response);
+    }
+
+    let data = changes.all(db).await?;
+
+    Ok(Json(serde_json::json!({ "data": data })).into_response())
+}
@@ -1,6 +1,122 @@
-use axum::response::IntoResponse;
+use {
+    axum::{
+        extract::{Query, State},
+        http::StatusCode,
+        response::IntoResponse,
+        Json,
+    },
+    chrono::{Duration, DurationRound, Utc},
+    sea_orm::{prelude::DateTime, ColumnTrait, EntityTrait, QueryFilter, QueryOrder},
+    serde::{Deserialize, Serialize},
+    std::collections::BTreeMap,
+};
 
-/// Handle stats Requests
-pub(crate) async fn stats_handler() -> impl IntoResponse {
-    unimplemented!()
+use crate::{
+    application::AppState,
+    entity::{event_count, network},
+    error::AppError,
+};
+
+/// Hourly counts go back one week at most
+const MAX_STATS_HOURS: i64 = 24 * 7;
+
+#[derive(Deserialize)]
+pub struct StatsParams {
+    network: Option<String>,
+    /// Count the events of the last hours only and list them hour by hour
+    hours: Option<i64>,
+}
+
+#[derive(Serialize)]
+struct HourlyEvents {
+    hour: DateTime,
+    events: BTreeMap<String, i64>,
+}
+
+#[derive(Serialize)]
+struct NetworkEvents {
+    network_id: String,
+    total: i64,
+    /// Number of processed events by event name
+    events: BTreeMap<String, i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hourly: Option<Vec<HourlyEvents>>,
+}
+
+/// Handle requests for the number of processed events of every network by event name
+pub(crate) async fn stats_handler(
+    state: State<AppState>,
+    params: Query<StatsParams>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(network = ?params.network, hours = ?params.hours, "Received stats request");
+    let db = &state.read_conn;
+
+    let mut query = event_count::Entity::find()
+        .order_by_asc(event_count::Column::NetworkId)
+        .order_by_asc(event_count::Column::Hour);
+
+    if let Some(network) = &params.network {
+        if network::Entity::find_by_id(network.clone())
+            .one(db)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::new(
+                StatusCode::NOT_FOUND,
+                "This network does not exist".to_string(),
+            ));
+        }
+
+        query = query.filter(event_count::Column::NetworkId.eq(network.as_str()));
+    }
+
+    if let Some(hours) = params.hours {
+        if !(1..=MAX_STATS_HOURS).contains(&hours) {
+            return Err(AppError::invalid_request(&format!(
+                "Hours must be between 1 and {MAX_STATS_HOURS}"
+            )));
+        }
+
+        // The current hour is one of them
+        let now = Utc::now().naive_utc();
+        let since =
+            now.duration_trunc(Duration::hours(1)).unwrap_or(now) - Duration::hours(hours - 1);
+
+        query = query.filter(event_count::Column::Hour.gte(since));
+    }
+
+    let mut data: Vec<NetworkEvents> = vec![];
+
+    for row in query.all(db).await? {
+        // Rows are ordered by network and hour, so each of them starts a single group
+        if data
+            .last()
+            .map_or(true, |last| last.network_id != row.network_id)
+        {
+            data.push(NetworkEvents {
+                network_id: row.network_id.clone(),
+                total: 0,
+                events: BTreeMap::new(),
+                hourly: params.hours.map(|_| vec![]),
+            });
+        }
+        let network = data.last_mut().expect("Network is added");
+
+        network.total += row.count;
+        *network.events.entry(row.event.clone()).or_default() += row.count;
+
+        if let Some(hourly) = &mut network.hourly {
+            if hourly.last().map_or(true, |last| last.hour != row.hour) {
+                hourly.push(HourlyEvents {
+                    hour: row.hour,
+                    events: BTreeMap::new(),
+                });
+            }
+            let hour = hourly.last_mut().expect("Hour is added");
+
+            hour.events.insert(row.event, row.count);
+        }
+    }
+
+    Ok(Json(serde_json::json!({ "data": data })))
 }
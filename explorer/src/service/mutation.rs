@@ -1,13 +1,20 @@
 use crate::entity::{
-    indexer, network,
-    {types::NetworkBackend, FromPayload},
+    address, address_report, asset, case, case_relation, event_count, indexer, network, reporter,
+    reporter_stake_change,
+    {
+        types::{AddressStatus, CaseStatus, NetworkBackend, ReporterStatus},
+        FromPayload,
+    },
 };
 
 use super::get_network_id;
 
 use {
     chrono::{DateTime, NaiveDateTime, Utc},
-    sea_orm::*,
+    sea_orm::{
+        sea_query::{Expr, Func, OnConflict},
+        *,
+    },
     uuid::Uuid,
 };
 
@@ -24,15 +31,41 @@ impl EntityMutation {
     where
         <M::Entity as EntityTrait>::Model: IntoActiveModel<M>,
         M: ActiveModelBehavior + FromPayload<T> + Send,
+    {
+        Self::new_entity::<M, T>(payload, network_id, timestamp)?
+            .insert(db)
+            .await
+    }
+
+    /// Active model of an entity, created by the payload at the block timestamp
+    pub fn new_entity<M, T>(payload: T, network_id: String, timestamp: u64) -> Result<M, DbErr>
+    where
+        M: ActiveModelBehavior + FromPayload<T>,
     {
         let created_at = Some(
             NaiveDateTime::from_timestamp_opt(timestamp as i64, 0)
                 .ok_or(DbErr::Custom("Invalid block timestamp".to_string()))?,
         );
 
-        M::from(network_id, created_at, created_at, payload)
-            .insert(db)
+        Ok(M::from(network_id, created_at, created_at, payload))
+    }
+
+    /// Inserts the entities with a single multi-row statement
+    ///
+    /// The statement fails as a whole if any of the rows exists already, known entities
+    /// have to be updated with `update_entity` instead.
+    pub async fn create_entities<M>(db: &DbConn, models: Vec<M>) -> Result<(), DbErr>
+    where
+        M: ActiveModelTrait + ActiveModelBehavior + Send,
+    {
+        if models.is_empty() {
+            return Ok(());
+        }
+
+        M::Entity::insert_many(models)
+            .exec_without_returning(db)
             .await
+            .map(|_| ())
     }
 
     /// Universal method for updating entities in database
@@ -56,6 +89,275 @@ impl EntityMutation {
             .await
     }
 
+    /// Marks the address report as withdrawn, the record itself is kept for audit
+    pub async fn withdraw_address(
+        db: &DbConn,
+        network_id: String,
+        address: String,
+        reason: String,
+        timestamp: u64,
+    ) -> Result<address::Model, DbErr> {
+        let withdrawn_at = NaiveDateTime::from_timestamp_opt(timestamp as i64, 0)
+            .ok_or(DbErr::Custom("Invalid withdrawal timestamp".to_string()))?;
+
+        address::ActiveModel {
+            network_id: Set(network_id),
+            address: Set(address),
+            status: Set(AddressStatus::Withdrawn),
+            withdrawal_reason: Set(Some(reason)),
+            withdrawn_at: Set(Some(withdrawn_at)),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    /// Deletes an asset whose creation never made it to the canonical chain
+    pub async fn delete_asset(
+        db: &DbConn,
+        network_id: String,
+        address: String,
+        asset_id: String,
+    ) -> Result<(), DbErr> {
+        asset::Entity::delete_by_id((network_id, address, asset_id))
+            .exec(db)
+            .await
+            .map(|_| ())
+    }
+
+    /// Deletes a case whose creation never made it to the canonical chain
+    ///
+    /// Withdrawn addresses of the case are kept for audit and still refer to it, in which
+    /// case it is closed instead. Returns the closed case.
+    pub async fn revert_case(
+        db: &DbConn,
+        network_id: String,
+        case_id: Uuid,
+        timestamp: u64,
+    ) -> Result<Option<case::Model>, DbErr> {
+        let referred = address::Entity::find()
+            .filter(address::Column::NetworkId.eq(network_id.as_str()))
+            .filter(address::Column::CaseId.eq(case_id))
+            .count(db)
+            .await?
+            + asset::Entity::find()
+                .filter(asset::Column::NetworkId.eq(network_id.as_str()))
+                .filter(asset::Column::CaseId.eq(case_id))
+                .count(db)
+                .await?;
+
+        if referred == 0 {
+            case::Entity::delete_by_id((network_id, case_id))
+                .exec(db)
+                .await?;
+
+            return Ok(None);
+        }
+
+        case::ActiveModel {
+            network_id: Set(network_id),
+            id: Set(case_id),
+            status: Set(CaseStatus::Closed),
+            updated_at: Set(block_time(timestamp)?),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+        .map(Some)
+    }
+
+    /// Deletes a reporter whose creation never made it to the canonical chain
+    ///
+    /// Withdrawn addresses and their reports are kept for audit and may still refer to the
+    /// reporter, in which case it is deactivated instead. Returns the deactivated reporter.
+    pub async fn revert_reporter(
+        db: &DbConn,
+        network_id: String,
+        reporter_id: Uuid,
+        timestamp: u64,
+    ) -> Result<Option<reporter::Model>, DbErr> {
+        let referred = case::Entity::find()
+            .filter(case::Column::NetworkId.eq(network_id.as_str()))
+            .filter(case::Column::ReporterId.eq(reporter_id))
+            .count(db)
+            .await?
+            + address::Entity::find()
+                .filter(address::Column::NetworkId.eq(network_id.as_str()))
+                .filter(address::Column::ReporterId.eq(reporter_id))
+                .count(db)
+                .await?
+            + asset::Entity::find()
+                .filter(asset::Column::NetworkId.eq(network_id.as_str()))
+                .filter(asset::Column::ReporterId.eq(reporter_id))
+                .count(db)
+                .await?
+            + address_report::Entity::find()
+                .filter(address_report::Column::NetworkId.eq(network_id.as_str()))
+                .filter(address_report::Column::ReporterId.eq(reporter_id))
+                .count(db)
+                .await?;
+
+        if referred == 0 {
+            reporter::Entity::delete_by_id((network_id, reporter_id))
+                .exec(db)
+                .await?;
+
+            return Ok(None);
+        }
+
+        reporter::ActiveModel {
+            network_id: Set(network_id),
+            id: Set(reporter_id),
+            status: Set(ReporterStatus::Inactive),
+            stake: Set("0".to_string()),
+            updated_at: Set(block_time(timestamp)?),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+        .map(Some)
+    }
+
+    /// Flags the reporter unless it is flagged already, so the time of the first flag is kept
+    ///
+    /// Returns false if the reporter is not stored.
+    pub async fn flag_reporter(
+        db: &DbConn,
+        network_id: String,
+        reporter_id: Uuid,
+        timestamp: u64,
+    ) -> Result<bool, DbErr> {
+        let flagged_at = NaiveDateTime::from_timestamp_opt(timestamp as i64, 0)
+            .ok_or(DbErr::Custom("Invalid block timestamp".to_string()))?;
+
+        let result = reporter::Entity::update_many()
+            .col_expr(
+                reporter::Column::FlaggedAt,
+                Func::coalesce([
+                    Expr::col(reporter::Column::FlaggedAt).into(),
+                    Expr::value(flagged_at),
+                ])
+                .into(),
+            )
+            .filter(reporter::Column::NetworkId.eq(network_id))
+            .filter(reporter::Column::Id.eq(reporter_id))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Keeps the current classification of the addresses by their reporters
+    ///
+    /// A reporter has a single report per address, so a later one replaces the former.
+    pub async fn record_address_reports(
+        db: &DbConn,
+        addresses: &[address::Model],
+    ) -> Result<(), DbErr> {
+        if addresses.is_empty() {
+            return Ok(());
+        }
+
+        address_report::Entity::insert_many(addresses.iter().map(address_report::ActiveModel::from))
+            .on_conflict(
+                OnConflict::columns([
+                    address_report::Column::NetworkId,
+                    address_report::Column::Address,
+                    address_report::Column::ReporterId,
+                ])
+                .update_columns([
+                    address_report::Column::Category,
+                    address_report::Column::Risk,
+                    address_report::Column::ReportedAt,
+                ])
+                .to_owned(),
+            )
+            .exec_without_returning(db)
+            .await
+            .map(|_| ())
+    }
+
+    /// Replaces the links of a case with the ones it has on chain
+    ///
+    /// A reorganization may drop a link, so the links missing from the case are removed.
+    pub async fn record_case_relations(
+        db: &DbConn,
+        network_id: String,
+        case_id: Uuid,
+        related_cases: &[Uuid],
+    ) -> Result<(), DbErr> {
+        case_relation::Entity::delete_many()
+            .filter(case_relation::Column::NetworkId.eq(network_id.as_str()))
+            .filter(case_relation::Column::CaseId.eq(case_id))
+            .filter(case_relation::Column::RelatedCaseId.is_not_in(related_cases.to_vec()))
+            .exec(db)
+            .await?;
+
+        if related_cases.is_empty() {
+            return Ok(());
+        }
+
+        case_relation::Entity::insert_many(related_cases.iter().map(|related_case_id| {
+            case_relation::ActiveModel::new(network_id.clone(), case_id, *related_case_id)
+        }))
+        .on_conflict(
+            OnConflict::columns([
+                case_relation::Column::NetworkId,
+                case_relation::Column::CaseId,
+                case_relation::Column::RelatedCaseId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(db)
+        .await
+        .map(|_| ())
+    }
+
+    /// Keeps a change of a reporter stake in the stake history
+    pub async fn record_stake_change(
+        db: &DbConn,
+        change: reporter_stake_change::ActiveModel,
+    ) -> Result<(), DbErr> {
+        reporter_stake_change::Entity::insert(change)
+            .exec_without_returning(db)
+            .await
+            .map(|_| ())
+    }
+
+    /// Adds processed events to the count of their network, event name and hour
+    pub async fn count_events(
+        db: &DbConn,
+        network_id: String,
+        event: String,
+        hour: NaiveDateTime,
+        count: i64,
+    ) -> Result<(), DbErr> {
+        let model = event_count::ActiveModel {
+            network_id: Set(network_id),
+            event: Set(event),
+            hour: Set(hour),
+            count: Set(count),
+        };
+
+        event_count::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    event_count::Column::NetworkId,
+                    event_count::Column::Event,
+                    event_count::Column::Hour,
+                ])
+                .value(
+                    event_count::Column::Count,
+                    Expr::col((event_count::Entity, event_count::Column::Count)).add(count),
+                )
+                .to_owned(),
+            )
+            .exec_without_returning(db)
+            .await
+            .map(|_| ())
+    }
+
     /// Method for creating network in database
     pub async fn create_network(
         db: &DbConn,
@@ -126,3 +428,8 @@ impl EntityMutation {
         .await
     }
 }
+
+fn block_time(timestamp: u64) -> Result<NaiveDateTime, DbErr> {
+    NaiveDateTime::from_timestamp_opt(timestamp as i64, 0)
+        .ok_or(DbErr::Custom("Invalid block timestamp".to_string()))
+}
@@ -1,11 +1,11 @@
 use {
     async_graphql::{InputType, OutputType},
     chrono::{Duration, NaiveDate},
-    sea_orm::{prelude::*, sea_query::Cond, PaginatorTrait},
+    sea_orm::{prelude::*, sea_query::Cond, PaginatorTrait, QueryOrder},
 };
 
 use crate::entity::{
-    network,
+    case, case_relation, network,
     pagination::{EntityInput, EntityPage, Paginator},
     types::NetworkBackend,
     EntityFilter,
@@ -23,6 +23,29 @@ impl EntityQuery {
         M::find_by_id(id).one(db).await
     }
 
+    /// Fetches the cases linked to a case, in the order of their creation
+    pub async fn find_related_cases(
+        db: &DbConn,
+        network_id: String,
+        case_id: Uuid,
+    ) -> Result<Vec<case::Model>, DbErr> {
+        let related: Vec<Uuid> = case_relation::Entity::find()
+            .filter(case_relation::Column::NetworkId.eq(network_id.as_str()))
+            .filter(case_relation::Column::CaseId.eq(case_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|relation| relation.related_case_id)
+            .collect();
+
+        case::Entity::find()
+            .filter(case::Column::NetworkId.eq(network_id))
+            .filter(case::Column::Id.is_in(related))
+            .order_by_asc(case::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
     /// Universal method for fetching entities from database
     pub async fn find_many<M>(
         db: &DbConn,
@@ -30,16 +53,16 @@ impl EntityQuery {
     ) -> Result<EntityPage<M::Model>, DbErr>
     where
         M: EntityTrait + EntityFilter,
-        <M as EntityFilter>::Filter: InputType,
+        <M as EntityFilter>::Filter: InputType + Default,
         <M as EntityFilter>::Condition: InputType + Default,
         M::Model: OutputType,
         M::Column: From<<M as EntityFilter>::Condition>,
     {
-        let mut query = M::find();
+        // Filters may carry defaults, so they are applied even if none are given
+        let filter = input.filtering.unwrap_or_default();
+        M::validate(&filter)?;
 
-        if let Some(filter) = input.filtering {
-            query = M::filter(query, &filter);
-        }
+        let mut query = M::filter(M::find(), &filter);
 
         if let Some(search) = input.search {
             query = Self::search(query, &search);
@@ -0,0 +1,397 @@
+use {
+    anyhow::{bail, Result},
+    futures::{
+        future::LocalBoxFuture,
+        stream::{self, StreamExt, TryStreamExt},
+        FutureExt,
+    },
+    hapi_core::{
+        client::entities::{address::Address, asset::Asset, case::Case, reporter::Reporter},
+        HapiCore,
+    },
+    sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter},
+    serde::Serialize,
+    std::{collections::HashMap, fmt::Debug},
+};
+
+use crate::entity::{
+    address, asset, case, reporter, sanitize_name,
+    types::{CaseStatus, Category, ReporterRole, ReporterStatus},
+};
+
+/// Entity types in the order they are verified
+const VERIFIED_ENTITIES: [VerifiedEntity; 4] = [
+    VerifiedEntity::Reporter,
+    VerifiedEntity::Case,
+    VerifiedEntity::Address,
+    VerifiedEntity::Asset,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerifiedEntity {
+    Reporter,
+    Case,
+    Address,
+    Asset,
+}
+
+/// On-chain entities of a single type, as one batch returns them
+pub enum ChainBatch {
+    Reporters(Vec<Reporter>),
+    Cases(Vec<Case>),
+    Addresses(Vec<Address>),
+    Assets(Vec<Asset>),
+}
+
+/// Contract state the stored entities are verified against
+pub trait ChainEntities {
+    fn count(&self, entity: VerifiedEntity) -> LocalBoxFuture<'_, Result<u64>>;
+
+    fn batch(
+        &self,
+        entity: VerifiedEntity,
+        skip: u64,
+        take: u64,
+    ) -> LocalBoxFuture<'_, Result<ChainBatch>>;
+}
+
+impl ChainEntities for dyn HapiCore {
+    fn count(&self, entity: VerifiedEntity) -> LocalBoxFuture<'_, Result<u64>> {
+        async move {
+            Ok(match entity {
+                VerifiedEntity::Reporter => self.get_reporter_count().await?,
+                VerifiedEntity::Case => self.get_case_count().await?,
+                VerifiedEntity::Address => self.get_address_count().await?,
+                VerifiedEntity::Asset => self.get_asset_count().await?,
+            })
+        }
+        .boxed_local()
+    }
+
+    fn batch(
+        &self,
+        entity: VerifiedEntity,
+        skip: u64,
+        take: u64,
+    ) -> LocalBoxFuture<'_, Result<ChainBatch>> {
+        async move {
+            Ok(match entity {
+                VerifiedEntity::Reporter => {
+                    ChainBatch::Reporters(self.get_reporters(skip, take).await?)
+                }
+                VerifiedEntity::Case => ChainBatch::Cases(self.get_cases(skip, take).await?),
+                VerifiedEntity::Address => {
+                    ChainBatch::Addresses(self.get_addresses(skip, take).await?)
+                }
+                VerifiedEntity::Asset => ChainBatch::Assets(self.get_assets(skip, take).await?),
+            })
+        }
+        .boxed_local()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyOptions {
+    /// The number of batches read at once
+    pub concurrency: usize,
+    /// The number of entities read by a single request
+    pub batch_size: u64,
+    /// Share of the batches that are read, between 0 exclusive and 1 inclusive
+    pub sample_rate: f64,
+}
+
+impl VerifyOptions {
+    fn validate(&self) -> Result<()> {
+        if self.concurrency == 0 {
+            bail!("Concurrency must be positive");
+        }
+
+        if self.batch_size == 0 {
+            bail!("Batch size must be positive");
+        }
+
+        if !(self.sample_rate > 0.0 && self.sample_rate <= 1.0) {
+            bail!("Sample rate must be greater than 0 and at most 1");
+        }
+
+        Ok(())
+    }
+
+    /// Sampled batches are spread evenly over the entities
+    fn is_sampled(&self, batch: u64) -> bool {
+        ((batch + 1) as f64 * self.sample_rate).floor() > (batch as f64 * self.sample_rate).floor()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Mismatch {
+    pub entity: VerifiedEntity,
+    /// Reporter or case id, or the address
+    pub id: String,
+    pub asset_id: Option<String>,
+    /// Field that differs, none if the entity is not stored at all
+    pub field: Option<String>,
+    pub stored: Option<String>,
+    pub on_chain: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct VerifyReport {
+    /// The number of on-chain entities compared to the stored ones
+    pub checked: u64,
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// Compares the entities of a network contract to the ones the explorer stores
+///
+/// Only the on-chain entities are looked up, so the stored entities that are missing on
+/// chain are not reported. Withdrawals are made by the explorer and are not compared.
+pub async fn verify<S>(
+    db: &DatabaseConnection,
+    network_id: &str,
+    source: &S,
+    options: &VerifyOptions,
+) -> Result<VerifyReport>
+where
+    S: ChainEntities + ?Sized,
+{
+    options.validate()?;
+
+    let mut report = VerifyReport::default();
+
+    for entity in VERIFIED_ENTITIES {
+        let count = source.count(entity).await?;
+        let batches = count.div_ceil(options.batch_size);
+
+        let (checked, mut mismatches) = stream::iter(0..batches)
+            .filter(|batch| futures::future::ready(options.is_sampled(*batch)))
+            .map(|batch| async move {
+                let skip = batch * options.batch_size;
+                let entities = source.batch(entity, skip, options.batch_size).await?;
+
+                compare_batch(db, network_id, entities).await
+            })
+            .buffer_unordered(options.concurrency)
+            .try_fold(
+                (0, vec![]),
+                |(checked, mut mismatches), (batch_checked, batch_mismatches)| async move {
+                    mismatches.extend(batch_mismatches);
+                    Ok((checked + batch_checked, mismatches))
+                },
+            )
+            .await?;
+
+        tracing::info!(?entity, count, checked, "Verified entities");
+
+        report.checked += checked;
+        report.mismatches.append(&mut mismatches);
+    }
+
+    report.mismatches.sort();
+
+    Ok(report)
+}
+
+/// Looks the batch up with a single query and compares its entities field by field
+async fn compare_batch(
+    db: &DatabaseConnection,
+    network_id: &str,
+    batch: ChainBatch,
+) -> Result<(u64, Vec<Mismatch>)> {
+    let mut diff = Diff::default();
+
+    match batch {
+        ChainBatch::Reporters(reporters) => {
+            let stored: HashMap<_, _> = reporter::Entity::find()
+                .filter(reporter::Column::NetworkId.eq(network_id))
+                .filter(reporter::Column::Id.is_in(reporters.iter().map(|r| r.id)))
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|model| (model.id, model))
+                .collect();
+
+            for on_chain in reporters {
+                let key = (VerifiedEntity::Reporter, on_chain.id.to_string(), None);
+                let Some(stored) = diff.found(key, stored.get(&on_chain.id)) else {
+                    continue;
+                };
+
+                diff.field("account", &stored.account, &on_chain.account);
+                diff.field("role", stored.role, ReporterRole::from(on_chain.role));
+                diff.field(
+                    "status",
+                    stored.status,
+                    ReporterStatus::from(on_chain.status),
+                );
+                diff.field("name", &stored.name, &sanitize_name(&on_chain.name));
+                diff.field("url", &stored.url, &on_chain.url);
+                diff.field("stake", &stored.stake, &on_chain.stake.to_string());
+                diff.field(
+                    "unlock_timestamp",
+                    &stored.unlock_timestamp,
+                    &on_chain.unlock_timestamp.to_string(),
+                );
+            }
+        }
+        ChainBatch::Cases(cases) => {
+            let stored: HashMap<_, _> = case::Entity::find()
+                .filter(case::Column::NetworkId.eq(network_id))
+                .filter(case::Column::Id.is_in(cases.iter().map(|c| c.id)))
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|model| (model.id, model))
+                .collect();
+
+            for on_chain in cases {
+                let key = (VerifiedEntity::Case, on_chain.id.to_string(), None);
+                let Some(stored) = diff.found(key, stored.get(&on_chain.id)) else {
+                    continue;
+                };
+
+                diff.field("name", &stored.name, &sanitize_name(&on_chain.name));
+                diff.field("url", &stored.url, &on_chain.url);
+                diff.field("status", stored.status, CaseStatus::from(on_chain.status));
+                diff.field("reporter_id", stored.reporter_id, on_chain.reporter_id);
+            }
+        }
+        ChainBatch::Addresses(addresses) => {
+            let stored: HashMap<_, _> = address::Entity::find()
+                .filter(address::Column::NetworkId.eq(network_id))
+                .filter(address::Column::Address.is_in(addresses.iter().map(|a| a.address.clone())))
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|model| (model.address.clone(), model))
+                .collect();
+
+            for on_chain in addresses {
+                let key = (VerifiedEntity::Address, on_chain.address.clone(), None);
+                let Some(stored) = diff.found(key, stored.get(&on_chain.address)) else {
+                    continue;
+                };
+
+                diff.field("case_id", stored.case_id, on_chain.case_id);
+                diff.field("reporter_id", stored.reporter_id, on_chain.reporter_id);
+                diff.field("risk", stored.risk, i16::from(on_chain.risk));
+                diff.field(
+                    "category",
+                    stored.category,
+                    Category::from(on_chain.category),
+                );
+                diff.field(
+                    "confirmations",
+                    &stored.confirmations,
+                    &on_chain.confirmations.to_string(),
+                );
+            }
+        }
+        ChainBatch::Assets(assets) => {
+            let stored: HashMap<_, _> = asset::Entity::find()
+                .filter(asset::Column::NetworkId.eq(network_id))
+                .filter(asset::Column::Address.is_in(assets.iter().map(|a| a.address.clone())))
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|model| ((model.address.clone(), model.id.clone()), model))
+                .collect();
+
+            for on_chain in assets {
+                let asset_id = on_chain.asset_id.to_string();
+                let key = (
+                    VerifiedEntity::Asset,
+                    on_chain.address.clone(),
+                    Some(asset_id.clone()),
+                );
+                let Some(stored) = diff.found(key, stored.get(&(on_chain.address, asset_id)))
+                else {
+                    continue;
+                };
+
+                diff.field("case_id", stored.case_id, on_chain.case_id);
+                diff.field("reporter_id", stored.reporter_id, on_chain.reporter_id);
+                diff.field("risk", stored.risk, i16::from(on_chain.risk));
+                diff.field(
+                    "category",
+                    stored.category,
+                    Category::from(on_chain.category),
+                );
+                diff.field(
+                    "confirmations",
+                    &stored.confirmations,
+                    &on_chain.confirmations.to_string(),
+                );
+            }
+        }
+    }
+
+    Ok((diff.checked, diff.mismatches))
+}
+
+type EntityKey = (VerifiedEntity, String, Option<String>);
+
+/// Collects the mismatches of the entity that is compared at the moment
+#[derive(Default)]
+struct Diff {
+    checked: u64,
+    current: Option<EntityKey>,
+    mismatches: Vec<Mismatch>,
+}
+
+impl Diff {
+    /// Starts comparing an on-chain entity, records it as missing if it is not stored
+    fn found<'m, M>(&mut self, key: EntityKey, stored: Option<&'m M>) -> Option<&'m M> {
+        self.checked += 1;
+
+        if stored.is_none() {
+            self.push(&key, None, None, None);
+        }
+
+        self.current = Some(key);
+
+        stored
+    }
+
+    fn field<T: PartialEq + Debug>(&mut self, name: &str, stored: T, on_chain: T) {
+        if stored == on_chain {
+            return;
+        }
+
+        let key = self.current.clone().expect("no entity is compared");
+        self.push(
+            &key,
+            Some(name.to_string()),
+            Some(to_plain_string(&stored)),
+            Some(to_plain_string(&on_chain)),
+        );
+    }
+
+    fn push(
+        &mut self,
+        (entity, id, asset_id): &EntityKey,
+        field: Option<String>,
+        stored: Option<String>,
+        on_chain: Option<String>,
+    ) {
+        self.mismatches.push(Mismatch {
+            entity: *entity,
+            id: id.clone(),
+            asset_id: asset_id.clone(),
+            field,
+            stored,
+            on_chain,
+        });
+    }
+}
+
+/// Debug output without the quotes around strings
+fn to_plain_string<T: Debug>(value: &T) -> String {
+    let value = format!("{value:?}");
+
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .map_or(value.clone(), ToString::to_string)
+}
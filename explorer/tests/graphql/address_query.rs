@@ -1,9 +1,10 @@
 use super::replacer;
-use crate::helpers::{FromTestPayload, RequestSender, TestApp, TestData};
+use crate::helpers::{get_test_data, FromTestPayload, RequestSender, TestApp, TestData};
 
 use {
+    chrono::NaiveDateTime,
     hapi_core::client::{entities::address::Address, events::EventName},
-    hapi_indexer::{PushData, PushPayload},
+    hapi_indexer::{PushData, PushEvent, PushPayload, PUSH_SCHEMA_VERSION},
     serde_json::{json, Value},
 };
 
@@ -19,6 +20,9 @@ const GET_ADDRESS_QUERY: &str = "
             confirmations
             createdAt
             updatedAt
+            status
+            withdrawalReason
+            withdrawnAt
         }
     }
 ";
@@ -252,3 +256,227 @@ async fn get_searched_addresses_test() {
         check_address(&payload, address)
     }
 }
+
+#[tokio::test]
+async fn withdrawn_address_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    for network in &test_app.networks {
+        let data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &data).await;
+
+        let created = data
+            .iter()
+            .find(|payload| payload.event.name == EventName::CreateAddress)
+            .expect("No address in test data");
+        let address = TestData::<Address>::from_payload(created, &network.model.id);
+
+        // The creation is reverted by a chain reorganization
+        let reverted = PushPayload {
+            schema_version: PUSH_SCHEMA_VERSION,
+            network_data: created.network_data.clone(),
+            event: PushEvent {
+                removed: true,
+                ..created.event.clone()
+            },
+            data: created.data.clone(),
+        };
+        test_app.send_events(&sender, &vec![reverted]).await;
+
+        let list_addresses = |status: Option<&str>| {
+            let mut filtering = json!({ "networkId": network.model.id });
+            if let Some(status) = status {
+                filtering["status"] = json!(status);
+            }
+
+            sender.send_graphql(
+                GET_MANY_ADDRESSES,
+                json!({ "input": { "filtering": filtering } }),
+            )
+        };
+
+        // Withdrawn addresses are hidden by default
+        let response = list_addresses(None).await.unwrap();
+        let listed = response["getManyAddresses"]["data"]
+            .as_array()
+            .expect("Empty response");
+        assert!(listed
+            .iter()
+            .all(|value| value["address"] != address.data.address));
+
+        let response = list_addresses(Some("WITHDRAWN")).await.unwrap();
+        assert_eq!(response["getManyAddresses"]["total"], 1);
+        assert_eq!(
+            response["getManyAddresses"]["data"][0]["address"],
+            address.data.address
+        );
+
+        // The record itself is kept for audit
+        let response = sender
+            .send_graphql(
+                GET_ADDRESS_QUERY,
+                json!({
+                    "address": address.data.address,
+                    "networkId": address.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        let value = &response["getAddress"];
+        assert_eq!(value["status"], "WITHDRAWN");
+        assert_ne!(value["withdrawalReason"], Value::Null);
+        assert_ne!(value["withdrawnAt"], Value::Null);
+    }
+}
+
+#[tokio::test]
+async fn invalidated_address_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+
+    let data = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&sender, &data).await;
+
+    let created = data
+        .iter()
+        .find(|payload| payload.event.name == EventName::CreateAddress)
+        .expect("No address in test data");
+    let address = TestData::<Address>::from_payload(created, &network.model.id);
+
+    // The network authority invalidates the report made in error
+    let invalidated = PushPayload {
+        schema_version: PUSH_SCHEMA_VERSION,
+        network_data: created.network_data.clone(),
+        event: PushEvent {
+            name: EventName::InvalidateAddress,
+            ..created.event.clone()
+        },
+        data: created.data.clone(),
+    };
+    test_app.send_events(&sender, &vec![invalidated]).await;
+
+    let response = sender
+        .send_graphql(
+            GET_ADDRESS_QUERY,
+            json!({
+                "address": address.data.address,
+                "networkId": address.network_id
+            }),
+        )
+        .await
+        .unwrap();
+
+    let value = &response["getAddress"];
+    assert_eq!(value["status"], "WITHDRAWN");
+    assert_eq!(
+        value["withdrawalReason"],
+        "Report was invalidated by the network authority"
+    );
+    assert_ne!(value["withdrawnAt"], Value::Null);
+}
+
+fn graphql_time(timestamp: i64) -> String {
+    NaiveDateTime::from_timestamp_opt(timestamp, 0)
+        .expect("Invalid timestamp")
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+/*
+Test cases:
+ - closed range lists only the addresses created within it
+ - range open at the end or at the start
+ - reversed range is rejected
+ */
+#[tokio::test]
+async fn addresses_time_range_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+
+    let data = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&sender, &data).await;
+
+    let created = data
+        .iter()
+        .find(|payload| payload.event.name == EventName::CreateAddress)
+        .expect("No address in test data");
+
+    let timestamps = [1000, 2000, 3000];
+    let payloads: Vec<PushPayload> = timestamps
+        .iter()
+        .map(|&timestamp| {
+            let mut payload = created.clone();
+            payload.event.timestamp = timestamp;
+
+            match &mut payload.data {
+                PushData::Address(address) => address.address = format!("address_{timestamp}"),
+                _ => panic!("Address payload is expected"),
+            }
+
+            payload
+        })
+        .collect();
+    test_app.send_events(&sender, &payloads).await;
+
+    let sender = &sender;
+    let list_addresses = |from: Option<i64>, to: Option<i64>| {
+        let mut filtering = json!({ "networkId": network.model.id });
+        if let Some(from) = from {
+            filtering["from"] = json!(graphql_time(from));
+        }
+        if let Some(to) = to {
+            filtering["to"] = json!(graphql_time(to));
+        }
+
+        async move {
+            let response = sender
+                .send_graphql(
+                    GET_MANY_ADDRESSES,
+                    json!({
+                        "input": {
+                            "filtering": filtering,
+                            "pagination": { "pageNum": 1, "pageSize": 100 }
+                        }
+                    }),
+                )
+                .await?;
+
+            let mut addresses: Vec<String> = response["getManyAddresses"]["data"]
+                .as_array()
+                .expect("Empty response")
+                .iter()
+                .map(|value| value["address"].as_str().unwrap().to_string())
+                .filter(|address| address.starts_with("address_"))
+                .collect();
+            addresses.sort();
+
+            anyhow::Ok(addresses)
+        }
+    };
+
+    assert_eq!(
+        list_addresses(Some(1500), Some(2500)).await.unwrap(),
+        vec!["address_2000"]
+    );
+
+    // Both ends are inclusive
+    assert_eq!(
+        list_addresses(Some(1000), Some(2000)).await.unwrap(),
+        vec!["address_1000", "address_2000"]
+    );
+
+    assert_eq!(
+        list_addresses(Some(1500), None).await.unwrap(),
+        vec!["address_2000", "address_3000"]
+    );
+    assert_eq!(
+        list_addresses(None, Some(2500)).await.unwrap(),
+        vec!["address_1000", "address_2000"]
+    );
+
+    assert!(list_addresses(Some(2500), Some(1500)).await.is_err());
+}
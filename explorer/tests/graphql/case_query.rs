@@ -1,6 +1,7 @@
 use super::replacer;
 use crate::helpers::{
-    create_address_data, create_asset_data, FromTestPayload, RequestSender, TestApp, TestData,
+    create_address_data, create_asset_data, create_case_data, FromTestPayload, RequestSender,
+    TestApp, TestData,
 };
 
 use {
@@ -46,6 +47,15 @@ const GET_MANY_CASES: &str = "
     }
 ";
 
+const GET_RELATED_CASES_QUERY: &str = "
+    query GetRelatedCases($id: UUID!, $networkId: String!) {
+        getRelatedCases(id: $id, networkId: $networkId) {
+            networkId
+            id
+        }
+    }
+";
+
 impl FromTestPayload for TestData<Case> {
     fn from_payload(payload: &PushPayload, network_id: &str) -> TestData<Case> {
         let entity = match &payload.data {
@@ -349,3 +359,83 @@ async fn get_searched_cases_test() {
         check_case(&payload, case)
     }
 }
+
+async fn get_related_case_ids(sender: &RequestSender, case: &TestData<Case>) -> Vec<Value> {
+    let response = sender
+        .send_graphql(
+            GET_RELATED_CASES_QUERY,
+            json!({
+                "id": case.data.id,
+                "networkId": case.network_id
+            }),
+        )
+        .await
+        .unwrap();
+
+    response["getRelatedCases"]
+        .as_array()
+        .expect("Empty response")
+        .iter()
+        .map(|case| case["id"].clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn get_related_cases_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let cases = test_app
+        .global_setup::<Case>(&sender, EventName::UpdateCase)
+        .await;
+
+    for case in cases {
+        let network = test_app.get_network(&case.network_id);
+
+        let mut related = create_case_data(
+            case.data.reporter_id,
+            &network.network,
+            network.model.chain_id.clone(),
+        );
+        test_app.send_events(&sender, &vec![related.clone()]).await;
+
+        let related_case = TestData::<Case>::from_payload(&related, &case.network_id);
+
+        // Links are mutual, so the indexer pushes both of the cases
+        let mut link = related.clone();
+        link.event.name = EventName::LinkCases;
+        link.event.tx_hash = format!("{}-link", link.event.tx_hash);
+        link.data = PushData::Case(Case {
+            related_cases: vec![related_case.data.id],
+            ..case.data.clone()
+        });
+        related.event = link.event.clone();
+        related.data = PushData::Case(Case {
+            related_cases: vec![case.data.id],
+            ..related_case.data.clone()
+        });
+
+        test_app.send_events(&sender, &vec![link, related]).await;
+
+        assert_eq!(
+            get_related_case_ids(&sender, &case).await,
+            vec![json!(related_case.data.id)]
+        );
+        assert_eq!(
+            get_related_case_ids(&sender, &related_case).await,
+            vec![json!(case.data.id)]
+        );
+
+        // A case that has lost its link, i.e. by a reorganization, drops the relation
+        let mut update = create_case_data(
+            case.data.reporter_id,
+            &network.network,
+            network.model.chain_id.clone(),
+        );
+        update.event.name = EventName::UpdateCase;
+        update.data = PushData::Case(case.data.clone());
+
+        test_app.send_events(&sender, &vec![update]).await;
+
+        assert!(get_related_case_ids(&sender, &case).await.is_empty());
+    }
+}
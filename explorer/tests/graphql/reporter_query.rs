@@ -1,8 +1,11 @@
 use super::replacer;
-use crate::helpers::{FromTestPayload, RequestSender, TestApp, TestData};
+use crate::helpers::{create_reporter_data, FromTestPayload, RequestSender, TestApp, TestData};
 
 use {
-    hapi_core::client::{entities::reporter::Reporter, events::EventName},
+    hapi_core::client::{
+        entities::reporter::{Reporter, ReporterRole, ReporterStatus},
+        events::EventName,
+    },
     hapi_indexer::{PushData, PushPayload},
     serde_json::{json, Value},
 };
@@ -262,3 +265,98 @@ async fn get_searched_reporters_test() {
         check_reporter(&payload, reporter)
     }
 }
+
+#[tokio::test]
+async fn get_reporters_by_role_and_status_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = test_app.networks.first().expect("No networks");
+
+    let combinations = [
+        (ReporterRole::Authority, ReporterStatus::Active),
+        (ReporterRole::Authority, ReporterStatus::Active),
+        (ReporterRole::Authority, ReporterStatus::Inactive),
+        (ReporterRole::Validator, ReporterStatus::Active),
+        (ReporterRole::Publisher, ReporterStatus::Unstaking),
+    ];
+
+    let payloads: Vec<PushPayload> = combinations
+        .iter()
+        .map(|(role, status)| {
+            let mut payload =
+                create_reporter_data(&network.network, network.model.chain_id.clone());
+            if let PushData::Reporter(reporter) = &mut payload.data {
+                reporter.role = role.clone();
+                reporter.status = status.clone();
+            }
+
+            payload
+        })
+        .collect();
+
+    test_app.send_events(&sender, &payloads).await;
+
+    let list_reporters = |filtering: Value| {
+        sender.send_graphql(
+            GET_MANY_REPORTERS,
+            json!({ "input": { "filtering": filtering, "ordering": "ASC" } }),
+        )
+    };
+
+    let ids = |response: &Value| -> Vec<String> {
+        let mut ids: Vec<String> = response["getManyReporters"]["data"]
+            .as_array()
+            .expect("Empty response")
+            .iter()
+            .map(|reporter| reporter["id"].as_str().unwrap().to_string())
+            .collect();
+        ids.sort();
+
+        ids
+    };
+
+    let expected_ids = |role: ReporterRole, status: Option<ReporterStatus>| -> Vec<String> {
+        let mut ids: Vec<String> = payloads
+            .iter()
+            .filter_map(|payload| match &payload.data {
+                PushData::Reporter(reporter)
+                    if reporter.role == role
+                        && status
+                            .as_ref()
+                            .map_or(true, |status| &reporter.status == status) =>
+                {
+                    Some(reporter.id.to_string())
+                }
+                _ => None,
+            })
+            .collect();
+        ids.sort();
+
+        ids
+    };
+
+    // Active authorities only
+    let response = list_reporters(json!({ "role": "AUTHORITY", "status": "ACTIVE" }))
+        .await
+        .unwrap();
+    assert_eq!(response["getManyReporters"]["total"], 2);
+    assert_eq!(
+        ids(&response),
+        expected_ids(ReporterRole::Authority, Some(ReporterStatus::Active))
+    );
+
+    // Role alone lists reporters of any status
+    let response = list_reporters(json!({ "role": "AUTHORITY" }))
+        .await
+        .unwrap();
+    assert_eq!(response["getManyReporters"]["total"], 3);
+    assert_eq!(ids(&response), expected_ids(ReporterRole::Authority, None));
+
+    let response = list_reporters(json!({ "role": "VALIDATOR", "status": "INACTIVE" }))
+        .await
+        .unwrap();
+    assert_eq!(response["getManyReporters"]["total"], 0);
+
+    // Roles are validated against the enum
+    assert!(list_reporters(json!({ "role": "OVERLORD" })).await.is_err());
+}
@@ -1,6 +1,6 @@
 use {
     anyhow::{bail, Result},
-    hapi_indexer::get_id_from_jwt,
+    hapi_indexer::{get_id_from_jwt, PushFormat},
     reqwest::{Client, Response},
     serde::Serialize,
     serde_json::{json, Value},
@@ -56,6 +56,26 @@ impl RequestSender {
         RequestSender::check_response(response).await
     }
 
+    /// Posts the body encoded the way the indexer does with the given push format
+    pub(crate) async fn send_encoded<T: Serialize + ?Sized>(
+        &self,
+        url: &str,
+        body: &T,
+        format: PushFormat,
+        token: &str,
+    ) -> Result<Value> {
+        let response = self
+            .web_client
+            .post(format!("{}/{}", &self.address, url))
+            .bearer_auth(token)
+            .header("content-type", format.content_type())
+            .body(format.encode(body)?)
+            .send()
+            .await?;
+
+        RequestSender::check_response(response).await
+    }
+
     pub(crate) async fn get(&self, url: &str) -> Result<Value> {
         let response = self
             .web_client
@@ -66,7 +86,36 @@ impl RequestSender {
         RequestSender::check_response(response).await
     }
 
+    /// Requests a streamed NDJSON response, its body is left for the caller to read
+    pub(crate) async fn get_ndjson(&self, url: &str) -> Result<Response> {
+        let response = self
+            .web_client
+            .get(format!("{}/{}", &self.address, url))
+            .header("accept", "application/x-ndjson")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            bail!(
+                "Failed to send request, status: {}, error: {}",
+                response.status().as_str(),
+                response.text().await.expect("Failed to get response text")
+            );
+        }
+
+        Ok(response)
+    }
+
     pub(crate) async fn send_heartbeat(&self, token: &str) -> Result<Value> {
+        self.send_heartbeat_with_cursor(token, json!({"cursor": "Block: 12345"}))
+            .await
+    }
+
+    pub(crate) async fn send_heartbeat_with_cursor(
+        &self,
+        token: &str,
+        cursor: Value,
+    ) -> Result<Value> {
         let id = get_id_from_jwt(token)?;
         let heartbeat_url = format!("{}/indexer/{}/heartbeat", self.address, id);
 
@@ -74,7 +123,7 @@ impl RequestSender {
             .web_client
             .put(heartbeat_url)
             .bearer_auth(token)
-            .json(&json!({"cursor": "Block: 12345"}))
+            .json(&cursor)
             .send()
             .await?;
 
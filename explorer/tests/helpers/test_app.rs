@@ -12,7 +12,6 @@ use {
     sea_orm::{DatabaseConnection, EntityTrait},
     std::{env, sync::Arc},
     tokio::{
-        net::TcpListener,
         spawn,
         sync::Notify,
         task::JoinHandle,
@@ -21,7 +20,8 @@ use {
 };
 
 pub const WAITING_INTERVAL: u64 = 100;
-pub const MIGRATION_COUNT: u32 = 10;
+/// The number of migrations rolled back by the tests, all of them but the first one
+pub const MIGRATION_COUNT: u32 = 19;
 pub const METRICS_ENV_VAR: &str = "ENABLE_METRICS";
 const TRACING_ENV_VAR: &str = "ENABLE_TRACING";
 
@@ -45,26 +45,31 @@ pub trait FromTestPayload {
 
 impl TestApp {
     pub async fn start(cors: Option<Vec<String>>) -> Self {
+        Self::start_with_configuration(generate_configuration(), cors).await
+    }
+
+    pub async fn start_with_configuration(
+        configuration: Configuration,
+        cors: Option<Vec<String>>,
+    ) -> Self {
         if env::var(TRACING_ENV_VAR).unwrap_or_default().eq("1") {
-            if let Err(e) = setup_tracing("debug", false) {
+            if let Err(e) = setup_tracing("debug", false, None) {
                 println!("Failed to setup tracing: {}", e);
             }
         }
 
-        let configuration = generate_configuration();
-
         let mut app = Application::from_configuration(&configuration)
             .await
             .expect("Failed to build app");
 
         let db_connection = TestApp::prepare_database(&app).await;
         let networks = Self::prepare_networks(&app).await;
-        let listener = TcpListener::bind(configuration.listener.clone())
-            .await
-            .expect("Failed to bind to address")
+        let listener =
+            Application::bind(configuration.listener).expect("Failed to bind to address");
+        let port = listener
             .local_addr()
-            .expect("Failed to get local address");
-        let port = listener.port();
+            .expect("Failed to get local address")
+            .port();
 
         let stop_signal = Arc::new(Notify::new());
         let receiver = stop_signal.clone();
@@ -13,7 +13,7 @@ use {
         },
         HapiCoreNetwork,
     },
-    hapi_indexer::{NetworkData, PushData, PushEvent, PushPayload},
+    hapi_indexer::{NetworkData, PushData, PushEvent, PushPayload, PUSH_SCHEMA_VERSION},
     rand::{distributions::Alphanumeric, thread_rng, Rng},
     std::str::FromStr,
     uuid::Uuid,
@@ -49,6 +49,7 @@ pub(crate) fn get_test_data(
         url: String::from("https://case1.com"),
         status: CaseStatus::Open,
         reporter_id: reporter_payload.id.to_owned(),
+        related_cases: vec![],
     };
 
     let mut address_payload = Address {
@@ -198,6 +199,29 @@ pub fn create_reporter_data(network: &HapiCoreNetwork, chain_id: Option<String>)
     )
 }
 
+pub fn create_case_data(
+    reporter_id: Uuid,
+    network: &HapiCoreNetwork,
+    chain_id: Option<String>,
+) -> PushPayload {
+    let payload = Case {
+        id: Uuid::new_v4(),
+        name: generate_random_string(),
+        url: String::from("https://case.com"),
+        status: CaseStatus::Open,
+        reporter_id,
+        related_cases: vec![],
+    };
+
+    let network_data = NetworkData {
+        network: network.to_owned(),
+        chain_id,
+        indexer_id: Uuid::parse_str(&get_jwt_id()).expect("Failed to parse jwt id"),
+    };
+
+    create_payload(network_data, EventName::CreateCase, PushData::Case(payload))
+}
+
 pub fn create_address_data(
     reporter_id: Uuid,
     case_id: Uuid,
@@ -263,9 +287,11 @@ fn create_payload(network_data: NetworkData, name: EventName, data: PushData) ->
         tx_hash,
         tx_index: 0,
         timestamp: Utc::now().timestamp() as u64,
+        removed: false,
     };
 
     PushPayload {
+        schema_version: PUSH_SCHEMA_VERSION,
         network_data,
         event,
         data,
@@ -0,0 +1,54 @@
+use {
+    hapi_explorer::{
+        configuration::{LogFileConfiguration, LogRotation},
+        observability::build_subscriber,
+    },
+    std::{env, fs},
+};
+
+/*
+Test cases:
+ - log line lands in the rolling file of the configured directory
+ - no file is written for stdout-only logging
+ */
+#[test]
+fn log_file_test() {
+    let directory = env::temp_dir().join("hapi_explorer_log_file_test");
+    fs::remove_dir_all(&directory).ok();
+
+    let log_file = LogFileConfiguration {
+        directory: directory.clone(),
+        prefix: "explorer.log".to_string(),
+        rotation: LogRotation::Daily,
+        max_files: Some(2),
+        stdout: false,
+    };
+
+    let subscriber = build_subscriber("info", true, Some(&log_file)).expect("Failed to build");
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!(target: "hapi_explorer", "log line for the file");
+    });
+
+    let files: Vec<_> = fs::read_dir(&directory)
+        .expect("Log directory is expected")
+        .map(|entry| entry.expect("Failed to read entry").path())
+        .collect();
+    assert_eq!(files.len(), 1);
+
+    let file_name = files[0].file_name().unwrap().to_string_lossy().to_string();
+    assert!(file_name.starts_with("explorer.log."), "{file_name}");
+
+    let content = fs::read_to_string(&files[0]).expect("Failed to read log file");
+    let line: serde_json::Value =
+        serde_json::from_str(content.lines().next().expect("Log line is expected"))
+            .expect("Log line is JSON");
+    assert_eq!(line["message"], "log line for the file");
+
+    // Without a log file nothing lands in the directory
+    fs::remove_dir_all(&directory).ok();
+    let subscriber = build_subscriber("info", false, None).expect("Failed to build");
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!(target: "hapi_explorer", "log line for stdout");
+    });
+    assert!(!directory.exists());
+}
@@ -1,4 +1,6 @@
 mod cli;
 mod graphql;
 mod helpers;
+mod logging;
 mod routes;
+mod verify;
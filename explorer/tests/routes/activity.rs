@@ -0,0 +1,116 @@
+use crate::helpers::{
+    create_address_data, create_asset_data, get_test_data, RequestSender, TestApp,
+};
+use hapi_core::client::events::EventName;
+use hapi_indexer::PushData;
+use uuid::Uuid;
+
+/*
+Test cases:
+ - entries of the reporter are listed in time order across pages
+ - unknown reporter
+ - invalid page size
+ */
+#[tokio::test]
+async fn reporter_activity_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+    let chain_id = network.model.chain_id.clone();
+
+    let test_data = get_test_data(&network.network, chain_id.clone());
+    let (mut reporter, mut case) = (test_data[0].clone(), test_data[1].clone());
+
+    let (reporter_id, case_id) = match &case.data {
+        PushData::Case(case) => (case.reporter_id, case.id),
+        _ => panic!("Case payload is expected"),
+    };
+
+    reporter.event.timestamp = 1000;
+    case.event.timestamp = 1100;
+
+    let mut payloads = vec![reporter, case.clone()];
+
+    for i in 0..5 {
+        let mut address =
+            create_address_data(reporter_id, case_id, &network.network, chain_id.clone());
+        address.event.timestamp = 1200 + i;
+        payloads.push(address);
+    }
+
+    let mut asset = create_asset_data(reporter_id, case_id, &network.network, chain_id.clone());
+    asset.event.timestamp = 1300;
+    payloads.push(asset);
+
+    case.event.name = EventName::UpdateCase;
+    case.event.timestamp = 1400;
+    payloads.push(case);
+
+    let mut address_update = payloads[2].clone();
+    address_update.event.name = EventName::UpdateAddress;
+    address_update.event.timestamp = 1500;
+    payloads.push(address_update);
+
+    test_app.send_events(&sender, &payloads).await;
+
+    // entries of the reporter are listed in time order across pages
+    let mut entries = vec![];
+
+    for page in 1..=3 {
+        let response = sender
+            .get(&format!(
+                "reporter/{reporter_id}/activity?page={page}&page_size=4"
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response["meta"]["total"], 9);
+        assert_eq!(response["meta"]["page_count"], 3);
+
+        entries.extend(response["data"].as_array().expect("Empty response").clone());
+    }
+
+    let events: Vec<&str> = entries
+        .iter()
+        .map(|entry| entry["event"].as_str().expect("Event is missing"))
+        .collect();
+
+    assert_eq!(
+        events,
+        [
+            "create_case",
+            "create_address",
+            "create_address",
+            "create_address",
+            "create_address",
+            "create_address",
+            "create_asset",
+            "update_case",
+            "update_address",
+        ]
+    );
+
+    let timestamps: Vec<&str> = entries
+        .iter()
+        .map(|entry| entry["timestamp"].as_str().expect("Timestamp is missing"))
+        .collect();
+    assert!(timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+
+    assert!(entries
+        .iter()
+        .all(|entry| entry["network_id"] == network.model.id));
+    assert_eq!(entries[0]["id"], case_id.to_string());
+    assert!(entries[6]["asset_id"].is_string());
+
+    // unknown reporter
+    assert!(sender
+        .get(&format!("reporter/{}/activity", Uuid::new_v4()))
+        .await
+        .is_err());
+
+    // invalid page size
+    assert!(sender
+        .get(&format!("reporter/{reporter_id}/activity?page_size=0"))
+        .await
+        .is_err());
+}
@@ -0,0 +1,120 @@
+use crate::helpers::{generate_configuration, get_test_data, RequestSender, TestApp};
+use hapi_core::HapiCoreNetwork;
+use hapi_indexer::{PushData, PushPayload};
+
+fn latest_asset(events: &[PushPayload]) -> hapi_core::client::entities::asset::Asset {
+    events
+        .iter()
+        .rev()
+        .find_map(|event| match &event.data {
+            PushData::Asset(asset) => Some(asset.clone()),
+            _ => None,
+        })
+        .expect("Asset payload is expected")
+}
+
+/*
+Test cases:
+ - asset is found with its latest state
+ - unknown asset id
+ - malformed asset id
+ */
+#[tokio::test]
+async fn asset_lookup_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+
+    let events = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&sender, &events).await;
+
+    let asset = latest_asset(&events);
+
+    let response = sender
+        .get(&format!(
+            "asset?network={}&address={}&asset_id={}",
+            network.model.id, asset.address, asset.asset_id
+        ))
+        .await
+        .unwrap();
+
+    let data = &response["data"];
+    assert_eq!(data["network_id"], network.model.id);
+    assert_eq!(data["address"], asset.address);
+    assert_eq!(data["id"], asset.asset_id.to_string());
+    assert_eq!(data["case_id"], asset.case_id.to_string());
+    assert_eq!(data["reporter_id"], asset.reporter_id.to_string());
+    assert_eq!(data["risk"], asset.risk);
+    assert_eq!(data["category"], "scam");
+    assert_eq!(data["confirmations"], asset.confirmations.to_string());
+    assert_eq!(data["confirmation_threshold"], 1);
+    assert_eq!(data["confirmed"], true);
+
+    // unknown asset id
+    let error = sender
+        .get(&format!(
+            "asset?network={}&address={}&asset_id=87654321",
+            network.model.id, asset.address
+        ))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 404"), "{error}");
+
+    // malformed asset id
+    let error = sender
+        .get(&format!(
+            "asset?network={}&address={}&asset_id=0xabcz",
+            network.model.id, asset.address
+        ))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 400"), "{error}");
+}
+
+/*
+Test cases:
+ - asset with as many confirmations as the threshold of its network is confirmed
+ - asset one confirmation short of the threshold of its network is not
+ */
+#[tokio::test]
+async fn asset_confirmation_threshold_test() {
+    let (reached, short) = (HapiCoreNetwork::Ethereum, HapiCoreNetwork::Solana);
+    let confirmations = latest_asset(&get_test_data(&reached, None)).confirmations;
+
+    let mut configuration = generate_configuration();
+    configuration.confirmations.networks.extend([
+        (reached.to_string(), confirmations),
+        (short.to_string(), confirmations + 1),
+    ]);
+
+    let test_app = TestApp::start_with_configuration(configuration, None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    for (network, threshold, confirmed) in [
+        (&reached, confirmations, true),
+        (&short, confirmations + 1, false),
+    ] {
+        let test_network = test_app
+            .networks
+            .iter()
+            .find(|test_network| &test_network.network == network)
+            .expect("Network is expected");
+
+        let events = get_test_data(network, test_network.model.chain_id.clone());
+        test_app.send_events(&sender, &events).await;
+
+        let asset = latest_asset(&events);
+        assert_eq!(asset.confirmations, confirmations);
+
+        let response = sender
+            .get(&format!(
+                "asset?network={}&address={}&asset_id={}",
+                test_network.model.id, asset.address, asset.asset_id
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response["data"]["confirmation_threshold"], threshold);
+        assert_eq!(response["data"]["confirmed"], confirmed, "{network}");
+    }
+}
@@ -0,0 +1,59 @@
+use crate::helpers::{create_jwt, get_test_data, RequestSender, TestApp, WAITING_INTERVAL};
+use {
+    hapi_core::client::{entities::reporter::Reporter, events::EventName},
+    hapi_explorer::entity::reporter,
+    hapi_indexer::PushData,
+    sea_orm::{sea_query::Expr, EntityTrait},
+    tokio::time::{sleep, Duration},
+};
+
+/*
+Test cases:
+ - repeated search request within the TTL is served without hitting the DB
+ - applied push payload invalidates the cached response
+ */
+#[tokio::test]
+async fn cache_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let reporters = test_app
+        .global_setup::<Reporter>(&sender, EventName::UpdateReporter)
+        .await;
+
+    let response = sender.get("search?q=authority").await.unwrap();
+    assert_eq!(response["meta"]["total"], reporters.len());
+
+    // Rename reporters behind the server's back, a DB read would not find them anymore
+    reporter::Entity::update_many()
+        .col_expr(reporter::Column::Name, Expr::value("Renamed reporter"))
+        .exec(&test_app.db_connection)
+        .await
+        .expect("Failed to rename reporters");
+
+    let cached = sender.get("search?q=authority").await.unwrap();
+    assert_eq!(cached, response);
+
+    // Any case event invalidates search responses
+    let network = test_app.networks.first().expect("No networks");
+    let case_payload = get_test_data(&network.network, network.model.chain_id.clone())
+        .into_iter()
+        .find(|payload| {
+            payload.event.name == EventName::UpdateCase && matches!(payload.data, PushData::Case(_))
+        })
+        .expect("No case update payload");
+
+    sender
+        .send(
+            "events",
+            &case_payload,
+            &create_jwt("my_ultra_secure_secret"),
+        )
+        .await
+        .expect("Failed to send event");
+
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    let response = sender.get("search?q=authority").await.unwrap();
+    assert_eq!(response["meta"]["total"], 0);
+}
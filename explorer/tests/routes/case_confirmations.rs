@@ -0,0 +1,110 @@
+use crate::helpers::{
+    create_address_data, generate_configuration, get_test_data, RequestSender, TestApp,
+};
+use hapi_core::HapiCoreNetwork;
+use hapi_indexer::PushData;
+use uuid::Uuid;
+
+/*
+Test cases:
+ - confirmed and pending addresses and assets are counted against the network threshold
+ - items are filtered to the unconfirmed ones, the counts are not
+ - unknown case
+ */
+#[tokio::test]
+async fn case_confirmations_test() {
+    let network = HapiCoreNetwork::Ethereum;
+
+    // The test data confirms the address 20 times and the asset 25 times
+    let mut configuration = generate_configuration();
+    configuration
+        .confirmations
+        .networks
+        .insert(network.to_string(), 21);
+
+    let test_app = TestApp::start_with_configuration(configuration, None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network_id = network.to_string();
+    let chain_id = test_app.get_network(&network_id).model.chain_id.clone();
+
+    let mut events = get_test_data(&network, chain_id.clone());
+    let (reporter_id, case_id) = match (&events[0].data, &events[1].data) {
+        (PushData::Reporter(reporter), PushData::Case(case)) => (reporter.id, case.id),
+        _ => panic!("Reporter and case payloads are expected"),
+    };
+
+    // One more address without confirmations and one over the threshold
+    let pending = create_address_data(reporter_id, case_id, &network, chain_id.clone());
+    let mut confirmed = create_address_data(reporter_id, case_id, &network, chain_id);
+    if let PushData::Address(address) = &mut confirmed.data {
+        address.confirmations = 30;
+    }
+    events.extend([pending, confirmed]);
+
+    test_app.send_events(&sender, &events).await;
+
+    let response = sender
+        .get(&format!("case/{network_id}/{case_id}/confirmations"))
+        .await
+        .unwrap();
+
+    let data = &response["data"];
+    assert_eq!(data["case_id"], case_id.to_string());
+    assert_eq!(data["confirmation_threshold"], 21);
+    assert_eq!(data["addresses"]["confirmed"], 1);
+    assert_eq!(data["addresses"]["pending"], 2);
+    assert_eq!(data["assets"]["confirmed"], 1);
+    assert_eq!(data["assets"]["pending"], 0);
+    assert_eq!(data["progress"], 50.0);
+
+    let items = data["items"].as_array().expect("Empty response");
+    assert_eq!(items.len(), 4);
+
+    let mut confirmations: Vec<_> = items
+        .iter()
+        .map(|item| {
+            (
+                item["confirmations"].as_u64().unwrap(),
+                item["confirmed"].clone(),
+            )
+        })
+        .collect();
+    confirmations.sort_by_key(|(count, _)| *count);
+    assert_eq!(
+        confirmations,
+        vec![
+            (0, false.into()),
+            (20, false.into()),
+            (25, true.into()),
+            (30, true.into())
+        ]
+    );
+
+    // only unconfirmed items
+    let response = sender
+        .get(&format!(
+            "case/{network_id}/{case_id}/confirmations?unconfirmed=true"
+        ))
+        .await
+        .unwrap();
+
+    let data = &response["data"];
+    assert_eq!(data["progress"], 50.0);
+    assert_eq!(data["addresses"]["pending"], 2);
+
+    let items = data["items"].as_array().expect("Empty response");
+    assert_eq!(items.len(), 2);
+    assert!(items
+        .iter()
+        .all(|item| item["kind"] == "address" && item["confirmed"] == false));
+
+    // unknown case
+    let error = sender
+        .get(&format!(
+            "case/{network_id}/{}/confirmations",
+            Uuid::new_v4()
+        ))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("status: 404"), "{error}");
+}
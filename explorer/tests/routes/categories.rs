@@ -0,0 +1,39 @@
+use crate::helpers::{RequestSender, TestApp};
+use hapi_core::client::entities::category::Category;
+
+/*
+Test cases:
+ - every on-chain category is listed with its code
+ - codes are decoded to the same categories as the contracts encode them
+ */
+#[tokio::test]
+async fn categories_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let response = sender.get("categories").await.unwrap();
+    let data = response["data"].as_array().expect("Empty response");
+
+    // Category::HighRiskJurisdiction is the last one on-chain
+    assert_eq!(data.len(), Category::HighRiskJurisdiction as usize + 1);
+
+    for (index, category) in data.iter().enumerate() {
+        let code = category["code"].as_u64().expect("Code is expected");
+        assert_eq!(code, index as u64);
+
+        let on_chain = Category::try_from(code as u8).expect("Unknown category code");
+        assert_eq!(category["name"], on_chain.to_string());
+        assert_eq!(on_chain.clone() as u8 as u64, code);
+
+        assert!(category["label"]
+            .as_str()
+            .is_some_and(|label| !label.is_empty()));
+        assert!(category["tier"].as_u64().is_some_and(|tier| tier <= 4));
+    }
+
+    assert_eq!(data[5]["value"], "de_fi");
+    assert_eq!(data[5]["label"], "DeFi application");
+    assert_eq!(data[12]["value"], "scam");
+    assert_eq!(data[12]["tier"], 3);
+    assert_eq!(data[0]["tier"], 0);
+}
@@ -0,0 +1,53 @@
+use crate::helpers::{RequestSender, TestApp};
+use hapi_core::client::{entities::address::Address, events::EventName};
+
+/*
+Test cases:
+ - address reported on every network is linked to every case
+ - threshold above the number of linked cases
+ - threshold below two cases
+ */
+#[tokio::test]
+async fn cross_case_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    // Every network gets the same address in a case of its own
+    let addresses = test_app
+        .global_setup::<Address>(&sender, EventName::UpdateAddress)
+        .await;
+    let case_count = test_app.networks.len();
+
+    // address reported on every network is linked to every case
+    let response = sender.get("addresses/cross-case").await.unwrap();
+    let data = response["data"].as_array().expect("Empty response");
+
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0]["address"], addresses[0].data.address);
+    assert_eq!(data[0]["case_count"], case_count);
+
+    let cases = data[0]["cases"].as_array().expect("Cases are missing");
+    assert_eq!(cases.len(), case_count);
+    for address in &addresses {
+        assert!(cases.iter().any(|case| {
+            case["network_id"] == address.network_id
+                && case["case_id"] == address.data.case_id.to_string()
+        }));
+    }
+
+    // threshold above the number of linked cases
+    let response = sender
+        .get(&format!(
+            "addresses/cross-case?min_cases={}",
+            case_count + 1
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response["meta"]["total"], 0);
+
+    // threshold below two cases
+    assert!(sender
+        .get("addresses/cross-case?min_cases=1")
+        .await
+        .is_err());
+}
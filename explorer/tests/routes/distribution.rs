@@ -0,0 +1,158 @@
+use crate::helpers::{
+    create_address_data, create_reporter_data, get_test_data, RequestSender, TestApp,
+};
+use hapi_core::client::{entities::category::Category, events::EventName};
+use hapi_indexer::{PushData, PushPayload};
+use serde_json::json;
+use uuid::Uuid;
+
+fn reporter_id(payload: &PushPayload) -> Uuid {
+    match &payload.data {
+        PushData::Reporter(reporter) => reporter.id,
+        _ => panic!("Reporter payload is expected"),
+    }
+}
+
+fn reclassify(
+    payload: &PushPayload,
+    reporter_id: Uuid,
+    risk: u8,
+    category: Category,
+) -> PushPayload {
+    let mut payload = payload.clone();
+    payload.event.name = EventName::UpdateAddress;
+
+    if let PushData::Address(address) = &mut payload.data {
+        address.reporter_id = reporter_id;
+        address.risk = risk;
+        address.category = category;
+    }
+
+    payload
+}
+
+/*
+Test cases:
+ - conflicting reports of several reporters across networks
+ - withdrawn report is not counted
+ - unknown address
+ */
+#[tokio::test]
+async fn address_distribution_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let (first, second) = (&test_app.networks[0], &test_app.networks[1]);
+
+    // The first network has two reporters, the address is reported by the first one
+    // and then reclassified by the other
+    let setup = get_test_data(&first.network, first.model.chain_id.clone());
+    let (reporter, case) = (setup[0].clone(), setup[1].clone());
+    let other_reporter = create_reporter_data(&first.network, first.model.chain_id.clone());
+
+    let (first_reporter_id, other_reporter_id) =
+        (reporter_id(&reporter), reporter_id(&other_reporter));
+    let case_id = match &case.data {
+        PushData::Case(case) => case.id,
+        _ => panic!("Case payload is expected"),
+    };
+
+    let created = create_address_data(
+        first_reporter_id,
+        case_id,
+        &first.network,
+        first.model.chain_id.clone(),
+    );
+    let address = match &created.data {
+        PushData::Address(address) => address.address.clone(),
+        _ => panic!("Address payload is expected"),
+    };
+    let reclassified = reclassify(&created, other_reporter_id, 10, Category::Sanctions);
+
+    test_app
+        .send_events(
+            &sender,
+            &vec![reporter, case, other_reporter, created, reclassified],
+        )
+        .await;
+
+    // The second network agrees with the first reporter
+    let setup = get_test_data(&second.network, second.model.chain_id.clone());
+    let (reporter, case) = (setup[0].clone(), setup[1].clone());
+    let second_reporter_id = reporter_id(&reporter);
+    let case_id = match &case.data {
+        PushData::Case(case) => case.id,
+        _ => panic!("Case payload is expected"),
+    };
+
+    let mut created = create_address_data(
+        second_reporter_id,
+        case_id,
+        &second.network,
+        second.model.chain_id.clone(),
+    );
+    if let PushData::Address(payload) = &mut created.data {
+        payload.address = address.clone();
+        payload.risk = 8;
+    }
+
+    test_app
+        .send_events(&sender, &vec![reporter, case, created.clone()])
+        .await;
+
+    // conflicting reports of several reporters across networks
+    let response = sender
+        .get(&format!("addresses/{address}/distribution"))
+        .await
+        .unwrap();
+    let data = &response["data"];
+
+    assert_eq!(data["address"], address);
+    assert_eq!(data["report_count"], 3);
+    assert_eq!(
+        data["risk"],
+        json!({
+            "distribution": { "6": 1, "8": 1, "10": 1 },
+            "median": 8.0,
+            "min": 6,
+            "max": 10,
+        })
+    );
+    assert_eq!(
+        data["category"],
+        json!({
+            "distribution": { "de_fi": 2, "sanctions": 1 },
+            "modal": ["de_fi"],
+        })
+    );
+
+    let reports = data["reports"].as_array().expect("Reports are missing");
+    for (network_id, reporter_id, risk, category) in [
+        (&first.model.id, first_reporter_id, 6, "de_fi"),
+        (&first.model.id, other_reporter_id, 10, "sanctions"),
+        (&second.model.id, second_reporter_id, 8, "de_fi"),
+    ] {
+        assert!(reports.iter().any(|report| {
+            report["network_id"] == *network_id
+                && report["reporter_id"] == reporter_id.to_string()
+                && report["risk"] == risk
+                && report["category"] == category
+        }));
+    }
+
+    // withdrawn report is not counted
+    created.event.removed = true;
+    test_app.send_events(&sender, &vec![created]).await;
+
+    let response = sender
+        .get(&format!("addresses/{address}/distribution"))
+        .await
+        .unwrap();
+    let data = &response["data"];
+
+    assert_eq!(data["report_count"], 2);
+    assert_eq!(data["risk"]["median"], 8.0);
+    assert_eq!(data["category"]["modal"], json!(["de_fi", "sanctions"]));
+
+    // unknown address
+    assert!(sender.get("addresses/unknown/distribution").await.is_err());
+}
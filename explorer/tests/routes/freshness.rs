@@ -0,0 +1,71 @@
+use crate::helpers::{get_test_data, RequestSender, TestApp};
+use chrono::{NaiveDateTime, Utc};
+
+/*
+Test cases:
+ - nothing is indexed yet
+ - the latest event timestamp is reported for the indexed network only
+ - unknown network
+ */
+#[tokio::test]
+async fn freshness_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    // nothing is indexed yet
+    let response = sender.get("freshness").await.unwrap();
+    let data = response["data"].as_array().expect("Empty response");
+
+    assert_eq!(data.len(), test_app.networks.len());
+    assert!(data
+        .iter()
+        .all(|network| network["latest_event_at"].is_null() && network["stale_seconds"].is_null()));
+
+    // the latest event timestamp is reported for the indexed network only
+    let network = &test_app.networks[0];
+    let now = Utc::now().timestamp() as u64;
+
+    let mut payloads = get_test_data(&network.network, network.model.chain_id.clone());
+    let count = payloads.len() as u64;
+
+    // Events are a minute apart, the latest one is two minutes old
+    for (i, payload) in payloads.iter_mut().enumerate() {
+        payload.event.timestamp = now - 60 * (count + 1) + 60 * i as u64;
+    }
+    let latest = payloads.last().unwrap().event.timestamp;
+
+    test_app.send_events(&sender, &payloads).await;
+
+    let response = sender
+        .get(&format!("freshness?network={}", network.model.id))
+        .await
+        .unwrap();
+    let data = response["data"].as_array().expect("Empty response");
+    assert_eq!(data.len(), 1);
+
+    let latest_event_at = NaiveDateTime::parse_from_str(
+        data[0]["latest_event_at"]
+            .as_str()
+            .expect("Timestamp is missing"),
+        "%Y-%m-%dT%H:%M:%S",
+    )
+    .expect("Failed to parse timestamp");
+    assert_eq!(latest_event_at.timestamp() as u64, latest);
+
+    let stale_seconds = data[0]["stale_seconds"]
+        .as_i64()
+        .expect("Staleness is missing");
+    assert!((120..180).contains(&stale_seconds), "{stale_seconds}");
+
+    let response = sender.get("freshness").await.unwrap();
+    let others = response["data"]
+        .as_array()
+        .expect("Empty response")
+        .iter()
+        .filter(|data| data["network_id"] != network.model.id)
+        .collect::<Vec<_>>();
+    assert!(others.iter().all(|data| data["latest_event_at"].is_null()));
+
+    // unknown network
+    assert!(sender.get("freshness?network=unknown").await.is_err());
+}
@@ -1,12 +1,68 @@
-use crate::helpers::{RequestSender, TestApp};
+use {
+    hapi_explorer::{configuration::NetworkConfiguration, entity::types::NetworkBackend},
+    std::time::{Duration, Instant},
+    tokio::{net::TcpListener, spawn},
+};
+
+use crate::helpers::{generate_configuration, RequestSender, TestApp};
 
 #[tokio::test]
 async fn health_check_test() {
     let test_app = TestApp::start(None).await;
     let client = RequestSender::new(test_app.server_addr.to_owned());
 
-    client
+    let response = client
         .get("health")
         .await
         .expect("Failed to get health check");
+
+    assert_eq!(response["status"], "ok");
+    assert_eq!(response["networks"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn health_check_with_hanging_rpc_test() {
+    // Accepts connections but never answers
+    let rpc = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind RPC listener");
+    let rpc_addr = rpc.local_addr().expect("Failed to get RPC address");
+
+    spawn(async move {
+        let mut connections = vec![];
+        while let Ok((socket, _)) = rpc.accept().await {
+            connections.push(socket);
+        }
+    });
+
+    let mut configuration = generate_configuration();
+    configuration.health.rpc_timeout_ms = Duration::from_millis(200);
+    configuration.networks = vec![NetworkConfiguration {
+        id: "ethereum".to_string(),
+        name: "Ethereum".to_string(),
+        backend: NetworkBackend::Evm,
+        chain_id: None,
+        authority: "test_authority".to_string(),
+        stake_token: "test_stake_token".to_string(),
+        rpc_url: Some(format!("http://{rpc_addr}")),
+        contract_address: None,
+    }];
+
+    let test_app = TestApp::start_with_configuration(configuration, None).await;
+    let client = RequestSender::new(test_app.server_addr.to_owned());
+
+    let started = Instant::now();
+    let response = client
+        .get("health")
+        .await
+        .expect("Failed to get health check");
+
+    assert!(started.elapsed() < Duration::from_secs(2), "{response}");
+    assert_eq!(response["status"], "degraded");
+    assert_eq!(response["networks"][0]["network_id"], "ethereum");
+    assert_eq!(response["networks"][0]["status"], "degraded");
+    assert_eq!(
+        response["networks"][0]["chain_head"],
+        serde_json::Value::Null
+    );
 }
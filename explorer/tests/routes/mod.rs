@@ -1,5 +1,23 @@
+mod activity;
+mod asset;
+mod cache;
+mod case_confirmations;
+mod categories;
 mod cors;
+mod cross_case;
+mod distribution;
+mod freshness;
 mod health_check;
 mod indexer;
 mod metrics;
+mod ndjson;
+mod network_status;
+mod recent;
+mod replica;
+mod report_rate;
+mod reporter_networks;
+mod search;
+mod stake_history;
+mod stats;
+mod startup;
 mod webhook_processing;
@@ -0,0 +1,103 @@
+use crate::helpers::{create_address_data, get_test_data, RequestSender, TestApp};
+use hapi_indexer::PushData;
+use serde_json::Value;
+use uuid::Uuid;
+
+/*
+Test cases:
+ - reporter activity is streamed line by line with the same entries as the JSON listing
+ - stake history is streamed as NDJSON
+ - unknown reporter gives an error status instead of a stream
+ */
+#[tokio::test]
+async fn ndjson_stream_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+    let chain_id = network.model.chain_id.clone();
+
+    let test_data = get_test_data(&network.network, chain_id.clone());
+    let (mut reporter, mut case) = (test_data[0].clone(), test_data[1].clone());
+
+    let (reporter_id, case_id) = match &case.data {
+        PushData::Case(case) => (case.reporter_id, case.id),
+        _ => panic!("Case payload is expected"),
+    };
+
+    reporter.event.timestamp = 1000;
+    case.event.timestamp = 1100;
+
+    let mut payloads = vec![reporter, case];
+
+    // More entries than the server reads ahead of the client
+    for i in 0..60 {
+        let mut address =
+            create_address_data(reporter_id, case_id, &network.network, chain_id.clone());
+        address.event.timestamp = 1200 + i;
+        payloads.push(address);
+    }
+
+    test_app.send_events(&sender, &payloads).await;
+
+    let listed = sender
+        .get(&format!("reporter/{reporter_id}/activity?page_size=100"))
+        .await
+        .unwrap();
+    let listed = listed["data"].as_array().expect("Empty response");
+    assert_eq!(listed.len(), 61);
+
+    // reporter activity is streamed line by line with the same entries as the JSON listing
+    let mut response = sender
+        .get_ndjson(&format!("reporter/{reporter_id}/activity"))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/x-ndjson",
+        "{:?}",
+        response.headers()
+    );
+    assert!(response.headers().get("content-length").is_none());
+
+    let mut body = vec![];
+    let mut chunks = 0;
+
+    while let Some(chunk) = response.chunk().await.unwrap() {
+        body.extend_from_slice(&chunk);
+        chunks += 1;
+    }
+
+    // A buffered body would arrive as a single chunk
+    assert!(chunks > 1, "{chunks}");
+
+    let body = String::from_utf8(body).expect("Body must be UTF-8");
+    assert!(body.ends_with('\n'));
+
+    let streamed: Vec<Value> = body
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Every line must be a JSON object"))
+        .collect();
+
+    assert!(streamed.iter().all(Value::is_object));
+    assert_eq!(&streamed, listed);
+
+    // stake history is streamed as NDJSON
+    let response = sender
+        .get_ndjson(&format!("reporter/{reporter_id}/stake-history"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.headers()["content-type"], "application/x-ndjson");
+
+    let body = response.text().await.unwrap();
+    assert!(body
+        .lines()
+        .all(|line| serde_json::from_str::<Value>(line).is_ok()));
+
+    // unknown reporter gives an error status instead of a stream
+    assert!(sender
+        .get_ndjson(&format!("reporter/{}/activity", Uuid::new_v4()))
+        .await
+        .is_err());
+}
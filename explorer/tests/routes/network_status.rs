@@ -0,0 +1,115 @@
+use {
+    axum::{routing::post, Json, Router, Server},
+    hapi_explorer::{configuration::NetworkConfiguration, entity::types::NetworkBackend},
+    serde_json::{json, Value},
+    std::net::TcpListener,
+    tokio::spawn,
+};
+
+use crate::helpers::{generate_configuration, RequestSender, TestApp};
+
+/// Starts an EVM RPC node that is always at the given block
+fn start_rpc(chain_head: u64) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind RPC listener");
+    let address = listener.local_addr().expect("Failed to get RPC address");
+
+    let router = Router::new().route(
+        "/",
+        post(move || async move {
+            Json(json!({ "jsonrpc": "2.0", "id": 1, "result": format!("{chain_head:#x}") }))
+        }),
+    );
+
+    spawn(async move {
+        Server::from_tcp(listener)
+            .expect("Failed to start RPC node")
+            .serve(router.into_make_service())
+            .await
+            .expect("Failed to run RPC node");
+    });
+
+    format!("http://{address}")
+}
+
+fn configured_network(id: &str, rpc_url: String) -> NetworkConfiguration {
+    NetworkConfiguration {
+        id: id.to_string(),
+        name: id.to_string(),
+        backend: NetworkBackend::Evm,
+        chain_id: Some(format!("{id}_chain_id")),
+        authority: "test_authority".to_string(),
+        stake_token: "test_stake_token".to_string(),
+        rpc_url: Some(rpc_url),
+        contract_address: Some(format!("{id}_contract")),
+    }
+}
+
+fn network_status<'a>(response: &'a Value, network_id: &str) -> &'a Value {
+    response["data"]
+        .as_array()
+        .expect("Empty response")
+        .iter()
+        .find(|status| status["network_id"] == network_id)
+        .expect("Network is not listed")
+}
+
+/*
+Test cases:
+ - every network is listed with its contract address, cursor, chain head and lag
+ - network within the lag limit is healthy
+ - network too far behind the chain head is degraded
+ - network without heartbeats is halted
+ */
+#[tokio::test]
+async fn network_status_test() {
+    let mut configuration = generate_configuration();
+    configuration.health.max_indexer_lag = 100;
+    configuration.networks = vec![
+        configured_network("ethereum", start_rpc(1000)),
+        configured_network("sepolia", start_rpc(5000)),
+    ];
+
+    let test_app = TestApp::start_with_configuration(configuration, None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    for (network_id, block) in [("ethereum", 990), ("sepolia", 1000)] {
+        sender
+            .send_heartbeat_with_cursor(
+                &test_app.get_network(network_id).token,
+                json!({ "Block": block }),
+            )
+            .await
+            .expect("Failed to send heartbeat");
+    }
+
+    let response = sender
+        .get("networks/status")
+        .await
+        .expect("Failed to get network status");
+
+    assert_eq!(
+        response["data"].as_array().unwrap().len(),
+        test_app.networks.len()
+    );
+
+    let ethereum = network_status(&response, "ethereum");
+    assert_eq!(ethereum["contract_address"], "ethereum_contract");
+    assert_eq!(ethereum["cursor"], 990);
+    assert_eq!(ethereum["chain_head"], 1000);
+    assert_eq!(ethereum["lag"], 10);
+    assert!(ethereum["last_heartbeat"].is_string());
+    assert_eq!(ethereum["status"], "healthy");
+
+    let sepolia = network_status(&response, "sepolia");
+    assert_eq!(sepolia["contract_address"], "sepolia_contract");
+    assert_eq!(sepolia["cursor"], 1000);
+    assert_eq!(sepolia["chain_head"], 5000);
+    assert_eq!(sepolia["lag"], 4000);
+    assert_eq!(sepolia["status"], "degraded");
+
+    let near = network_status(&response, "near");
+    assert!(near["contract_address"].is_null());
+    assert!(near["last_heartbeat"].is_null());
+    assert!(near["lag"].is_null());
+    assert_eq!(near["status"], "halted");
+}
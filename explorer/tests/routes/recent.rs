@@ -0,0 +1,143 @@
+use crate::helpers::{
+    create_address_data, create_asset_data, get_test_data, RequestSender, TestApp,
+};
+use hapi_core::client::events::EventName;
+use hapi_indexer::PushData;
+use serde_json::Value;
+
+fn entries(response: &Value) -> Vec<(String, String, String)> {
+    response["data"]
+        .as_array()
+        .expect("Empty response")
+        .iter()
+        .map(|entry| {
+            (
+                entry["network_id"].as_str().unwrap().to_string(),
+                entry["entity"].as_str().unwrap().to_string(),
+                entry["event"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+/*
+Test cases:
+ - latest changes of every entity type are merged newest first
+ - feed is limited to a single network
+ - limit is applied to the merged feed
+ - unknown network and invalid limit
+ */
+#[tokio::test]
+async fn recent_activity_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let (network, other) = (&test_app.networks[0], &test_app.networks[1]);
+    let chain_id = network.model.chain_id.clone();
+
+    let test_data = get_test_data(&network.network, chain_id.clone());
+    let (mut reporter, mut case) = (test_data[0].clone(), test_data[1].clone());
+
+    let (reporter_id, case_id) = match &case.data {
+        PushData::Case(case) => (case.reporter_id, case.id),
+        _ => panic!("Case payload is expected"),
+    };
+
+    reporter.event.timestamp = 1000;
+    case.event.timestamp = 1100;
+
+    let mut address = create_address_data(reporter_id, case_id, &network.network, chain_id.clone());
+    address.event.timestamp = 1200;
+
+    let mut asset = create_asset_data(reporter_id, case_id, &network.network, chain_id.clone());
+    asset.event.timestamp = 1300;
+
+    let mut reporter_update = reporter.clone();
+    reporter_update.event.name = EventName::UpdateReporter;
+    reporter_update.event.timestamp = 1400;
+
+    let address_id = match &address.data {
+        PushData::Address(address) => address.address.clone(),
+        _ => panic!("Address payload is expected"),
+    };
+
+    let mut address_update = address.clone();
+    address_update.event.name = EventName::UpdateAddress;
+    address_update.event.timestamp = 1500;
+
+    // The other network changes last
+    let mut other_reporter = get_test_data(&other.network, other.model.chain_id.clone())[0].clone();
+    other_reporter.event.timestamp = 1600;
+
+    test_app
+        .send_events(
+            &sender,
+            &vec![
+                reporter,
+                case,
+                address,
+                asset,
+                reporter_update,
+                address_update,
+                other_reporter,
+            ],
+        )
+        .await;
+
+    let id = network.model.id.clone();
+    let expected = vec![
+        (
+            id.clone(),
+            "address".to_string(),
+            "update_address".to_string(),
+        ),
+        (
+            id.clone(),
+            "reporter".to_string(),
+            "update_reporter".to_string(),
+        ),
+        (id.clone(), "asset".to_string(), "create_asset".to_string()),
+        (id.clone(), "case".to_string(), "create_case".to_string()),
+    ];
+
+    // feed is limited to a single network
+    let response = sender.get(&format!("recent?network={id}")).await.unwrap();
+    assert_eq!(entries(&response), expected);
+
+    let response = &response["data"];
+    assert_eq!(response[0]["id"], address_id);
+    assert_eq!(response[1]["id"], reporter_id.to_string());
+    assert_eq!(response[3]["id"], case_id.to_string());
+    assert!(response[2]["asset_id"].is_string());
+    assert!(response
+        .as_array()
+        .unwrap()
+        .windows(2)
+        .all(|pair| pair[0]["timestamp"].as_str() > pair[1]["timestamp"].as_str()));
+
+    // latest changes of every network are merged, limit is applied to the merged feed
+    let response = sender.get("recent?limit=2").await.unwrap();
+    assert_eq!(
+        entries(&response),
+        vec![
+            (
+                other.model.id.clone(),
+                "reporter".to_string(),
+                "create_reporter".to_string()
+            ),
+            expected[0].clone(),
+        ]
+    );
+
+    // unknown network
+    let error = sender.get("recent?network=unknown").await.unwrap_err();
+    assert!(error.to_string().contains("status: 404"), "{error}");
+
+    // invalid limit
+    for limit in [0, 101] {
+        let error = sender
+            .get(&format!("recent?limit={limit}"))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("status: 400"), "{error}");
+    }
+}
@@ -0,0 +1,79 @@
+use crate::helpers::{generate_configuration, get_test_data, RequestSender, TestApp};
+use hapi_explorer::migrations::Migrator;
+use sea_orm::{ConnectionTrait, Database};
+use sea_orm_migration::MigratorTrait;
+
+/// Creates an empty database next to the test one, standing for a replica that is behind
+async fn prepare_replica(database_url: &str) -> String {
+    let replica_url = format!("{database_url}_replica");
+    let name = replica_url
+        .rsplit('/')
+        .next()
+        .expect("Database name is expected");
+
+    let primary = Database::connect(database_url)
+        .await
+        .expect("Failed to connect to the database");
+
+    // The database is left from a previous run if the creation fails
+    let _ = primary
+        .execute_unprepared(&format!("CREATE DATABASE {name}"))
+        .await;
+    primary.close().await.expect("Failed to close connection");
+
+    let replica = Database::connect(&replica_url)
+        .await
+        .expect("Failed to connect to the replica");
+    Migrator::fresh(&replica)
+        .await
+        .expect("Failed to migrate the replica");
+    replica.close().await.expect("Failed to close connection");
+
+    replica_url
+}
+
+/*
+Test cases:
+ - events are written to the primary database
+ - read-only endpoints are served by the replica
+ - data freshness is read from the primary
+ */
+#[tokio::test]
+async fn replica_test() {
+    let mut configuration = generate_configuration();
+    configuration.replica_database_url = Some(prepare_replica(&configuration.database_url).await);
+
+    let test_app = TestApp::start_with_configuration(configuration, None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+
+    let payloads = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&sender, &payloads).await;
+
+    // events are written to the primary database
+    for payload in &payloads {
+        test_app
+            .check_entity(payload.data.clone(), network.model.id.clone())
+            .await;
+    }
+
+    // read-only endpoints are served by the replica, that has none of them
+    let response = sender.get("recent").await.unwrap();
+    assert_eq!(
+        response["data"].as_array().expect("Empty response").len(),
+        0
+    );
+
+    let response = sender.get("search?q=reporter").await.unwrap();
+    assert_eq!(response["meta"]["total"], 0, "{response}");
+
+    // data freshness is read from the primary
+    let response = sender
+        .get(&format!("freshness?network={}", network.model.id))
+        .await
+        .unwrap();
+    assert!(
+        response["data"][0]["latest_event_at"].is_string(),
+        "{response}"
+    );
+}
@@ -0,0 +1,116 @@
+use crate::helpers::{
+    create_address_data, create_reporter_data, generate_configuration, get_test_data,
+    RequestSender, TestApp,
+};
+use hapi_indexer::PushData;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const GET_REPORTER_FLAG: &str = "
+    query GetReporter($id: UUID!, $networkId: String!) {
+        getReporter(id: $id, networkId: $networkId) {
+            id
+            flaggedAt
+        }
+    }
+";
+
+const MAX_REPORTS: usize = 3;
+
+fn configuration_window_secs() -> u64 {
+    generate_configuration().report_rate.window_ms.as_secs()
+}
+
+async fn flagged_at(sender: &RequestSender, network_id: &str, reporter_id: Uuid) -> Value {
+    let response = sender
+        .send_graphql(
+            GET_REPORTER_FLAG,
+            json!({ "id": reporter_id, "networkId": network_id }),
+        )
+        .await
+        .unwrap();
+
+    response["getReporter"]["flaggedAt"].clone()
+}
+
+/*
+Test cases:
+ - reporter with more reports within the window than the limit is flagged
+ - reporter within the limit is not
+ - redelivered events and reindexed events older than the window are not counted
+ - reports are stored either way
+ */
+#[tokio::test]
+async fn report_rate_flag_test() {
+    let mut configuration = generate_configuration();
+    configuration.report_rate.max_reports = MAX_REPORTS;
+
+    let test_app = TestApp::start_with_configuration(configuration, None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+    let chain_id = network.model.chain_id.clone();
+
+    let test_data = get_test_data(&network.network, chain_id.clone());
+    let (reporter, case) = (test_data[0].clone(), test_data[1].clone());
+
+    let (spamming_id, case_id) = match &case.data {
+        PushData::Case(case) => (case.reporter_id, case.id),
+        _ => panic!("Case payload is expected"),
+    };
+
+    let calm = create_reporter_data(&network.network, chain_id.clone());
+    let calm_id = match &calm.data {
+        PushData::Reporter(reporter) => reporter.id,
+        _ => panic!("Reporter payload is expected"),
+    };
+
+    let mut payloads = vec![reporter, calm, case];
+
+    // The case is a report too, so the spamming reporter goes one report over the limit
+    for _ in 0..MAX_REPORTS {
+        payloads.push(create_address_data(
+            spamming_id,
+            case_id,
+            &network.network,
+            chain_id.clone(),
+        ));
+    }
+    for _ in 0..MAX_REPORTS {
+        payloads.push(create_address_data(
+            calm_id,
+            case_id,
+            &network.network,
+            chain_id.clone(),
+        ));
+    }
+
+    test_app.send_events(&sender, &payloads).await;
+
+    // The calm reporter is at the limit, deliveries of the same events don't add to it
+    let redelivered = payloads[payloads.len() - MAX_REPORTS..].to_vec();
+    test_app.send_events(&sender, &redelivered).await;
+
+    // An event of a reindexed block older than the window doesn't add to it either
+    let mut reindexed = create_address_data(calm_id, case_id, &network.network, chain_id.clone());
+    reindexed.event.timestamp -= 2 * configuration_window_secs();
+    test_app.send_events(&sender, &vec![reindexed]).await;
+
+    let flag = flagged_at(&sender, &network.model.id, spamming_id).await;
+    assert!(
+        flag.is_string(),
+        "Reporter is expected to be flagged: {flag}"
+    );
+
+    let flag = flagged_at(&sender, &network.model.id, calm_id).await;
+    assert!(
+        flag.is_null(),
+        "Reporter is not expected to be flagged: {flag}"
+    );
+
+    // Reports of the flagged reporter are stored anyway
+    for payload in &payloads[3..] {
+        test_app
+            .check_entity(payload.data.clone(), network.model.id.clone())
+            .await;
+    }
+}
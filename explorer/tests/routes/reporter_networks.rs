@@ -0,0 +1,139 @@
+use crate::helpers::{generate_configuration, get_test_data, RequestSender, TestApp};
+use hapi_core::client::solana::get_reporter_account_address;
+use hapi_core::HapiCoreNetwork;
+use hapi_explorer::{
+    configuration::NetworkConfiguration, entity::types::NetworkBackend, service::EntityMutation,
+};
+use hapi_indexer::{PushData, PushPayload};
+use uuid::Uuid;
+
+const PROGRAM_ID: &str = "39WzZqJgkK2QuQxV9jeguKRgHE65Q3HywqPwBzdrKn2B";
+const DEVNET_ID: &str = "solana-devnet";
+const DEVNET_CHAIN_ID: &str = "devnet_chain_id";
+
+fn with_reporter(payload: &PushPayload, id: Uuid, name: &str) -> PushPayload {
+    let mut payload = payload.clone();
+
+    match &mut payload.data {
+        PushData::Reporter(reporter) => {
+            reporter.id = id;
+            reporter.name = name.to_string();
+        }
+        _ => panic!("Reporter payload is expected"),
+    }
+
+    payload
+}
+
+/*
+Test cases:
+ - a reporter registered on two networks lists both records
+ - the Solana record holds the reporter PDA, the EVM record has none
+ - a Solana network registered under a non-canonical id gets the PDA of the program network
+ - lookup by reporter name
+ - unknown reporter
+ */
+#[tokio::test]
+async fn reporter_networks_test() {
+    let mut configuration = generate_configuration();
+    configuration.networks = vec![
+        NetworkConfiguration {
+            id: "solana".to_string(),
+            name: "solana".to_string(),
+            backend: NetworkBackend::Solana,
+            chain_id: Some("solana_chain_id".to_string()),
+            authority: "test_authority".to_string(),
+            stake_token: "test_stake_token".to_string(),
+            rpc_url: None,
+            contract_address: Some(PROGRAM_ID.to_string()),
+        },
+        NetworkConfiguration {
+            id: DEVNET_ID.to_string(),
+            name: "Solana Devnet".to_string(),
+            backend: NetworkBackend::Solana,
+            chain_id: Some(DEVNET_CHAIN_ID.to_string()),
+            authority: "test_authority".to_string(),
+            stake_token: "test_stake_token".to_string(),
+            rpc_url: None,
+            contract_address: Some(PROGRAM_ID.to_string()),
+        },
+    ];
+
+    let test_app = TestApp::start_with_configuration(configuration, None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    EntityMutation::create_network(
+        &test_app.db_connection,
+        DEVNET_ID.to_string(),
+        "Solana Devnet".to_string(),
+        NetworkBackend::Solana,
+        Some(DEVNET_CHAIN_ID.to_string()),
+        "test_authority".to_string(),
+        "test_stake_token".to_string(),
+    )
+    .await
+    .expect("Failed to create network");
+
+    let reporter_id = Uuid::new_v4();
+    let name = format!("Reporter {reporter_id}");
+
+    for network_id in ["ethereum", "solana"] {
+        let network = test_app.get_network(network_id);
+        let reporter = get_test_data(&network.network, network.model.chain_id.clone())[0].clone();
+
+        test_app
+            .send_events(&sender, &vec![with_reporter(&reporter, reporter_id, &name)])
+            .await;
+    }
+
+    let reporter =
+        get_test_data(&HapiCoreNetwork::Solana, Some(DEVNET_CHAIN_ID.to_string()))[0].clone();
+    test_app
+        .send_events(&sender, &vec![with_reporter(&reporter, reporter_id, &name)])
+        .await;
+
+    let response = sender
+        .get(&format!("reporter/{reporter_id}/networks"))
+        .await
+        .unwrap();
+
+    let records = response["data"].as_array().expect("Empty response");
+    assert_eq!(records.len(), 3, "{records:?}");
+
+    assert_eq!(records[0]["network_id"], "ethereum");
+    assert_eq!(records[0]["backend"], "Evm");
+    assert!(records[0]["reporter_address"].is_null());
+
+    assert_eq!(records[1]["network_id"], "solana");
+    assert_eq!(records[1]["backend"], "Solana");
+    assert_eq!(
+        records[1]["reporter_address"],
+        get_reporter_account_address(PROGRAM_ID, "solana", reporter_id).unwrap()
+    );
+
+    assert_eq!(records[2]["network_id"], DEVNET_ID);
+    assert_eq!(records[2]["backend"], "Solana");
+    assert_eq!(
+        records[2]["reporter_address"],
+        records[1]["reporter_address"]
+    );
+
+    assert!(records
+        .iter()
+        .all(|record| record["id"] == reporter_id.to_string()
+            && record["name"] == name
+            && record["role"] == "publisher"));
+
+    // lookup by name
+    let response = sender
+        .get(&format!("reporter/{}/networks", name.replace(' ', "%20")))
+        .await
+        .unwrap();
+    assert_eq!(response["data"].as_array(), Some(records));
+
+    // unknown reporter
+    assert!(sender
+        .get(&format!("reporter/{}/networks", Uuid::new_v4()))
+        .await
+        .is_err());
+}
@@ -0,0 +1,50 @@
+use crate::helpers::{RequestSender, TestApp};
+use hapi_core::client::{entities::reporter::Reporter, events::EventName};
+
+/*
+Test cases:
+ - search reporters by name in different case
+ - search cases by name substring
+ - search with unmatched query
+ - search with empty query
+ */
+#[tokio::test]
+async fn search_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let reporters = test_app
+        .global_setup::<Reporter>(&sender, EventName::UpdateReporter)
+        .await;
+
+    // search reporters by name in different case
+    let response = sender.get("search?q=AUTHORITY%20rep").await.unwrap();
+    let data = response["data"].as_array().expect("Empty response");
+
+    assert_eq!(data.len(), reporters.len());
+    for result in data {
+        assert_eq!(result["type"], "reporter");
+        assert_eq!(result["name"], "Authority reporter");
+
+        assert!(reporters.iter().any(|r| {
+            r.network_id == result["network_id"] && r.data.id.to_string() == result["id"]
+        }));
+    }
+
+    // search cases by name substring
+    let response = sender.get("search?q=losed%20case").await.unwrap();
+    let data = response["data"].as_array().expect("Empty response");
+
+    assert_eq!(data.len(), test_app.networks.len());
+    for result in data {
+        assert_eq!(result["type"], "case");
+        assert_eq!(result["name"], "Closed case 1");
+    }
+
+    // search with unmatched query
+    let response = sender.get("search?q=100%25").await.unwrap();
+    assert_eq!(response["meta"]["total"], 0);
+
+    // search with empty query
+    assert!(sender.get("search?q=%20").await.is_err());
+}
@@ -0,0 +1,73 @@
+use crate::helpers::{get_test_data, RequestSender, TestApp};
+use hapi_core::client::events::EventName;
+use hapi_indexer::{PushData, PushPayload};
+use uuid::Uuid;
+
+fn with_stake(payload: &PushPayload, event: EventName, stake: u64, timestamp: u64) -> PushPayload {
+    let mut payload = payload.clone();
+    payload.event.name = event;
+    payload.event.timestamp = timestamp;
+
+    match &mut payload.data {
+        PushData::Reporter(reporter) => reporter.stake = stake.into(),
+        _ => panic!("Reporter payload is expected"),
+    }
+
+    payload
+}
+
+/*
+Test cases:
+ - a deposit then a release give the history with amounts and balances
+ - a payload pushed again adds no entry
+ - unknown reporter
+ */
+#[tokio::test]
+async fn reporter_stake_history_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+
+    let mut reporter = get_test_data(&network.network, network.model.chain_id.clone())[0].clone();
+    reporter.event.timestamp = 1000;
+
+    let reporter_id = match &reporter.data {
+        PushData::Reporter(reporter) => reporter.id,
+        _ => panic!("Reporter payload is expected"),
+    };
+
+    let deposit = with_stake(&reporter, EventName::ActivateReporter, 1000, 1100);
+    let release = with_stake(&reporter, EventName::Unstake, 0, 1300);
+
+    test_app
+        .send_events(&sender, &vec![reporter, deposit.clone(), deposit, release])
+        .await;
+
+    let response = sender
+        .get(&format!("reporter/{reporter_id}/stake-history"))
+        .await
+        .unwrap();
+
+    let history = response["data"].as_array().expect("Empty response");
+    assert_eq!(history.len(), 2, "{history:?}");
+
+    assert_eq!(history[0]["event"], "activate_reporter");
+    assert_eq!(history[0]["amount"], "1000");
+    assert_eq!(history[0]["balance"], "1000");
+
+    assert_eq!(history[1]["event"], "unstake");
+    assert_eq!(history[1]["amount"], "-1000");
+    assert_eq!(history[1]["balance"], "0");
+
+    assert!(history
+        .iter()
+        .all(|entry| entry["network_id"] == network.model.id
+            && entry["reporter_id"] == reporter_id.to_string()));
+    assert!(history[0]["changed_at"].as_str() < history[1]["changed_at"].as_str());
+
+    // unknown reporter
+    assert!(sender
+        .get(&format!("reporter/{}/stake-history", Uuid::new_v4()))
+        .await
+        .is_err());
+}
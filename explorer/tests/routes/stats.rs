@@ -0,0 +1,102 @@
+use crate::helpers::{create_jwt, get_test_data, RequestSender, TestApp};
+use serde_json::{json, Map, Value};
+
+fn event_counts(response: &Value, network_id: &str) -> Option<Value> {
+    response["data"]
+        .as_array()
+        .expect("Empty response")
+        .iter()
+        .find(|network| network["network_id"] == network_id)
+        .map(|network| network["events"].clone())
+}
+
+/*
+Test cases:
+ - events are counted per network by event name
+ - events of a batch are counted as well, reverted events are not
+ - hourly counts add up to the totals
+ - unknown network and invalid hours
+ */
+#[tokio::test]
+async fn event_stats_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let (network, other) = (&test_app.networks[0], &test_app.networks[1]);
+
+    let response = sender.get("stats").await.unwrap();
+    assert_eq!(response["data"], json!([]));
+
+    // a mix of creations, updates and confirmations
+    let payloads = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&sender, &payloads).await;
+
+    let mut expected = Map::new();
+    for payload in &payloads {
+        let count = expected
+            .entry(payload.event.name.to_string())
+            .or_insert(json!(0));
+        *count = json!(count.as_i64().unwrap() + 1);
+    }
+
+    let response = sender
+        .get(&format!("stats?network={}", network.model.id))
+        .await
+        .unwrap();
+    assert_eq!(response["data"].as_array().unwrap().len(), 1);
+    assert_eq!(response["data"][0]["total"], payloads.len());
+    assert!(response["data"][0].get("hourly").is_none());
+    assert_eq!(
+        event_counts(&response, &network.model.id),
+        Some(Value::Object(expected.clone()))
+    );
+
+    // batches are counted the same way, reverted events are not
+    let mut batch = get_test_data(&other.network, other.model.chain_id.clone())[..3].to_vec();
+    let mut reverted = batch[2].clone();
+    reverted.event.removed = true;
+    batch.push(reverted);
+
+    sender
+        .send("events/bulk", &batch, &create_jwt("my_ultra_secure_secret"))
+        .await
+        .expect("Failed to send batch");
+
+    let response = sender.get("stats").await.unwrap();
+    assert_eq!(response["data"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        event_counts(&response, &network.model.id),
+        Some(Value::Object(expected))
+    );
+
+    let other_counts: Map<String, Value> = batch[..3]
+        .iter()
+        .map(|payload| (payload.event.name.to_string(), json!(1)))
+        .collect();
+    assert_eq!(
+        event_counts(&response, &other.model.id),
+        Some(Value::Object(other_counts))
+    );
+
+    // hourly counts, the test may run across an hour boundary
+    let response = sender
+        .get(&format!("stats?network={}&hours=2", network.model.id))
+        .await
+        .unwrap();
+    let data = &response["data"][0];
+    let hourly = data["hourly"]
+        .as_array()
+        .expect("Hourly counts are missing");
+    assert!((1..=2).contains(&hourly.len()), "{hourly:?}");
+
+    let hourly_total: i64 = hourly
+        .iter()
+        .flat_map(|hour| hour["events"].as_object().unwrap().values())
+        .map(|count| count.as_i64().unwrap())
+        .sum();
+    assert_eq!(hourly_total, payloads.len() as i64);
+
+    // unknown network and invalid hours
+    assert!(sender.get("stats?network=unknown").await.is_err());
+    assert!(sender.get("stats?hours=0").await.is_err());
+    assert!(sender.get("stats?hours=1000").await.is_err());
+}
@@ -1,4 +1,14 @@
-use crate::helpers::{create_jwt, get_test_data, RequestSender, TestApp, WAITING_INTERVAL};
+use crate::helpers::{
+    create_address_data, create_jwt, create_reporter_data, get_test_data, RequestSender, TestApp,
+    WAITING_INTERVAL,
+};
+use hapi_core::client::{entities::reporter::ReporterStatus, events::EventName};
+use hapi_explorer::entity::{
+    address, asset, case, reporter,
+    types::{AddressStatus, CaseStatus, ReporterStatus as ReporterModelStatus},
+};
+use hapi_indexer::{PushData, PushFormat, PushPayload};
+use sea_orm::EntityTrait;
 use tokio::time::{sleep, Duration};
 
 #[tokio::test]
@@ -23,3 +33,419 @@ async fn webhook_processing_test() {
         }
     }
 }
+
+#[tokio::test]
+async fn repeated_payloads_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+
+        for payload in &test_data {
+            indexer_mock
+                .send("events", payload, &token)
+                .await
+                .expect("Failed to send event");
+            sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+        }
+
+        // Reindexed ranges push the same payloads again
+        for payload in test_data {
+            indexer_mock
+                .send("events", &payload, &token)
+                .await
+                .expect("Failed to send repeated event");
+            sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+            test_app
+                .check_entity(payload.data, network.model.id.clone())
+                .await;
+        }
+    }
+}
+
+/*
+Test cases:
+ - unstaked reporter is stored inactive with no stake, whatever state the payload holds
+ - the state read after staking again is applied with its own event
+ */
+#[tokio::test]
+async fn unstake_payload_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+    let network = &test_app.networks[0];
+
+    let created = create_reporter_data(&network.network, network.model.chain_id.clone());
+    let with_state = |event: EventName, status: ReporterStatus, stake: u64, unlock: u64| {
+        let mut payload = created.clone();
+        payload.event.name = event;
+
+        match &mut payload.data {
+            PushData::Reporter(reporter) => {
+                reporter.status = status;
+                reporter.stake = stake.into();
+                reporter.unlock_timestamp = unlock;
+            }
+            _ => panic!("Reporter payload is expected"),
+        }
+
+        payload
+    };
+
+    // The reporter has staked again by the time the indexer reads it for the unstake event
+    let restaked = with_state(EventName::ActivateReporter, ReporterStatus::Active, 500, 0);
+    let payloads = [
+        created.clone(),
+        with_state(EventName::ActivateReporter, ReporterStatus::Active, 1000, 0),
+        with_state(
+            EventName::DeactivateReporter,
+            ReporterStatus::Unstaking,
+            1000,
+            12345,
+        ),
+        with_state(EventName::Unstake, ReporterStatus::Active, 500, 0),
+    ];
+
+    for payload in &payloads {
+        indexer_mock
+            .send("events", payload, &token)
+            .await
+            .expect("Failed to send event");
+        sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+    }
+
+    let released = with_state(EventName::Unstake, ReporterStatus::Inactive, 0, 0);
+    test_app
+        .check_entity(released.data, network.model.id.clone())
+        .await;
+
+    indexer_mock
+        .send("events", &restaked, &token)
+        .await
+        .expect("Failed to send event");
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    test_app
+        .check_entity(restaked.data, network.model.id.clone())
+        .await;
+}
+
+#[tokio::test]
+async fn confirmation_payload_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+
+    for network in &test_app.networks {
+        let created = get_test_data(&network.network, network.model.chain_id.clone())
+            .into_iter()
+            .filter(|payload| {
+                [
+                    EventName::CreateReporter,
+                    EventName::CreateCase,
+                    EventName::CreateAddress,
+                    EventName::CreateAsset,
+                ]
+                .contains(&payload.event.name)
+            })
+            .collect::<Vec<_>>();
+
+        for payload in &created {
+            indexer_mock
+                .send("events", payload, &token)
+                .await
+                .expect("Failed to send event");
+            sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+        }
+
+        // Confirmations carry the on-chain state, in which the risk was raised in the meantime
+        for mut payload in created {
+            match &mut payload.data {
+                PushData::Address(address) => {
+                    payload.event.name = EventName::ConfirmAddress;
+                    address.risk += 2;
+                    address.confirmations += 1;
+                }
+                PushData::Asset(asset) => {
+                    payload.event.name = EventName::ConfirmAsset;
+                    asset.risk += 2;
+                    asset.confirmations += 1;
+                }
+                _ => continue,
+            }
+
+            indexer_mock
+                .send("events", &payload, &token)
+                .await
+                .expect("Failed to send confirmation");
+            sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+            test_app
+                .check_entity(payload.data, network.model.id.clone())
+                .await;
+        }
+    }
+}
+
+/*
+Test cases:
+ - reverted asset creation deletes the asset
+ - reverted case and reporter creations, that a withdrawn address still refers to, close
+   the case and deactivate the reporter
+ - reverted creation of a reporter without cases and reports deletes the reporter
+ */
+#[tokio::test]
+async fn reverted_creations_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+    let db = &test_app.db_connection;
+
+    for network in &test_app.networks {
+        let network_id = network.model.id.clone();
+        let mut data = get_test_data(&network.network, network.model.chain_id.clone());
+
+        // Reported nothing before its creation was reverted
+        let lone_reporter = create_reporter_data(&network.network, network.model.chain_id.clone());
+        let lone_reporter_id = match &lone_reporter.data {
+            PushData::Reporter(reporter) => reporter.id,
+            _ => panic!("Reporter payload is expected"),
+        };
+        data.push(lone_reporter);
+
+        for payload in &data {
+            indexer_mock
+                .send("events", payload, &token)
+                .await
+                .expect("Failed to send event");
+            sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+        }
+
+        // The indexer withdraws reorganized creations latest first
+        let reverted = data
+            .iter()
+            .rev()
+            .filter(|payload| {
+                [
+                    EventName::CreateReporter,
+                    EventName::CreateCase,
+                    EventName::CreateAddress,
+                    EventName::CreateAsset,
+                ]
+                .contains(&payload.event.name)
+            })
+            .map(|payload| {
+                let mut payload = payload.clone();
+                payload.event.removed = true;
+                payload
+            })
+            .collect::<Vec<_>>();
+
+        for payload in &reverted {
+            indexer_mock
+                .send("events", payload, &token)
+                .await
+                .expect("Failed to send reverted event");
+            sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+        }
+
+        for payload in &reverted {
+            match &payload.data {
+                PushData::Asset(data) => {
+                    let stored = asset::Entity::find_by_id((
+                        network_id.clone(),
+                        data.address.clone(),
+                        data.asset_id.to_string(),
+                    ))
+                    .one(db)
+                    .await
+                    .expect("Failed to find asset");
+                    assert!(stored.is_none());
+                }
+                PushData::Address(data) => {
+                    let stored =
+                        address::Entity::find_by_id((network_id.clone(), data.address.clone()))
+                            .one(db)
+                            .await
+                            .expect("Failed to find address")
+                            .expect("Withdrawn address is kept");
+                    assert_eq!(stored.status, AddressStatus::Withdrawn);
+                }
+                PushData::Case(data) => {
+                    let stored = case::Entity::find_by_id((network_id.clone(), data.id))
+                        .one(db)
+                        .await
+                        .expect("Failed to find case")
+                        .expect("Case of a withdrawn address is kept");
+                    assert_eq!(stored.status, CaseStatus::Closed);
+                }
+                PushData::Reporter(data) => {
+                    let stored = reporter::Entity::find_by_id((network_id.clone(), data.id))
+                        .one(db)
+                        .await
+                        .expect("Failed to find reporter");
+
+                    if data.id == lone_reporter_id {
+                        assert!(stored.is_none());
+                        continue;
+                    }
+
+                    let stored = stored.expect("Reporter of a withdrawn address is kept");
+                    assert_eq!(stored.status, ReporterModelStatus::Inactive);
+                    assert_eq!(stored.stake, "0");
+                }
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn bulk_payloads_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+
+    for network in &test_app.networks {
+        let mut batch = get_test_data(&network.network, network.model.chain_id.clone());
+
+        let (reporter_id, case_id) = match &batch[2].data {
+            PushData::Address(address) => (address.reporter_id, address.case_id),
+            _ => panic!("Address payload is expected"),
+        };
+
+        // Backfill-sized batch of new addresses, reported after the test data
+        let new_addresses = (0..500)
+            .map(|_| {
+                create_address_data(
+                    reporter_id,
+                    case_id,
+                    &network.network,
+                    network.model.chain_id.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        batch.extend(new_addresses.clone());
+
+        let created = batch
+            .iter()
+            .filter(|payload| {
+                [
+                    EventName::CreateReporter,
+                    EventName::CreateCase,
+                    EventName::CreateAddress,
+                    EventName::CreateAsset,
+                ]
+                .contains(&payload.event.name)
+            })
+            .count();
+
+        let response = indexer_mock
+            .send("events/bulk", &batch, &token)
+            .await
+            .expect("Failed to send batch");
+
+        assert_eq!(response["inserted"], created);
+        assert_eq!(response["processed"], batch.len() - created);
+
+        // Reindexed ranges push the same batch again, now every payload is an update
+        let response = indexer_mock
+            .send("events/bulk", &batch, &token)
+            .await
+            .expect("Failed to send repeated batch");
+
+        assert_eq!(response["inserted"], 0);
+        assert_eq!(response["processed"], batch.len());
+
+        // Test data ends with the final state of every entity
+        for payload in get_last_states(&batch).into_iter().chain(new_addresses) {
+            test_app
+                .check_entity(payload.data, network.model.id.clone())
+                .await;
+        }
+    }
+}
+
+#[tokio::test]
+async fn msgpack_payloads_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        let (single, batch) = test_data.split_at(test_data.len() / 2);
+
+        for payload in single {
+            indexer_mock
+                .send_encoded("events", payload, PushFormat::Msgpack, &token)
+                .await
+                .expect("Failed to send event");
+            sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+        }
+
+        let response = indexer_mock
+            .send_encoded("events/bulk", batch, PushFormat::Msgpack, &token)
+            .await
+            .expect("Failed to send batch");
+        assert_eq!(
+            response["inserted"].as_u64().unwrap() + response["processed"].as_u64().unwrap(),
+            batch.len() as u64
+        );
+
+        for payload in get_last_states(&test_data) {
+            test_app
+                .check_entity(payload.data, network.model.id.clone())
+                .await;
+        }
+    }
+
+    // A body that isn't MessagePack is rejected, not taken for JSON
+    let error = indexer_mock
+        .send_encoded("events", "not a payload", PushFormat::Msgpack, &token)
+        .await
+        .expect_err("Malformed body is accepted");
+    assert!(error.to_string().contains("422"), "{error}");
+}
+
+#[tokio::test]
+async fn oversized_batch_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let token = create_jwt("my_ultra_secure_secret");
+
+    let network = &test_app.networks[0];
+    let payload = create_reporter_data(&network.network, network.model.chain_id.clone());
+    let batch = vec![payload; 1001];
+
+    let error = indexer_mock
+        .send("events/bulk", &batch, &token)
+        .await
+        .expect_err("Oversized batch must be rejected");
+
+    assert!(error.to_string().contains("413"), "{error}");
+}
+
+fn get_last_states(batch: &[PushPayload]) -> Vec<PushPayload> {
+    let mut states: Vec<PushPayload> = vec![];
+
+    for payload in batch {
+        let key = entity_key(&payload.data);
+
+        states.retain(|state| entity_key(&state.data) != key);
+        states.push(payload.clone());
+    }
+
+    states
+}
+
+fn entity_key(data: &PushData) -> String {
+    match data {
+        PushData::Address(address) => address.address.clone(),
+        PushData::Asset(asset) => format!("{}.{}", asset.address, asset.asset_id),
+        PushData::Case(case) => case.id.to_string(),
+        PushData::Reporter(reporter) => reporter.id.to_string(),
+    }
+}
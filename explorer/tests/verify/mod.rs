@@ -0,0 +1,218 @@
+use {
+    anyhow::Result,
+    futures::{future::LocalBoxFuture, FutureExt},
+    hapi_core::client::entities::{address::Address, asset::Asset, case::Case, reporter::Reporter},
+    hapi_explorer::verify::{verify, ChainBatch, ChainEntities, VerifiedEntity, VerifyOptions},
+    hapi_indexer::{PushData, PushPayload},
+    std::cell::Cell,
+    tokio::time::{sleep, Duration},
+};
+
+use crate::helpers::{create_address_data, get_test_data, RequestSender, TestApp};
+
+mod solana;
+
+/// Contract state built from the latest payload of every entity
+#[derive(Default)]
+struct MockChain {
+    reporters: Vec<Reporter>,
+    cases: Vec<Case>,
+    addresses: Vec<Address>,
+    assets: Vec<Asset>,
+    in_flight: Cell<usize>,
+    max_in_flight: Cell<usize>,
+}
+
+impl MockChain {
+    fn from_payloads(payloads: &[PushPayload]) -> Self {
+        let mut chain = Self::default();
+
+        for payload in payloads {
+            match payload.data.clone() {
+                PushData::Reporter(reporter) => {
+                    chain.reporters.retain(|r| r.id != reporter.id);
+                    chain.reporters.push(reporter);
+                }
+                PushData::Case(case) => {
+                    chain.cases.retain(|c| c.id != case.id);
+                    chain.cases.push(case);
+                }
+                PushData::Address(address) => {
+                    chain.addresses.retain(|a| a.address != address.address);
+                    chain.addresses.push(address);
+                }
+                PushData::Asset(asset) => {
+                    chain.assets.retain(|a| a.address != asset.address);
+                    chain.assets.push(asset);
+                }
+            }
+        }
+
+        chain
+    }
+}
+
+fn page<T: Clone>(entities: &[T], skip: u64, take: u64) -> Vec<T> {
+    entities
+        .iter()
+        .skip(skip as usize)
+        .take(take as usize)
+        .cloned()
+        .collect()
+}
+
+impl ChainEntities for MockChain {
+    fn count(&self, entity: VerifiedEntity) -> LocalBoxFuture<'_, Result<u64>> {
+        let count = match entity {
+            VerifiedEntity::Reporter => self.reporters.len(),
+            VerifiedEntity::Case => self.cases.len(),
+            VerifiedEntity::Address => self.addresses.len(),
+            VerifiedEntity::Asset => self.assets.len(),
+        };
+
+        async move { Ok(count as u64) }.boxed_local()
+    }
+
+    fn batch(
+        &self,
+        entity: VerifiedEntity,
+        skip: u64,
+        take: u64,
+    ) -> LocalBoxFuture<'_, Result<ChainBatch>> {
+        async move {
+            self.in_flight.set(self.in_flight.get() + 1);
+            self.max_in_flight
+                .set(self.max_in_flight.get().max(self.in_flight.get()));
+
+            sleep(Duration::from_millis(20)).await;
+
+            self.in_flight.set(self.in_flight.get() - 1);
+
+            Ok(match entity {
+                VerifiedEntity::Reporter => {
+                    ChainBatch::Reporters(page(&self.reporters, skip, take))
+                }
+                VerifiedEntity::Case => ChainBatch::Cases(page(&self.cases, skip, take)),
+                VerifiedEntity::Address => ChainBatch::Addresses(page(&self.addresses, skip, take)),
+                VerifiedEntity::Asset => ChainBatch::Assets(page(&self.assets, skip, take)),
+            })
+        }
+        .boxed_local()
+    }
+}
+
+/*
+Test cases:
+ - stored entities that match the contract state are not reported
+ - batches are read with the configured concurrency at most
+ - a seeded field mismatch and an unstored address are reported
+ - sampling reads only a share of the batches
+ - invalid options are rejected
+ */
+#[tokio::test]
+async fn verify_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+    let chain_id = network.model.chain_id.clone();
+    let db = &test_app.db_connection;
+
+    let mut test_data = get_test_data(&network.network, chain_id.clone());
+    let (reporter_id, case_id) = match &test_data[1].data {
+        PushData::Case(case) => (case.reporter_id, case.id),
+        _ => panic!("Case payload is expected"),
+    };
+
+    for _ in 0..5 {
+        test_data.push(create_address_data(
+            reporter_id,
+            case_id,
+            &network.network,
+            chain_id.clone(),
+        ));
+    }
+
+    test_app.send_events(&sender, &test_data).await;
+
+    let options = VerifyOptions {
+        concurrency: 2,
+        batch_size: 1,
+        sample_rate: 1.0,
+    };
+
+    // stored entities that match the contract state are not reported
+    let chain = MockChain::from_payloads(&test_data);
+    let report = verify(db, &network.model.id, &chain, &options)
+        .await
+        .expect("Failed to verify");
+
+    assert_eq!(report.checked, 9);
+    assert!(report.mismatches.is_empty());
+
+    // batches are read with the configured concurrency at most
+    assert_eq!(chain.max_in_flight.get(), options.concurrency);
+
+    // a seeded field mismatch and an unstored address are reported
+    let mut chain = MockChain::from_payloads(&test_data);
+    chain.addresses[0].risk = 1;
+    let seeded = chain.addresses[0].address.clone();
+
+    let mut unstored = chain.addresses[1].clone();
+    unstored.address = "0x0000000000000000000000000000000000000001".to_string();
+    chain.addresses.push(unstored.clone());
+
+    let report = verify(db, &network.model.id, &chain, &options)
+        .await
+        .expect("Failed to verify");
+
+    assert_eq!(report.checked, 10);
+    assert_eq!(report.mismatches.len(), 2);
+    assert!(chain.max_in_flight.get() <= options.concurrency);
+
+    let json = serde_json::to_value(&report).expect("Failed to serialize report");
+    let mismatches = json["mismatches"].as_array().unwrap();
+
+    let risk = mismatches
+        .iter()
+        .find(|mismatch| mismatch["id"] == seeded.as_str())
+        .expect("Seeded mismatch is not reported");
+    assert_eq!(risk["entity"], "address");
+    assert_eq!(risk["field"], "risk");
+    assert_eq!(risk["stored"], "10");
+    assert_eq!(risk["on_chain"], "1");
+
+    let missing = mismatches
+        .iter()
+        .find(|mismatch| mismatch["id"] == unstored.address.as_str())
+        .expect("Unstored address is not reported");
+    assert!(missing["field"].is_null());
+    assert!(missing["stored"].is_null());
+
+    // sampling reads only a share of the batches
+    let sampled = VerifyOptions {
+        sample_rate: 0.5,
+        ..options.clone()
+    };
+    let report = verify(db, &network.model.id, &chain, &sampled)
+        .await
+        .expect("Failed to verify");
+
+    assert!(report.checked < 10);
+    assert!(report.checked > 0);
+
+    // invalid options are rejected
+    for invalid in [
+        VerifyOptions {
+            concurrency: 0,
+            ..options.clone()
+        },
+        VerifyOptions {
+            sample_rate: 0.0,
+            ..options.clone()
+        },
+    ] {
+        assert!(verify(db, &network.model.id, &chain, &invalid)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,271 @@
+use {
+    anchor_lang::AccountSerialize,
+    base64::{engine::general_purpose::STANDARD, Engine},
+    hapi_core::{
+        client::solana::byte_array_from_str, HapiCoreNetwork, HapiCoreOptions, RpcTimeouts,
+    },
+    hapi_explorer::verify::{verify, VerifyOptions},
+    hapi_indexer::{PushData, PushPayload},
+    mockito::{Server, ServerGuard},
+    serde_json::{json, Value},
+    solana_sdk::pubkey::Pubkey,
+    std::{
+        collections::HashMap,
+        str::FromStr,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    },
+};
+
+use crate::helpers::{create_address_data, get_test_data, RequestSender, TestApp};
+
+const PROGRAM_ID: &str = "39WzZqJgkK2QuQxV9jeguKRgHE65Q3HywqPwBzdrKn2B";
+
+fn encode_address(address: &str) -> [u8; 64] {
+    let mut res = [0u8; 64];
+    let bytes = address.as_bytes();
+    res[..bytes.len()].copy_from_slice(bytes);
+
+    res
+}
+
+/// Serializes the latest state of an entity into its program account
+fn account_data(payload_data: PushData) -> Vec<u8> {
+    let mut data = vec![];
+
+    match payload_data {
+        PushData::Address(address) => hapi_core_solana::Address {
+            version: 1,
+            bump: 255,
+            network: Pubkey::default(),
+            address: encode_address(&address.address),
+            category: address.category.into(),
+            risk_score: address.risk,
+            case_id: address.case_id.as_u128(),
+            reporter_id: address.reporter_id.as_u128(),
+            confirmations: address.confirmations,
+            is_invalidated: false,
+        }
+        .try_serialize(&mut data),
+        PushData::Asset(asset) => {
+            let mut id = [0_u8; 32];
+            byte_array_from_str(&asset.asset_id.to_string(), &mut id)
+                .expect("Failed to parse asset id");
+
+            hapi_core_solana::Asset {
+                version: 1,
+                bump: 255,
+                network: Pubkey::default(),
+                address: encode_address(&asset.address),
+                id,
+                category: asset.category.into(),
+                risk_score: asset.risk,
+                case_id: asset.case_id.as_u128(),
+                reporter_id: asset.reporter_id.as_u128(),
+                confirmations: asset.confirmations,
+            }
+            .try_serialize(&mut data)
+        }
+        PushData::Case(case) => hapi_core_solana::Case {
+            version: 1,
+            bump: 255,
+            network: Pubkey::default(),
+            id: case.id.as_u128(),
+            name: case.name,
+            reporter_id: case.reporter_id.as_u128(),
+            status: case.status.into(),
+            url: case.url,
+            evidence: vec![],
+            related_cases: vec![],
+        }
+        .try_serialize(&mut data),
+        PushData::Reporter(reporter) => hapi_core_solana::Reporter {
+            version: 1,
+            bump: 255,
+            network: Pubkey::default(),
+            id: reporter.id.as_u128(),
+            name: reporter.name,
+            account: Pubkey::from_str(&reporter.account).expect("Invalid reporter account"),
+            role: reporter.role.into(),
+            status: reporter.status.into(),
+            unlock_timestamp: reporter.unlock_timestamp,
+            url: reporter.url,
+            stake: reporter.stake.into(),
+        }
+        .try_serialize(&mut data),
+    }
+    .expect("Failed to serialize account");
+
+    data
+}
+
+fn encode_account(data: &[u8]) -> Value {
+    json!({
+        "data": [STANDARD.encode(data), "base64"],
+        "executable": false,
+        "lamports": 100,
+        "owner": PROGRAM_ID,
+        "rentEpoch": 0,
+    })
+}
+
+/// Solana RPC node that serves the program accounts of the latest payloads
+///
+/// Accounts are listed in reverse order of their keys, the way no client would sort them.
+async fn mock_program_accounts(payloads: &[PushPayload]) -> (ServerGuard, Arc<AtomicUsize>) {
+    let mut accounts: HashMap<String, Vec<u8>> = HashMap::new();
+    let mut keys: HashMap<String, String> = HashMap::new();
+
+    for payload in payloads {
+        let entity = match &payload.data {
+            PushData::Reporter(reporter) => reporter.id.to_string(),
+            PushData::Case(case) => case.id.to_string(),
+            PushData::Address(address) => address.address.clone(),
+            PushData::Asset(asset) => format!("{}.{}", asset.address, asset.asset_id),
+        };
+
+        let key = keys
+            .entry(entity)
+            .or_insert_with(|| Pubkey::new_unique().to_string())
+            .clone();
+
+        accounts.insert(key, account_data(payload.data.clone()));
+    }
+
+    let listings = Arc::new(AtomicUsize::new(0));
+    let counter = listings.clone();
+
+    let mut server = Server::new_async().await;
+    server
+        .mock("POST", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |request| {
+            let request: Value =
+                serde_json::from_slice(request.body().expect("Request has no body"))
+                    .expect("Invalid request");
+
+            let result = match request["method"].as_str() {
+                Some("getVersion") => json!({ "solana-core": "1.16.17", "feature-set": 0 }),
+                Some("getProgramAccounts") => {
+                    counter.fetch_add(1, Ordering::SeqCst);
+
+                    let discriminator = request["params"][1]["filters"][0]["memcmp"]["bytes"]
+                        .as_str()
+                        .expect("Accounts are not filtered by type")
+                        .to_string();
+
+                    let mut listed: Vec<&String> = accounts
+                        .iter()
+                        .filter(|(_, data)| bs58::encode(&data[..8]).into_string() == discriminator)
+                        .map(|(key, _)| key)
+                        .collect();
+                    listed.sort_by_key(|key| std::cmp::Reverse(Pubkey::from_str(key).unwrap()));
+
+                    listed
+                        .into_iter()
+                        .map(|key| json!({ "pubkey": key, "account": encode_account(&[]) }))
+                        .collect()
+                }
+                Some("getMultipleAccounts") => {
+                    let value: Vec<Value> = request["params"][0]
+                        .as_array()
+                        .expect("Accounts are not listed")
+                        .iter()
+                        .map(|key| {
+                            accounts
+                                .get(key.as_str().unwrap())
+                                .map_or(Value::Null, |data| encode_account(data))
+                        })
+                        .collect();
+
+                    json!({ "context": { "slot": 1 }, "value": value })
+                }
+                method => panic!("Unexpected RPC method: {method:?}"),
+            };
+
+            json!({ "jsonrpc": "2.0", "result": result, "id": request["id"] })
+                .to_string()
+                .into_bytes()
+        })
+        .create_async()
+        .await;
+
+    (server, listings)
+}
+
+/*
+Test cases:
+ - every program account is checked once, however many batches it takes
+ */
+#[tokio::test]
+async fn verify_solana_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = test_app
+        .networks
+        .iter()
+        .find(|network| network.network == HapiCoreNetwork::Solana)
+        .expect("Solana network is not configured");
+    let chain_id = network.model.chain_id.clone();
+    let db = &test_app.db_connection;
+
+    let mut test_data = get_test_data(&network.network, chain_id.clone());
+    let (reporter_id, case_id) = match &test_data[1].data {
+        PushData::Case(case) => (case.reporter_id, case.id),
+        _ => panic!("Case payload is expected"),
+    };
+
+    for _ in 0..5 {
+        test_data.push(create_address_data(
+            reporter_id,
+            case_id,
+            &network.network,
+            chain_id.clone(),
+        ));
+    }
+
+    // Reporter accounts are Solana keys on chain
+    let account = Pubkey::new_unique().to_string();
+    for payload in test_data.iter_mut() {
+        if let PushData::Reporter(reporter) = &mut payload.data {
+            reporter.account = account.clone();
+        }
+    }
+
+    test_app.send_events(&sender, &test_data).await;
+
+    let (server, listings) = mock_program_accounts(&test_data).await;
+
+    let client = HapiCoreOptions {
+        provider_url: server.url(),
+        fallback_provider_urls: vec![],
+        contract_address: PROGRAM_ID.to_string(),
+        private_key: None,
+        chain_id: None,
+        account_id: None,
+        network: HapiCoreNetwork::Solana,
+        rpc_timeouts: RpcTimeouts::default(),
+    }
+    .into_client()
+    .expect("Failed to create client");
+
+    let options = VerifyOptions {
+        concurrency: 2,
+        batch_size: 2,
+        sample_rate: 1.0,
+    };
+
+    // every program account is checked once, however many batches it takes
+    let report = verify(db, &network.model.id, client.as_ref(), &options)
+        .await
+        .expect("Failed to verify");
+
+    assert_eq!(report.checked, 9);
+    assert!(report.mismatches.is_empty(), "{:?}", report.mismatches);
+
+    // A count and a listing per batch: 1 reporter, 1 case, 6 addresses and 1 asset
+    assert_eq!(listings.load(Ordering::SeqCst), 4 + 1 + 1 + 3 + 1);
+}
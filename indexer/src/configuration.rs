@@ -1,9 +1,17 @@
 use {
     config::{Config, ConfigError, File, FileFormat},
-    hapi_core::HapiCoreNetwork,
+    ethers::types::Address as EthAddress,
+    hapi_core::{HapiCoreNetwork, RpcTimeouts},
+    near_primitives::types::AccountId,
     serde::Deserialize,
     serde_with::{serde_as, DurationMilliSeconds},
-    std::{env, time::Duration},
+    solana_sdk::pubkey::Pubkey,
+    std::{collections::HashMap, env, str::FromStr, time::Duration},
+};
+
+use crate::indexer::{
+    client::{ClientKind, PageSizes},
+    push::PushFormat,
 };
 
 pub const CONFIG_PATH: &str = "configuration.toml";
@@ -15,6 +23,10 @@ pub struct Configuration {
     #[serde(default = "default_loglevel")]
     pub log_level: String,
 
+    /// Per-module filter directives, i.e. `info,hapi_indexer=debug`, take precedence over `log_level`
+    #[serde(default)]
+    pub log_filter: Option<String>,
+
     /// Whether to use JSON logging
     #[serde(default = "default_is_json_logging")]
     pub is_json_logging: bool,
@@ -38,10 +50,18 @@ pub struct IndexerConfiguration {
     /// The RPC node URL
     pub rpc_node_url: String,
 
+    /// EVM RPC node URLs to fail over to when the RPC node is unreachable, in order
+    #[serde(default)]
+    pub rpc_node_fallback_urls: Vec<String>,
+
+    /// Node to fetch EVM contract logs from with `eth_getLogs`, the RPC node by default
+    #[serde(default)]
+    pub log_source_url: Option<String>,
+
     /// URL to send webhooks to
     pub webhook_url: String,
 
-    /// The HAPI Core contract address
+    /// The HAPI Core contract address: `0x` address on EVM, account ID on NEAR, program ID on Solana
     pub contract_address: String,
 
     /// The number of milliseconds between wait checks
@@ -53,13 +73,212 @@ pub struct IndexerConfiguration {
     #[serde(default = "default_state_file")]
     pub state_file: String,
 
+    /// Average block time of the network in milliseconds, used as the polling pause once all blocks are indexed
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default)]
+    pub block_time_ms: Option<Duration>,
+
     /// The number of milliseconds between iterations in the fetching
     #[serde_as(as = "DurationMilliSeconds<u64>")]
     #[serde(default = "default_delay")]
     pub fetching_delay: Duration,
 
+    /// Blocks per fetched EVM or NEAR page, takes precedence over `INDEXER_PAGE_SIZE`
+    #[serde(default)]
+    pub page_size: Option<u64>,
+
+    /// Blocks per fetched page far behind the chain head, takes precedence over `INDEXER_CATCH_UP_PAGE_SIZE`
+    #[serde(default)]
+    pub catch_up_page_size: Option<u64>,
+
+    /// Blocks behind the chain head to fetch catch-up pages from, takes precedence over `INDEXER_CATCH_UP_THRESHOLD`
+    #[serde(default)]
+    pub catch_up_threshold: Option<u64>,
+
     /// JWT token to use for the webhook
     pub jwt_token: String,
+
+    /// The maximum number of milliseconds a single fetching or processing step may take
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_rpc_timeout")]
+    pub rpc_timeout_ms: Duration,
+
+    /// Timeouts of single EVM RPC requests in milliseconds by method name, on top of the defaults
+    #[serde_as(as = "HashMap<_, DurationMilliSeconds<u64>>")]
+    #[serde(default)]
+    pub rpc_method_timeouts_ms: HashMap<String, Duration>,
+
+    /// The number of milliseconds without completed iterations after which the indexer is unhealthy
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_watchdog_threshold")]
+    pub watchdog_threshold_ms: Duration,
+
+    /// The maximum number of undelivered webhook payloads kept for retry before indexing is paused
+    #[serde(default = "default_max_pending_pushes")]
+    pub max_pending_pushes: usize,
+
+    /// The number of attempts to deliver a webhook payload before it counts as failed, independent of RPC retries
+    #[serde(default = "default_push_retry_attempts")]
+    pub push_retry_attempts: u32,
+
+    /// The pause in milliseconds after the first failed webhook delivery, doubled with every next one
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_push_retry_backoff")]
+    pub push_retry_backoff_ms: Duration,
+
+    /// The longest pause in milliseconds between webhook delivery attempts
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_push_retry_max_backoff")]
+    pub push_retry_max_backoff_ms: Duration,
+
+    /// Whether payloads that failed every delivery attempt are dead-lettered instead of kept for retry
+    #[serde(default)]
+    pub dead_letter_pushes: bool,
+
+    /// The number of latest blocks whose payloads are remembered to be withdrawn on a reorganization, 0 disables it
+    #[serde(default = "default_reorg_depth")]
+    pub reorg_depth: u64,
+
+    /// What to do with EVM logs, whose topic is not an event of the contract ABI
+    #[serde(default)]
+    pub unknown_event_policy: UnknownEventPolicy,
+
+    /// Whether EVM block requests of the fetched jobs are sent in JSON-RPC batches: auto, enabled or disabled
+    #[serde(default)]
+    pub rpc_batching: RpcBatching,
+
+    /// The number of logs failing to decode within the window that are skipped, one more halts the indexing
+    #[serde(default)]
+    pub max_decode_failures: usize,
+
+    /// The window in milliseconds that decode failures are counted in
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_decode_failure_window")]
+    pub decode_failure_window_ms: Duration,
+
+    /// The number of milliseconds to wait when the RPC node reports an EVM chain head behind the cursor
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_stale_head_wait")]
+    pub stale_head_wait_ms: Duration,
+
+    /// The number of milliseconds the last EVM chain head is used for while the RPC node fails to serve it
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(default = "default_chain_head_ttl")]
+    pub chain_head_ttl_ms: Duration,
+
+    /// Encoding of the webhook payloads: json or msgpack, the explorer accepts both
+    #[serde(default)]
+    pub push_format: PushFormat,
+
+    /// The number of networks of the process indexed at the same time
+    #[serde(default = "default_scheduler_concurrency")]
+    pub scheduler_concurrency: usize,
+
+    /// The number of iterations in a row the network runs before its turn is passed on
+    #[serde(default = "default_scheduler_weight")]
+    pub scheduler_weight: u32,
+}
+
+/// Handling of the events that the indexer doesn't know, i.e. ones added by a contract upgrade
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownEventPolicy {
+    /// Log the event and go on with the next one
+    #[default]
+    Skip,
+    /// Stop indexing until the indexer is updated
+    Fail,
+}
+
+/// Use of JSON-RPC batch requests, which not every EVM node supports
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcBatching {
+    /// Try a batch and stop batching if the node rejects it
+    #[default]
+    Auto,
+    /// Always batch, a failed batch only falls back for its own requests
+    Enabled,
+    Disabled,
+}
+
+impl IndexerConfiguration {
+    /// Default request timeouts of the client with the configured ones applied
+    pub fn rpc_timeouts(&self) -> RpcTimeouts {
+        let mut timeouts = RpcTimeouts::default();
+        timeouts.methods.extend(self.rpc_method_timeouts_ms.clone());
+
+        timeouts
+    }
+
+    /// Checks that the contract address has the format of the network
+    ///
+    /// Clients parse the address lazily or not at all, so a mismatched one would
+    /// otherwise surface only as failed RPC requests.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let address = self.contract_address.as_str();
+
+        let error = match ClientKind::from(&self.network) {
+            ClientKind::Evm => EthAddress::from_str(address)
+                .err()
+                .map(|e| format!("expected a 0x-prefixed hex address: {e}")),
+            ClientKind::Near if is_hex_address(address) => {
+                Some("expected a NEAR account ID, not a 0x-prefixed hex address".to_string())
+            }
+            ClientKind::Near => AccountId::from_str(address)
+                .err()
+                .map(|e| format!("expected a NEAR account ID: {e}")),
+            ClientKind::Solana => Pubkey::from_str(address)
+                .err()
+                .map(|e| format!("expected a base58 program ID: {e}")),
+        };
+
+        match error {
+            Some(error) => Err(ConfigError::Message(format!(
+                "Invalid contract address `{address}` for {} network, {error}",
+                self.network
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Configured page sizes, the ones of the env variables otherwise
+    pub(crate) fn page_sizes(&self) -> PageSizes {
+        let defaults = PageSizes::from_env();
+        let steady = self.page_size.unwrap_or(defaults.steady);
+
+        PageSizes {
+            steady,
+            // Without a catch-up size the page size is used all along
+            catch_up: self
+                .catch_up_page_size
+                .or_else(|| env::var("INDEXER_CATCH_UP_PAGE_SIZE").ok()?.parse().ok())
+                .unwrap_or(steady),
+            catch_up_threshold: self
+                .catch_up_threshold
+                .unwrap_or(defaults.catch_up_threshold),
+        }
+    }
+
+    /// Configured block time or a typical one for the network
+    pub fn block_time(&self) -> Duration {
+        self.block_time_ms.unwrap_or_else(|| {
+            Duration::from_millis(match self.network {
+                HapiCoreNetwork::Ethereum | HapiCoreNetwork::Sepolia => 12_000,
+                HapiCoreNetwork::Bsc => 3_000,
+                HapiCoreNetwork::Solana => 400,
+                HapiCoreNetwork::Near => 1_000,
+                HapiCoreNetwork::Bitcoin => 600_000,
+            })
+        })
+    }
+}
+
+// NEAR account IDs may consist of lowercase hex characters, so an EVM address is rejected explicitly
+fn is_hex_address(address: &str) -> bool {
+    address
+        .strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
 }
 
 fn default_is_json_logging() -> bool {
@@ -82,20 +301,144 @@ fn default_delay() -> Duration {
     Duration::from_millis(100)
 }
 
+fn default_rpc_timeout() -> Duration {
+    Duration::from_millis(60_000)
+}
+
+fn default_watchdog_threshold() -> Duration {
+    Duration::from_millis(300_000)
+}
+
+fn default_max_pending_pushes() -> usize {
+    1000
+}
+
+fn default_push_retry_attempts() -> u32 {
+    3
+}
+
+fn default_push_retry_backoff() -> Duration {
+    Duration::from_millis(500)
+}
+
+fn default_push_retry_max_backoff() -> Duration {
+    Duration::from_millis(10_000)
+}
+
+fn default_reorg_depth() -> u64 {
+    64
+}
+
+fn default_decode_failure_window() -> Duration {
+    Duration::from_millis(600_000)
+}
+
+fn default_stale_head_wait() -> Duration {
+    Duration::from_millis(5000)
+}
+
+fn default_chain_head_ttl() -> Duration {
+    Duration::from_millis(10_000)
+}
+
+fn default_scheduler_concurrency() -> usize {
+    1
+}
+
+fn default_scheduler_weight() -> u32 {
+    1
+}
+
 fn default_state_file() -> String {
     String::from("data/state.json")
 }
 
+/// Configuration file set with the `CONFIG_PATH` env variable
+pub fn config_path() -> String {
+    env::var("CONFIG_PATH").unwrap_or_else(|_| CONFIG_PATH.to_string())
+}
+
 pub fn get_configuration() -> Result<Configuration, ConfigError> {
-    let config_path = env::var("CONFIG_PATH").unwrap_or_else(|_| CONFIG_PATH.to_string());
+    load_configuration(&config_path())
+}
 
+pub fn load_configuration(config_path: &str) -> Result<Configuration, ConfigError> {
     let settings = Config::builder()
         .add_source(
-            File::with_name(&config_path)
+            File::with_name(config_path)
                 .format(FileFormat::Toml)
                 .required(true),
         )
         .build()?;
 
-    settings.try_deserialize::<Configuration>()
+    parse_configuration(settings)
+}
+
+fn parse_configuration(settings: Config) -> Result<Configuration, ConfigError> {
+    let configuration = settings.try_deserialize::<Configuration>()?;
+    configuration.indexer.validate()?;
+
+    Ok(configuration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(network: &str, contract_address: &str) -> Result<Configuration, ConfigError> {
+        let source = format!(
+            r#"
+            [indexer]
+            network = "{network}"
+            rpc_node_url = "http://localhost:3030"
+            webhook_url = "http://localhost:3000"
+            contract_address = "{contract_address}"
+            jwt_token = "token"
+            "#
+        );
+
+        let settings = Config::builder()
+            .add_source(File::from_str(&source, FileFormat::Toml))
+            .build()?;
+
+        parse_configuration(settings)
+    }
+
+    #[test]
+    fn near_configuration_is_accepted() {
+        let configuration = parse("near", "hapi-core.testnet").expect("Invalid configuration");
+
+        assert_eq!(configuration.indexer.network, HapiCoreNetwork::Near);
+        assert_eq!(configuration.indexer.contract_address, "hapi-core.testnet");
+    }
+
+    #[test]
+    fn scheduler_runs_a_single_network_by_default() {
+        let configuration = parse("ethereum", "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0")
+            .expect("Invalid configuration");
+
+        assert_eq!(configuration.indexer.scheduler_concurrency, 1);
+        assert_eq!(configuration.indexer.scheduler_weight, 1);
+    }
+
+    #[test]
+    fn evm_shaped_near_configuration_is_rejected() {
+        for address in [
+            "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0",
+            // Lowercase hex passes the account ID syntax check
+            "0x9fe46736679d2d9a65f0992f2272de9f3c7fa6e0",
+        ] {
+            let error = parse("near", address).err().expect("Address is accepted");
+            assert!(error.to_string().contains("NEAR account ID"), "{error}");
+        }
+    }
+
+    #[test]
+    fn contract_address_matches_network() {
+        assert!(parse("ethereum", "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0").is_ok());
+        assert!(parse("ethereum", "hapi-core.testnet").is_err());
+
+        assert!(parse("solana", "FgE5ySSi6fbnfYGGRyaeW8y6p8A5KybXPyQ2DdxPCNRk").is_ok());
+        assert!(parse("solana", "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0").is_err());
+    }
 }
@@ -0,0 +1,83 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// Counts decode failures within a sliding window and trips once they exceed the limit
+///
+/// A broken contract upgrade makes every following log fail the same way, so a burst of
+/// failures halts the indexing instead of skipping events one after another.
+#[derive(Debug)]
+pub(crate) struct DecodeBreaker {
+    /// The number of failures within the window that are still skipped
+    max_failures: usize,
+    window: Duration,
+    failures: VecDeque<Instant>,
+}
+
+impl DecodeBreaker {
+    pub fn new(max_failures: usize, window: Duration) -> Self {
+        Self {
+            max_failures,
+            window,
+            failures: VecDeque::new(),
+        }
+    }
+
+    /// Records a failure, returns true if the breaker trips
+    pub fn record_failure(&mut self, at: Instant) -> bool {
+        while self
+            .failures
+            .front()
+            .is_some_and(|failure| at.duration_since(*failure) > self.window)
+        {
+            self.failures.pop_front();
+        }
+
+        self.failures.push_back(at);
+
+        self.failures.len() > self.max_failures
+    }
+
+    /// The number of failures within the window, as of the latest one
+    pub fn failures(&self) -> usize {
+        self.failures.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn burst_of_failures_trips_the_breaker() {
+        let mut breaker = DecodeBreaker::new(3, Duration::from_secs(60));
+        let start = Instant::now();
+
+        for i in 0..3 {
+            assert!(!breaker.record_failure(start + Duration::from_secs(i)));
+        }
+
+        assert!(breaker.record_failure(start + Duration::from_secs(3)));
+        assert_eq!(breaker.failures(), 4);
+    }
+
+    #[test]
+    fn failures_outside_the_window_are_forgotten() {
+        let mut breaker = DecodeBreaker::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+
+        for i in 0..10 {
+            assert!(!breaker.record_failure(start + Duration::from_secs(i * 6)));
+        }
+
+        assert_eq!(breaker.failures(), 2);
+    }
+
+    #[test]
+    fn zero_limit_trips_on_the_first_failure() {
+        let mut breaker = DecodeBreaker::new(0, Duration::from_secs(10));
+
+        assert!(breaker.record_failure(Instant::now()));
+    }
+}
@@ -1,50 +1,184 @@
 use {
     anyhow::{bail, Result},
-    ethers::{abi::Token, providers::Middleware, types::Filter},
-    hapi_core::{client::events::EventName, HapiCore, HapiCoreEvm},
-    std::{cmp::min, str::FromStr},
+    ethers::{
+        abi::Token,
+        providers::{Middleware, ProviderError, RpcError},
+        types::{Address as EthAddress, Log, U256},
+    },
+    hapi_core::{
+        client::{events::EventName, evm::LogHeader},
+        HapiCore, HapiCoreEvm,
+    },
+    std::{
+        collections::HashSet,
+        fmt::Display,
+        str::FromStr,
+        sync::Mutex,
+        time::{Duration, Instant},
+    },
     uuid::Uuid,
 };
 
 use crate::{
+    configuration::UnknownEventPolicy,
     indexer::{
-        client::indexer_client::PAGE_SIZE,
-        push::{NetworkData, PushData, PushEvent, PushPayload},
-        IndexerJob,
+        client::indexer_client::PageSizes,
+        jobs::log_event_key,
+        push::{NetworkData, PushData, PushEvent, PushPayload, PUSH_SCHEMA_VERSION},
+        IndexerError, IndexerJob,
     },
     IndexingCursor,
 };
 
-use super::indexer_client::FetchingArtifacts;
+use super::{
+    indexer_client::{FetchingArtifacts, HeadPosition},
+    log_source::LogSource,
+};
 
 async fn get_event_list(
     client: &HapiCoreEvm,
+    log_source: &dyn LogSource,
     from_block: u64,
-    latest_block: u64,
+    to_block: u64,
+    unknown_event_policy: UnknownEventPolicy,
 ) -> Result<Vec<IndexerJob>> {
-    let mut event_list = vec![];
-    let filter = Filter::default().address(client.contract.address());
+    let logs = log_source
+        .get_logs(client.contract.address(), from_block, to_block)
+        .await?;
 
-    // Substracting 1 from page size because the result will include filter limits
-    let to_block = min(PAGE_SIZE.to_owned() - 1 + from_block, latest_block);
+    let logs = filter_unknown_events(client, logs, unknown_event_policy)?;
 
-    let logs = client
-        .contract
-        .client()
-        .get_logs(&filter.clone().from_block(from_block).to_block(to_block))
-        .await?;
+    Ok(logs_to_jobs(logs))
+}
+
+/// Drops the logs that are not events of the contract ABI, or fails on them
+fn filter_unknown_events(
+    client: &HapiCoreEvm,
+    logs: Vec<Log>,
+    policy: UnknownEventPolicy,
+) -> Result<Vec<Log>> {
+    let mut known = Vec::with_capacity(logs.len());
+
+    for log in logs {
+        if is_known_event(client, &log) {
+            known.push(log);
+        } else {
+            handle_unknown_event(&log, policy)?;
+        }
+    }
+
+    Ok(known)
+}
 
-    logs.into_iter().for_each(|log| {
-        event_list.push(IndexerJob::Log(log));
+fn is_known_event(client: &HapiCoreEvm, log: &Log) -> bool {
+    log.topics.first().is_some_and(|topic| {
+        client
+            .contract
+            .abi()
+            .events()
+            .any(|event| event.signature() == *topic)
+    })
+}
+
+/// Logs the unknown event topic and fails if the policy says so
+fn handle_unknown_event(log: &Log, policy: UnknownEventPolicy) -> Result<()> {
+    let topic = log
+        .topics
+        .first()
+        .map_or("none".to_string(), |topic| format!("{topic:?}"));
+    let tx_hash = log.transaction_hash.map(|hash| format!("{hash:?}"));
+
+    match policy {
+        UnknownEventPolicy::Skip => {
+            tracing::warn!(topic, tx_hash, "Skipping log of an unknown event");
+            Ok(())
+        }
+        UnknownEventPolicy::Fail => {
+            tracing::error!(topic, tx_hash, "Log of an unknown event");
+            Err(decode_error(log, "unknown event topic").into())
+        }
+    }
+}
+
+/// Orders logs by their position on the chain, as nodes are not required to return them sorted
+///
+/// A log returned twice, i.e. by overlapping responses, is kept once. The explorer ignores
+/// repeated events anyway, this only saves the processing.
+fn logs_to_jobs(mut logs: Vec<Log>) -> Vec<IndexerJob> {
+    logs.sort_by_key(|log| (log.block_number, log.log_index));
+
+    let count = logs.len();
+    let mut seen = HashSet::new();
+    logs.retain(|log| match log_event_key(log) {
+        Some(key) => seen.insert(key),
+        // Pending logs have no hash, there is nothing to compare them by
+        None => true,
     });
 
-    Ok(event_list)
+    if logs.len() < count {
+        tracing::warn!(duplicates = count - logs.len(), "Dropped duplicate logs");
+    }
+
+    logs.into_iter().map(IndexerJob::Log).collect()
+}
+
+/// The latest block of the chain, as last served by the RPC node
+///
+/// The chain head is fetched on its own at the start of every iteration. If the request
+/// fails, a head fetched within the TTL stands in for it, so that a transient failure
+/// doesn't abort an iteration that can proceed up to the known head. The next iteration
+/// requests the head again.
+#[derive(Debug, Default)]
+pub(crate) struct ChainHead {
+    ttl: Duration,
+    latest: Mutex<Option<(u64, Instant)>>,
 }
 
-#[tracing::instrument(skip(client))]
+impl ChainHead {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            latest: Mutex::new(None),
+        }
+    }
+
+    /// Requests the chain head, falls back to the cached one if it is fresh enough
+    pub(super) async fn fetch(&self, client: &HapiCoreEvm) -> Result<u64> {
+        match client.provider.get_block_number().await {
+            Ok(block) => {
+                let block = block.as_u64();
+                *self.latest.lock().expect("Chain head lock is poisoned") =
+                    Some((block, Instant::now()));
+
+                Ok(block)
+            }
+            Err(error) => match self.cached() {
+                Some(block) => {
+                    tracing::warn!(%error, block, "Failed to get chain head, using the cached one");
+                    Ok(block)
+                }
+                None => Err(rpc_error(error)),
+            },
+        }
+    }
+
+    fn cached(&self) -> Option<u64> {
+        let latest = *self.latest.lock().expect("Chain head lock is poisoned");
+
+        latest
+            .filter(|(_, fetched_at)| fetched_at.elapsed() < self.ttl)
+            .map(|(block, _)| block)
+    }
+}
+
+#[tracing::instrument(skip(client, chain_head))]
 pub(super) async fn fetch_evm_jobs(
     client: &HapiCoreEvm,
+    log_source: &dyn LogSource,
+    chain_head: &ChainHead,
     current_cursor: &IndexingCursor,
+    page_sizes: PageSizes,
+    unknown_event_policy: UnknownEventPolicy,
 ) -> Result<FetchingArtifacts> {
     let current_block = match current_cursor {
         IndexingCursor::None => 0,
@@ -52,17 +186,50 @@ pub(super) async fn fetch_evm_jobs(
         _ => bail!("Evm network must have a block cursor"),
     };
 
-    let latest_block = client.provider.get_block_number().await?.as_u64();
+    let latest_block = chain_head.fetch(client).await?;
+
+    // A lagging node or a failover to one may serve a head below the indexed blocks,
+    // the cursor stays until the node catches up
+    if latest_block + 1 < current_block {
+        tracing::warn!(
+            latest_block,
+            cursor_block = current_block - 1,
+            "Chain head is behind the cursor"
+        );
+
+        return Ok(FetchingArtifacts {
+            jobs: vec![],
+            cursor: current_cursor.clone(),
+            head: HeadPosition::Stale,
+            chain_head: Some(IndexingCursor::Block(latest_block)),
+        });
+    }
 
     if current_block < latest_block {
         tracing::info!(current_block, "Fetching evm jobs from");
 
-        let event_list = get_event_list(client, current_block, latest_block).await?;
-        tracing::info!(count = event_list.len(), "Found jobs");
+        let to_block = page_sizes.page_end(current_block, latest_block);
 
+        let event_list = get_event_list(
+            client,
+            log_source,
+            current_block,
+            to_block,
+            unknown_event_policy,
+        )
+        .await?;
+        tracing::info!(count = event_list.len(), to_block, "Found jobs");
+
+        // The rest of the blocks is fetched in the next iterations
         return Ok(FetchingArtifacts {
             jobs: event_list,
-            cursor: IndexingCursor::Block(latest_block),
+            cursor: IndexingCursor::Block(to_block),
+            head: if to_block < latest_block {
+                HeadPosition::Behind
+            } else {
+                HeadPosition::Reached
+            },
+            chain_head: Some(IndexingCursor::Block(latest_block)),
         });
     }
 
@@ -71,20 +238,61 @@ pub(super) async fn fetch_evm_jobs(
     Ok(FetchingArtifacts {
         jobs: vec![],
         cursor: current_cursor.clone(),
+        head: HeadPosition::Reached,
+        chain_head: Some(IndexingCursor::Block(latest_block)),
     })
 }
 
-#[tracing::instrument(skip(client, network_data),
+/// Jobs of the closed block range, fetched page by page
+#[tracing::instrument(skip(client, log_source))]
+pub(super) async fn fetch_evm_range_jobs(
+    client: &HapiCoreEvm,
+    log_source: &dyn LogSource,
+    from_block: u64,
+    to_block: u64,
+    page_sizes: PageSizes,
+    unknown_event_policy: UnknownEventPolicy,
+) -> Result<Vec<IndexerJob>> {
+    let mut jobs = vec![];
+    let mut page_start = from_block;
+
+    while page_start <= to_block {
+        let page_end = page_sizes.page_end(page_start, to_block);
+
+        jobs.extend(
+            get_event_list(
+                client,
+                log_source,
+                page_start,
+                page_end,
+                unknown_event_policy,
+            )
+            .await?,
+        );
+        page_start = page_end + 1;
+    }
+
+    tracing::info!(count = jobs.len(), "Found jobs in range");
+
+    Ok(jobs)
+}
+
+#[tracing::instrument(skip(client, block_timestamp, network_data),
     fields(hash = log.transaction_hash.map_or("None".to_string(), |s| s.to_string())))]
 pub(super) async fn process_evm_job(
     client: &HapiCoreEvm,
-    log: &ethers::types::Log,
+    log: &Log,
+    block_timestamp: Option<u64>,
     network_data: NetworkData,
+    unknown_event_policy: UnknownEventPolicy,
 ) -> Result<Option<Vec<PushPayload>>> {
-    let log_header = if let Some(header) = client.decode_event(log)? {
-        header
-    } else {
-        return Ok(None);
+    let log_header = match client.decode_event(log) {
+        Ok(Some(header)) => header,
+        Ok(None) => {
+            handle_unknown_event(log, unknown_event_policy)?;
+            return Ok(None);
+        }
+        Err(e) => return Err(decode_error(log, e).into()),
     };
 
     let tx_hash = format!(
@@ -98,11 +306,17 @@ pub(super) async fn process_evm_job(
         .ok_or_else(|| anyhow::anyhow!("Unable to parse block number"))?
         .as_u64();
 
-    let block = client
-        .provider
-        .get_block(block_number)
-        .await?
-        .ok_or_else(|| anyhow::anyhow!("Unable to get block"))?;
+    let timestamp = match block_timestamp {
+        Some(timestamp) => timestamp,
+        None => client
+            .provider
+            .get_block(block_number)
+            .await
+            .map_err(rpc_error)?
+            .ok_or_else(|| anyhow::anyhow!("Unable to get block"))?
+            .timestamp
+            .as_u64(),
+    };
 
     tracing::info!(
         name = log_header.name,
@@ -112,42 +326,43 @@ pub(super) async fn process_evm_job(
         "Processing event",
     );
 
-    let data = match log_header.to_ref() {
-        (
-            "ReporterCreated"
-            | "ReporterUpdated"
-            | "ReporterActivated"
-            | "ReporterDeactivated"
-            | "ReporterStakeWithdrawn",
-            [reporter_id, ..],
-        ) => get_evm_reporter_payload(client, reporter_id).await?,
-        ("CaseCreated" | "CaseUpdated", [case_id, ..]) => {
-            get_evm_case_payload(client, case_id).await?
-        }
-        ("AddressCreated" | "AddressUpdated", [addr, ..]) => {
-            get_evm_address_payload(client, addr).await?
-        }
-        ("AssetCreated" | "AssetUpdated", [addr, id, ..]) => {
-            get_evm_asset_payload(client, addr, id).await?
-        }
-        ("AuthorityChanged" | "StakeConfigurationChanged" | "RewardConfigurationChanged", [..]) => {
-            tracing::info!("Configuration is changed");
-            None
+    let target = decode_event_target(&log_header).map_err(|e| decode_error(log, e))?;
+
+    let data = match target {
+        Some(EvmEventTarget::Reporter(reporter_id)) => {
+            tracing::info!(?reporter_id, "Reporter is created or modified");
+            Some(client.get_reporter(&reporter_id.to_string()).await?.into())
         }
-        _ => {
-            tracing::warn!(name = log_header.name, tokens = ?log_header.tokens, "Ignoring event");
-            None
+        Some(EvmEventTarget::Case(case_id)) => {
+            tracing::info!(?case_id, "Case is created or modified");
+            Some(client.get_case(&case_id.to_string()).await?.into())
+        }
+        Some(EvmEventTarget::Address(addr)) => {
+            tracing::info!(?addr, "Address is created or modified");
+            Some(client.get_address(&format!("{addr:?}")).await?.into())
         }
+        Some(EvmEventTarget::Asset(addr, id)) => {
+            tracing::info!(?addr, ?id, "Asset is created or modified");
+            Some(
+                client
+                    .get_asset(&format!("{addr:?}"), &id.into())
+                    .await?
+                    .into(),
+            )
+        }
+        None => None,
     };
 
     if let Some(data) = data {
         Ok(Some(vec![PushPayload {
+            schema_version: PUSH_SCHEMA_VERSION,
             network_data,
             event: PushEvent {
                 name: EventName::from_str(&log_header.name)?,
                 tx_hash,
                 tx_index: 0,
-                timestamp: block.timestamp.as_u64(),
+                timestamp,
+                removed: log.removed.unwrap_or(false),
             },
             data,
         }]))
@@ -156,63 +371,806 @@ pub(super) async fn process_evm_job(
     }
 }
 
-async fn get_evm_reporter_payload(
+/// Whether the block with the given hash is still on the canonical chain
+pub(super) async fn is_canonical_evm_block(
     client: &HapiCoreEvm,
-    reporter_id: &Token,
-) -> Result<Option<PushData>> {
-    if let Some(reporter_id) = reporter_id.clone().into_uint() {
-        let reporter_id = Uuid::from_u128(reporter_id.as_u128());
-        tracing::info!(?reporter_id, "Reporter is created or modified");
+    number: u64,
+    hash: &str,
+) -> Result<bool> {
+    let block = client.provider.get_block(number).await.map_err(rpc_error)?;
+
+    Ok(block
+        .and_then(|block| block.hash)
+        .is_some_and(|canonical| format!("{canonical:?}") == hash))
+}
+
+/// Keys of the contract events that are on the canonical chain within the block range
+pub(super) async fn fetch_evm_event_keys(
+    client: &HapiCoreEvm,
+    log_source: &dyn LogSource,
+    from_block: u64,
+    to_block: u64,
+) -> Result<HashSet<String>> {
+    let logs = log_source
+        .get_logs(client.contract.address(), from_block, to_block)
+        .await?;
+
+    Ok(logs.iter().filter_map(log_event_key).collect())
+}
+
+/// Fetches the current state of the payload entity
+pub(super) async fn refresh_evm_data(client: &HapiCoreEvm, data: &PushData) -> Result<PushData> {
+    let data = match data {
+        PushData::Address(address) => client.get_address(&address.address).await?.into(),
+        PushData::Asset(asset) => client
+            .get_asset(&asset.address, &asset.asset_id)
+            .await?
+            .into(),
+        PushData::Case(case) => client.get_case(&case.id.to_string()).await?.into(),
+        PushData::Reporter(reporter) => client.get_reporter(&reporter.id.to_string()).await?.into(),
+    };
+
+    Ok(data)
+}
 
-        let reporter = client.get_reporter(&reporter_id.to_string()).await?;
+/// JSON-RPC error codes that nodes use for rate limiting and temporary outages
+const RETRIABLE_RPC_CODES: [i64; 2] = [-32005, -32603];
 
-        Ok(Some(reporter.into()))
+/// Marks temporary failures of the RPC node as retriable
+pub(super) fn rpc_error(error: ProviderError) -> anyhow::Error {
+    if is_retriable_rpc_error(&error) {
+        IndexerError::RpcTransport(error.to_string()).into()
     } else {
-        tracing::warn!(?reporter_id, "Unable to parse reporter id");
-        Ok(None)
+        error.into()
     }
 }
 
-async fn get_evm_case_payload(client: &HapiCoreEvm, case_id: &Token) -> Result<Option<PushData>> {
-    if let Some(case_id) = case_id.clone().into_uint() {
-        let case_id = Uuid::from_u128(case_id.as_u128());
-        tracing::info!(?case_id, "Case is created or modified");
+pub(super) fn is_retriable_rpc_error(error: &ProviderError) -> bool {
+    match error {
+        ProviderError::HTTPError(_) => true,
+        // Without an error response the transport has failed: the request timed out or
+        // the body is not a JSON-RPC response, like the plain text pages of load balancers
+        ProviderError::JsonRpcClientError(error) => RpcError::as_error_response(error.as_ref())
+            .map_or(true, |response| {
+                RETRIABLE_RPC_CODES.contains(&response.code)
+            }),
+        _ => false,
+    }
+}
 
-        let case = client.get_case(&case_id.to_string()).await?;
+/// Attaches the log location and its raw content to a decoding failure
+fn decode_error(log: &Log, reason: impl Display) -> IndexerError {
+    fn or_unknown<T: std::fmt::Debug>(value: Option<T>) -> String {
+        value.map_or("unknown".to_string(), |v| format!("{v:?}"))
+    }
 
-        Ok(Some(case.into()))
-    } else {
-        tracing::warn!(?case_id, "Unable to parse case id");
-        Ok(None)
+    IndexerError::Decode {
+        tx_hash: or_unknown(log.transaction_hash),
+        block_number: or_unknown(log.block_number),
+        log_index: or_unknown(log.log_index),
+        topic: or_unknown(log.topics.first()),
+        data: log.data.to_string(),
+        reason: reason.to_string(),
     }
 }
 
-async fn get_evm_address_payload(client: &HapiCoreEvm, addr: &Token) -> Result<Option<PushData>> {
-    if let Some(addr) = addr.clone().into_address() {
-        tracing::info!(?addr, "Address is created or modified");
+/// Entity that has to be fetched from the contract after an event
+#[derive(Debug, PartialEq)]
+enum EvmEventTarget {
+    Reporter(Uuid),
+    Case(Uuid),
+    Address(EthAddress),
+    Asset(EthAddress, U256),
+}
 
-        let address = client.get_address(&format!("{addr:?}")).await?;
+/// Extracts the changed entity from a decoded event
+///
+/// Tokens come in the order of the event declaration, regardless of whether a field
+/// was indexed (topic) or not (data). Each arm mirrors the full parameter list of the
+/// corresponding `HapiCore.sol` event, so that a layout change fails loudly
+/// instead of picking a wrong field.
+fn decode_event_target(log_header: &LogHeader) -> Result<Option<EvmEventTarget>> {
+    let target = match log_header.to_ref() {
+        (
+            "ReporterCreated" | "ReporterUpdated",
+            [Token::Uint(id), Token::Address(_), Token::Uint(_)],
+        )
+        | (
+            "ReporterActivated" | "ReporterDeactivated" | "ReporterStakeWithdrawn",
+            [Token::Uint(id)],
+        ) => Some(EvmEventTarget::Reporter(uuid_from_token(id)?)),
+        ("CaseCreated" | "CaseUpdated", [Token::Uint(id)]) => {
+            Some(EvmEventTarget::Case(uuid_from_token(id)?))
+        }
+        (
+            "AddressCreated" | "AddressUpdated",
+            [Token::Address(addr), Token::Uint(_), Token::Uint(_)],
+        )
+        | ("AddressConfirmed", [Token::Address(addr)]) => Some(EvmEventTarget::Address(*addr)),
+        (
+            "AssetCreated" | "AssetUpdated",
+            [Token::Address(addr), Token::Uint(id), Token::Uint(_), Token::Uint(_)],
+        )
+        | ("AssetConfirmed", [Token::Address(addr), Token::Uint(id)]) => {
+            Some(EvmEventTarget::Asset(*addr, *id))
+        }
+        (
+            "Initialized"
+            | "AuthorityChanged"
+            | "StakeConfigurationChanged"
+            | "RewardConfigurationChanged",
+            _,
+        ) => {
+            tracing::info!("Configuration is changed");
+            None
+        }
+        // A known event, which doesn't match its declaration
+        (name, tokens) if EventName::from_str(name).is_ok() => {
+            bail!("Unexpected tokens in `{name}` event: {tokens:?}")
+        }
+        _ => {
+            tracing::warn!(name = log_header.name, tokens = ?log_header.tokens, "Ignoring event");
+            None
+        }
+    };
 
-        Ok(Some(address.into()))
-    } else {
-        tracing::warn!(?addr, "Unable to parse address");
-        Ok(None)
+    Ok(target)
+}
+
+// Reporter and case ids are `uint128` in the contract
+fn uuid_from_token(id: &U256) -> Result<Uuid> {
+    if id.bits() > 128 {
+        bail!("Id {id} does not fit into uint128");
     }
+
+    Ok(Uuid::from_u128(id.as_u128()))
 }
 
-async fn get_evm_asset_payload(
-    client: &HapiCoreEvm,
-    addr: &Token,
-    id: &Token,
-) -> Result<Option<PushData>> {
-    if let (Some(addr), Some(id)) = (addr.clone().into_address(), id.clone().into_uint()) {
-        tracing::info!(?addr, ?id, "Asset is created or modified");
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::indexer::client::indexer_client::PAGE_SIZE,
+        ethers::types::{H256, U64},
+        hapi_core::{HapiCoreNetwork, HapiCoreOptions, RpcTimeouts},
+    };
 
-        let asset = client.get_asset(&format!("{addr:?}"), &id.into()).await?;
+    const CONTRACT_ADDRESS: &str = "0x2947F98C42597966a0ec25e92843c09ac18Fbab7";
 
-        Ok(Some(asset.into()))
-    } else {
-        tracing::warn!(?addr, ?id, "Unable to parse asset");
-        Ok(None)
+    fn create_client() -> HapiCoreEvm {
+        create_client_at("http://127.0.0.1:8545")
+    }
+
+    fn create_client_at(provider_url: &str) -> HapiCoreEvm {
+        HapiCoreEvm::new(HapiCoreOptions {
+            provider_url: provider_url.to_string(),
+            fallback_provider_urls: vec![],
+            contract_address: CONTRACT_ADDRESS.to_string(),
+            private_key: None,
+            chain_id: None,
+            account_id: None,
+            network: HapiCoreNetwork::Ethereum,
+            rpc_timeouts: RpcTimeouts::default(),
+        })
+        .expect("Failed to create client")
+    }
+
+    fn id_topic(id: Uuid) -> H256 {
+        let mut bytes = [0u8; 32];
+        bytes[16..].copy_from_slice(&id.as_u128().to_be_bytes());
+
+        H256::from(bytes)
+    }
+
+    // Builds a log the way the EVM does: signature and indexed fields go to topics,
+    // the rest is ABI-encoded into data
+    fn decode(
+        client: &HapiCoreEvm,
+        name: &str,
+        indexed: Vec<H256>,
+        data: Vec<Token>,
+    ) -> Option<EvmEventTarget> {
+        let signature = client
+            .contract
+            .abi()
+            .event(name)
+            .expect("Unknown event")
+            .signature();
+
+        let log = Log {
+            address: client.contract.address(),
+            topics: [vec![signature], indexed].concat(),
+            data: ethers::abi::encode(&data).into(),
+            ..Default::default()
+        };
+
+        let log_header = client
+            .decode_event(&log)
+            .expect("Failed to decode event")
+            .expect("Event is not in the ABI");
+
+        assert_eq!(log_header.name, name);
+
+        decode_event_target(&log_header).expect("Failed to decode event target")
+    }
+
+    #[test]
+    fn jobs_are_ordered_by_log_position() {
+        let positions = [(12, 0), (10, 3), (12, 1), (10, 0), (11, 7)];
+
+        let logs = positions
+            .iter()
+            .map(|&(block, index): &(u64, u64)| Log {
+                block_number: Some(block.into()),
+                log_index: Some(index.into()),
+                ..Default::default()
+            })
+            .collect();
+
+        let ordered: Vec<(u64, u64)> = logs_to_jobs(logs)
+            .into_iter()
+            .map(|job| match job {
+                IndexerJob::Log(log) => (
+                    log.block_number.unwrap().as_u64(),
+                    log.log_index.unwrap().as_u64(),
+                ),
+                _ => panic!("Unexpected job: {job:?}"),
+            })
+            .collect();
+
+        assert_eq!(ordered, vec![(10, 0), (10, 3), (11, 7), (12, 0), (12, 1)]);
+    }
+
+    /// Logs served from memory, in place of an RPC node or a log API
+    #[derive(Default)]
+    struct MockLogSource {
+        logs: Vec<Log>,
+        requests: std::sync::Mutex<Vec<(u64, u64)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl LogSource for MockLogSource {
+        async fn get_logs(
+            &self,
+            contract: EthAddress,
+            from_block: u64,
+            to_block: u64,
+        ) -> Result<Vec<Log>> {
+            self.requests.lock().unwrap().push((from_block, to_block));
+
+            Ok(self
+                .logs
+                .iter()
+                .filter(|log| log.address == contract)
+                .filter(|log| {
+                    log.block_number
+                        .is_some_and(|block| (from_block..=to_block).contains(&block.as_u64()))
+                })
+                .rev()
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn range_jobs_come_from_log_source() {
+        let client = create_client();
+        let signature = client
+            .contract
+            .abi()
+            .event("CaseCreated")
+            .expect("Unknown event")
+            .signature();
+
+        let log = |address, topic, block: u64| Log {
+            address,
+            topics: vec![topic, id_topic(Uuid::new_v4())],
+            block_number: Some(block.into()),
+            ..Default::default()
+        };
+
+        let source = MockLogSource {
+            logs: vec![
+                log(client.contract.address(), signature, 5),
+                log(client.contract.address(), signature, 2 * *PAGE_SIZE),
+                log(client.contract.address(), H256::random(), 10),
+                log(EthAddress::random(), signature, 20),
+                log(client.contract.address(), signature, 3 * *PAGE_SIZE),
+            ],
+            ..Default::default()
+        };
+
+        let jobs = fetch_evm_range_jobs(
+            &client,
+            &source,
+            1,
+            2 * *PAGE_SIZE,
+            PageSizes::from_env(),
+            UnknownEventPolicy::Skip,
+        )
+        .await
+        .expect("Failed to fetch range");
+
+        // Unknown events, other contracts and blocks out of the range are left out
+        let blocks: Vec<u64> = jobs.iter().filter_map(IndexerJob::height).collect();
+        assert_eq!(blocks, vec![5, 2 * *PAGE_SIZE]);
+
+        assert_eq!(
+            *source.requests.lock().unwrap(),
+            vec![(1, *PAGE_SIZE), (*PAGE_SIZE + 1, 2 * *PAGE_SIZE),]
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_logs_are_fetched_once() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":"0xa"}"#)
+            .create_async()
+            .await;
+
+        let client = create_client_at(&server.url());
+        let signature = client
+            .contract
+            .abi()
+            .event("CaseCreated")
+            .expect("Unknown event")
+            .signature();
+
+        let log = |log_index: u64| Log {
+            address: client.contract.address(),
+            topics: vec![signature, id_topic(Uuid::new_v4())],
+            block_number: Some(5.into()),
+            transaction_hash: Some(H256::repeat_byte(1)),
+            log_index: Some(log_index.into()),
+            ..Default::default()
+        };
+
+        let duplicate = log(0);
+        let source = MockLogSource {
+            logs: vec![duplicate.clone(), log(1), duplicate],
+            ..Default::default()
+        };
+
+        let artifacts = fetch_evm_jobs(
+            &client,
+            &source,
+            &ChainHead::default(),
+            &IndexingCursor::Block(0),
+            PageSizes::from_env(),
+            UnknownEventPolicy::Skip,
+        )
+        .await
+        .expect("Failed to fetch jobs");
+
+        let indexes: Vec<u64> = artifacts
+            .jobs
+            .iter()
+            .map(|job| match job {
+                IndexerJob::Log(log) => log.log_index.unwrap().as_u64(),
+                _ => panic!("Unexpected job: {job:?}"),
+            })
+            .collect();
+
+        assert_eq!(indexes, vec![0, 1]);
+        assert_eq!(artifacts.cursor, IndexingCursor::Block(10));
+        assert_eq!(artifacts.head, HeadPosition::Reached);
+    }
+
+    #[tokio::test]
+    async fn page_behind_head_is_reported() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":"0x64"}"#)
+            .create_async()
+            .await;
+
+        let client = create_client_at(&server.url());
+        let source = MockLogSource::default();
+        let page_sizes = PageSizes {
+            steady: 10,
+            catch_up: 10,
+            catch_up_threshold: 1000,
+        };
+
+        let artifacts = fetch_evm_jobs(
+            &client,
+            &source,
+            &ChainHead::default(),
+            &IndexingCursor::Block(0),
+            page_sizes,
+            UnknownEventPolicy::Skip,
+        )
+        .await
+        .expect("Failed to fetch jobs");
+
+        // An empty page far from the head is not a sign of having caught up
+        assert!(artifacts.jobs.is_empty());
+        assert_eq!(artifacts.cursor, IndexingCursor::Block(10));
+        assert_eq!(artifacts.head, HeadPosition::Behind);
+    }
+
+    #[tokio::test]
+    async fn stale_chain_head_is_reported() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":"0x5"}"#)
+            .create_async()
+            .await;
+
+        let client = create_client_at(&server.url());
+        let source = MockLogSource::default();
+
+        let artifacts = fetch_evm_jobs(
+            &client,
+            &source,
+            &ChainHead::default(),
+            &IndexingCursor::Block(10),
+            PageSizes::from_env(),
+            UnknownEventPolicy::Skip,
+        )
+        .await
+        .expect("Stale head must not fail the fetching");
+
+        assert_eq!(artifacts.head, HeadPosition::Stale);
+        assert!(artifacts.jobs.is_empty());
+        assert_eq!(artifacts.cursor, IndexingCursor::Block(10));
+        assert!(source.requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn cached_chain_head_covers_failed_request() {
+        let mut server = mockito::Server::new_async().await;
+        let head = server
+            .mock("POST", "/")
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":"0xa"}"#)
+            .create_async()
+            .await;
+
+        let client = create_client_at(&server.url());
+        let source = MockLogSource::default();
+        let chain_head = ChainHead::new(Duration::from_secs(10));
+        fetch_evm_jobs(
+            &client,
+            &source,
+            &chain_head,
+            &IndexingCursor::Block(4),
+            PageSizes::from_env(),
+            UnknownEventPolicy::Skip,
+        )
+        .await
+        .expect("Failed to fetch jobs");
+
+        // eth_blockNumber fails once, the range up to the cached head is fetched anyway
+        head.remove_async().await;
+        let failure = server
+            .mock("POST", "/")
+            .with_status(503)
+            .with_body("503 error")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "method": "eth_blockNumber" }),
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let artifacts = fetch_evm_jobs(
+            &client,
+            &source,
+            &chain_head,
+            &IndexingCursor::Block(6),
+            PageSizes::from_env(),
+            UnknownEventPolicy::Skip,
+        )
+        .await
+        .expect("Cached head must let the fetching proceed");
+
+        failure.assert_async().await;
+        assert_eq!(artifacts.cursor, IndexingCursor::Block(10));
+        assert_eq!(*source.requests.lock().unwrap(), vec![(5, 10), (7, 10)]);
+
+        // without a cached head the failure aborts the iteration as before
+        let result = fetch_evm_jobs(
+            &client,
+            &source,
+            &ChainHead::default(),
+            &IndexingCursor::Block(6),
+            PageSizes::from_env(),
+            UnknownEventPolicy::Skip,
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decode_reporter_events() {
+        let client = create_client();
+        let id = Uuid::new_v4();
+        let account = EthAddress::random();
+
+        for name in ["ReporterCreated", "ReporterUpdated"] {
+            assert_eq!(
+                decode(
+                    &client,
+                    name,
+                    vec![id_topic(id)],
+                    vec![Token::Address(account), Token::Uint(2.into())]
+                ),
+                Some(EvmEventTarget::Reporter(id))
+            );
+        }
+
+        for name in [
+            "ReporterActivated",
+            "ReporterDeactivated",
+            "ReporterStakeWithdrawn",
+        ] {
+            assert_eq!(
+                decode(&client, name, vec![id_topic(id)], vec![]),
+                Some(EvmEventTarget::Reporter(id))
+            );
+        }
+    }
+
+    #[test]
+    fn decode_case_events() {
+        let client = create_client();
+        let id = Uuid::new_v4();
+
+        for name in ["CaseCreated", "CaseUpdated"] {
+            assert_eq!(
+                decode(&client, name, vec![id_topic(id)], vec![]),
+                Some(EvmEventTarget::Case(id))
+            );
+        }
+    }
+
+    #[test]
+    fn decode_address_events() {
+        let client = create_client();
+        let addr = EthAddress::random();
+
+        for name in ["AddressCreated", "AddressUpdated"] {
+            assert_eq!(
+                decode(
+                    &client,
+                    name,
+                    vec![H256::from(addr)],
+                    vec![Token::Uint(5.into()), Token::Uint(3.into())]
+                ),
+                Some(EvmEventTarget::Address(addr))
+            );
+        }
+
+        // Confirmation changes the confirmation count of the address
+        assert_eq!(
+            decode(&client, "AddressConfirmed", vec![H256::from(addr)], vec![]),
+            Some(EvmEventTarget::Address(addr))
+        );
+    }
+
+    #[test]
+    fn decode_asset_events() {
+        let client = create_client();
+        let addr = EthAddress::random();
+        let asset_id = U256::from(12345678);
+
+        for name in ["AssetCreated", "AssetUpdated"] {
+            assert_eq!(
+                decode(
+                    &client,
+                    name,
+                    vec![H256::from(addr)],
+                    vec![
+                        Token::Uint(asset_id),
+                        Token::Uint(7.into()),
+                        Token::Uint(5.into())
+                    ]
+                ),
+                Some(EvmEventTarget::Asset(addr, asset_id))
+            );
+        }
+
+        assert_eq!(
+            decode(
+                &client,
+                "AssetConfirmed",
+                vec![H256::from(addr)],
+                vec![Token::Uint(asset_id)]
+            ),
+            Some(EvmEventTarget::Asset(addr, asset_id))
+        );
+    }
+
+    #[test]
+    fn decode_configuration_events() {
+        let client = create_client();
+        let token = Token::Address(EthAddress::random());
+        let amount = Token::Uint(100.into());
+
+        assert_eq!(
+            decode(&client, "Initialized", vec![], vec![Token::Uint(1.into())]),
+            None
+        );
+        assert_eq!(
+            decode(&client, "AuthorityChanged", vec![], vec![token.clone()]),
+            None
+        );
+        assert_eq!(
+            decode(
+                &client,
+                "StakeConfigurationChanged",
+                vec![],
+                [vec![token.clone()], vec![amount.clone(); 5]].concat()
+            ),
+            None
+        );
+        assert_eq!(
+            decode(
+                &client,
+                "RewardConfigurationChanged",
+                vec![],
+                [vec![token], vec![amount; 4]].concat()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn reject_mismatched_tokens() {
+        // Address in place of the asset id
+        let log_header = LogHeader {
+            name: "AssetCreated".to_string(),
+            tokens: vec![
+                Token::Address(EthAddress::random()),
+                Token::Address(EthAddress::random()),
+                Token::Uint(7.into()),
+                Token::Uint(5.into()),
+            ],
+        };
+
+        assert!(decode_event_target(&log_header).is_err());
+
+        // Id that doesn't fit into uint128
+        let log_header = LogHeader {
+            name: "CaseCreated".to_string(),
+            tokens: vec![Token::Uint(U256::MAX)],
+        };
+
+        assert!(decode_event_target(&log_header).is_err());
+    }
+
+    #[tokio::test]
+    async fn decode_error_context() {
+        let client = create_client();
+        let signature = client
+            .contract
+            .abi()
+            .event("ReporterCreated")
+            .expect("Unknown event")
+            .signature();
+
+        // The data is too short for the declared non-indexed fields
+        let log = Log {
+            address: client.contract.address(),
+            topics: vec![signature, id_topic(Uuid::new_v4())],
+            data: vec![0xde, 0xad, 0xbe, 0xef].into(),
+            transaction_hash: Some(H256::repeat_byte(0xab)),
+            block_number: Some(U64::from(1234)),
+            log_index: Some(U256::from(7)),
+            ..Default::default()
+        };
+
+        let network_data = NetworkData {
+            indexer_id: Uuid::new_v4(),
+            network: HapiCoreNetwork::Ethereum,
+            chain_id: None,
+        };
+
+        let error = process_evm_job(&client, &log, None, network_data, UnknownEventPolicy::Skip)
+            .await
+            .expect_err("Malformed log must not be decoded");
+
+        let Some(IndexerError::Decode {
+            tx_hash,
+            block_number,
+            log_index,
+            topic,
+            data,
+            reason,
+        }) = error.downcast_ref::<IndexerError>()
+        else {
+            panic!("Unexpected error: {error:?}");
+        };
+
+        assert_eq!(tx_hash, &format!("{:?}", H256::repeat_byte(0xab)));
+        assert_eq!(block_number, "1234");
+        assert_eq!(log_index, "7");
+        assert_eq!(topic, &format!("{signature:?}"));
+        assert_eq!(data, "0xdeadbeef");
+        assert!(reason.contains("ReporterCreated"));
+
+        assert!(!IndexerError::is_retriable(&error));
+        assert!(error.to_string().contains("0xdeadbeef"));
+    }
+
+    #[tokio::test]
+    async fn unknown_event_policy() {
+        let client = create_client();
+        let signature = client
+            .contract
+            .abi()
+            .event("CaseCreated")
+            .expect("Unknown event")
+            .signature();
+
+        let known = Log {
+            address: client.contract.address(),
+            topics: vec![signature, id_topic(Uuid::new_v4())],
+            block_number: Some(U64::from(10)),
+            ..Default::default()
+        };
+        // Event of an upgraded contract, which is not in the ABI
+        let unknown = Log {
+            address: client.contract.address(),
+            topics: vec![H256::repeat_byte(0x42)],
+            transaction_hash: Some(H256::repeat_byte(0xab)),
+            block_number: Some(U64::from(11)),
+            ..Default::default()
+        };
+        let logs = vec![known.clone(), unknown.clone()];
+
+        let network_data = NetworkData {
+            indexer_id: Uuid::new_v4(),
+            network: HapiCoreNetwork::Ethereum,
+            chain_id: None,
+        };
+
+        // Skipped by default
+        assert_eq!(UnknownEventPolicy::default(), UnknownEventPolicy::Skip);
+
+        let filtered = filter_unknown_events(&client, logs.clone(), UnknownEventPolicy::Skip)
+            .expect("Unknown event must be skipped");
+        assert_eq!(filtered, vec![known]);
+
+        let payloads = process_evm_job(
+            &client,
+            &unknown,
+            None,
+            network_data.clone(),
+            UnknownEventPolicy::Skip,
+        )
+        .await
+        .expect("Unknown event must be skipped");
+        assert!(payloads.is_none());
+
+        // Fails with the topic in the error
+        let error = filter_unknown_events(&client, logs, UnknownEventPolicy::Fail)
+            .expect_err("Unknown event must fail");
+        assert!(error
+            .to_string()
+            .contains(&format!("{:?}", H256::repeat_byte(0x42))));
+        assert!(!IndexerError::is_retriable(&error));
+
+        let error = process_evm_job(
+            &client,
+            &unknown,
+            None,
+            network_data,
+            UnknownEventPolicy::Fail,
+        )
+        .await
+        .expect_err("Unknown event must fail");
+        assert!(matches!(
+            error.downcast_ref::<IndexerError>(),
+            Some(IndexerError::Decode { .. })
+        ));
     }
 }
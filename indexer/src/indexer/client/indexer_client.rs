@@ -1,110 +1,638 @@
 use {
-    anyhow::Result,
-    hapi_core::{HapiCoreEvm, HapiCoreNear, HapiCoreNetwork, HapiCoreOptions, HapiCoreSolana},
-    std::time::Duration,
-    tokio::time::sleep,
+    anyhow::{bail, Result},
+    hapi_core::{
+        client::result::ClientError, HapiCore, HapiCoreEvm, HapiCoreNear, HapiCoreNetwork,
+        HapiCoreOptions, HapiCoreSolana, RpcTimeouts,
+    },
+    near_jsonrpc_client::errors::JsonRpcError,
+    solana_client::client_error::ClientErrorKind,
+    std::{cmp::min, collections::HashSet, future::Future, sync::Arc, time::Duration},
+    tokio::time::{sleep, timeout},
     uuid::Uuid,
 };
 
 use super::{
-    evm::{fetch_evm_jobs, process_evm_job},
+    evm::{
+        fetch_evm_event_keys, fetch_evm_jobs, fetch_evm_range_jobs, is_canonical_evm_block,
+        is_retriable_rpc_error, process_evm_job, refresh_evm_data, ChainHead,
+    },
+    log_source::{LogSource, RpcLogSource},
     near::{fetch_near_jobs, process_near_job},
+    rpc_batch::BlockTimestamps,
     solana::{fetch_solana_jobs, process_solana_job},
 };
 
-use crate::indexer::{
-    push::{NetworkData, PushPayload},
-    IndexerJob, IndexingCursor,
+use crate::{
+    configuration::{IndexerConfiguration, RpcBatching, UnknownEventPolicy},
+    indexer::{
+        push::{NetworkData, PushPayload},
+        IndexerError, IndexerJob, IndexingCursor,
+    },
 };
 
 pub const DEFAULT_PAGE_SIZE: u64 = 500;
+pub const DEFAULT_CATCH_UP_THRESHOLD: u64 = 10_000;
 lazy_static::lazy_static! {
     pub static ref PAGE_SIZE: u64 = std::env::var("INDEXER_PAGE_SIZE").map_or(DEFAULT_PAGE_SIZE, |s| s.parse::<u64>().unwrap_or(DEFAULT_PAGE_SIZE));
+    pub static ref CATCH_UP_PAGE_SIZE: u64 = std::env::var("INDEXER_CATCH_UP_PAGE_SIZE").map_or(*PAGE_SIZE, |s| s.parse::<u64>().unwrap_or(*PAGE_SIZE));
+    pub static ref CATCH_UP_THRESHOLD: u64 = std::env::var("INDEXER_CATCH_UP_THRESHOLD").map_or(DEFAULT_CATCH_UP_THRESHOLD, |s| s.parse::<u64>().unwrap_or(DEFAULT_CATCH_UP_THRESHOLD));
+}
+
+/// Block range page sizes of the EVM and NEAR fetching
+///
+/// Far behind the chain head large pages speed the backfill up, near the head small pages
+/// keep the reorganization exposure and the latency of new events low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PageSizes {
+    pub steady: u64,
+    pub catch_up: u64,
+    /// The number of blocks behind the head above which the catch-up size is used
+    pub catch_up_threshold: u64,
+}
+
+impl PageSizes {
+    /// Sizes set with the `INDEXER_PAGE_SIZE`, `INDEXER_CATCH_UP_PAGE_SIZE` and
+    /// `INDEXER_CATCH_UP_THRESHOLD` env variables
+    pub fn from_env() -> Self {
+        Self {
+            steady: *PAGE_SIZE,
+            catch_up: *CATCH_UP_PAGE_SIZE,
+            catch_up_threshold: *CATCH_UP_THRESHOLD,
+        }
+    }
+
+    /// Size of the page that starts at the block
+    pub fn size_at(&self, from_block: u64, latest_block: u64) -> u64 {
+        if latest_block.saturating_sub(from_block) > self.catch_up_threshold {
+            self.catch_up.max(1)
+        } else {
+            self.steady.max(1)
+        }
+    }
+
+    /// The last block of the page that starts at the block
+    pub fn page_end(&self, from_block: u64, latest_block: u64) -> u64 {
+        // Substracting 1 from page size because the range includes both limits
+        min(
+            self.size_at(from_block, latest_block) - 1 + from_block,
+            latest_block,
+        )
+    }
+}
+
+/// Contract implementation that serves a network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClientKind {
+    Evm,
+    Near,
+    Solana,
+}
+
+impl From<&HapiCoreNetwork> for ClientKind {
+    // No catch-all arm: a new network must be explicitly assigned to a client
+    fn from(network: &HapiCoreNetwork) -> Self {
+        match network {
+            HapiCoreNetwork::Ethereum => ClientKind::Evm,
+            HapiCoreNetwork::Bsc => ClientKind::Evm,
+            HapiCoreNetwork::Sepolia => ClientKind::Evm,
+            HapiCoreNetwork::Near => ClientKind::Near,
+            HapiCoreNetwork::Solana => ClientKind::Solana,
+            // Bitcoin addresses are reported to the HAPI program on Solana
+            HapiCoreNetwork::Bitcoin => ClientKind::Solana,
+        }
+    }
 }
 
 pub(crate) enum HapiClient {
-    Evm(HapiCoreEvm),
+    Evm(HapiCoreEvm, Arc<dyn LogSource>),
     Near(HapiCoreNear),
     Solana(HapiCoreSolana),
 }
 
+/// Position of the fetched cursor relative to the chain head
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HeadPosition {
+    /// More blocks are left to fetch in the next pages
+    Behind,
+    /// The cursor has caught up with the chain head
+    Reached,
+    /// The node serves a chain head behind the cursor
+    Stale,
+}
+
 pub(crate) struct FetchingArtifacts {
     pub jobs: Vec<IndexerJob>,
     pub cursor: IndexingCursor,
+    pub head: HeadPosition,
+    /// The chain head returned by the node, if the fetch requested one
+    pub chain_head: Option<IndexingCursor>,
+}
+
+/// Settings the indexer client is created with
+pub(crate) struct IndexerClientOptions {
+    pub network_data: NetworkData,
+    pub rpc_node_url: String,
+    /// EVM RPC node URLs to fail over to, in order
+    pub rpc_node_fallback_urls: Vec<String>,
+    pub contract_address: String,
+    pub fetching_delay: Duration,
+    /// The maximum duration of a single fetching or processing step
+    pub rpc_timeout: Duration,
+    /// Timeouts of the single RPC requests
+    pub rpc_timeouts: RpcTimeouts,
+    pub unknown_event_policy: UnknownEventPolicy,
+    /// Node to fetch EVM logs from, the RPC node by default
+    pub log_source_url: Option<String>,
+}
+
+impl IndexerClientOptions {
+    pub fn from_configuration(network_data: NetworkData, cfg: &IndexerConfiguration) -> Self {
+        Self {
+            network_data,
+            rpc_node_url: cfg.rpc_node_url.clone(),
+            rpc_node_fallback_urls: cfg.rpc_node_fallback_urls.clone(),
+            contract_address: cfg.contract_address.clone(),
+            fetching_delay: cfg.fetching_delay,
+            rpc_timeout: cfg.rpc_timeout_ms,
+            rpc_timeouts: cfg.rpc_timeouts(),
+            unknown_event_policy: cfg.unknown_event_policy,
+            log_source_url: cfg.log_source_url.clone(),
+        }
+    }
 }
 
 pub(crate) struct IndexerClient {
     client: HapiClient,
+    /// Timestamps of the EVM job blocks, fetched ahead in batches
+    block_timestamps: Option<BlockTimestamps>,
+    page_sizes: PageSizes,
     fetching_delay: Duration,
+    /// The latest EVM chain head, used while the node fails to serve it
+    chain_head: ChainHead,
+    rpc_timeout: Duration,
     network_data: NetworkData,
+    unknown_event_policy: UnknownEventPolicy,
 }
 
 impl IndexerClient {
-    pub fn new(
-        network_data: NetworkData,
-        rpc_node_url: &str,
-        contract_address: &str,
-        fetching_delay: Duration,
-    ) -> Result<Self> {
+    pub fn new(options: IndexerClientOptions) -> Result<Self> {
+        let IndexerClientOptions {
+            network_data,
+            rpc_node_url,
+            rpc_node_fallback_urls,
+            contract_address,
+            fetching_delay,
+            rpc_timeout,
+            rpc_timeouts,
+            unknown_event_policy,
+            log_source_url,
+        } = options;
+
         let options = HapiCoreOptions {
-            provider_url: rpc_node_url.to_string(),
-            contract_address: contract_address.to_string(),
+            provider_url: rpc_node_url.clone(),
+            fallback_provider_urls: rpc_node_fallback_urls,
+            contract_address,
             private_key: None,
             chain_id: None,
             account_id: None,
             network: network_data.network.clone(),
+            rpc_timeouts,
         };
 
-        let client = match network_data.network {
-            HapiCoreNetwork::Ethereum | HapiCoreNetwork::Bsc | HapiCoreNetwork::Sepolia => {
-                HapiClient::Evm(HapiCoreEvm::new(options)?)
-            }
-            HapiCoreNetwork::Near => HapiClient::Near(HapiCoreNear::new(options)?),
-            HapiCoreNetwork::Solana | HapiCoreNetwork::Bitcoin => {
-                HapiClient::Solana(HapiCoreSolana::new(options)?)
+        let block_timestamps = match ClientKind::from(&network_data.network) {
+            ClientKind::Evm => Some(BlockTimestamps::new(&rpc_node_url, RpcBatching::default())),
+            ClientKind::Near | ClientKind::Solana => None,
+        };
+
+        let client = match ClientKind::from(&network_data.network) {
+            ClientKind::Evm => {
+                let client = HapiCoreEvm::new(options)?;
+                let log_source: Arc<dyn LogSource> = match log_source_url.as_deref() {
+                    Some(url) => Arc::new(RpcLogSource::from_url(url)?),
+                    None => Arc::new(RpcLogSource::new(client.provider.clone())),
+                };
+
+                HapiClient::Evm(client, log_source)
             }
+            ClientKind::Near => HapiClient::Near(HapiCoreNear::new(options)?),
+            ClientKind::Solana => HapiClient::Solana(HapiCoreSolana::new(options)?),
         };
 
         Ok(Self {
             client,
+            block_timestamps,
             network_data,
+            page_sizes: PageSizes::from_env(),
             fetching_delay,
+            chain_head: ChainHead::default(),
+            rpc_timeout,
+            unknown_event_policy,
         })
     }
 
     pub(crate) async fn fetch_jobs(&self, cursor: &IndexingCursor) -> Result<FetchingArtifacts> {
-        let artifacts = match &self.client {
-            HapiClient::Evm(client) => fetch_evm_jobs(client, cursor).await?,
-            HapiClient::Solana(client) => {
-                fetch_solana_jobs(client, cursor, self.fetching_delay).await?
+        let fetching = async {
+            match &self.client {
+                HapiClient::Evm(client, log_source) => {
+                    let artifacts = fetch_evm_jobs(
+                        client,
+                        log_source.as_ref(),
+                        &self.chain_head,
+                        cursor,
+                        self.page_sizes,
+                        self.unknown_event_policy,
+                    )
+                    .await?;
+
+                    self.prefetch_block_timestamps(&artifacts.jobs).await;
+
+                    Ok(artifacts)
+                }
+                HapiClient::Solana(client) => {
+                    fetch_solana_jobs(client, cursor, self.fetching_delay).await
+                }
+                HapiClient::Near(client) => fetch_near_jobs(client, cursor, self.page_sizes).await,
             }
-            HapiClient::Near(client) => fetch_near_jobs(client, cursor).await?,
         };
 
+        let artifacts = timeout(self.rpc_timeout, fetching).await.map_err(|_| {
+            IndexerError::RpcTransport(format!(
+                "Fetching jobs timed out after {} ms",
+                self.rpc_timeout.as_millis()
+            ))
+        })??;
+
         sleep(self.fetching_delay).await;
 
         Ok(artifacts)
     }
 
+    /// Jobs of the closed block range, regardless of the indexing cursor
+    ///
+    /// Only EVM networks are indexed by blocks, NEAR and Solana cursors are transactions.
+    pub(crate) async fn fetch_range_jobs(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<IndexerJob>> {
+        match &self.client {
+            HapiClient::Evm(client, log_source) => {
+                self.with_rpc_timeout("Fetching range", async {
+                    let jobs = fetch_evm_range_jobs(
+                        client,
+                        log_source.as_ref(),
+                        from_block,
+                        to_block,
+                        self.page_sizes,
+                        self.unknown_event_policy,
+                    )
+                    .await?;
+
+                    self.prefetch_block_timestamps(&jobs).await;
+
+                    Ok(jobs)
+                })
+                .await
+            }
+            HapiClient::Near(_) | HapiClient::Solana(_) => bail!(
+                "Block range can't be reindexed on {} network",
+                self.network_data.network
+            ),
+        }
+    }
+
+    /// Processes a single job into webhook payloads
+    ///
+    /// Payloads are ordered by their index in the transaction, and jobs themselves are
+    /// fetched in chain order (block, then log or receipt position), so that the receiver
+    /// never gets an update of an entity before its creation.
     pub(crate) async fn handle_process(
         &self,
         job: &IndexerJob,
     ) -> Result<Option<Vec<PushPayload>>> {
-        match (&self.client, job) {
-            (HapiClient::Evm(client), IndexerJob::Log(log)) => {
-                process_evm_job(client, log, self.network_data.clone()).await
+        let processing = async {
+            match (&self.client, job) {
+                (HapiClient::Evm(client, _), IndexerJob::Log(log)) => {
+                    let block_timestamp = self
+                        .block_timestamps
+                        .as_ref()
+                        .zip(job.height())
+                        .and_then(|(timestamps, block)| timestamps.get(block));
+
+                    process_evm_job(
+                        client,
+                        log,
+                        block_timestamp,
+                        self.network_data.clone(),
+                        self.unknown_event_policy,
+                    )
+                    .await
+                }
+                (HapiClient::Solana(client), IndexerJob::Transaction(hash)) => {
+                    process_solana_job(client, hash, self.network_data.clone()).await
+                }
+                (HapiClient::Near(client), IndexerJob::TransactionReceipt(receipt)) => {
+                    process_near_job(client, receipt, self.network_data.clone()).await
+                }
+                // Jobs are created by the same client, so this is a bug rather than bad data
+                (
+                    HapiClient::Evm(..),
+                    IndexerJob::Transaction(_) | IndexerJob::TransactionReceipt(_),
+                )
+                | (HapiClient::Solana(_), IndexerJob::Log(_) | IndexerJob::TransactionReceipt(_))
+                | (HapiClient::Near(_), IndexerJob::Log(_) | IndexerJob::Transaction(_)) => {
+                    bail!(
+                        "Job can't be processed on {} network: {job:?}",
+                        self.network_data.network
+                    )
+                }
             }
-            (HapiClient::Solana(client), IndexerJob::Transaction(hash)) => {
-                process_solana_job(client, hash, self.network_data.clone()).await
+        };
+
+        let mut payloads = timeout(self.rpc_timeout, processing)
+            .await
+            .map_err(|_| {
+                IndexerError::RpcTransport(format!(
+                    "Processing job timed out after {} ms",
+                    self.rpc_timeout.as_millis()
+                ))
+            })?
+            .map_err(client_error)?;
+
+        if let Some(payloads) = payloads.as_mut() {
+            payloads.sort_by_key(|payload| payload.event.tx_index);
+        }
+
+        Ok(payloads)
+    }
+
+    /// Whether an indexed block is still on the canonical chain
+    ///
+    /// Reorganizations are tracked on EVM networks only: NEAR jobs come from final blocks
+    /// and Solana ones are fetched at confirmed commitment.
+    pub(crate) async fn is_canonical(&self, number: u64, hash: &str) -> Result<bool> {
+        match &self.client {
+            HapiClient::Evm(client, _) => {
+                self.with_rpc_timeout(
+                    "Checking block",
+                    is_canonical_evm_block(client, number, hash),
+                )
+                .await
+            }
+            HapiClient::Near(_) | HapiClient::Solana(_) => Ok(true),
+        }
+    }
+
+    /// Keys of the events that are on the canonical chain within the block range
+    pub(crate) async fn fetch_event_keys(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<HashSet<String>> {
+        match &self.client {
+            HapiClient::Evm(client, log_source) => {
+                self.with_rpc_timeout(
+                    "Fetching events",
+                    fetch_evm_event_keys(client, log_source.as_ref(), from_block, to_block),
+                )
+                .await
             }
-            (HapiClient::Near(client), IndexerJob::TransactionReceipt(receipt)) => {
-                process_near_job(client, receipt, self.network_data.clone()).await
+            HapiClient::Near(_) | HapiClient::Solana(_) => Ok(HashSet::new()),
+        }
+    }
+
+    /// Replaces the payload data with the current state of the entity
+    pub(crate) async fn refresh_payload(&self, mut payload: PushPayload) -> Result<PushPayload> {
+        if let HapiClient::Evm(client, _) = &self.client {
+            payload.data = self
+                .with_rpc_timeout(
+                    "Refreshing payload",
+                    refresh_evm_data(client, &payload.data),
+                )
+                .await?;
+        }
+
+        Ok(payload)
+    }
+
+    async fn prefetch_block_timestamps(&self, jobs: &[IndexerJob]) {
+        if let Some(timestamps) = &self.block_timestamps {
+            timestamps
+                .prefetch(jobs.iter().filter_map(IndexerJob::height))
+                .await;
+        }
+    }
+
+    /// Version of the contract the network runs, if the contract keeps one
+    pub(crate) async fn get_contract_version(&self) -> Result<Option<u64>> {
+        self.with_rpc_timeout("Fetching contract version", async {
+            let version = match &self.client {
+                HapiClient::Evm(client, _) => client.get_contract_version().await,
+                HapiClient::Near(client) => client.get_contract_version().await,
+                HapiClient::Solana(client) => client.get_contract_version().await,
+            }?;
+
+            Ok(version)
+        })
+        .await
+    }
+
+    async fn with_rpc_timeout<T>(
+        &self,
+        action: &str,
+        future: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        timeout(self.rpc_timeout, future).await.map_err(|_| {
+            IndexerError::RpcTransport(format!(
+                "{action} timed out after {} ms",
+                self.rpc_timeout.as_millis()
+            ))
+        })?
+    }
+
+    /// Replaces the backend that EVM logs are fetched from
+    pub(crate) fn set_log_source(&mut self, source: Arc<dyn LogSource>) -> Result<()> {
+        match &mut self.client {
+            HapiClient::Evm(_, log_source) => {
+                *log_source = source;
+                Ok(())
             }
-            _ => unimplemented!(),
+            HapiClient::Near(_) | HapiClient::Solana(_) => bail!(
+                "Logs are not fetched on {} network",
+                self.network_data.network
+            ),
         }
     }
 
     pub(crate) fn get_id(&self) -> Uuid {
         self.network_data.indexer_id
     }
+
+    /// Page sizes of the next block range fetches
+    pub(crate) fn set_page_sizes(&mut self, page_sizes: PageSizes) {
+        self.page_sizes = page_sizes;
+    }
+
+    /// Whether block requests of EVM jobs are batched
+    pub(crate) fn set_rpc_batching(&mut self, batching: RpcBatching) {
+        if let Some(timestamps) = &self.block_timestamps {
+            timestamps.set_batching(batching);
+        }
+    }
+
+    /// How long the last EVM chain head stands in for one that the node fails to serve
+    pub(crate) fn set_chain_head_ttl(&mut self, ttl: Duration) {
+        self.chain_head = ChainHead::new(ttl);
+    }
+
+    pub(crate) fn page_sizes(&self) -> PageSizes {
+        self.page_sizes
+    }
+
+    /// Pause after every fetch, and between Solana signature pages
+    pub(crate) fn set_fetching_delay(&mut self, fetching_delay: Duration) {
+        self.fetching_delay = fetching_delay;
+    }
+
+    pub(crate) fn get_network(&self) -> HapiCoreNetwork {
+        self.network_data.network.clone()
+    }
+
+    /// Makes sure that the cursor is the kind of position the client fetches jobs from:
+    /// blocks on EVM and NEAR, transactions on Solana
+    pub(crate) fn check_cursor(&self, cursor: &IndexingCursor) -> Result<()> {
+        let fits = matches!(
+            (&self.client, cursor),
+            (
+                HapiClient::Evm(..) | HapiClient::Near(_),
+                IndexingCursor::Block(_)
+            ) | (HapiClient::Solana(_), IndexingCursor::Transaction(_))
+        );
+
+        if !fits {
+            bail!(
+                "Cursor {cursor} doesn't fit the {} network",
+                self.get_network()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Marks failed requests of the entities read while processing as retriable, like the
+/// fetching requests, so that a node outage doesn't dead-letter the job
+fn client_error(error: anyhow::Error) -> anyhow::Error {
+    let retriable = match error.downcast_ref::<ClientError>() {
+        Some(ClientError::Provider(error)) => is_retriable_rpc_error(error),
+        Some(ClientError::TimeoutError(_)) => true,
+        Some(ClientError::RpcQueryError(JsonRpcError::TransportError(_))) => true,
+        Some(ClientError::SolanaRpcError(error)) => {
+            matches!(
+                error.kind(),
+                ClientErrorKind::Io(_) | ClientErrorKind::Reqwest(_)
+            )
+        }
+        _ => false,
+    };
+
+    if retriable {
+        IndexerError::RpcTransport(error.to_string()).into()
+    } else {
+        error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, ethers::providers::ProviderError};
+
+    #[test]
+    fn networks_are_served_by_their_clients() {
+        let cases = [
+            (HapiCoreNetwork::Ethereum, ClientKind::Evm),
+            (HapiCoreNetwork::Bsc, ClientKind::Evm),
+            (HapiCoreNetwork::Sepolia, ClientKind::Evm),
+            (HapiCoreNetwork::Near, ClientKind::Near),
+            (HapiCoreNetwork::Solana, ClientKind::Solana),
+            (HapiCoreNetwork::Bitcoin, ClientKind::Solana),
+        ];
+
+        for (network, kind) in cases {
+            assert_eq!(ClientKind::from(&network), kind, "{network}");
+        }
+    }
+
+    #[test]
+    fn catch_up_page_size_is_used_far_behind_the_head() {
+        let sizes = PageSizes {
+            steady: 100,
+            catch_up: 5000,
+            catch_up_threshold: 10_000,
+        };
+
+        assert_eq!(sizes.size_at(1, 50_000), 5000);
+        assert_eq!(sizes.page_end(1, 50_000), 5000);
+
+        // Near the head and at the threshold the steady size is used
+        assert_eq!(sizes.size_at(45_000, 50_000), 100);
+        assert_eq!(sizes.size_at(40_000, 50_000), 100);
+        assert_eq!(sizes.page_end(49_950, 50_000), 50_000);
+
+        // A cursor ahead of the observed head is not behind at all
+        assert_eq!(sizes.size_at(50_010, 50_000), 100);
+    }
+
+    #[tokio::test]
+    async fn reject_job_of_another_network() {
+        let client = IndexerClient::new(IndexerClientOptions {
+            network_data: NetworkData {
+                indexer_id: Uuid::new_v4(),
+                network: HapiCoreNetwork::Ethereum,
+                chain_id: None,
+            },
+            rpc_node_url: "http://127.0.0.1:8545".to_string(),
+            rpc_node_fallback_urls: vec![],
+            contract_address: "0x2947F98C42597966a0ec25e92843c09ac18Fbab7".to_string(),
+            fetching_delay: Duration::from_millis(100),
+            rpc_timeout: Duration::from_millis(1000),
+            rpc_timeouts: RpcTimeouts::default(),
+            unknown_event_policy: UnknownEventPolicy::Skip,
+            log_source_url: None,
+        })
+        .expect("Failed to create client");
+
+        let result = client
+            .handle_process(&IndexerJob::Transaction("hash".to_string()))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn client_transport_failures_are_retriable() {
+        let timeout = client_error(ClientError::TimeoutError("Request timed out".into()).into());
+        assert!(IndexerError::is_retriable(&timeout));
+
+        let http = client_error(
+            ClientError::Provider(ProviderError::HTTPError(
+                reqwest::Client::new()
+                    .get("http://[::1")
+                    .build()
+                    .unwrap_err(),
+            ))
+            .into(),
+        );
+        assert!(IndexerError::is_retriable(&http));
+
+        // Missing entities and bad data fail the same way on every attempt
+        let missing = client_error(ClientError::InvalidReporter.into());
+        assert!(!IndexerError::is_retriable(&missing));
+        assert!(missing.downcast_ref::<ClientError>().is_some());
+
+        let reverted = client_error(
+            ClientError::Ethers("`get_case` reverted with: Case not found".into()).into(),
+        );
+        assert!(!IndexerError::is_retriable(&reverted));
+    }
 }
@@ -0,0 +1,60 @@
+use {
+    anyhow::Result,
+    async_trait::async_trait,
+    ethers::{
+        providers::{Http, JsonRpcClient, Middleware, Provider},
+        types::{Address as EthAddress, Filter, Log},
+    },
+};
+
+use super::evm::rpc_error;
+
+/// Backend that EVM contract logs are fetched from
+///
+/// Logs are expected in the `eth_getLogs` format, so that a specialized log API can
+/// replace the RPC node without changes to decoding and processing.
+#[async_trait]
+pub trait LogSource: Send + Sync {
+    /// Logs of the contract within the closed block range, in any order
+    async fn get_logs(
+        &self,
+        contract: EthAddress,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Log>>;
+}
+
+/// Logs fetched with `eth_getLogs` from a JSON-RPC node
+pub struct RpcLogSource<P> {
+    provider: Provider<P>,
+}
+
+impl<P> RpcLogSource<P> {
+    pub fn new(provider: Provider<P>) -> Self {
+        Self { provider }
+    }
+}
+
+impl RpcLogSource<Http> {
+    /// Node other than the indexing one, i.e. an archive node with a larger log range limit
+    pub fn from_url(url: &str) -> Result<Self> {
+        Ok(Self::new(Provider::<Http>::try_from(url)?))
+    }
+}
+
+#[async_trait]
+impl<P: JsonRpcClient> LogSource for RpcLogSource<P> {
+    async fn get_logs(
+        &self,
+        contract: EthAddress,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Log>> {
+        let filter = Filter::default()
+            .address(contract)
+            .from_block(from_block)
+            .to_block(to_block);
+
+        self.provider.get_logs(&filter).await.map_err(rpc_error)
+    }
+}
@@ -1,7 +1,14 @@
 mod evm;
 mod indexer_client;
+mod log_source;
 mod near;
+mod rpc_batch;
 mod solana;
 
-pub(crate) use indexer_client::IndexerClient;
-pub use near::NearReceipt;
+pub(crate) use indexer_client::{
+    ClientKind, HeadPosition, IndexerClient, IndexerClientOptions, PageSizes,
+};
+pub use {
+    log_source::{LogSource, RpcLogSource},
+    near::NearReceipt,
+};
@@ -16,20 +16,20 @@ use {
             ActionView, ReceiptEnumView, ReceiptView, StateChangeCauseView, StateChangesRequestView,
         },
     },
-    std::{cmp::min, collections::HashSet},
+    std::collections::HashSet,
     uuid::Uuid,
 };
 
 use crate::{
     indexer::{
-        client::indexer_client::PAGE_SIZE,
-        push::{NetworkData, PushEvent, PushPayload},
+        client::indexer_client::PageSizes,
+        push::{NetworkData, PushEvent, PushPayload, PUSH_SCHEMA_VERSION},
         IndexerJob,
     },
     IndexingCursor,
 };
 
-use super::indexer_client::FetchingArtifacts;
+use super::indexer_client::{FetchingArtifacts, HeadPosition};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct NearReceipt {
@@ -71,13 +71,15 @@ async fn get_receipts_list(
                         .header
                         .timestamp_nanosec;
 
+                    // Receipts keep the order of their first change in the block
+                    let mut seen = HashSet::new();
+
                     changes
                         .changes
                         .iter()
                         .map(|change| get_hash_from_cause(&change.cause))
-                        .collect::<HashSet<CryptoHash>>()
-                        .iter()
-                        .for_each(|&hash| {
+                        .filter(|hash| seen.insert(*hash))
+                        .for_each(|hash| {
                             event_list.push(IndexerJob::TransactionReceipt(NearReceipt {
                                 hash,
                                 block_height,
@@ -99,6 +101,7 @@ async fn get_receipts_list(
 pub(super) async fn fetch_near_jobs(
     client: &HapiCoreNear,
     current_cursor: &IndexingCursor,
+    page_sizes: PageSizes,
 ) -> Result<FetchingArtifacts> {
     let start_block = match current_cursor {
         IndexingCursor::None => 0,
@@ -118,7 +121,7 @@ pub(super) async fn fetch_near_jobs(
     if start_block < latest_block {
         tracing::info!(start_block, "Fetching near jobs from");
 
-        let final_block = min(PAGE_SIZE.to_owned() - 1 + start_block, latest_block);
+        let final_block = page_sizes.page_end(start_block, latest_block);
 
         let event_list: Vec<IndexerJob> =
             get_receipts_list(client, start_block, final_block).await?;
@@ -128,6 +131,12 @@ pub(super) async fn fetch_near_jobs(
         return Ok(FetchingArtifacts {
             jobs: event_list,
             cursor: IndexingCursor::Block(final_block),
+            head: if final_block < latest_block {
+                HeadPosition::Behind
+            } else {
+                HeadPosition::Reached
+            },
+            chain_head: Some(IndexingCursor::Block(latest_block)),
         });
     }
 
@@ -136,6 +145,8 @@ pub(super) async fn fetch_near_jobs(
     Ok(FetchingArtifacts {
         jobs: vec![],
         cursor: current_cursor.clone(),
+        head: HeadPosition::Reached,
+        chain_head: Some(IndexingCursor::Block(latest_block)),
     })
 }
 
@@ -186,23 +197,22 @@ pub(super) async fn process_near_job(
                 let account_id = get_field_from_args(&args, "sender_id")?;
                 client.get_reporter_by_account(&account_id).await?.into()
             }
-            EventName::CreateCase | EventName::UpdateCase => {
+            EventName::CreateCase | EventName::UpdateCase | EventName::LinkCases => {
                 tracing::info!("Case is created or modified");
 
                 let id = get_id_from_args(&args).await?;
                 client.get_case(&id.to_string()).await?.into()
             }
-            EventName::CreateAddress | EventName::UpdateAddress => {
+            EventName::CreateAddress
+            | EventName::UpdateAddress
+            | EventName::ConfirmAddress
+            | EventName::InvalidateAddress => {
                 tracing::info!("Address is created or modified");
 
                 let address = get_field_from_args(&args, "address")?;
                 client.get_address(&address).await?.into()
             }
-            EventName::ConfirmAddress | EventName::ConfirmAsset => {
-                tracing::info!("Confirmation is received");
-                return Ok(None);
-            }
-            EventName::CreateAsset | EventName::UpdateAsset => {
+            EventName::CreateAsset | EventName::UpdateAsset | EventName::ConfirmAsset => {
                 tracing::info!("Asset is created or modified");
                 let addr = get_field_from_args(&args, "address")?;
                 let asset_id = get_field_from_args(&args, "id")?;
@@ -225,12 +235,14 @@ pub(super) async fn process_near_job(
         };
 
         return Ok(Some(vec![PushPayload {
+            schema_version: PUSH_SCHEMA_VERSION,
             network_data,
             event: PushEvent {
                 name: event_name,
                 tx_hash: receipt.hash.to_string(),
                 tx_index: 0,
                 timestamp: receipt.timestamp,
+                removed: false,
             },
             data,
         }]));
@@ -0,0 +1,262 @@
+use {
+    anyhow::{anyhow, bail, Result},
+    serde_json::{json, Value},
+    std::{
+        collections::{BTreeSet, HashMap},
+        sync::Mutex,
+    },
+};
+
+use crate::configuration::RpcBatching;
+
+/// The maximum number of requests sent in a single batch, nodes commonly limit it to 100
+const MAX_BATCH_SIZE: usize = 100;
+
+/// Whether the node is known to accept JSON-RPC batches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BatchSupport {
+    Unknown,
+    Supported,
+    Unsupported,
+}
+
+/// Block timestamps of the queued EVM jobs, fetched in JSON-RPC batches
+///
+/// Every job needs the timestamp of its block, so fetching them together for a page of jobs
+/// saves a request per job. Jobs without a prefetched timestamp fetch their block one by one,
+/// which is also the fallback for nodes that reject batches.
+pub(crate) struct BlockTimestamps {
+    client: reqwest::Client,
+    url: String,
+    support: Mutex<BatchSupport>,
+    timestamps: Mutex<HashMap<u64, u64>>,
+}
+
+impl BlockTimestamps {
+    pub fn new(url: &str, batching: RpcBatching) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.to_string(),
+            support: Mutex::new(Self::initial_support(batching)),
+            timestamps: Mutex::default(),
+        }
+    }
+
+    pub fn set_batching(&self, batching: RpcBatching) {
+        *self.lock_support() = Self::initial_support(batching);
+    }
+
+    fn initial_support(batching: RpcBatching) -> BatchSupport {
+        match batching {
+            RpcBatching::Auto => BatchSupport::Unknown,
+            RpcBatching::Enabled => BatchSupport::Supported,
+            RpcBatching::Disabled => BatchSupport::Unsupported,
+        }
+    }
+
+    /// Fetches the timestamps of the blocks, a failure leaves them to single requests
+    ///
+    /// Timestamps of the previous page are dropped, so the cache holds a single page at most.
+    pub async fn prefetch(&self, blocks: impl IntoIterator<Item = u64>) {
+        self.lock_timestamps().clear();
+
+        if *self.lock_support() == BatchSupport::Unsupported {
+            return;
+        }
+
+        let blocks: Vec<u64> = blocks
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        for chunk in blocks.chunks(MAX_BATCH_SIZE) {
+            match self.fetch(chunk).await {
+                Ok(timestamps) => {
+                    let mut support = self.lock_support();
+                    if *support == BatchSupport::Unknown {
+                        tracing::info!("RPC node supports JSON-RPC batches");
+                        *support = BatchSupport::Supported;
+                    }
+                    drop(support);
+
+                    self.lock_timestamps().extend(timestamps);
+                }
+                Err(error) => {
+                    let mut support = self.lock_support();
+                    if *support == BatchSupport::Unknown {
+                        tracing::warn!(
+                            ?error,
+                            "RPC node doesn't support JSON-RPC batches, blocks are fetched one by one"
+                        );
+                        *support = BatchSupport::Unsupported;
+                    } else {
+                        tracing::warn!(?error, "Batch of block requests failed");
+                    }
+
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Timestamp of the block if it has been prefetched
+    ///
+    /// The timestamp stays cached for the other jobs of the same block.
+    pub fn get(&self, block: u64) -> Option<u64> {
+        self.lock_timestamps().get(&block).copied()
+    }
+
+    async fn fetch(&self, blocks: &[u64]) -> Result<HashMap<u64, u64>> {
+        let requests: Vec<Value> = blocks
+            .iter()
+            .enumerate()
+            .map(|(id, block)| {
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "method": "eth_getBlockByNumber",
+                    "params": [format!("{block:#x}"), false],
+                })
+            })
+            .collect();
+
+        let response: Value = self
+            .client
+            .post(&self.url)
+            .json(&requests)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        // Nodes without batch support answer with a single error object
+        let Value::Array(responses) = response else {
+            bail!("Unexpected batch response: {response}");
+        };
+
+        let mut timestamps = HashMap::with_capacity(responses.len());
+
+        for response in responses {
+            if let Some(error) = response.get("error") {
+                bail!("Block request failed: {error}");
+            }
+
+            let block = response
+                .get("id")
+                .and_then(Value::as_u64)
+                .and_then(|id| blocks.get(id as usize))
+                .ok_or_else(|| anyhow!("Unexpected id in batch response: {response}"))?;
+
+            // Blocks that the node doesn't have yet are left to single requests
+            if let Some(timestamp) = response.pointer("/result/timestamp") {
+                let timestamp = timestamp
+                    .as_str()
+                    .and_then(|hex| u64::from_str_radix(hex.trim_start_matches("0x"), 16).ok())
+                    .ok_or_else(|| anyhow!("Unexpected block timestamp: {timestamp}"))?;
+
+                timestamps.insert(*block, timestamp);
+            }
+        }
+
+        Ok(timestamps)
+    }
+
+    fn lock_support(&self) -> std::sync::MutexGuard<'_, BatchSupport> {
+        self.support.lock().expect("Batch support lock is poisoned")
+    }
+
+    fn lock_timestamps(&self) -> std::sync::MutexGuard<'_, HashMap<u64, u64>> {
+        self.timestamps.lock().expect("Timestamps lock is poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, mockito::Server};
+
+    fn block_response(id: usize, timestamp: u64) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "result": { "number": "0x0", "timestamp": format!("{timestamp:#x}") },
+        })
+    }
+
+    #[tokio::test]
+    async fn timestamps_are_fetched_in_one_request() {
+        let mut server = Server::new_async().await;
+        let batch = server
+            .mock("POST", "/")
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!([block_response(1, 1700000200), block_response(0, 1700000100)]).to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let timestamps = BlockTimestamps::new(&server.url(), RpcBatching::Auto);
+
+        // Jobs of the same block share the request
+        timestamps.prefetch([10, 11, 10]).await;
+
+        batch.assert_async().await;
+        assert_eq!(*timestamps.lock_support(), BatchSupport::Supported);
+        assert_eq!(timestamps.get(10), Some(1700000100));
+        assert_eq!(timestamps.get(11), Some(1700000200));
+        // Every job of the block gets its timestamp
+        assert_eq!(timestamps.get(10), Some(1700000100));
+        assert_eq!(timestamps.get(12), None);
+    }
+
+    #[tokio::test]
+    async fn timestamps_are_kept_for_a_single_page() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_header("content-type", "application/json")
+            .with_body(json!([block_response(0, 1700000100)]).to_string())
+            .create_async()
+            .await;
+
+        let timestamps = BlockTimestamps::new(&server.url(), RpcBatching::Auto);
+
+        timestamps.prefetch([10]).await;
+        assert_eq!(timestamps.get(10), Some(1700000100));
+
+        timestamps.prefetch([20]).await;
+        assert_eq!(timestamps.get(10), None);
+        assert_eq!(timestamps.get(20), Some(1700000100));
+    }
+
+    #[tokio::test]
+    async fn rejected_batch_falls_back_to_single_requests() {
+        let mut server = Server::new_async().await;
+        let batch = server
+            .mock("POST", "/")
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": null,
+                    "error": { "code": -32600, "message": "batch requests are not supported" },
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let timestamps = BlockTimestamps::new(&server.url(), RpcBatching::Auto);
+
+        timestamps.prefetch([10]).await;
+        // The node is not asked for batches again
+        timestamps.prefetch([11]).await;
+
+        batch.assert_async().await;
+        assert_eq!(*timestamps.lock_support(), BatchSupport::Unsupported);
+        assert_eq!(timestamps.get(10), None);
+    }
+}
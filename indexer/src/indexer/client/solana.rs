@@ -10,15 +10,17 @@ use {
         get_solana_account,
     },
     solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config,
-    solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature},
+    solana_sdk::{
+        commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature, system_program,
+    },
     std::time::Duration,
     std::{collections::VecDeque, str::FromStr},
     tokio::time::sleep,
 };
 
 use crate::indexer::{
-    client::indexer_client::{FetchingArtifacts, PAGE_SIZE},
-    push::{NetworkData, PushData, PushEvent, PushPayload},
+    client::indexer_client::{FetchingArtifacts, HeadPosition, PAGE_SIZE},
+    push::{NetworkData, PushData, PushEvent, PushPayload, PUSH_SCHEMA_VERSION},
     IndexerJob, IndexingCursor,
 };
 
@@ -26,6 +28,14 @@ const REPORTER_ACCOUNT_INDEX: usize = 2;
 const CASE_ACCOUNT_INDEX: usize = 3;
 const ADDRESS_ACCOUNT_INDEX: usize = 4;
 const ASSET_ACCOUNT_INDEX: usize = 4;
+/// Invalidation is signed by the network authority, the address follows the network
+const INVALIDATED_ADDRESS_ACCOUNT_INDEX: usize = 2;
+/// Links are mutual, the related case follows the case
+const RELATED_CASE_ACCOUNT_INDEX: usize = 4;
+
+/// Batch confirmation has the system program in place of the address, followed by
+/// address and confirmation pairs
+const BATCH_SYSTEM_PROGRAM_INDEX: usize = 4;
 
 async fn get_signature_list(
     client: &HapiCoreSolana,
@@ -92,15 +102,18 @@ pub(super) async fn fetch_solana_jobs(
     let signature_list = get_signature_list(client, signature_cursor, fetching_delay).await?;
     tracing::info!(count = signature_list.len(), "Found jobs");
 
-    let new_cursor = if let Some(recent) = signature_list.last() {
-        IndexingCursor::try_from(recent.clone())?
-    } else {
-        current_cursor.clone()
-    };
+    // Signatures are fetched up to the newest one, which is the chain head
+    let chain_head = signature_list
+        .last()
+        .cloned()
+        .map(IndexingCursor::try_from)
+        .transpose()?;
 
     Ok(FetchingArtifacts {
         jobs: signature_list,
-        cursor: new_cursor,
+        cursor: chain_head.clone().unwrap_or_else(|| current_cursor.clone()),
+        head: HeadPosition::Reached,
+        chain_head,
     })
 }
 
@@ -123,7 +136,7 @@ pub(super) async fn process_solana_job(
     let mut payloads = vec![];
 
     for instruction in instructions {
-        if let Some(data) = get_instruction_data(client, &instruction).await? {
+        for data in get_instruction_data(client, &instruction).await? {
             tracing::info!(
                 name = instruction.name.to_string(),
                 signature,
@@ -134,12 +147,14 @@ pub(super) async fn process_solana_job(
             );
 
             payloads.push(PushPayload {
+                schema_version: PUSH_SCHEMA_VERSION,
                 network_data: network_data.clone(),
                 event: PushEvent {
-                    name: instruction.name,
+                    name: instruction.name.clone(),
                     tx_hash: signature.to_string(),
                     tx_index: instruction.id.into(),
                     timestamp: instruction.blocktime,
+                    removed: false,
                 },
                 data,
             });
@@ -152,7 +167,7 @@ pub(super) async fn process_solana_job(
 async fn get_instruction_data(
     client: &HapiCoreSolana,
     instruction: &DecodedInstruction,
-) -> Result<Option<PushData>> {
+) -> Result<Vec<PushData>> {
     match instruction.name {
         EventName::CreateReporter
         | EventName::UpdateReporter
@@ -164,7 +179,7 @@ async fn get_instruction_data(
 
             tracing::info!(?reporter.id, "Reporter is created or modified");
 
-            return Ok(Some(reporter.into()));
+            return Ok(vec![reporter.into()]);
         }
 
         EventName::CreateCase | EventName::UpdateCase => {
@@ -173,24 +188,64 @@ async fn get_instruction_data(
 
             tracing::info!(?case.id, "Case is created or modified");
 
-            return Ok(Some(case.into()));
+            return Ok(vec![case.into()]);
         }
+        EventName::LinkCases => {
+            let mut data = vec![];
+
+            for index in [CASE_ACCOUNT_INDEX, RELATED_CASE_ACCOUNT_INDEX] {
+                let account = get_pubkey(&instruction.account_keys, index)?;
+                let case = get_solana_account!(client, &account, Case)?;
+
+                tracing::info!(?case.id, "Case is linked");
+
+                data.push(case.into());
+            }
+
+            return Ok(data);
+        }
+
+        EventName::ConfirmAddress if is_batch_confirmation(&instruction.account_keys)? => {
+            let mut data = vec![];
+
+            for account in instruction
+                .account_keys
+                .iter()
+                .skip(BATCH_SYSTEM_PROGRAM_INDEX + 1)
+                .step_by(2)
+            {
+                let address = get_solana_account!(client, &Pubkey::from_str(account)?, Address)?;
 
-        EventName::CreateAddress | EventName::UpdateAddress => {
+                tracing::info!(address.address, "Address is confirmed in a batch");
+
+                data.push(address.into());
+            }
+
+            return Ok(data);
+        }
+        EventName::CreateAddress | EventName::UpdateAddress | EventName::ConfirmAddress => {
             let account = get_pubkey(&instruction.account_keys, ADDRESS_ACCOUNT_INDEX)?;
             let address = get_solana_account!(client, &account, Address)?;
 
             tracing::info!(address.address, "Address is created or modified");
 
-            return Ok(Some(address.into()));
+            return Ok(vec![address.into()]);
         }
-        EventName::CreateAsset | EventName::UpdateAsset => {
+        EventName::InvalidateAddress => {
+            let account = get_pubkey(&instruction.account_keys, INVALIDATED_ADDRESS_ACCOUNT_INDEX)?;
+            let address = get_solana_account!(client, &account, Address)?;
+
+            tracing::info!(address.address, "Address is invalidated");
+
+            return Ok(vec![address.into()]);
+        }
+        EventName::CreateAsset | EventName::UpdateAsset | EventName::ConfirmAsset => {
             let account = get_pubkey(&instruction.account_keys, ASSET_ACCOUNT_INDEX)?;
             let asset = get_solana_account!(client, &account, Asset)?;
 
             tracing::info!(asset.address, ?asset.asset_id, "Asset is created or modified");
 
-            return Ok(Some(asset.into()));
+            return Ok(vec![asset.into()]);
         }
 
         EventName::Initialize => {
@@ -201,12 +256,13 @@ async fn get_instruction_data(
         | EventName::SetAuthority => {
             tracing::info!("Configuration is changed");
         }
-        EventName::ConfirmAddress | EventName::ConfirmAsset => {
-            tracing::info!("Confirmation is received");
-        }
     }
 
-    Ok(None)
+    Ok(vec![])
+}
+
+fn is_batch_confirmation(accounts: &[String]) -> Result<bool> {
+    Ok(get_pubkey(accounts, BATCH_SYSTEM_PROGRAM_INDEX)? == system_program::ID)
 }
 
 fn get_pubkey(accounts: &[String], index: usize) -> Result<Pubkey> {
@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum IndexerError {
+    /// The RPC node did not respond in time or the connection failed, the step can be retried
+    #[error("RPC transport error: {0}")]
+    RpcTransport(String),
+
+    /// An EVM log doesn't match the contract ABI, most likely the event layout has changed
+    #[error(
+        "Failed to decode log {log_index} of transaction {tx_hash} in block {block_number} \
+        (topic: {topic}, data: {data}): {reason}"
+    )]
+    Decode {
+        tx_hash: String,
+        block_number: String,
+        log_index: String,
+        topic: String,
+        data: String,
+        reason: String,
+    },
+}
+
+impl IndexerError {
+    /// Whether the indexer can retry the failed step instead of stopping
+    pub fn is_retriable(error: &anyhow::Error) -> bool {
+        matches!(
+            error.downcast_ref::<IndexerError>(),
+            Some(IndexerError::RpcTransport(_))
+        )
+    }
+
+    /// Whether the failed step is a log that doesn't match the contract ABI
+    pub fn is_decode_failure(error: &anyhow::Error) -> bool {
+        matches!(
+            error.downcast_ref::<IndexerError>(),
+            Some(IndexerError::Decode { .. })
+        )
+    }
+}
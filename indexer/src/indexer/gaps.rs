@@ -0,0 +1,128 @@
+use {
+    anyhow::Result,
+    serde::{Deserialize, Serialize},
+    std::fmt,
+};
+
+use super::{Indexer, IndexingCursor, PersistedState};
+
+/// The number of separate ranges remembered, the oldest ones are dropped beyond it
+const MAX_PROCESSED_RANGES: usize = 1000;
+
+/// Closed range of block heights
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockRange {
+    pub from: u64,
+    pub to: u64,
+}
+
+impl fmt::Display for BlockRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.from, self.to)
+    }
+}
+
+/// Block ranges that the persisted cursor has moved over, merged where they meet
+///
+/// The cursor only moves forward block by block, so the ranges stay a single one unless
+/// it jumps, i.e. when the indexer is started from a supplied cursor.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ProcessedRanges {
+    /// Ranges in ascending order, neither overlapping nor adjacent
+    ranges: Vec<BlockRange>,
+}
+
+impl ProcessedRanges {
+    /// Records the blocks between the previous cursor and the new one
+    pub fn advance(&mut self, previous: &IndexingCursor, cursor: &IndexingCursor) {
+        let IndexingCursor::Block(to) = *cursor else {
+            return;
+        };
+
+        match *previous {
+            IndexingCursor::Block(from) if from < to => self.record(from + 1, to),
+            IndexingCursor::Block(_) => {}
+            // The first indexed block, nothing is known about the ones before it
+            _ => self.record(to, to),
+        }
+    }
+
+    pub fn record(&mut self, from: u64, to: u64) {
+        let mut merged = BlockRange { from, to };
+
+        // Ranges that overlap or touch the new one are folded into it
+        self.ranges.retain(|range| {
+            let joins = range.from <= merged.to.saturating_add(1)
+                && merged.from <= range.to.saturating_add(1);
+
+            if joins {
+                merged.from = merged.from.min(range.from);
+                merged.to = merged.to.max(range.to);
+            }
+
+            !joins
+        });
+
+        let at = self.ranges.partition_point(|range| range.to < merged.from);
+        self.ranges.insert(at, merged);
+
+        if self.ranges.len() > MAX_PROCESSED_RANGES {
+            self.ranges.remove(0);
+        }
+    }
+
+    /// Blocks between the processed ranges, oldest first
+    pub fn gaps(&self) -> Vec<BlockRange> {
+        self.ranges
+            .windows(2)
+            .map(|pair| BlockRange {
+                from: pair[0].to + 1,
+                to: pair[1].from - 1,
+            })
+            .collect()
+    }
+}
+
+impl Indexer {
+    /// Reads the block ranges skipped by the persisted cursor, so that they can be reindexed
+    ///
+    /// Only block cursors are tracked, networks indexed by transaction never report gaps.
+    pub fn find_gaps(&self) -> Result<Vec<BlockRange>> {
+        Ok(PersistedState::from_file(&self.state_file)?
+            .processed_ranges
+            .gaps())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gap_between_ranges_is_found() {
+        let mut ranges = ProcessedRanges::default();
+
+        ranges.advance(&IndexingCursor::None, &IndexingCursor::Block(10));
+        ranges.advance(&IndexingCursor::Block(10), &IndexingCursor::Block(20));
+        assert!(ranges.gaps().is_empty());
+
+        // The cursor jumped from 20 to 30
+        ranges.advance(&IndexingCursor::Block(30), &IndexingCursor::Block(40));
+        // A rewind doesn't record anything
+        ranges.advance(&IndexingCursor::Block(40), &IndexingCursor::Block(35));
+        ranges.record(50, 60);
+
+        assert_eq!(
+            ranges.gaps(),
+            [
+                BlockRange { from: 21, to: 30 },
+                BlockRange { from: 41, to: 49 }
+            ]
+        );
+
+        // Filling a gap merges the ranges around it
+        ranges.record(21, 49);
+        assert!(ranges.gaps().is_empty());
+        assert_eq!(ranges.ranges, [BlockRange { from: 10, to: 60 }]);
+    }
+}
@@ -10,3 +10,50 @@ pub(crate) enum IndexerJob {
     Log(Log),
     TransactionReceipt(NearReceipt),
 }
+
+impl IndexerJob {
+    /// Number and hash of the block, for jobs of chains that may be reorganized
+    pub fn block(&self) -> Option<(u64, String)> {
+        match self {
+            IndexerJob::Log(log) => {
+                Some((log.block_number?.as_u64(), format!("{:?}", log.block_hash?)))
+            }
+            IndexerJob::Transaction(_) | IndexerJob::TransactionReceipt(_) => None,
+        }
+    }
+
+    /// Block number or height of the job, Solana transactions are not tied to one
+    pub fn height(&self) -> Option<u64> {
+        match self {
+            IndexerJob::Log(log) => log.block_number.map(|number| number.as_u64()),
+            IndexerJob::TransactionReceipt(receipt) => Some(receipt.block_height),
+            IndexerJob::Transaction(_) => None,
+        }
+    }
+
+    /// Position of the event on the chain, which tells if it survived a reorganization
+    pub fn event_key(&self) -> Option<String> {
+        match self {
+            IndexerJob::Log(log) => log_event_key(log),
+            IndexerJob::Transaction(_) | IndexerJob::TransactionReceipt(_) => None,
+        }
+    }
+}
+
+/// Transaction hash and log index
+pub(crate) fn log_event_key(log: &Log) -> Option<String> {
+    Some(format!(
+        "{:?}:{}",
+        log.transaction_hash?,
+        log.log_index.unwrap_or_default()
+    ))
+}
+
+/// Job that failed to process and was skipped, so that the jobs after it are indexed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DeadLetter {
+    pub job: IndexerJob,
+    pub error: String,
+    /// Unix timestamp of the failure
+    pub failed_at: u64,
+}
@@ -3,26 +3,41 @@ use {
     std::{
         collections::VecDeque,
         path::PathBuf,
-        sync::Arc,
+        sync::{
+            atomic::{AtomicBool, AtomicU64},
+            Arc,
+        },
         time::{Duration, SystemTime, UNIX_EPOCH},
     },
-    tokio::sync::Mutex,
+    tokio::sync::{watch, Mutex},
+    tokio_util::sync::CancellationToken,
 };
 
+pub(crate) mod breaker;
 pub(crate) mod client;
+pub(crate) mod error;
+pub(crate) mod gaps;
 pub(crate) mod heartbeat;
 pub(crate) mod jobs;
 pub(crate) mod jwt;
 pub(crate) mod logic;
 pub(crate) mod persistence;
+pub(crate) mod progress;
 pub(crate) mod push;
+pub(crate) mod reindex;
+pub(crate) mod reload;
+pub(crate) mod reorg;
+pub(crate) mod scheduler;
 pub(crate) mod server;
+pub(crate) mod shutdown;
 pub(crate) mod state;
 
 pub(crate) use {
     client::IndexerClient,
+    error::IndexerError,
     jobs::IndexerJob,
     persistence::PersistedState,
+    push::PushPayload,
     state::{IndexerState, IndexingCursor},
 };
 
@@ -34,12 +49,90 @@ pub struct Indexer {
     /// Current state of the indexer
     state: Arc<Mutex<IndexerState>>,
 
+    /// Cursor of the last persisted indexing position
+    cursor: Arc<Mutex<IndexingCursor>>,
+
+    /// The latest chain head returned by the node
+    chain_head: Arc<Mutex<Option<IndexingCursor>>>,
+
+    /// Cursor supplied by an operator to start from instead of the persisted one
+    start_cursor: Option<IndexingCursor>,
+
+    /// Version of the contract read at startup, if the contract keeps one
+    contract_version: Arc<Mutex<Option<u64>>>,
+
+    /// Unix timestamp of the last completed indexing iteration
+    last_iteration: Arc<AtomicU64>,
+
+    /// The maximum time without completed iterations before the indexer is reported as stalled
+    watchdog_threshold: Duration,
+
     /// Stack of transactions to index
     jobs: VecDeque<IndexerJob>,
 
+    /// Payloads that failed to be delivered to the webhook, in the order of events
+    pending_pushes: VecDeque<PushPayload>,
+
+    /// The maximum number of undelivered payloads before the indexing is paused
+    max_pending_pushes: usize,
+
+    /// How undelivered payloads are retried
+    push_retry: push::PushRetry,
+
+    /// Webhook delivery counters, shared with the server
+    push_metrics: Arc<push::PushMetrics>,
+
+    /// Payloads that failed to be delivered and were skipped, persisted along with the cursor
+    dead_pushes: Vec<push::DeadPush>,
+
+    /// Block ranges that the persisted cursor has moved over
+    processed_ranges: gaps::ProcessedRanges,
+
+    /// Payloads pushed from the latest blocks, which may still be replaced by a reorganization
+    recent_blocks: reorg::RecentBlocks,
+
+    /// The number of latest blocks to remember payloads of
+    reorg_depth: u64,
+
+    /// Jobs that failed to process and were skipped, persisted along with the cursor
+    dead_letters: Vec<jobs::DeadLetter>,
+
+    /// The number of failed attempts to process the job at the front of the queue
+    failed_attempts: u32,
+
     /// The number of milliseconds between wait checks
     wait_interval_ms: Duration,
 
+    /// The pause before the next check for updates once the indexer has caught up with the chain
+    idle_interval: Duration,
+
+    /// The pause before the next check for updates while the node serves a chain head behind the cursor
+    stale_head_wait: Duration,
+
+    /// Halts the indexing once too many logs fail to decode
+    decode_breaker: breaker::DecodeBreaker,
+
+    /// How the progress of long runs of jobs is reported
+    progress_output: progress::ProgressOutput,
+
+    /// Progress of the jobs fetched by the last check for updates
+    backlog: Option<progress::ProgressReporter>,
+
+    /// Set by an operator to stop fetching without leaving the current state and cursor
+    paused: Arc<AtomicBool>,
+
+    /// Cancelled once the process shuts down, after the server has stopped
+    shutdown: CancellationToken,
+
+    /// Cancelled once the process shuts down, stops the server
+    server_shutdown: CancellationToken,
+
+    /// Turns shared with indexers of other networks in the same process
+    scheduler: Option<scheduler::SchedulerShare>,
+
+    /// Settings reloaded from the configuration file while running
+    settings: Option<watch::Receiver<reload::RuntimeSettings>>,
+
     /// Abstract client to access blockchain data
     client: IndexerClient,
 
@@ -54,4 +147,7 @@ pub struct Indexer {
 
     /// JWT token to use for webhooks
     jwt_token: String,
+
+    /// Encoding of the payloads sent to the webhook
+    push_format: push::PushFormat,
 }
@@ -7,11 +7,29 @@ use {
     },
 };
 
-use super::IndexingCursor;
+use super::{
+    gaps::ProcessedRanges, jobs::DeadLetter, push::DeadPush, reorg::RecentBlocks, IndexingCursor,
+};
 
 #[derive(Serialize, Deserialize)]
 pub struct PersistedState {
     pub cursor: IndexingCursor,
+
+    /// Payloads of the latest blocks, kept to withdraw them after a reorganization
+    #[serde(default)]
+    pub recent_blocks: RecentBlocks,
+
+    /// Jobs skipped after a failure, the latest ones last
+    #[serde(default)]
+    pub(crate) dead_letters: Vec<DeadLetter>,
+
+    /// Payloads skipped after failed deliveries, the latest ones last
+    #[serde(default)]
+    pub(crate) dead_pushes: Vec<DeadPush>,
+
+    /// Block ranges that the cursor has moved over, to find the skipped ones
+    #[serde(default)]
+    pub processed_ranges: ProcessedRanges,
 }
 
 impl PersistedState {
@@ -0,0 +1,242 @@
+use std::{
+    io::{IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+/// The minimum time between two progress reports
+const REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Width of the progress bar in characters
+const BAR_WIDTH: usize = 30;
+
+/// How the progress of long indexing runs is reported
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProgressOutput {
+    #[default]
+    Off,
+    /// A progress bar redrawn in place on stderr
+    Bar,
+    /// Periodic log lines
+    Log,
+}
+
+impl ProgressOutput {
+    /// A bar if stderr is a terminal, log lines otherwise, nothing with JSON logging
+    pub fn detect(is_json_logging: bool) -> Self {
+        if is_json_logging {
+            ProgressOutput::Off
+        } else if std::io::stderr().is_terminal() {
+            ProgressOutput::Bar
+        } else {
+            ProgressOutput::Log
+        }
+    }
+}
+
+/// Position of a run that is being reported
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ProgressSnapshot {
+    pub done: usize,
+    pub total: usize,
+    /// Block of the last processed job, if the jobs of the network have blocks
+    pub block: Option<u64>,
+    pub target_block: Option<u64>,
+    /// Processed jobs per second
+    pub rate: f64,
+    pub eta: Option<Duration>,
+}
+
+impl ProgressSnapshot {
+    fn percent(&self) -> usize {
+        (self.done * 100).checked_div(self.total).unwrap_or(100)
+    }
+
+    fn describe(&self) -> String {
+        let mut line = String::new();
+
+        if let (Some(block), Some(target)) = (self.block, self.target_block) {
+            line.push_str(&format!("block {block}/{target}, "));
+        }
+
+        line.push_str(&format!(
+            "{}/{} jobs ({}%), {:.1} jobs/s",
+            self.done,
+            self.total,
+            self.percent(),
+            self.rate
+        ));
+
+        if let Some(eta) = self.eta {
+            line.push_str(&format!(", ETA {}", format_duration(eta)));
+        }
+
+        line
+    }
+}
+
+/// Reports how far a run of queued jobs has got
+pub(crate) struct ProgressReporter {
+    output: ProgressOutput,
+    label: &'static str,
+    total: usize,
+    done: usize,
+    block: Option<u64>,
+    target_block: Option<u64>,
+    started: Instant,
+    last_report: Instant,
+    reported: bool,
+}
+
+impl ProgressReporter {
+    pub fn new(
+        output: ProgressOutput,
+        label: &'static str,
+        total: usize,
+        target_block: Option<u64>,
+    ) -> Self {
+        let now = Instant::now();
+
+        Self {
+            output,
+            label,
+            total,
+            done: 0,
+            block: None,
+            target_block,
+            started: now,
+            last_report: now,
+            reported: false,
+        }
+    }
+
+    /// Counts a processed job and reports the progress once the interval has passed
+    pub fn advance(&mut self, block: Option<u64>) {
+        self.done += 1;
+        self.block = block.or(self.block);
+
+        let now = Instant::now();
+        if now.duration_since(self.last_report) >= REPORT_INTERVAL {
+            self.last_report = now;
+            self.reported = true;
+            self.report(&self.snapshot(now));
+        }
+    }
+
+    /// Reports the final position of a run that took long enough to be reported
+    pub fn finish(&mut self) {
+        if !self.reported {
+            return;
+        }
+
+        self.report(&self.snapshot(Instant::now()));
+
+        if self.output == ProgressOutput::Bar {
+            eprintln!();
+        }
+    }
+
+    pub fn snapshot(&self, now: Instant) -> ProgressSnapshot {
+        let elapsed = now.duration_since(self.started).as_secs_f64();
+        let rate = if elapsed > 0.0 {
+            self.done as f64 / elapsed
+        } else {
+            0.0
+        };
+
+        let remaining = self.total.saturating_sub(self.done);
+        let eta = (rate > 0.0).then(|| Duration::from_secs_f64(remaining as f64 / rate));
+
+        ProgressSnapshot {
+            done: self.done,
+            total: self.total,
+            block: self.block,
+            target_block: self.target_block,
+            rate,
+            eta,
+        }
+    }
+
+    fn report(&self, snapshot: &ProgressSnapshot) {
+        match self.output {
+            ProgressOutput::Off => {}
+            ProgressOutput::Log => {
+                tracing::info!(
+                    done = snapshot.done,
+                    total = snapshot.total,
+                    block = snapshot.block,
+                    target_block = snapshot.target_block,
+                    "{}: {}",
+                    self.label,
+                    snapshot.describe()
+                );
+            }
+            ProgressOutput::Bar => {
+                let filled = BAR_WIDTH * snapshot.percent() / 100;
+                let mut stderr = std::io::stderr().lock();
+
+                // Progress output is best effort, a closed stderr must not stop indexing
+                let _ = write!(
+                    stderr,
+                    "\r{} [{}{}] {}\x1b[K",
+                    self.label,
+                    "#".repeat(filled),
+                    "-".repeat(BAR_WIDTH - filled),
+                    snapshot.describe()
+                );
+                let _ = stderr.flush();
+            }
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+
+    match (seconds / 3600, seconds % 3600 / 60, seconds % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, s) => format!("{m}m {s}s"),
+        (h, m, _) => format!("{h}h {m}m"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_and_eta_follow_processed_jobs() {
+        let mut reporter = ProgressReporter::new(ProgressOutput::Off, "Reindexing", 100, Some(500));
+
+        for block in 0..25 {
+            reporter.advance(Some(100 + block));
+        }
+        reporter.advance(None);
+
+        let snapshot = reporter.snapshot(reporter.started + Duration::from_secs(13));
+
+        assert_eq!(snapshot.done, 26);
+        assert_eq!(snapshot.block, Some(124));
+        assert_eq!(snapshot.rate, 2.0);
+        assert_eq!(snapshot.eta, Some(Duration::from_secs(37)));
+        assert_eq!(
+            snapshot.describe(),
+            "block 124/500, 26/100 jobs (26%), 2.0 jobs/s, ETA 37s"
+        );
+    }
+
+    #[test]
+    fn no_eta_before_any_job() {
+        let reporter = ProgressReporter::new(ProgressOutput::Off, "Indexing", 0, None);
+        let snapshot = reporter.snapshot(reporter.started);
+
+        assert_eq!(snapshot.eta, None);
+        assert_eq!(snapshot.describe(), "0/0 jobs (100%), 0.0 jobs/s");
+    }
+
+    #[test]
+    fn durations_are_readable() {
+        assert_eq!(format_duration(Duration::from_secs(59)), "59s");
+        assert_eq!(format_duration(Duration::from_secs(61)), "1m 1s");
+        assert_eq!(format_duration(Duration::from_secs(7322)), "2h 2m");
+    }
+}
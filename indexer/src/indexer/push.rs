@@ -7,20 +7,163 @@ use {
         },
         HapiCoreNetwork,
     },
-    serde::{Deserialize, Serialize},
+    reqwest::header::CONTENT_TYPE,
+    serde::{de, de::DeserializeOwned, Deserialize, Deserializer, Serialize},
+    serde_json::Value,
+    std::{
+        sync::atomic::{AtomicU64, Ordering},
+        time::Duration,
+    },
+    tokio::time::sleep,
     uuid::Uuid,
 };
 
-use super::Indexer;
+use super::{now, Indexer};
+
+/// The number of dead-lettered payloads kept in the persisted state
+const MAX_DEAD_PUSHES: usize = 1000;
+
+/// Version of the payload shape, bumped with every change that older payloads don't fit
+///
+/// Version 1 payloads were sent before the version field was introduced.
+pub const PUSH_SCHEMA_VERSION: u32 = 2;
+
+/// Encoding of the payloads sent to the webhook, told to the receiver by the `Content-Type` header
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PushFormat {
+    #[default]
+    Json,
+    /// MessagePack with named fields, smaller and faster to parse than JSON
+    Msgpack,
+}
+
+impl PushFormat {
+    pub const JSON_CONTENT_TYPE: &'static str = "application/json";
+    pub const MSGPACK_CONTENT_TYPE: &'static str = "application/msgpack";
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => Self::JSON_CONTENT_TYPE,
+            Self::Msgpack => Self::MSGPACK_CONTENT_TYPE,
+        }
+    }
+
+    /// The format of a request body, `None` for the media types other than MessagePack and JSON
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+
+        match media_type.to_ascii_lowercase().as_str() {
+            "application/json" => Some(Self::Json),
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(Self::Msgpack)
+            }
+            _ => None,
+        }
+    }
+
+    /// Encodes the value, MessagePack keeps the field names and the text form of
+    /// identifiers, so that both formats carry the same document
+    pub fn encode<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>> {
+        Ok(match self {
+            Self::Json => serde_json::to_vec(value)?,
+            Self::Msgpack => {
+                let mut buffer = vec![];
+                value.serialize(
+                    &mut rmp_serde::Serializer::new(&mut buffer)
+                        .with_struct_map()
+                        .with_human_readable(),
+                )?;
+                buffer
+            }
+        })
+    }
+
+    pub fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T> {
+        Ok(match self {
+            Self::Json => serde_json::from_slice(bytes)?,
+            Self::Msgpack => {
+                T::deserialize(&mut rmp_serde::Deserializer::new(bytes).with_human_readable())?
+            }
+        })
+    }
+}
 
 /// Webhook payload
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+///
+/// Payloads of older schema versions, i.e. kept in the state file or a replay log, are
+/// upgraded to the current shape on deserialization.
+#[derive(Serialize, Debug, PartialEq, Clone)]
 pub struct PushPayload {
+    pub schema_version: u32,
     pub network_data: NetworkData,
     pub event: PushEvent,
     pub data: PushData,
 }
 
+/// Payload in the shape of the current schema version
+#[derive(Deserialize)]
+struct CurrentPushPayload {
+    schema_version: u32,
+    network_data: NetworkData,
+    event: PushEvent,
+    data: PushData,
+}
+
+impl<'de> Deserialize<'de> for PushPayload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value =
+            upgrade_payload(Value::deserialize(deserializer)?).map_err(de::Error::custom)?;
+        let payload: CurrentPushPayload =
+            serde_json::from_value(value).map_err(de::Error::custom)?;
+
+        Ok(Self {
+            schema_version: payload.schema_version,
+            network_data: payload.network_data,
+            event: payload.event,
+            data: payload.data,
+        })
+    }
+}
+
+/// Brings a payload of any known schema version to the current one, step by step
+fn upgrade_payload(mut value: Value) -> Result<Value, String> {
+    let version = match value.get("schema_version") {
+        None => 1,
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| format!("invalid payload schema version: {version}"))?,
+    };
+
+    if version > PUSH_SCHEMA_VERSION {
+        return Err(format!(
+            "payload schema version {version} is newer than the supported {PUSH_SCHEMA_VERSION}"
+        ));
+    }
+
+    if version < 2 {
+        value = upgrade_v1(value)?;
+    }
+
+    Ok(value)
+}
+
+/// Version 1 payloads have no version field and may lack the reorganization flag
+fn upgrade_v1(mut value: Value) -> Result<Value, String> {
+    let payload = value
+        .as_object_mut()
+        .ok_or_else(|| "payload must be an object".to_string())?;
+
+    if let Some(event) = payload.get_mut("event").and_then(Value::as_object_mut) {
+        event.entry("removed").or_insert(Value::Bool(false));
+    }
+
+    payload.insert("schema_version".to_string(), Value::from(2));
+
+    Ok(value)
+}
+
 /// Network data
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct NetworkData {
@@ -43,6 +186,9 @@ pub struct PushEvent {
     pub tx_index: u64,
     /// Timestamp of the transaction block
     pub timestamp: u64,
+    /// Whether the event was dropped from the canonical chain by a reorganization
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub removed: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -77,6 +223,63 @@ impl From<Reporter> for PushData {
     }
 }
 
+/// Delivery attempts of a webhook payload, apart from the retries of RPC requests
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PushRetry {
+    pub attempts: u32,
+    pub backoff: Duration,
+    pub max_backoff: Duration,
+    /// Whether a payload that failed every attempt is dead-lettered instead of kept for retry
+    pub dead_letter: bool,
+}
+
+impl PushRetry {
+    /// The pause after the given number of failed attempts
+    fn backoff(&self, failed_attempts: u32) -> Duration {
+        let factor = 2u32.saturating_pow(failed_attempts.saturating_sub(1));
+        self.backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+/// Webhook delivery counters since the start of the indexer
+#[derive(Debug, Default)]
+pub(crate) struct PushMetrics {
+    delivered: AtomicU64,
+    retried: AtomicU64,
+    failed: AtomicU64,
+    dead_lettered: AtomicU64,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub(crate) struct PushMetricsOutput {
+    pub delivered: u64,
+    /// Failed attempts that were followed by another one
+    pub retried: u64,
+    /// Deliveries that failed every attempt
+    pub failed: u64,
+    pub dead_lettered: u64,
+}
+
+impl PushMetrics {
+    pub fn snapshot(&self) -> PushMetricsOutput {
+        PushMetricsOutput {
+            delivered: self.delivered.load(Ordering::Relaxed),
+            retried: self.retried.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            dead_lettered: self.dead_lettered.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Payload that failed every delivery attempt and was skipped
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DeadPush {
+    pub payload: PushPayload,
+    pub error: String,
+    /// Unix timestamp of the failure
+    pub failed_at: u64,
+}
+
 impl Indexer {
     pub(crate) async fn send_webhook(&self, payload: &PushPayload) -> Result<()> {
         let url = format!("{}/events", self.webhook_url,);
@@ -85,7 +288,8 @@ impl Indexer {
             .web_client
             .post(url)
             .bearer_auth(self.jwt_token.as_str())
-            .json(payload)
+            .header(CONTENT_TYPE, self.push_format.content_type())
+            .body(self.push_format.encode(payload)?)
             .send()
             .await?;
 
@@ -95,6 +299,78 @@ impl Indexer {
 
         Ok(())
     }
+
+    /// Delivers the payload, pausing between failed attempts with a doubling backoff
+    pub(crate) async fn push_with_retry(&self, payload: &PushPayload) -> Result<()> {
+        let mut attempt = 1;
+
+        loop {
+            match self.send_webhook(payload).await {
+                Ok(()) => {
+                    self.push_metrics.delivered.fetch_add(1, Ordering::Relaxed);
+                    return Ok(());
+                }
+                Err(error) if attempt < self.push_retry.attempts => {
+                    let backoff = self.push_retry.backoff(attempt);
+                    tracing::debug!(%error, attempt, ?backoff, "Webhook delivery failed, retrying");
+
+                    self.push_metrics.retried.fetch_add(1, Ordering::Relaxed);
+                    sleep(backoff).await;
+                    attempt += 1;
+                }
+                Err(error) => {
+                    self.push_metrics.failed.fetch_add(1, Ordering::Relaxed);
+                    return Err(error);
+                }
+            }
+        }
+    }
+
+    /// Delivers buffered payloads in order, stopping at the first failure unless
+    /// failed payloads are dead-lettered
+    pub(crate) async fn flush_pending_pushes(&mut self) {
+        while let Some(payload) = self.pending_pushes.front() {
+            if let Err(error) = self.push_with_retry(payload).await {
+                if !self.push_retry.dead_letter {
+                    tracing::warn!(
+                        %error,
+                        pending = self.pending_pushes.len(),
+                        "Webhook delivery failed, payloads are kept for retry"
+                    );
+                    return;
+                }
+
+                if let Some(payload) = self.pending_pushes.pop_front() {
+                    self.dead_letter_push(payload, &error);
+                }
+                continue;
+            }
+
+            self.pending_pushes.pop_front();
+        }
+    }
+
+    /// Records an undelivered payload, the oldest records are dropped once there are too many
+    fn dead_letter_push(&mut self, payload: PushPayload, error: &anyhow::Error) {
+        tracing::error!(
+            %error,
+            event = ?payload.event,
+            "Webhook delivery failed every attempt, payload is dead-lettered"
+        );
+
+        if self.dead_pushes.len() >= MAX_DEAD_PUSHES {
+            self.dead_pushes.remove(0);
+        }
+
+        self.dead_pushes.push(DeadPush {
+            payload,
+            error: error.to_string(),
+            failed_at: now().unwrap_or_default(),
+        });
+        self.push_metrics
+            .dead_lettered
+            .fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +383,7 @@ mod tests {
     fn test_push_payload_serialization() {
         // Create a sample PushPayload
         let payload = PushPayload {
+            schema_version: PUSH_SCHEMA_VERSION,
             network_data: NetworkData {
                 indexer_id: uuid::uuid!("f6b9e9a0-9b7a-4e1a-8b0a-9e2a5e8e4b5e"),
                 network: HapiCoreNetwork::Ethereum,
@@ -118,6 +395,7 @@ mod tests {
                     .to_string(),
                 tx_index: 0,
                 timestamp: 1690888679,
+                removed: false,
             },
             data: PushData::Address(Address {
                 address: "0x922ffdfcb57de5dd6f641f275e98b684ce5576a3".to_string(),
@@ -134,7 +412,7 @@ mod tests {
 
         assert_eq!(
             json,
-            r#"{"network_data":{"indexer_id":"f6b9e9a0-9b7a-4e1a-8b0a-9e2a5e8e4b5e","network":"Ethereum","chain_id":null},"event":{"name":"create_address","tx_hash":"acf0734ab380f3964e1f23b1fd4f5a5125250208ec17ff11c9999451c138949f","tx_index":0,"timestamp":1690888679},"data":{"Address":{"address":"0x922ffdfcb57de5dd6f641f275e98b684ce5576a3","case_id":"de1659f2-b802-49ee-98dd-6e4ce0453067","reporter_id":"1466cf4f-1d71-4153-b9ad-4a9c1b48101e","risk":0,"category":"None","confirmations":3}}}"#
+            r#"{"schema_version":2,"network_data":{"indexer_id":"f6b9e9a0-9b7a-4e1a-8b0a-9e2a5e8e4b5e","network":"Ethereum","chain_id":null},"event":{"name":"create_address","tx_hash":"acf0734ab380f3964e1f23b1fd4f5a5125250208ec17ff11c9999451c138949f","tx_index":0,"timestamp":1690888679},"data":{"Address":{"address":"0x922ffdfcb57de5dd6f641f275e98b684ce5576a3","case_id":"de1659f2-b802-49ee-98dd-6e4ce0453067","reporter_id":"1466cf4f-1d71-4153-b9ad-4a9c1b48101e","risk":0,"category":"None","confirmations":3}}}"#
         );
 
         // Deserialize the JSON back into a PushPayload
@@ -143,4 +421,109 @@ mod tests {
         // Ensure that the deserialized PushPayload matches the original
         assert_eq!(payload, deserialized_payload);
     }
+
+    #[test]
+    fn payload_round_trips_in_every_format() {
+        let payload = PushPayload {
+            schema_version: PUSH_SCHEMA_VERSION,
+            network_data: NetworkData {
+                indexer_id: uuid::uuid!("f6b9e9a0-9b7a-4e1a-8b0a-9e2a5e8e4b5e"),
+                network: HapiCoreNetwork::Sepolia,
+                chain_id: Some("11155111".to_string()),
+            },
+            event: PushEvent {
+                name: EventName::UpdateAddress,
+                tx_hash: "0x01".to_string(),
+                tx_index: 3,
+                timestamp: 1690888679,
+                removed: true,
+            },
+            data: PushData::Address(Address {
+                address: "0x922ffdfcb57de5dd6f641f275e98b684ce5576a3".to_string(),
+                case_id: uuid::uuid!("de1659f2-b802-49ee-98dd-6e4ce0453067"),
+                reporter_id: uuid::uuid!("1466cf4f-1d71-4153-b9ad-4a9c1b48101e"),
+                category: Category::Scam,
+                risk: 7,
+                confirmations: 0,
+            }),
+        };
+
+        for format in [PushFormat::Json, PushFormat::Msgpack] {
+            let bytes = format.encode(&payload).unwrap();
+            let decoded: PushPayload = format.decode(&bytes).unwrap();
+            assert_eq!(decoded, payload, "{format:?}");
+
+            let batch = vec![payload.clone(), payload.clone()];
+            let decoded: Vec<PushPayload> = format.decode(&format.encode(&batch).unwrap()).unwrap();
+            assert_eq!(decoded, batch, "{format:?}");
+
+            // Both formats carry the same document, so the schema version is checked alike
+            let document: Value = format.decode(&bytes).unwrap();
+            assert_eq!(document["schema_version"], PUSH_SCHEMA_VERSION);
+            assert_eq!(
+                document["network_data"]["indexer_id"],
+                "f6b9e9a0-9b7a-4e1a-8b0a-9e2a5e8e4b5e"
+            );
+
+            let mut newer = document;
+            newer["schema_version"] = Value::from(PUSH_SCHEMA_VERSION + 1);
+            let bytes = format.encode(&newer).unwrap();
+            assert!(format.decode::<PushPayload>(&bytes).is_err(), "{format:?}");
+        }
+
+        assert!(
+            PushFormat::Msgpack.encode(&payload).unwrap().len()
+                < PushFormat::Json.encode(&payload).unwrap().len()
+        );
+    }
+
+    #[test]
+    fn push_format_is_told_by_content_type() {
+        for format in [PushFormat::Json, PushFormat::Msgpack] {
+            assert_eq!(
+                PushFormat::from_content_type(format.content_type()),
+                Some(format)
+            );
+        }
+
+        assert_eq!(
+            PushFormat::from_content_type("application/json; charset=utf-8"),
+            Some(PushFormat::Json)
+        );
+        assert_eq!(
+            PushFormat::from_content_type("application/x-msgpack"),
+            Some(PushFormat::Msgpack)
+        );
+        assert_eq!(PushFormat::from_content_type("text/plain"), None);
+    }
+
+    #[test]
+    fn push_backoff_is_doubled_up_to_max() {
+        let retry = PushRetry {
+            attempts: 5,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(300),
+            dead_letter: false,
+        };
+
+        let pauses: Vec<u128> = (1..5).map(|n| retry.backoff(n).as_millis()).collect();
+        assert_eq!(pauses, [100, 200, 300, 300]);
+    }
+
+    #[test]
+    fn v1_payload_is_upgraded() {
+        let json = r#"{"network_data":{"indexer_id":"f6b9e9a0-9b7a-4e1a-8b0a-9e2a5e8e4b5e","network":"Ethereum","chain_id":null},"event":{"name":"create_case","tx_hash":"0x01","tx_index":0,"timestamp":1690888679},"data":{"Case":{"id":"de1659f2-b802-49ee-98dd-6e4ce0453067","name":"Case","url":"https://case.com","status":"Open","reporter_id":"1466cf4f-1d71-4153-b9ad-4a9c1b48101e"}}}"#;
+
+        let payload: PushPayload = serde_json::from_str(json).unwrap();
+
+        assert_eq!(payload.schema_version, PUSH_SCHEMA_VERSION);
+        assert_eq!(payload.event.name, EventName::CreateCase);
+        assert!(!payload.event.removed);
+        assert!(matches!(payload.data, PushData::Case(ref case) if case.name == "Case"));
+
+        // Payloads of a newer indexer are not guessed at
+        let mut newer: Value = serde_json::from_str(json).unwrap();
+        newer["schema_version"] = Value::from(PUSH_SCHEMA_VERSION + 1);
+        assert!(serde_json::from_value::<PushPayload>(newer).is_err());
+    }
 }
@@ -0,0 +1,48 @@
+use anyhow::{bail, Result};
+
+use super::{progress::ProgressReporter, Indexer};
+
+impl Indexer {
+    /// Fetches the events of the closed block range and pushes their payloads again
+    ///
+    /// Neither the cursor nor the state file is touched, so the range can be reindexed
+    /// while the live indexer is running. Payloads hold the current on-chain state of the
+    /// entities and the webhook receiver applies repeated ones as updates.
+    pub async fn reindex(&self, from_block: u64, to_block: u64) -> Result<usize> {
+        if from_block > to_block {
+            bail!("Invalid block range: {from_block} is greater than {to_block}");
+        }
+
+        tracing::info!(from_block, to_block, "Reindexing block range");
+
+        let jobs = self.client.fetch_range_jobs(from_block, to_block).await?;
+        let mut pushed = 0;
+        let mut progress = ProgressReporter::new(
+            self.progress_output,
+            "Reindexing",
+            jobs.len(),
+            Some(to_block),
+        );
+
+        for job in &jobs {
+            let processed = self.client.handle_process(job).await?;
+            progress.advance(job.height());
+
+            let Some(payloads) = processed else {
+                continue;
+            };
+
+            // Unlike the live indexing, payloads failing every attempt are not kept for retry:
+            // the whole range can be safely pushed again
+            for payload in &payloads {
+                self.push_with_retry(payload).await?;
+                pushed += 1;
+            }
+        }
+
+        progress.finish();
+        tracing::info!(jobs = jobs.len(), pushed, "Block range is reindexed");
+
+        Ok(pushed)
+    }
+}
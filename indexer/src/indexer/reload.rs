@@ -0,0 +1,167 @@
+use {
+    anyhow::Result,
+    std::{
+        path::{Path, PathBuf},
+        time::Duration,
+    },
+    tokio::{
+        sync::{mpsc, watch},
+        time::sleep,
+    },
+};
+
+use crate::{
+    configuration::{load_configuration, Configuration},
+    indexer::client::PageSizes,
+    observability::reload_configured_filter,
+};
+
+/// Settings that are applied to the running indexer once the configuration file changes
+///
+/// Everything else, i.e. the RPC node, webhook and contract, takes a restart.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RuntimeSettings {
+    pub page_sizes: PageSizes,
+    pub wait_interval: Duration,
+    pub idle_interval: Duration,
+    pub fetching_delay: Duration,
+    pub max_pending_pushes: usize,
+}
+
+impl From<&Configuration> for RuntimeSettings {
+    fn from(cfg: &Configuration) -> Self {
+        let indexer = &cfg.indexer;
+
+        Self {
+            page_sizes: indexer.page_sizes(),
+            wait_interval: indexer.wait_interval_ms,
+            idle_interval: indexer.block_time().max(indexer.wait_interval_ms),
+            fetching_delay: indexer.fetching_delay,
+            max_pending_pushes: indexer.max_pending_pushes.max(1),
+        }
+    }
+}
+
+/// Settings that are read on startup only
+#[derive(Debug, PartialEq)]
+struct ConnectionSettings<'a> {
+    listener: &'a str,
+    network: String,
+    chain_id: Option<&'a str>,
+    rpc_node_url: &'a str,
+    rpc_node_fallback_urls: &'a [String],
+    log_source_url: Option<&'a str>,
+    webhook_url: &'a str,
+    contract_address: &'a str,
+    jwt_token: &'a str,
+    state_file: &'a str,
+}
+
+impl<'a> From<&'a Configuration> for ConnectionSettings<'a> {
+    fn from(cfg: &'a Configuration) -> Self {
+        let indexer = &cfg.indexer;
+
+        Self {
+            listener: &cfg.listener,
+            network: indexer.network.to_string(),
+            chain_id: indexer.chain_id.as_deref(),
+            rpc_node_url: &indexer.rpc_node_url,
+            rpc_node_fallback_urls: &indexer.rpc_node_fallback_urls,
+            log_source_url: indexer.log_source_url.as_deref(),
+            webhook_url: &indexer.webhook_url,
+            contract_address: &indexer.contract_address,
+            jwt_token: &indexer.jwt_token,
+            state_file: &indexer.state_file,
+        }
+    }
+}
+
+/// Sends a poll every interval until the receiver is dropped
+pub(crate) fn poll_every(interval: Duration) -> mpsc::Receiver<()> {
+    let (sender, receiver) = mpsc::channel(1);
+
+    tokio::spawn(async move {
+        loop {
+            sleep(interval).await;
+
+            if sender.send(()).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+/// Checks the configuration file on every poll and publishes the runtime settings of every valid change
+///
+/// A file that fails to parse is reported and skipped, the running settings stay in place.
+pub(crate) fn watch_configuration(
+    path: PathBuf,
+    mut polls: mpsc::Receiver<()>,
+) -> Result<watch::Receiver<RuntimeSettings>> {
+    let mut current = load(&path)?;
+    let mut contents = read_contents(&path);
+    let (sender, receiver) = watch::channel(RuntimeSettings::from(&current));
+
+    tokio::spawn(async move {
+        while polls.recv().await.is_some() {
+            if sender.is_closed() {
+                break;
+            }
+
+            // Contents are compared as modification times may have a coarse resolution
+            let last_contents = read_contents(&path);
+            if last_contents == contents {
+                continue;
+            }
+            contents = last_contents;
+
+            let cfg = match load(&path) {
+                Ok(cfg) => cfg,
+                Err(error) => {
+                    tracing::warn!(
+                        ?error,
+                        "Changed configuration is invalid, keeping the running one"
+                    );
+                    continue;
+                }
+            };
+
+            if ConnectionSettings::from(&cfg) != ConnectionSettings::from(&current) {
+                tracing::warn!("Connection settings were changed, they are applied on restart");
+            }
+
+            if (&cfg.log_level, &cfg.log_filter) != (&current.log_level, &current.log_filter) {
+                if let Err(error) =
+                    reload_configured_filter(&cfg.log_level, cfg.log_filter.as_deref())
+                {
+                    tracing::warn!(?error, "Failed to reload log filter");
+                }
+            }
+
+            let settings = RuntimeSettings::from(&cfg);
+            sender.send_if_modified(|running| {
+                let changed = *running != settings;
+                if changed {
+                    tracing::info!(?settings, "Runtime settings are reloaded");
+                    *running = settings;
+                }
+                changed
+            });
+
+            current = cfg;
+        }
+    });
+
+    Ok(receiver)
+}
+
+fn load(path: &Path) -> Result<Configuration> {
+    load_configuration(&path.to_string_lossy())
+        .map_err(|e| anyhow::anyhow!("Configuration parsing error: {e}"))
+}
+
+fn read_contents(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+}
@@ -0,0 +1,302 @@
+use {
+    hapi_core::client::events::EventName,
+    serde::{Deserialize, Serialize},
+    std::collections::{HashSet, VecDeque},
+};
+
+use super::push::{PushData, PushPayload};
+
+/// Payload pushed to the webhook with the position of its event on the chain
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PushedEvent {
+    /// Transaction hash and log index of the event
+    pub key: String,
+    pub payload: PushPayload,
+}
+
+/// Indexed block with the payloads pushed from it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PushedBlock {
+    pub number: u64,
+    pub hash: String,
+    pub events: Vec<PushedEvent>,
+}
+
+/// The latest indexed blocks that may still be replaced by a reorganization
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RecentBlocks {
+    /// Blocks with pushed payloads, oldest first
+    blocks: VecDeque<PushedBlock>,
+
+    /// Keys of rewound events that are on the new chain too, so they are not pushed again
+    #[serde(default)]
+    surviving: HashSet<String>,
+}
+
+impl RecentBlocks {
+    /// Remembers a pushed event, forgetting blocks that are `depth` blocks behind
+    pub fn record(&mut self, number: u64, hash: String, event: PushedEvent, depth: u64) {
+        match self.blocks.back_mut() {
+            Some(block) if block.number == number && block.hash == hash => {
+                block.events.push(event);
+            }
+            _ => self.blocks.push_back(PushedBlock {
+                number,
+                hash,
+                events: vec![event],
+            }),
+        }
+
+        while self
+            .blocks
+            .front()
+            .is_some_and(|block| block.number + depth <= number)
+        {
+            self.blocks.pop_front();
+        }
+    }
+
+    /// Blocks from the latest to the oldest
+    pub fn latest_first(&self) -> impl Iterator<Item = &PushedBlock> {
+        self.blocks.iter().rev()
+    }
+
+    /// Forgets the given number of the latest blocks, remembering their events that are
+    /// present on the new chain
+    pub fn rewind(&mut self, count: usize, present: &HashSet<String>) {
+        let at = self.blocks.len().saturating_sub(count);
+
+        for block in self.blocks.split_off(at) {
+            self.surviving.extend(
+                block
+                    .events
+                    .into_iter()
+                    .map(|event| event.key)
+                    .filter(|key| present.contains(key)),
+            );
+        }
+    }
+
+    /// Whether the event has been pushed before a reorganization and survived it
+    pub fn take_surviving(&mut self, key: &str) -> bool {
+        self.surviving.remove(key)
+    }
+}
+
+fn is_creation(name: &EventName) -> bool {
+    matches!(
+        name,
+        EventName::CreateReporter
+            | EventName::CreateCase
+            | EventName::CreateAddress
+            | EventName::CreateAsset
+    )
+}
+
+fn entity_key(data: &PushData) -> String {
+    match data {
+        PushData::Address(address) => format!("address:{}", address.address),
+        PushData::Asset(asset) => format!("asset:{}:{}", asset.address, asset.asset_id),
+        PushData::Case(case) => format!("case:{}", case.id),
+        PushData::Reporter(reporter) => format!("reporter:{}", reporter.id),
+    }
+}
+
+/// The event that brings an entity to its current state, whatever changed it before
+fn update_event(data: &PushData) -> EventName {
+    match data {
+        PushData::Address(_) => EventName::UpdateAddress,
+        PushData::Asset(_) => EventName::UpdateAsset,
+        PushData::Case(_) => EventName::UpdateCase,
+        PushData::Reporter(_) => EventName::UpdateReporter,
+    }
+}
+
+/// Payloads undoing the rewound events that are missing on the new chain, latest first
+///
+/// Creations are marked as removed, so the receiver drops the entity. Other events of an
+/// entity whose creation is removed are skipped, as the entity is gone altogether. The
+/// remaining ones become plain updates, whose data is the one seen before the
+/// reorganization until it is refreshed with the state on the new chain.
+pub fn removed_payloads(rewound: &[PushedBlock], present: &HashSet<String>) -> Vec<PushPayload> {
+    let missing: Vec<&PushedEvent> = rewound
+        .iter()
+        .flat_map(|block| block.events.iter())
+        .filter(|event| !present.contains(&event.key))
+        .collect();
+
+    let removed_entities: HashSet<String> = missing
+        .iter()
+        .filter(|event| is_creation(&event.payload.event.name))
+        .map(|event| entity_key(&event.payload.data))
+        .collect();
+
+    missing
+        .into_iter()
+        .filter(|event| {
+            is_creation(&event.payload.event.name)
+                || !removed_entities.contains(&entity_key(&event.payload.data))
+        })
+        .rev()
+        .map(|event| {
+            let mut payload = event.payload.clone();
+
+            if is_creation(&payload.event.name) {
+                payload.event.removed = true;
+            } else {
+                payload.event.name = update_event(&payload.data);
+            }
+
+            payload
+        })
+        .collect()
+}
+
+/// Whether the payload has to be refreshed with the entity state on the new chain
+pub fn needs_refresh(payload: &PushPayload) -> bool {
+    !is_creation(&payload.event.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::indexer::push::{NetworkData, PushEvent, PUSH_SCHEMA_VERSION},
+        hapi_core::{
+            client::entities::{
+                address::Address,
+                case::{Case, CaseStatus},
+                category::Category,
+            },
+            HapiCoreNetwork,
+        },
+        uuid::Uuid,
+    };
+
+    fn pushed_event(key: &str, name: EventName, address: &str) -> PushedEvent {
+        PushedEvent {
+            key: key.to_string(),
+            payload: PushPayload {
+                schema_version: PUSH_SCHEMA_VERSION,
+                network_data: NetworkData {
+                    indexer_id: Uuid::nil(),
+                    network: HapiCoreNetwork::Ethereum,
+                    chain_id: None,
+                },
+                event: PushEvent {
+                    name,
+                    tx_hash: key.to_string(),
+                    tx_index: 0,
+                    timestamp: 123,
+                    removed: false,
+                },
+                data: PushData::Address(Address {
+                    address: address.to_string(),
+                    case_id: Uuid::nil(),
+                    reporter_id: Uuid::nil(),
+                    risk: 5,
+                    category: Category::Scam,
+                    confirmations: 0,
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn reverted_changes_become_updates() {
+        let mut case_update = pushed_event("0xe:0", EventName::UpdateCase, "0x4");
+        case_update.payload.data = PushData::Case(Case {
+            id: Uuid::nil(),
+            name: "Case".to_string(),
+            url: "https://case.com".to_string(),
+            status: CaseStatus::Closed,
+            reporter_id: Uuid::nil(),
+            related_cases: vec![],
+        });
+
+        let rewound = vec![PushedBlock {
+            number: 12,
+            hash: "hash12".to_string(),
+            events: vec![
+                pushed_event("0xf:0", EventName::ConfirmAddress, "0x4"),
+                case_update,
+            ],
+        }];
+
+        let removed = removed_payloads(&rewound, &HashSet::new());
+
+        // Neither is dropped by the receiver, both take the state on the new chain
+        assert!(removed.iter().all(|payload| !payload.event.removed));
+        assert!(removed.iter().all(needs_refresh));
+        assert_eq!(removed[0].event.name, EventName::UpdateCase);
+        assert_eq!(removed[1].event.name, EventName::UpdateAddress);
+    }
+
+    #[test]
+    fn blocks_are_kept_within_depth() {
+        let mut blocks = RecentBlocks::default();
+
+        for number in [1, 1, 2, 5, 7] {
+            let event = pushed_event(&format!("0x{number}"), EventName::CreateAddress, "0x1");
+            blocks.record(number, format!("hash{number}"), event, 5);
+        }
+
+        let numbers: Vec<(u64, usize)> = blocks
+            .latest_first()
+            .map(|block| (block.number, block.events.len()))
+            .collect();
+        assert_eq!(numbers, vec![(7, 1), (5, 1)]);
+
+        blocks.rewind(1, &HashSet::from(["0x7".to_string()]));
+        assert_eq!(blocks.latest_first().count(), 1);
+
+        assert!(blocks.take_surviving("0x7"));
+        assert!(!blocks.take_surviving("0x7"));
+        assert!(!blocks.take_surviving("0x5"));
+    }
+
+    #[test]
+    fn missing_events_are_removed() {
+        let rewound = vec![
+            PushedBlock {
+                number: 10,
+                hash: "hash10".to_string(),
+                events: vec![
+                    pushed_event("0xa:0", EventName::CreateAddress, "0x1"),
+                    pushed_event("0xb:1", EventName::UpdateAddress, "0x2"),
+                ],
+            },
+            PushedBlock {
+                number: 11,
+                hash: "hash11".to_string(),
+                events: vec![
+                    pushed_event("0xc:0", EventName::UpdateAddress, "0x1"),
+                    pushed_event("0xd:0", EventName::CreateAddress, "0x3"),
+                ],
+            },
+        ];
+
+        // The creation of 0x3 made it to the new chain
+        let present = HashSet::from(["0xd:0".to_string()]);
+        let removed = removed_payloads(&rewound, &present);
+
+        let keys: Vec<(&str, EventName)> = removed
+            .iter()
+            .map(|payload| (payload.event.tx_hash.as_str(), payload.event.name.clone()))
+            .collect();
+
+        // The update of 0x1 is covered by the removal of its creation, entities that are
+        // left are brought to their state on the new chain
+        assert_eq!(
+            keys,
+            vec![
+                ("0xb:1", EventName::UpdateAddress),
+                ("0xa:0", EventName::CreateAddress),
+            ]
+        );
+        assert!(!removed[0].event.removed);
+        assert!(needs_refresh(&removed[0]));
+        assert!(removed[1].event.removed);
+        assert!(!needs_refresh(&removed[1]));
+    }
+}
@@ -0,0 +1,262 @@
+use {
+    serde::Serialize,
+    std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex,
+        },
+        time::Instant,
+    },
+    tokio::sync::{OwnedSemaphorePermit, Semaphore},
+};
+
+/// Indexing progress of a network that shares the scheduler
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct NetworkProgress {
+    pub network: String,
+    pub weight: u32,
+    /// The number of indexing iterations run so far
+    pub iterations: u64,
+    /// Total time spent waiting for a turn, in milliseconds
+    pub waited_ms: u64,
+}
+
+/// Limits the number of networks indexed at the same time within one process
+///
+/// Permits are handed out in the order they are requested, so a network that gives its
+/// permit back queues behind the others and a busy chain can't starve a slow one. A network
+/// keeps the permit for as many iterations as its weight before queueing again.
+#[derive(Clone)]
+pub struct IndexingScheduler {
+    permits: Arc<Semaphore>,
+    networks: Arc<Mutex<Vec<Arc<Mutex<NetworkProgress>>>>>,
+    pauses: PauseSwitches,
+}
+
+impl IndexingScheduler {
+    pub fn new(concurrency: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(concurrency.max(1))),
+            networks: Arc::default(),
+            pauses: PauseSwitches::default(),
+        }
+    }
+
+    /// Pauses or resumes the indexing of a network sharing the scheduler
+    ///
+    /// Returns false if no indexer of the network shares the scheduler.
+    pub fn set_paused(&self, network: &str, paused: bool) -> bool {
+        match self.pauses.get(network) {
+            Some(switch) => {
+                switch.store(paused, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Progress of every network sharing the scheduler
+    pub fn progress(&self) -> Vec<NetworkProgress> {
+        self.networks
+            .lock()
+            .expect("Scheduler lock is poisoned")
+            .iter()
+            .map(|progress| progress.lock().expect("Progress lock is poisoned").clone())
+            .collect()
+    }
+
+    pub(crate) fn share(
+        &self,
+        network: String,
+        weight: u32,
+        paused: Arc<AtomicBool>,
+    ) -> SchedulerShare {
+        self.pauses.register(network.clone(), paused);
+
+        let weight = weight.max(1);
+        let progress = Arc::new(Mutex::new(NetworkProgress {
+            network,
+            weight,
+            ..Default::default()
+        }));
+
+        self.networks
+            .lock()
+            .expect("Scheduler lock is poisoned")
+            .push(progress.clone());
+
+        SchedulerShare {
+            permits: self.permits.clone(),
+            weight,
+            permit: None,
+            turns_left: 0,
+            progress,
+            pauses: self.pauses.clone(),
+        }
+    }
+}
+
+/// Pause switches of the networks sharing the scheduler, by network name
+#[derive(Clone, Default)]
+pub(crate) struct PauseSwitches(Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>);
+
+impl PauseSwitches {
+    fn register(&self, network: String, paused: Arc<AtomicBool>) {
+        self.0
+            .lock()
+            .expect("Pause switches lock is poisoned")
+            .insert(network, paused);
+    }
+
+    pub fn get(&self, network: &str) -> Option<Arc<AtomicBool>> {
+        self.0
+            .lock()
+            .expect("Pause switches lock is poisoned")
+            .get(network)
+            .cloned()
+    }
+}
+
+/// Turns of a single network within the scheduler
+pub(crate) struct SchedulerShare {
+    permits: Arc<Semaphore>,
+    weight: u32,
+    permit: Option<OwnedSemaphorePermit>,
+    /// Iterations left before the permit is given back
+    turns_left: u32,
+    progress: Arc<Mutex<NetworkProgress>>,
+    pauses: PauseSwitches,
+}
+
+impl SchedulerShare {
+    /// Waits until the network may run the next indexing iteration
+    pub async fn turn(&mut self) {
+        if self.permit.is_none() || self.turns_left == 0 {
+            self.release();
+
+            let started = Instant::now();
+            let permit = self
+                .permits
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("Scheduler semaphore is never closed");
+
+            self.permit = Some(permit);
+            self.turns_left = self.weight;
+            self.lock_progress().waited_ms += started.elapsed().as_millis() as u64;
+        }
+
+        self.turns_left -= 1;
+        self.lock_progress().iterations += 1;
+    }
+
+    /// Gives the permit back, i.e. while the network waits for new blocks
+    pub fn release(&mut self) {
+        self.permit = None;
+        self.turns_left = 0;
+    }
+
+    /// Progress that keeps updating along with the share
+    pub fn shared_progress(&self) -> Arc<Mutex<NetworkProgress>> {
+        self.progress.clone()
+    }
+
+    /// Switches of every network sharing the scheduler, to pause them from any server
+    pub fn pauses(&self) -> PauseSwitches {
+        self.pauses.clone()
+    }
+
+    fn lock_progress(&self) -> std::sync::MutexGuard<'_, NetworkProgress> {
+        self.progress.lock().expect("Progress lock is poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::time::Duration,
+        tokio::{spawn, time::sleep},
+    };
+
+    #[tokio::test]
+    async fn busy_network_does_not_starve_the_other() {
+        let scheduler = IndexingScheduler::new(1);
+        let stop = Arc::new(AtomicBool::new(false));
+
+        // Both networks always have work to do, an iteration takes a millisecond
+        let tasks: Vec<_> = [("ethereum", 3), ("solana", 1)]
+            .into_iter()
+            .map(|(network, weight)| {
+                let mut share = scheduler.share(network.to_string(), weight, Arc::default());
+                let stop = stop.clone();
+
+                spawn(async move {
+                    while !stop.load(Ordering::Relaxed) {
+                        share.turn().await;
+                        sleep(Duration::from_millis(1)).await;
+                    }
+                })
+            })
+            .collect();
+
+        sleep(Duration::from_millis(200)).await;
+        stop.store(true, Ordering::Relaxed);
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let progress = scheduler.progress();
+        let (ethereum, solana) = (&progress[0], &progress[1]);
+
+        assert!(solana.iterations > 0, "{progress:?}");
+        assert!(solana.waited_ms > 0, "{progress:?}");
+
+        // Turns alternate, so iterations follow the weights
+        let ratio = ethereum.iterations as f64 / solana.iterations as f64;
+        assert!((2.0..=4.0).contains(&ratio), "{progress:?}");
+    }
+
+    #[tokio::test]
+    async fn released_permit_goes_to_the_waiting_network() {
+        let scheduler = IndexingScheduler::new(1);
+        let mut first = scheduler.share("ethereum".to_string(), 10, Arc::default());
+        let mut second = scheduler.share("near".to_string(), 1, Arc::default());
+
+        first.turn().await;
+
+        // The first network holds the permit for its next turns
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), second.turn())
+                .await
+                .is_err()
+        );
+
+        first.release();
+        second.turn().await;
+
+        assert_eq!(first.lock_progress().iterations, 1);
+        assert_eq!(second.lock_progress().iterations, 1);
+    }
+
+    #[test]
+    fn networks_are_paused_by_name() {
+        let scheduler = IndexingScheduler::new(2);
+        let paused = Arc::new(AtomicBool::new(false));
+        let share = scheduler.share("ethereum".to_string(), 1, paused.clone());
+
+        assert!(scheduler.set_paused("ethereum", true));
+        assert!(paused.load(Ordering::Relaxed));
+
+        // Other shares switch the same flag
+        let switch = share.pauses().get("ethereum").unwrap();
+        switch.store(false, Ordering::Relaxed);
+        assert!(!paused.load(Ordering::Relaxed));
+
+        assert!(!scheduler.set_paused("solana", true));
+        assert!(share.pauses().get("solana").is_none());
+    }
+}
@@ -1,31 +1,75 @@
 use {
     anyhow::Result,
     axum::{
-        extract::State,
-        routing::{get, put},
+        extract::{Path, Query, State},
+        http::StatusCode,
+        routing::{get, post, put},
         Json, Router, Server,
     },
-    serde::Serialize,
-    std::{future::Future, sync::Arc, time::Duration},
+    hapi_core::HapiCoreNetwork,
+    hyper::server::{conn::AddrIncoming, Builder},
+    serde::{Deserialize, Serialize},
+    std::{
+        future::Future,
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            Arc,
+        },
+        time::Duration,
+    },
     tokio::{
+        select,
         sync::Mutex,
         task::{spawn, JoinHandle},
         time::sleep,
     },
 };
 
-use super::{state::IndexerState, Indexer};
+use crate::observability::{get_log_filter, set_log_filter};
+
+use super::{
+    now,
+    push::{PushMetrics, PushMetricsOutput},
+    scheduler::{NetworkProgress, PauseSwitches},
+    state::{IndexerState, IndexingCursor},
+    Indexer,
+};
+
+#[derive(Clone)]
+struct ServerState {
+    state: Arc<Mutex<IndexerState>>,
+    cursor: Arc<Mutex<IndexingCursor>>,
+    chain_head: Arc<Mutex<Option<IndexingCursor>>>,
+    network: HapiCoreNetwork,
+    contract_version: Arc<Mutex<Option<u64>>>,
+    push_metrics: Arc<PushMetrics>,
+    last_iteration: Arc<AtomicU64>,
+    watchdog_threshold: Duration,
+    scheduling: Option<Arc<std::sync::Mutex<NetworkProgress>>>,
+    paused: Arc<AtomicBool>,
+    /// Pause switches of the other networks sharing the scheduler
+    pauses: Option<PauseSwitches>,
+}
 
 impl Indexer {
     async fn shutdown_signal(&self) -> impl Future<Output = ()> {
         let shared_state = self.state.clone();
-        async move {
+        let server_shutdown = self.server_shutdown.clone();
+
+        let stopped = async move {
             loop {
                 sleep(Duration::from_secs(1)).await;
                 if matches!(*shared_state.lock().await, IndexerState::Stopped { .. }) {
                     break;
                 }
             }
+        };
+
+        async move {
+            select! {
+                _ = stopped => {}
+                _ = server_shutdown.cancelled() => {}
+            }
         }
     }
 
@@ -33,13 +77,50 @@ impl Indexer {
         Router::new()
             .route("/state", get(get_state))
             .route("/stop", put(stop))
-            .with_state(self.state.clone())
+            .route("/health", get(get_health))
+            .route("/cursor", get(get_cursor))
+            .route("/networks/:network/pause", post(pause))
+            .route("/networks/:network/resume", post(resume))
+            .route(
+                "/log-filter",
+                get(get_log_filter_handler).put(set_log_filter_handler),
+            )
+            .with_state(ServerState {
+                state: self.state.clone(),
+                cursor: self.cursor.clone(),
+                chain_head: self.chain_head.clone(),
+                network: self.client.get_network(),
+                contract_version: self.contract_version.clone(),
+                push_metrics: self.push_metrics.clone(),
+                last_iteration: self.last_iteration.clone(),
+                watchdog_threshold: self.watchdog_threshold,
+                scheduling: self
+                    .scheduler
+                    .as_ref()
+                    .map(|scheduler| scheduler.shared_progress()),
+                paused: self.paused.clone(),
+                pauses: self.scheduler.as_ref().map(|scheduler| scheduler.pauses()),
+            })
     }
 
     pub async fn spawn_server(&self, addr: &str) -> Result<JoinHandle<Result<()>>> {
         tracing::debug!(?addr, "Start server");
 
-        let server = Server::bind(&addr.parse()?)
+        self.serve(Server::bind(&addr.parse()?)).await
+    }
+
+    /// Starts the server on a bound listener, i.e. one bound to port 0 to get a free port
+    pub async fn spawn_server_on(
+        &self,
+        listener: std::net::TcpListener,
+    ) -> Result<JoinHandle<Result<()>>> {
+        tracing::debug!(addr = ?listener.local_addr()?, "Start server");
+
+        self.serve(Server::from_tcp(listener)?).await
+    }
+
+    async fn serve(&self, builder: Builder<AddrIncoming>) -> Result<JoinHandle<Result<()>>> {
+        let server = builder
             .serve(self.create_router().into_make_service())
             .with_graceful_shutdown(self.shutdown_signal().await);
 
@@ -52,12 +133,28 @@ impl Indexer {
 #[derive(Serialize)]
 struct GetStateOutput {
     state: IndexerState,
+    /// Webhook deliveries since the start
+    pushes: PushMetricsOutput,
+    /// Turns taken with the indexers of other networks, if the scheduler is shared
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduling: Option<NetworkProgress>,
+    /// Whether the indexing is paused by an operator
+    paused: bool,
 }
 
-async fn get_state(State(shared_state): State<Arc<Mutex<IndexerState>>>) -> Json<GetStateOutput> {
-    let state = shared_state.lock().await.clone();
+async fn get_state(State(shared_state): State<ServerState>) -> Json<GetStateOutput> {
+    let state = shared_state.state.lock().await.clone();
+    let scheduling = shared_state
+        .scheduling
+        .as_ref()
+        .map(|progress| progress.lock().expect("Progress lock is poisoned").clone());
 
-    Json(GetStateOutput { state })
+    Json(GetStateOutput {
+        state,
+        pushes: shared_state.push_metrics.snapshot(),
+        scheduling,
+        paused: shared_state.paused.load(Ordering::Relaxed),
+    })
 }
 
 #[derive(Serialize)]
@@ -65,10 +162,171 @@ struct StopOutput {
     success: bool,
 }
 
-async fn stop(State(shared_state): State<Arc<Mutex<IndexerState>>>) -> Json<StopOutput> {
-    shared_state.lock().await.transition(IndexerState::Stopped {
-        message: "Stopped by user".to_string(),
-    });
+async fn stop(State(shared_state): State<ServerState>) -> Json<StopOutput> {
+    shared_state
+        .state
+        .lock()
+        .await
+        .transition(IndexerState::Stopped {
+            message: "Stopped by user".to_string(),
+        });
 
     Json(StopOutput { success: true })
 }
+
+#[derive(Serialize)]
+struct HealthOutput {
+    healthy: bool,
+    last_iteration: u64,
+    /// Why the indexing is halted, if it is
+    #[serde(skip_serializing_if = "Option::is_none")]
+    halted: Option<String>,
+    /// Version of the contract read at startup, `null` if it's unknown
+    contract_version: Option<u64>,
+}
+
+async fn get_health(State(shared_state): State<ServerState>) -> (StatusCode, Json<HealthOutput>) {
+    let last_iteration = shared_state.last_iteration.load(Ordering::Relaxed);
+    let elapsed = now().unwrap_or_default().saturating_sub(last_iteration);
+
+    // Halted indexer keeps iterating, but doesn't index anything
+    let halted = match &*shared_state.state.lock().await {
+        IndexerState::Halted { reason, .. } => Some(reason.clone()),
+        _ => None,
+    };
+
+    let healthy = elapsed <= shared_state.watchdog_threshold.as_secs() && halted.is_none();
+
+    if elapsed > shared_state.watchdog_threshold.as_secs() {
+        tracing::warn!(
+            elapsed,
+            "No indexing iteration completed within the watchdog threshold"
+        );
+    }
+
+    let contract_version = *shared_state.contract_version.lock().await;
+
+    let status = if healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (
+        status,
+        Json(HealthOutput {
+            healthy,
+            last_iteration,
+            halted,
+            contract_version,
+        }),
+    )
+}
+
+#[derive(Deserialize)]
+struct CursorQuery {
+    network: Option<HapiCoreNetwork>,
+}
+
+#[derive(Serialize)]
+struct CursorOutput {
+    network: HapiCoreNetwork,
+    /// Position of the last indexed event with all payloads delivered
+    cursor: IndexingCursor,
+    /// The latest chain head returned by the node
+    chain_head: Option<IndexingCursor>,
+}
+
+async fn get_cursor(
+    State(shared_state): State<ServerState>,
+    Query(query): Query<CursorQuery>,
+) -> Result<Json<CursorOutput>, StatusCode> {
+    // The indexer serves a single network, any other one is unknown here
+    if query
+        .network
+        .is_some_and(|network| network != shared_state.network)
+    {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let cursor = shared_state.cursor.lock().await.clone();
+    let chain_head = shared_state.chain_head.lock().await.clone();
+
+    Ok(Json(CursorOutput {
+        network: shared_state.network,
+        cursor,
+        chain_head,
+    }))
+}
+
+#[derive(Serialize)]
+struct PauseOutput {
+    network: String,
+    paused: bool,
+}
+
+async fn pause(
+    State(shared_state): State<ServerState>,
+    Path(network): Path<String>,
+) -> Result<Json<PauseOutput>, StatusCode> {
+    set_paused(&shared_state, network, true)
+}
+
+async fn resume(
+    State(shared_state): State<ServerState>,
+    Path(network): Path<String>,
+) -> Result<Json<PauseOutput>, StatusCode> {
+    set_paused(&shared_state, network, false)
+}
+
+/// Networks other than the indexed one are known only if they share the scheduler
+fn set_paused(
+    shared_state: &ServerState,
+    network: String,
+    paused: bool,
+) -> Result<Json<PauseOutput>, StatusCode> {
+    let switch = if network == shared_state.network.to_string() {
+        shared_state.paused.clone()
+    } else {
+        shared_state
+            .pauses
+            .as_ref()
+            .and_then(|pauses| pauses.get(&network))
+            .ok_or(StatusCode::NOT_FOUND)?
+    };
+
+    switch.store(paused, Ordering::Relaxed);
+
+    if paused {
+        tracing::info!(network, "Indexing is paused");
+    } else {
+        tracing::info!(network, "Indexing is resumed");
+    }
+
+    Ok(Json(PauseOutput { network, paused }))
+}
+
+#[derive(Serialize, Deserialize)]
+struct LogFilter {
+    filter: String,
+}
+
+async fn get_log_filter_handler() -> Result<Json<LogFilter>, StatusCode> {
+    // Tracing is set up by the binary, an embedding application may use its own subscriber
+    let filter = get_log_filter().ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(Json(LogFilter { filter }))
+}
+
+async fn set_log_filter_handler(
+    Json(input): Json<LogFilter>,
+) -> Result<Json<LogFilter>, (StatusCode, String)> {
+    if get_log_filter().is_none() {
+        return Err((StatusCode::NOT_FOUND, "Tracing is not set up".to_string()));
+    }
+
+    set_log_filter(&input.filter).map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+    tracing::info!(filter = %input.filter, "Log filter is changed");
+
+    Ok(Json(input))
+}
@@ -0,0 +1,149 @@
+use {
+    anyhow::Result,
+    std::time::Duration,
+    tokio::{
+        task::JoinHandle,
+        time::{timeout_at, Instant},
+    },
+    tokio_util::sync::CancellationToken,
+};
+
+/// Coordinates the shutdown of the servers and the indexing tasks of the process
+///
+/// Servers stop accepting requests first, then the indexers finish the running iteration and
+/// persist their state. Tasks that are still running once the drain timeout has passed are
+/// aborted.
+#[derive(Clone)]
+pub struct Shutdown {
+    servers: CancellationToken,
+    indexers: CancellationToken,
+    drain_timeout: Duration,
+}
+
+impl Shutdown {
+    pub fn new(drain_timeout: Duration) -> Self {
+        Self {
+            servers: CancellationToken::new(),
+            indexers: CancellationToken::new(),
+            drain_timeout,
+        }
+    }
+
+    pub(crate) fn server_token(&self) -> CancellationToken {
+        self.servers.clone()
+    }
+
+    pub(crate) fn indexer_token(&self) -> CancellationToken {
+        self.indexers.clone()
+    }
+
+    /// Cancels the servers, then the indexers, and waits for their tasks to exit
+    ///
+    /// Returns the number of tasks aborted after the drain timeout.
+    pub async fn run(
+        &self,
+        servers: Vec<JoinHandle<Result<()>>>,
+        indexers: Vec<JoinHandle<Result<()>>>,
+    ) -> usize {
+        let deadline = Instant::now() + self.drain_timeout;
+
+        self.servers.cancel();
+        let mut aborted = drain(servers, deadline).await;
+
+        self.indexers.cancel();
+        aborted += drain(indexers, deadline).await;
+
+        aborted
+    }
+}
+
+async fn drain(tasks: Vec<JoinHandle<Result<()>>>, deadline: Instant) -> usize {
+    let mut aborted = 0;
+
+    for mut task in tasks {
+        match timeout_at(deadline, &mut task).await {
+            Ok(Ok(Ok(()))) => {}
+            Ok(Ok(Err(error))) => tracing::warn!(?error, "Task failed while shutting down"),
+            Ok(Err(error)) => tracing::warn!(?error, "Task failed to execute to completion"),
+            Err(_) => {
+                tracing::warn!("Task did not stop within the drain timeout, aborting");
+                task.abort();
+                aborted += 1;
+            }
+        }
+    }
+
+    aborted
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::{
+            sync::{
+                atomic::{AtomicUsize, Ordering},
+                Arc,
+            },
+            time::Instant as StdInstant,
+        },
+        tokio::spawn,
+    };
+
+    #[tokio::test]
+    async fn tasks_exit_on_cancellation() {
+        let shutdown = Shutdown::new(Duration::from_secs(5));
+        let observed = Arc::new(AtomicUsize::new(0));
+
+        // Servers are cancelled while the indexers are still running
+        let servers = (0..2)
+            .map(|_| {
+                let (servers, indexers) = (shutdown.server_token(), shutdown.indexer_token());
+                let observed = observed.clone();
+                spawn(async move {
+                    servers.cancelled().await;
+                    if !indexers.is_cancelled() {
+                        observed.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Ok(())
+                })
+            })
+            .collect();
+
+        let indexers = (0..3)
+            .map(|_| {
+                let token = shutdown.indexer_token();
+                let observed = observed.clone();
+                spawn(async move {
+                    token.cancelled().await;
+                    observed.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                })
+            })
+            .collect();
+
+        let started = StdInstant::now();
+        assert_eq!(shutdown.run(servers, indexers).await, 0);
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(observed.load(Ordering::Relaxed), 5);
+    }
+
+    #[tokio::test]
+    async fn stuck_task_is_aborted() {
+        let shutdown = Shutdown::new(Duration::from_millis(100));
+
+        let stuck = spawn(async { std::future::pending::<Result<()>>().await });
+        let token = shutdown.indexer_token();
+        let indexer = spawn(async move {
+            token.cancelled().await;
+            Ok(())
+        });
+
+        let started = StdInstant::now();
+        assert_eq!(shutdown.run(vec![stuck], vec![indexer]).await, 1);
+
+        let elapsed = started.elapsed();
+        assert!(elapsed >= Duration::from_millis(100), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(1), "{elapsed:?}");
+    }
+}
@@ -1,7 +1,7 @@
 use {
-    anyhow::{anyhow, Result},
+    anyhow::{anyhow, bail, Result},
     serde::{Deserialize, Serialize},
-    std::fmt::Display,
+    std::{fmt::Display, str::FromStr},
 };
 
 use super::jobs::IndexerJob;
@@ -41,6 +41,31 @@ impl Display for IndexingCursor {
     }
 }
 
+/// Parses a cursor supplied by an operator: `block:<HEIGHT>` or `transaction:<HASH>`
+impl FromStr for IndexingCursor {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (kind, position) = value.split_once(':').ok_or_else(|| {
+            anyhow!("Cursor must look like `block:<HEIGHT>` or `transaction:<HASH>`, got `{value}`")
+        })?;
+        let position = position.trim();
+
+        match kind.trim().to_lowercase().as_str() {
+            "block" => {
+                Ok(IndexingCursor::Block(position.parse().map_err(|e| {
+                    anyhow!("Invalid block height `{position}`: {e}")
+                })?))
+            }
+            "transaction" if !position.is_empty() => {
+                Ok(IndexingCursor::Transaction(position.to_string()))
+            }
+            "transaction" => bail!("Transaction hash of the cursor is empty"),
+            _ => bail!("Unknown cursor type `{kind}`, expected `block` or `transaction`"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub(crate) enum IndexerState {
     /// App is initializing: reading config and recovering the persisted state
@@ -51,6 +76,11 @@ pub(crate) enum IndexerState {
     Processing { cursor: IndexingCursor },
     /// App is waiting: waiting for new blocks and transactions until timestamp
     Waiting { cursor: IndexingCursor, until: u64 },
+    /// App is halted by repeated decode failures: the cursor is kept until an operator intervenes
+    Halted {
+        cursor: IndexingCursor,
+        reason: String,
+    },
     /// App is stopped: no more indexing, with exit message
     Stopped { message: String },
 }
@@ -68,6 +98,9 @@ impl IndexerState {
             // If the new state is waiting, and the current state is also waiting, just move on
             (IndexerState::Waiting { .. }, IndexerState::Waiting { .. }) => true,
 
+            // Stays halted until stopped
+            (IndexerState::Halted { .. }, IndexerState::Halted { .. }) => true,
+
             // If the new state is processing, and the current state is also processing, just move on
             (IndexerState::Processing { .. }, IndexerState::Processing { .. }) => {
                 *self = new_state;
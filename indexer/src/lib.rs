@@ -3,9 +3,15 @@ mod indexer;
 pub mod observability;
 
 pub use indexer::{
+    client::{LogSource, RpcLogSource},
+    error::IndexerError,
+    gaps::{BlockRange, ProcessedRanges},
     jwt::get_id_from_jwt,
     persistence::PersistedState,
-    push::{NetworkData, PushData, PushEvent, PushPayload},
+    progress::ProgressOutput,
+    push::{NetworkData, PushData, PushEvent, PushFormat, PushPayload, PUSH_SCHEMA_VERSION},
+    scheduler::{IndexingScheduler, NetworkProgress},
+    shutdown::Shutdown,
     state::IndexingCursor,
     Indexer,
 };
@@ -1,26 +1,74 @@
 use {
     anyhow::{bail, Result},
+    clap::{Parser, Subcommand},
+    std::{
+        io::{self, Write},
+        time::Duration,
+    },
     tokio::{
-        select,
+        select, signal,
         task::{spawn, JoinError},
     },
 };
 
 use hapi_indexer::{
-    configuration::get_configuration,
+    configuration::{config_path, get_configuration},
     observability::{setup_json_tracing, setup_tracing},
-    Indexer,
+    BlockRange, Indexer, IndexingCursor, IndexingScheduler, ProgressOutput, Shutdown,
 };
 
+/// How often the configuration file is checked for changes
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long the server and the indexing get to stop on a signal before they are aborted
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Parser)]
+#[command(version, about = "Indexer for HAPI Protocol contracts")]
+struct IndexerCli {
+    #[arg(
+        long,
+        value_name = "CURSOR",
+        help = "Start after the cursor instead of the persisted state: `block:<HEIGHT>` or `transaction:<HASH>`"
+    )]
+    from_cursor: Option<IndexingCursor>,
+
+    #[arg(long, help = "Start from the supplied cursor without confirmation")]
+    yes: bool,
+
+    #[command(subcommand)]
+    command: Option<IndexerCommand>,
+}
+
+#[derive(Subcommand)]
+enum IndexerCommand {
+    #[command(
+        about = "Push payloads of the closed block range again, without moving the indexing cursor"
+    )]
+    Reindex {
+        #[arg(long, help = "First block of the range")]
+        from_block: u64,
+
+        #[arg(long, help = "Last block of the range (inclusive)")]
+        to_block: u64,
+    },
+    #[command(
+        about = "List the block ranges that the persisted cursor has skipped, i.e. after starting from a supplied cursor"
+    )]
+    FindGaps,
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
+    let cli = IndexerCli::parse();
+
     let cfg =
         get_configuration().map_err(|e| anyhow::anyhow!("Configuration parsing error: {e}"))?;
 
     if cfg.is_json_logging {
-        setup_json_tracing(&cfg.log_level)?;
+        setup_json_tracing(&cfg.log_level, cfg.log_filter.as_deref())?;
     } else {
-        setup_tracing(&cfg.log_level)?;
+        setup_tracing(&cfg.log_level, cfg.log_filter.as_deref())?;
     }
 
     tracing::info!(
@@ -29,21 +77,112 @@ async fn main() -> Result<()> {
         env!("CARGO_PKG_VERSION")
     );
 
-    let mut indexer = Indexer::new(cfg.indexer)?;
+    let scheduler = IndexingScheduler::new(cfg.indexer.scheduler_concurrency);
+    let weight = cfg.indexer.scheduler_weight;
+
+    let mut indexer = Indexer::new(cfg.indexer)?
+        .with_scheduler(&scheduler, weight)
+        .with_progress(ProgressOutput::detect(cfg.is_json_logging));
+
+    match cli.command {
+        Some(IndexerCommand::Reindex {
+            from_block,
+            to_block,
+        }) => {
+            indexer.reindex(from_block, to_block).await?;
+            return Ok(());
+        }
+        Some(IndexerCommand::FindGaps) => {
+            print_gaps(&indexer.find_gaps()?);
+            return Ok(());
+        }
+        None => {}
+    }
+
+    if let Some(cursor) = cli.from_cursor {
+        indexer = indexer.with_start_cursor(cursor.clone())?;
+
+        if !cli.yes && !confirm_start_cursor(&cursor)? {
+            bail!("Start from {cursor} is not confirmed");
+        }
+    }
 
-    let server_task = indexer.spawn_server(&cfg.listener).await?;
-    let indexer_task = spawn(async move { indexer.run().await });
+    let shutdown = Shutdown::new(DRAIN_TIMEOUT);
+    let mut indexer = indexer
+        .with_config_reload(config_path(), CONFIG_POLL_INTERVAL)?
+        .with_shutdown(&shutdown);
+
+    let mut server_task = indexer.spawn_server(&cfg.listener).await?;
+    let mut indexer_task = spawn(async move { indexer.run().await });
 
     select! {
-        server_result = server_task => {
+        server_result = &mut server_task => {
             handle_result(server_result).await
         }
-        indexer_result = indexer_task => {
+        indexer_result = &mut indexer_task => {
             handle_result(indexer_result).await
         }
+        _ = shutdown_signal() => {
+            tracing::info!("Signal received, shutting down");
+
+            let aborted = shutdown.run(vec![server_task], vec![indexer_task]).await;
+            if aborted > 0 {
+                bail!("{aborted} tasks did not stop within {DRAIN_TIMEOUT:?}");
+            }
+
+            Ok(())
+        }
+    }
+}
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install signal handler")
+            .recv()
+            .await;
+    };
+
+    select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
     }
 }
 
+fn print_gaps(gaps: &[BlockRange]) {
+    if gaps.is_empty() {
+        println!("No gaps found in the indexed blocks");
+        return;
+    }
+
+    for gap in gaps {
+        println!(
+            "Blocks {gap} are not indexed, reindex them with `reindex --from-block {} --to-block {}`",
+            gap.from, gap.to
+        );
+    }
+}
+
+/// Starting away from the persisted state pushes events again or skips them
+fn confirm_start_cursor(cursor: &IndexingCursor) -> Result<bool> {
+    print!(
+        "Indexing will start after {cursor}, the persisted cursor is ignored. \
+        Events may be pushed again or skipped. Continue? [y/N] "
+    );
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
 async fn handle_result(result: Result<Result<(), anyhow::Error>, JoinError>) -> Result<()> {
     match result {
         Ok(Ok(_)) => Ok(()),
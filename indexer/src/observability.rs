@@ -1,35 +1,170 @@
 use {
     anyhow::{anyhow, Result},
+    std::sync::OnceLock,
     tracing::subscriber,
-    tracing_subscriber::{fmt::Subscriber, EnvFilter},
+    tracing_subscriber::{fmt, prelude::*, reload, EnvFilter, Registry},
 };
 
-pub fn setup_tracing(log_level: &str) -> Result<()> {
-    let subscriber = Subscriber::builder()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-            format!("{}={log_level}", to_snake_case(env!("CARGO_PKG_NAME"))).into()
-        }))
-        .with_writer(std::io::stdout)
-        .finish();
+/// Handle to replace the log filter of the global subscriber
+pub type LogFilterHandle = reload::Handle<EnvFilter, Registry>;
 
-    subscriber::set_global_default(subscriber)
-        .map_err(|e| anyhow!("Failed to set up tracing subscriber: {:?}", e))
+static LOG_FILTER: OnceLock<LogFilterHandle> = OnceLock::new();
+
+/// Filter from `RUST_LOG`, then from the configured directives, then from the crate log level
+fn initial_filter(log_level: &str, log_filter: Option<&str>) -> Result<EnvFilter> {
+    if let Ok(filter) = EnvFilter::try_from_default_env() {
+        return Ok(filter);
+    }
+
+    match log_filter {
+        Some(directives) => parse_filter(directives),
+        None => parse_filter(&format!(
+            "{}={log_level}",
+            to_snake_case(env!("CARGO_PKG_NAME"))
+        )),
+    }
+}
+
+fn parse_filter(directives: &str) -> Result<EnvFilter> {
+    EnvFilter::try_new(directives).map_err(|e| anyhow!("Invalid log filter `{directives}`: {e}"))
 }
 
-pub fn setup_json_tracing(log_level: &str) -> Result<()> {
-    let subscriber = Subscriber::builder()
-        .json()
-        .flatten_event(true)
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-            format!("{}={log_level}", to_snake_case(env!("CARGO_PKG_NAME"))).into()
-        }))
-        .with_writer(std::io::stdout)
-        .finish();
+pub fn setup_tracing(log_level: &str, log_filter: Option<&str>) -> Result<()> {
+    let (filter, handle) = reload::Layer::new(initial_filter(log_level, log_filter)?);
+    let subscriber = tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt::layer().with_writer(std::io::stdout));
+
+    set_global_default(subscriber, handle)
+}
+
+pub fn setup_json_tracing(log_level: &str, log_filter: Option<&str>) -> Result<()> {
+    let (filter, handle) = reload::Layer::new(initial_filter(log_level, log_filter)?);
+    let subscriber = tracing_subscriber::registry().with(filter).with(
+        fmt::layer()
+            .json()
+            .flatten_event(true)
+            .with_writer(std::io::stdout),
+    );
+
+    set_global_default(subscriber, handle)
+}
 
+fn set_global_default<S>(subscriber: S, handle: LogFilterHandle) -> Result<()>
+where
+    S: tracing::Subscriber + Send + Sync + 'static,
+{
     subscriber::set_global_default(subscriber)
-        .map_err(|e| anyhow!("Failed to set up tracing subscriber: {:?}", e))
+        .map_err(|e| anyhow!("Failed to set up tracing subscriber: {:?}", e))?;
+
+    LOG_FILTER
+        .set(handle)
+        .map_err(|_| anyhow!("Log filter is already set up"))
+}
+
+/// Directives of the active log filter, if tracing is set up by this process
+pub fn get_log_filter() -> Option<String> {
+    LOG_FILTER
+        .get()
+        .and_then(|handle| handle.with_current(|filter| filter.to_string()).ok())
+}
+
+/// Replaces the active log filter, i.e. with `info,hapi_indexer=debug`
+pub fn set_log_filter(directives: &str) -> Result<()> {
+    let handle = LOG_FILTER
+        .get()
+        .ok_or_else(|| anyhow!("Tracing is not set up"))?;
+
+    reload_filter(handle, directives)
+}
+
+/// Replaces the active log filter with the one of the configured settings
+///
+/// `RUST_LOG` still takes precedence, as it does on startup.
+pub fn reload_configured_filter(log_level: &str, log_filter: Option<&str>) -> Result<()> {
+    let handle = LOG_FILTER
+        .get()
+        .ok_or_else(|| anyhow!("Tracing is not set up"))?;
+
+    handle
+        .reload(initial_filter(log_level, log_filter)?)
+        .map_err(|e| anyhow!("Failed to reload log filter: {e}"))
+}
+
+fn reload_filter(handle: &LogFilterHandle, directives: &str) -> Result<()> {
+    handle
+        .reload(parse_filter(directives)?)
+        .map_err(|e| anyhow!("Failed to reload log filter: {e}"))
 }
 
 fn to_snake_case(s: &str) -> String {
     s.to_lowercase().replace(['-', ' '], "_")
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::{
+            io::Write,
+            sync::{Arc, Mutex},
+        },
+    };
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Captured {
+        fn take(&self) -> String {
+            String::from_utf8(std::mem::take(&mut *self.0.lock().unwrap())).unwrap()
+        }
+    }
+
+    fn emit_events() {
+        tracing::debug!(target: "hapi_indexer::indexer", "indexer debug");
+        tracing::debug!(target: "hyper::client", "hyper debug");
+        tracing::info!(target: "hyper::client", "hyper info");
+    }
+
+    #[test]
+    fn per_module_directives() {
+        let captured = Captured::default();
+        let writer = captured.clone();
+
+        let (filter, handle) = reload::Layer::new(parse_filter("info,hapi_indexer=debug").unwrap());
+        let subscriber = tracing_subscriber::registry().with(filter).with(
+            fmt::layer()
+                .with_ansi(false)
+                .with_writer(move || writer.clone()),
+        );
+
+        subscriber::with_default(subscriber, || {
+            emit_events();
+
+            let output = captured.take();
+            assert!(output.contains("indexer debug"));
+            assert!(!output.contains("hyper debug"));
+            assert!(output.contains("hyper info"));
+
+            reload_filter(&handle, "warn,hyper=debug").unwrap();
+            emit_events();
+
+            let output = captured.take();
+            assert!(!output.contains("indexer debug"));
+            assert!(output.contains("hyper debug"));
+
+            assert!(reload_filter(&handle, "hapi_indexer=loud").is_err());
+        });
+    }
+}
@@ -1,4 +1,5 @@
 use {
+    hapi_core::HapiCoreNetwork,
     hapi_indexer::{
         configuration::IndexerConfiguration, observability::setup_tracing, Indexer, IndexingCursor,
         PersistedState, PushData,
@@ -16,22 +17,34 @@ mod simple_listener;
 use jwt::{get_jwt, get_jwt_id};
 use mocks::{
     create_pushdata, create_test_batches, evm_mock::EvmMock, near_mock::NearMock,
-    solana_mock::SolanaMock, webhook_mock::WebhookServiceMock, RpcMock, TestBatch, PAGE_SIZE,
+    solana_mock::SolanaMock, stalled_rpc::StalledRpcProxy, webhook_mock::WebhookServiceMock,
+    RpcFailure, RpcMock, TestBatch, PAGE_SIZE,
 };
 
 const TRACING_ENV_VAR: &str = "ENABLE_TRACING";
 const FETCHING_DELAY: Duration = Duration::from_millis(100);
+const RPC_TIMEOUT: Duration = Duration::from_millis(1000);
+const MAX_PENDING_PUSHES: usize = 1000;
+const REORG_DEPTH: u64 = 64;
+const PUSH_RETRY_ATTEMPTS: u32 = 3;
+const PUSH_RETRY_BACKOFF: Duration = Duration::from_millis(10);
+const REINDEX_STATE_FILE: &str = "data/evm_reindex_state.json";
 
 pub struct IndexerTest<T: RpcMock> {
     webhook_mock: WebhookServiceMock,
     rpc_mock: T,
     cursor: IndexingCursor,
+    rpc_proxy: Option<StalledRpcProxy>,
+    max_pending_pushes: usize,
+    listener: Option<String>,
+    rpc_failures: u32,
+    push_retry_delay: Duration,
 }
 
 impl<T: RpcMock> IndexerTest<T> {
     pub fn new() -> Self {
         if env::var(TRACING_ENV_VAR).unwrap_or_default().eq("1") {
-            if let Err(e) = setup_tracing("trace") {
+            if let Err(e) = setup_tracing("trace", None) {
                 println!("Failed to setup tracing: {}", e);
             }
         }
@@ -43,6 +56,11 @@ impl<T: RpcMock> IndexerTest<T> {
             webhook_mock: WebhookServiceMock::new(),
             rpc_mock: T::initialize(),
             cursor: IndexingCursor::None,
+            rpc_proxy: None,
+            max_pending_pushes: MAX_PENDING_PUSHES,
+            listener: None,
+            rpc_failures: 0,
+            push_retry_delay: Duration::ZERO,
         }
     }
 
@@ -73,22 +91,74 @@ impl<T: RpcMock> IndexerTest<T> {
         }
     }
 
-    async fn indexing_iteration(&self) -> anyhow::Result<()> {
-        let cfg = IndexerConfiguration {
+    fn create_configuration(&self) -> IndexerConfiguration {
+        let rpc_node_url = self
+            .rpc_proxy
+            .as_ref()
+            .map_or_else(|| self.rpc_mock.get_mock_url(), |proxy| proxy.url.clone());
+
+        IndexerConfiguration {
             network: T::get_network(),
             chain_id: None,
-            rpc_node_url: self.rpc_mock.get_mock_url(),
+            rpc_node_url,
+            rpc_node_fallback_urls: vec![],
+            log_source_url: None,
             webhook_url: self.webhook_mock.server.url(),
             contract_address: T::get_contract_address(),
             wait_interval_ms: FETCHING_DELAY,
             state_file: T::STATE_FILE.to_string(),
+            block_time_ms: None,
             fetching_delay: FETCHING_DELAY,
+            page_size: None,
+            catch_up_page_size: None,
+            catch_up_threshold: None,
             jwt_token: get_jwt(),
-        };
+            rpc_timeout_ms: RPC_TIMEOUT,
+            rpc_method_timeouts_ms: Default::default(),
+            watchdog_threshold_ms: RPC_TIMEOUT.saturating_mul(10),
+            max_pending_pushes: self.max_pending_pushes,
+            push_retry_attempts: PUSH_RETRY_ATTEMPTS,
+            push_retry_backoff_ms: PUSH_RETRY_BACKOFF,
+            push_retry_max_backoff_ms: PUSH_RETRY_BACKOFF.saturating_mul(10),
+            dead_letter_pushes: false,
+            reorg_depth: REORG_DEPTH,
+            unknown_event_policy: Default::default(),
+            rpc_batching: Default::default(),
+            max_decode_failures: 0,
+            decode_failure_window_ms: Duration::from_millis(600_000),
+            stale_head_wait_ms: Duration::from_millis(100),
+            chain_head_ttl_ms: Duration::from_millis(10_000),
+            push_format: Default::default(),
+            scheduler_concurrency: 1,
+            scheduler_weight: 1,
+        }
+    }
+
+    async fn indexing_iteration(&self) -> anyhow::Result<()> {
+        let mut indexer =
+            Indexer::new(self.create_configuration()).expect("Failed to initialize indexer");
+
+        // The server outlives the indexer task, so that its state can be inspected afterwards
+        if let Some(listener) = &self.listener {
+            indexer
+                .spawn_server(listener)
+                .await
+                .expect("Failed to spawn server");
+        }
 
-        let mut indexer = Indexer::new(cfg).expect("Failed to initialize indexer");
         let indexer_task = async move { indexer.run().await };
-        let timer = FETCHING_DELAY.saturating_mul(T::get_delay_multiplier());
+        let mut timer = FETCHING_DELAY.saturating_mul(T::get_delay_multiplier());
+
+        // Stalled requests are retried only after the RPC timeout expires
+        if self.rpc_proxy.is_some() {
+            timer += RPC_TIMEOUT;
+        }
+
+        // Each failed RPC request is retried after the wait interval
+        timer += FETCHING_DELAY.saturating_mul(self.rpc_failures);
+
+        // Failed pushes are retried after a backoff
+        timer += self.push_retry_delay;
 
         println!(
             "==> Starting indexer with timer: {} millis",
@@ -142,6 +212,177 @@ impl<T: RpcMock> IndexerTest<T> {
         assert!(self.indexing_iteration().await.is_ok());
     }
 
+    pub async fn stalled_rpc_test(&mut self) {
+        println!(
+            "Starting stalled RPC test for {} network\n",
+            T::get_network()
+        );
+
+        let proxy = StalledRpcProxy::start(&self.rpc_mock.get_mock_url(), 1).await;
+        self.rpc_proxy = Some(proxy);
+
+        // The first RPC request never gets a response: the indexer should
+        // time out, retry the iteration and then index all events as usual
+        self.indexing_test().await;
+
+        let stalled = self
+            .rpc_proxy
+            .as_ref()
+            .map_or(0, |proxy| proxy.stalled_count());
+        assert_eq!(stalled, 1);
+
+        println!(
+            "Indexer recovered from stalled RPC on {}!",
+            T::get_network()
+        );
+    }
+
+    pub async fn rpc_failure_test(&mut self, method: &str, failures: &[RpcFailure]) {
+        println!(
+            "Starting RPC failure test for {} network\n",
+            T::get_network()
+        );
+
+        self.rpc_failures = failures.len() as u32;
+        let failure_mocks = self.rpc_mock.rpc_failure_mock(method, failures);
+
+        // The failed requests should be retried, then all events are indexed as usual
+        self.indexing_test().await;
+
+        failure_mocks.iter().for_each(|mock| mock.assert());
+
+        println!(
+            "Indexer recovered from RPC failures on {}!",
+            T::get_network()
+        );
+    }
+
+    pub async fn unavailable_webhook_test(&mut self) {
+        println!(
+            "Starting unavailable webhook test for {} network\n",
+            T::get_network()
+        );
+
+        let pushdata = create_pushdata::<T>();
+        let test_data = create_test_batches::<T>(&pushdata);
+        let batches = &test_data[..2];
+
+        self.max_pending_pushes = 2;
+
+        self.rpc_mock.fetching_jobs_mock(batches, &self.cursor);
+        self.rpc_mock.entity_getters_mock(pushdata);
+        batches
+            .iter()
+            .for_each(|batch| self.rpc_mock.processing_jobs_mock(batch));
+
+        // Every delivery fails: the indexer should keep retrying the buffered payloads
+        // and never reach the events behind them
+        self.webhook_mock
+            .set_unavailable_mocks(&batches.concat(), self.max_pending_pushes);
+
+        self.indexing_iteration().await.unwrap();
+
+        self.webhook_mock.check_mocks();
+
+        // The cursor stays right before the first undelivered event
+        let delivered = batches[0][..2].to_vec();
+        self.check_cursor(&[delivered]);
+
+        println!(
+            "Indexer paused on unavailable webhook on {}!",
+            T::get_network()
+        );
+    }
+
+    pub async fn flaky_webhook_test(&mut self) {
+        println!(
+            "Starting flaky webhook test for {} network\n",
+            T::get_network()
+        );
+
+        let pushdata = create_pushdata::<T>();
+        let test_data = create_test_batches::<T>(&pushdata);
+        let batches = &test_data[..2];
+
+        // Every payload is rejected before it is accepted, yet within the push retry attempts
+        let failures = PUSH_RETRY_ATTEMPTS - 1;
+        let payloads = batches
+            .iter()
+            .map(|batch| self.webhook_mock.set_flaky_mocks(batch, failures))
+            .sum::<usize>();
+
+        // The backoff doubles with every failed attempt of a payload
+        self.push_retry_delay = PUSH_RETRY_BACKOFF
+            .saturating_mul((1 << failures) - 1)
+            .saturating_mul(payloads as u32);
+
+        self.create_mocks(batches, Some(pushdata));
+        self.indexing_iteration().await.unwrap();
+
+        // Every payload is delivered, so the cursor is past all events
+        self.webhook_mock.check_mocks();
+        self.check_cursor(batches);
+
+        println!(
+            "Indexer recovered from webhook failures on {}!",
+            T::get_network()
+        );
+    }
+
+    pub async fn cursor_endpoint_test(&mut self) {
+        println!(
+            "Starting cursor endpoint test for {} network\n",
+            T::get_network()
+        );
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .expect("Failed to get free port")
+            .to_string();
+        self.listener = Some(listener.clone());
+
+        let pushdata = create_pushdata::<T>();
+        let test_data = create_test_batches::<T>(&pushdata);
+        let batches = &test_data[..2];
+
+        self.create_mocks(batches, Some(pushdata));
+        self.indexing_iteration().await.unwrap();
+        self.webhook_mock.check_mocks();
+
+        let response = reqwest::get(format!(
+            "http://{listener}/cursor?network={}",
+            T::get_network()
+        ))
+        .await
+        .expect("Failed to get cursor");
+        assert!(response.status().is_success());
+
+        let body: serde_json::Value =
+            serde_json::from_str(&response.text().await.expect("Failed to read response"))
+                .expect("Failed to parse response");
+
+        let expected = T::get_cursor(batches);
+        assert_eq!(body["cursor"], serde_json::to_value(&expected).unwrap());
+        let chain_head = T::get_chain_head(batches);
+        assert_eq!(
+            body["chain_head"],
+            serde_json::to_value(&chain_head).unwrap()
+        );
+
+        // The indexer doesn't serve other networks
+        let other_network = if T::get_network() == HapiCoreNetwork::Near {
+            HapiCoreNetwork::Solana
+        } else {
+            HapiCoreNetwork::Near
+        };
+        let response = reqwest::get(format!("http://{listener}/cursor?network={other_network}"))
+            .await
+            .expect("Failed to get cursor");
+        assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+
+        println!("Cursor endpoint is up to date on {}!", T::get_network());
+    }
+
     pub async fn run_test(&mut self) {
         println!("Starting test for {} network\n", T::get_network());
 
@@ -160,6 +401,123 @@ impl<T: RpcMock> IndexerTest<T> {
     }
 }
 
+impl IndexerTest<EvmMock> {
+    pub async fn reorg_test(&mut self) {
+        println!(
+            "Starting reorg test for {} network\n",
+            EvmMock::get_network()
+        );
+
+        let pushdata = create_pushdata::<EvmMock>();
+        let test_data = create_test_batches::<EvmMock>(&pushdata);
+        let batches = &test_data[..2];
+
+        self.create_mocks(batches, Some(pushdata));
+        self.indexing_iteration().await.unwrap();
+        self.webhook_mock.check_mocks();
+        self.check_cursor(batches);
+
+        // Blocks of the address creation and update are replaced by ones without events
+        let address_events = &batches[1][3..5];
+        let reorged: Vec<u64> = address_events.iter().map(|event| event.block).collect();
+        let latest_block = batches[1].last().expect("Empty batch").block;
+
+        self.rpc_mock.reorg_mock(&reorged, latest_block);
+
+        // The creation is withdrawn, which covers the update of the same address
+        self.webhook_mock.set_removed_mocks(&address_events[..1]);
+
+        self.indexing_iteration().await.unwrap();
+        self.webhook_mock.check_mocks();
+
+        // The indexer rewinds and catches up with the new chain
+        self.check_cursor(batches);
+
+        println!(
+            "Reorganized events are withdrawn on {}!",
+            EvmMock::get_network()
+        );
+    }
+
+    pub async fn contract_version_test(&mut self) {
+        println!(
+            "Starting contract version test for {} network\n",
+            EvmMock::get_network()
+        );
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .expect("Failed to get free port")
+            .to_string();
+        self.listener = Some(listener.clone());
+
+        self.rpc_mock.contract_version_mock(2);
+
+        let pushdata = create_pushdata::<EvmMock>();
+        let test_data = create_test_batches::<EvmMock>(&pushdata);
+        let batches = &test_data[..2];
+
+        self.create_mocks(batches, Some(pushdata));
+        self.indexing_iteration().await.unwrap();
+        self.webhook_mock.check_mocks();
+
+        let response = reqwest::get(format!("http://{listener}/health"))
+            .await
+            .expect("Failed to get health");
+
+        let body: serde_json::Value =
+            serde_json::from_str(&response.text().await.expect("Failed to read response"))
+                .expect("Failed to parse response");
+
+        assert_eq!(body["contract_version"], 2, "{body}");
+
+        println!(
+            "Contract version is reported on {}!",
+            EvmMock::get_network()
+        );
+    }
+
+    pub async fn reindex_test(&mut self) {
+        println!(
+            "Starting reindex test for {} network\n",
+            EvmMock::get_network()
+        );
+
+        let pushdata = create_pushdata::<EvmMock>();
+        let test_data = create_test_batches::<EvmMock>(&pushdata);
+        let batch = &test_data[1];
+
+        self.rpc_mock.range_logs_mock(batch);
+        self.rpc_mock.entity_getters_mock(pushdata);
+        self.rpc_mock.processing_jobs_mock(batch);
+        self.webhook_mock.set_mocks(batch);
+
+        // Other EVM tests share the state file, so a separate one is checked
+        let mut cfg = self.create_configuration();
+        cfg.state_file = REINDEX_STATE_FILE.to_string();
+        drop_state_file(REINDEX_STATE_FILE);
+
+        let indexer = Indexer::new(cfg).expect("Failed to initialize indexer");
+
+        let from_block = batch.first().expect("Empty batch").block;
+        let to_block = batch.last().expect("Empty batch").block;
+        let pushed = indexer
+            .reindex(from_block, to_block)
+            .await
+            .expect("Failed to reindex");
+
+        // Configuration changes don't produce payloads
+        let expected = batch.iter().filter(|event| event.data.is_some()).count();
+        assert_eq!(pushed, expected);
+        self.webhook_mock.check_mocks();
+
+        // The live cursor is not persisted by reindexing
+        assert!(!PathBuf::from(REINDEX_STATE_FILE).exists());
+
+        println!("Block range is reindexed on {}!", EvmMock::get_network());
+    }
+}
+
 impl<T: RpcMock> Drop for IndexerTest<T> {
     fn drop(&mut self) {
         drop_state_file(T::STATE_FILE);
@@ -186,3 +544,63 @@ async fn evm_indexer_test() {
 async fn near_indexer_test() {
     IndexerTest::<NearMock>::new().run_test().await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn evm_stalled_rpc_test() {
+    IndexerTest::<EvmMock>::new().stalled_rpc_test().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn evm_rpc_failure_test() {
+    IndexerTest::<EvmMock>::new()
+        .rpc_failure_test("eth_blockNumber", &[RpcFailure::Status(503)])
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn evm_rpc_error_response_test() {
+    let failures = [
+        RpcFailure::Status(429),
+        RpcFailure::JsonRpc {
+            code: -32005,
+            message: "limit exceeded".to_string(),
+        },
+        RpcFailure::Malformed,
+    ];
+
+    IndexerTest::<EvmMock>::new()
+        .rpc_failure_test("eth_blockNumber", &failures)
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn evm_unavailable_webhook_test() {
+    IndexerTest::<EvmMock>::new()
+        .unavailable_webhook_test()
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn evm_flaky_webhook_test() {
+    IndexerTest::<EvmMock>::new().flaky_webhook_test().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn evm_cursor_endpoint_test() {
+    IndexerTest::<EvmMock>::new().cursor_endpoint_test().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn evm_reorg_test() {
+    IndexerTest::<EvmMock>::new().reorg_test().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn evm_reindex_test() {
+    IndexerTest::<EvmMock>::new().reindex_test().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn evm_contract_version_test() {
+    IndexerTest::<EvmMock>::new().contract_version_test().await;
+}
@@ -8,7 +8,13 @@ use {
         types::{Address, Block, Bytes, Filter, Log, H256, U256},
         utils::keccak256,
     },
-    hapi_core::{client::events::EventName, HapiCoreNetwork},
+    hapi_core::{
+        client::{
+            encoding::{category_code, role_code},
+            events::EventName,
+        },
+        HapiCoreNetwork,
+    },
     hapi_indexer::{IndexingCursor, PushData},
     mockito::{Matcher, Server, ServerGuard},
     rand::RngCore,
@@ -81,6 +87,10 @@ impl RpcMock for EvmMock {
         self.server.url()
     }
 
+    fn get_server(&mut self) -> &mut ServerGuard {
+        &mut self.server
+    }
+
     fn get_cursor(batch: &[TestBatch]) -> IndexingCursor {
         batch
             .last()
@@ -89,6 +99,13 @@ impl RpcMock for EvmMock {
             .unwrap_or(IndexingCursor::None)
     }
 
+    fn get_chain_head(batches: &[TestBatch]) -> IndexingCursor {
+        // The head of the last batch in `fetching_jobs_mock`, which pages from the block 0
+        let blocks = batches.iter().map(|batch| batch.len() as u64).sum::<u64>();
+
+        IndexingCursor::Block(blocks.saturating_sub(1))
+    }
+
     fn entity_getters_mock(&mut self, data: Vec<PushData>) {
         data.iter().for_each(|data| self.processing_data_mock(data));
     }
@@ -112,13 +129,34 @@ impl RpcMock for EvmMock {
     }
 
     fn processing_jobs_mock(&mut self, batch: &TestBatch) {
-        batch
-            .iter()
-            .for_each(|event| self.block_request_mock(event.block));
+        batch.iter().for_each(|event| {
+            self.block_request_mock(event.block, H256::from_low_u64_be(event.block))
+        });
     }
 }
 
 impl EvmMock {
+    // Replaces the blocks with ones of other hashes, the new chain has no contract
+    // events from the first replaced block up to `latest_block`
+    pub fn reorg_mock(&mut self, blocks: &[u64], latest_block: u64) {
+        blocks
+            .iter()
+            .for_each(|block| self.block_request_mock(*block, H256::from_low_u64_be(!block)));
+
+        if let Some(first) = blocks.iter().min() {
+            self.logs_request_mock(&[], *first, latest_block);
+        }
+    }
+
+    // Serves the logs of the batch for the range from its first to its last block
+    pub fn range_logs_mock(&mut self, batch: &TestBatch) {
+        let from_block = batch.first().expect("Empty batch").block;
+        let to_block = batch.last().expect("Empty batch").block;
+
+        let logs = self.get_logs(batch);
+        self.logs_request_mock(&logs, from_block, to_block);
+    }
+
     fn latest_block_mock(&mut self, number: u64) {
         let response = json!({
            "jsonrpc": "2.0",
@@ -137,6 +175,26 @@ impl EvmMock {
             .create();
     }
 
+    /// Storage of the `Initializable` state that the contract version is read from
+    pub fn contract_version_mock(&mut self, version: u64) {
+        let response = json!({
+           "jsonrpc": "2.0",
+           "result": format!("{:#066x}", version),
+           "id": 1
+        });
+
+        self.server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&response.to_string())
+            .match_body(Matcher::PartialJson(json!({
+                "method": "eth_getStorageAt",
+            })))
+            .expect(1)
+            .create();
+    }
+
     fn get_logs(&self, batch: &TestBatch) -> Vec<Log> {
         let mut res = vec![];
         let address = CONTRACT_ADDRESS
@@ -233,15 +291,22 @@ impl EvmMock {
                     );
 
                     let id_topic = u128_to_bytes(data.id.as_u128()).into();
-                    let reporter: Address = data.account.parse().expect("Invalid address");
-                    let role = data.role.clone() as u8;
-
                     log.topics.append(&mut vec![id_topic]);
-                    log.data = ethers::abi::encode(&[
-                        Token::Address(reporter),
-                        Token::Uint(U256::from(role)),
-                    ])
-                    .into();
+
+                    // Only `ReporterCreated` and `ReporterUpdated` carry non-indexed fields
+                    if matches!(
+                        event.name,
+                        EventName::CreateReporter | EventName::UpdateReporter
+                    ) {
+                        let reporter: Address = data.account.parse().expect("Invalid address");
+                        let role = role_code(&data.role);
+
+                        log.data = ethers::abi::encode(&[
+                            Token::Address(reporter),
+                            Token::Uint(U256::from(role)),
+                        ])
+                        .into();
+                    }
                 }
                 EventName::CreateCase | EventName::UpdateCase => {
                     let_extract!(
@@ -262,16 +327,27 @@ impl EvmMock {
                     );
 
                     let addr: Address = data.address.parse().expect("Invalid address");
-                    let risk = data.risk;
-                    let category = data.category.clone() as u8;
                     let addr_topic = H256::from(addr);
 
                     log.topics.append(&mut vec![addr_topic]);
-                    log.data = ethers::abi::encode(&[
-                        Token::Uint(U256::from(risk)),
-                        Token::Uint(U256::from(category)),
-                    ])
-                    .into();
+
+                    // `AddressConfirmed` has the indexed address only
+                    if event.name != EventName::ConfirmAddress {
+                        let risk = data.risk;
+                        let category = category_code(&data.category);
+
+                        log.data = ethers::abi::encode(&[
+                            Token::Uint(U256::from(risk)),
+                            Token::Uint(U256::from(category)),
+                        ])
+                        .into();
+                    }
+                }
+                EventName::InvalidateAddress => {
+                    panic!("Address invalidation is a Solana instruction")
+                }
+                EventName::LinkCases => {
+                    panic!("Case linking is a Solana instruction")
                 }
                 EventName::CreateAsset | EventName::UpdateAsset | EventName::ConfirmAsset => {
                     let_extract!(
@@ -282,17 +358,24 @@ impl EvmMock {
 
                     let addr: Address = data.address.parse().expect("Invalid address");
                     let asset_id: U256 = data.asset_id.clone().into();
-                    let risk = data.risk;
-                    let category = data.category.clone() as u8;
                     let addr_topic = H256::from(addr);
 
                     log.topics.append(&mut vec![addr_topic]);
-                    log.data = ethers::abi::encode(&[
-                        Token::Uint(asset_id),
-                        Token::Uint(U256::from(risk)),
-                        Token::Uint(U256::from(category)),
-                    ])
-                    .into();
+
+                    // `AssetConfirmed` has the asset id as its only non-indexed field
+                    log.data = if event.name == EventName::ConfirmAsset {
+                        ethers::abi::encode(&[Token::Uint(asset_id)]).into()
+                    } else {
+                        let risk = data.risk;
+                        let category = category_code(&data.category);
+
+                        ethers::abi::encode(&[
+                            Token::Uint(asset_id),
+                            Token::Uint(U256::from(risk)),
+                            Token::Uint(U256::from(category)),
+                        ])
+                        .into()
+                    };
                 }
             }
 
@@ -330,8 +413,10 @@ impl EvmMock {
             .create();
     }
 
-    fn block_request_mock(&mut self, num: u64) {
+    fn block_request_mock(&mut self, num: u64, hash: H256) {
         let mut block: Block<H256> = Block::default();
+        block.number = Some(num.into());
+        block.hash = Some(hash);
         block.timestamp = 123.into();
 
         let response = json!({
@@ -365,7 +450,7 @@ impl EvmMock {
                     U256::from_big_endian(&u128_to_bytes(address.reporter_id.as_u128()));
                 let confirmations = U256::from(address.confirmations);
                 let risk = U256::from(address.risk);
-                let category = U256::from(address.category.clone() as u8);
+                let category = U256::from(category_code(&address.category));
 
                 let raw_tx = self.contract.get_address(addr).tx;
                 let responce = hex::encode(ethers::abi::encode(&[Token::Tuple(vec![
@@ -390,7 +475,7 @@ impl EvmMock {
                     U256::from_big_endian(&u128_to_bytes(asset.reporter_id.as_u128()));
                 let confirmations = U256::from(asset.confirmations);
                 let risk = U256::from(asset.risk);
-                let category = U256::from(asset.category.clone() as u8);
+                let category = U256::from(category_code(&asset.category));
 
                 let raw_tx = self
                     .contract
@@ -434,7 +519,7 @@ impl EvmMock {
                     .expect("Failed to parse address");
                 let name = reporter.name.to_owned();
                 let url = reporter.url.to_owned();
-                let role = U256::from(reporter.role.clone() as u8);
+                let role = U256::from(role_code(&reporter.role));
                 let status = U256::from(reporter.status.clone() as u8);
                 let stake = U256::from(reporter.stake.to_owned());
                 let unlock_timestamp = U256::from(reporter.unlock_timestamp);
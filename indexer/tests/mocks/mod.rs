@@ -15,6 +15,8 @@ use {
         HapiCoreNetwork,
     },
     hapi_indexer::{IndexingCursor, NetworkData, PushData},
+    mockito::{Matcher, Mock, ServerGuard},
+    serde_json::json,
     std::str::FromStr,
     uuid::Uuid,
 };
@@ -22,10 +24,22 @@ use {
 pub mod evm_mock;
 pub mod near_mock;
 pub mod solana_mock;
+pub mod stalled_rpc;
 pub mod webhook_mock;
 
 pub const PAGE_SIZE: u64 = 6;
 
+/// Failed response of the RPC node
+#[derive(Debug, Clone)]
+pub enum RpcFailure {
+    /// HTTP error status with a plain text body, like the ones sent by load balancers
+    Status(usize),
+    /// JSON-RPC error object
+    JsonRpc { code: i64, message: String },
+    /// Body that is not a valid JSON-RPC response
+    Malformed,
+}
+
 pub trait RpcMock {
     const STATE_FILE: &'static str;
 
@@ -47,9 +61,46 @@ pub trait RpcMock {
     // Returns the URL of the network mock server
     fn get_mock_url(&self) -> String;
 
+    // Returns the network mock server
+    fn get_server(&mut self) -> &mut ServerGuard;
+
+    // Queues failed responses for the RPC method, they are served in order before
+    // the regular ones. Should be called before the regular mocks are created
+    fn rpc_failure_mock(&mut self, method: &str, failures: &[RpcFailure]) -> Vec<Mock> {
+        failures
+            .iter()
+            .map(|failure| {
+                let (status, body) = match failure {
+                    RpcFailure::Status(status) => (*status, format!("{status} error")),
+                    RpcFailure::JsonRpc { code, message } => (
+                        200,
+                        json!({
+                            "jsonrpc": "2.0",
+                            "error": { "code": code, "message": message },
+                            "id": 1
+                        })
+                        .to_string(),
+                    ),
+                    RpcFailure::Malformed => (200, r#"{"jsonrpc":"2.0","result":"#.to_string()),
+                };
+
+                self.get_server()
+                    .mock("POST", "/")
+                    .with_status(status)
+                    .with_body(body)
+                    .match_body(Matcher::PartialJson(json!({ "method": method })))
+                    .expect(1)
+                    .create()
+            })
+            .collect()
+    }
+
     // Returns the cursor used in network indexing
     fn get_cursor(batch: &[TestBatch]) -> IndexingCursor;
 
+    // Returns the chain head served once the batches are fetched from the start
+    fn get_chain_head(batches: &[TestBatch]) -> IndexingCursor;
+
     // Should contain necessary mocks to handle check for updates
     fn fetching_jobs_mock(&mut self, batches: &[TestBatch], cursor: &IndexingCursor);
 
@@ -92,6 +143,7 @@ pub fn create_pushdata<T: RpcMock>() -> Vec<PushData> {
         url: String::from("https://case1.com"),
         status: CaseStatus::Open,
         reporter_id: Uuid::new_v4(),
+        related_cases: vec![],
     };
 
     let address = Address {
@@ -85,6 +85,10 @@ impl RpcMock for NearMock {
         self.server.url()
     }
 
+    fn get_server(&mut self) -> &mut ServerGuard {
+        &mut self.server
+    }
+
     fn get_cursor(batch: &[TestBatch]) -> IndexingCursor {
         batch
             .last()
@@ -93,6 +97,16 @@ impl RpcMock for NearMock {
             .unwrap_or(IndexingCursor::None)
     }
 
+    fn get_chain_head(batches: &[TestBatch]) -> IndexingCursor {
+        let block = batches
+            .last()
+            .and_then(|batch| batch.last())
+            .expect("Empty batch")
+            .block;
+
+        IndexingCursor::Block(block)
+    }
+
     fn entity_getters_mock(&mut self, data: Vec<PushData>) {
         self.mock_client_get_requests(&data[0], "get_reporter");
         self.mock_client_get_requests(&data[0], "get_reporter_by_account");
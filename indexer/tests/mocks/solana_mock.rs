@@ -92,6 +92,10 @@ impl RpcMock for SolanaMock {
         self.server.url()
     }
 
+    fn get_server(&mut self) -> &mut ServerGuard {
+        &mut self.server
+    }
+
     fn get_cursor(batch: &[TestBatch]) -> IndexingCursor {
         batch
             .first()
@@ -100,6 +104,18 @@ impl RpcMock for SolanaMock {
             .unwrap_or(IndexingCursor::None)
     }
 
+    fn get_chain_head(batches: &[TestBatch]) -> IndexingCursor {
+        // Signatures are served newest first, the head is the first one
+        let hash = batches
+            .first()
+            .and_then(|batch| batch.first())
+            .expect("Empty batch")
+            .hash
+            .clone();
+
+        IndexingCursor::Transaction(hash)
+    }
+
     fn entity_getters_mock(&mut self, data: Vec<PushData>) {
         // Mocking accounts request from payload data
         data.iter().for_each(|data| self.mock_accounts(data));
@@ -245,6 +261,7 @@ impl SolanaMock {
                     case_id: address.case_id.as_u128(),
                     reporter_id: address.reporter_id.as_u128(),
                     confirmations: address.confirmations,
+                    is_invalidated: false,
                 }
                 .try_serialize(&mut data)
                 .expect("Failed to serialize address");
@@ -283,6 +300,8 @@ impl SolanaMock {
                     reporter_id: case.reporter_id.as_u128(),
                     status: case.status.into(),
                     url: case.url,
+                    evidence: vec![],
+                    related_cases: case.related_cases.iter().map(|id| id.as_u128()).collect(),
                 }
                 .try_serialize(&mut data)
                 .expect("Failed to serialize case");
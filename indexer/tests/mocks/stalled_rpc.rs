@@ -0,0 +1,70 @@
+use {
+    std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    tokio::{
+        io::copy_bidirectional,
+        net::{TcpListener, TcpStream},
+        task::JoinHandle,
+    },
+};
+
+/// TCP proxy in front of an RPC mock, that never responds to the first `stalls` connections
+pub struct StalledRpcProxy {
+    pub url: String,
+    stalled: Arc<AtomicUsize>,
+    handle: JoinHandle<()>,
+}
+
+impl StalledRpcProxy {
+    pub async fn start(target_url: &str, stalls: usize) -> Self {
+        let target = target_url.trim_start_matches("http://").to_string();
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind proxy listener");
+        let url = format!(
+            "http://{}",
+            listener.local_addr().expect("Failed to get proxy address")
+        );
+
+        let stalled = Arc::new(AtomicUsize::new(0));
+        let counter = stalled.clone();
+
+        let handle = tokio::spawn(async move {
+            // Stalled connections are kept open until the proxy is dropped
+            let mut held = vec![];
+
+            while let Ok((mut inbound, _)) = listener.accept().await {
+                if counter.load(Ordering::SeqCst) < stalls {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    held.push(inbound);
+                    continue;
+                }
+
+                let target = target.clone();
+                tokio::spawn(async move {
+                    if let Ok(mut outbound) = TcpStream::connect(&target).await {
+                        let _ = copy_bidirectional(&mut inbound, &mut outbound).await;
+                    }
+                });
+            }
+        });
+
+        Self {
+            url,
+            stalled,
+            handle,
+        }
+    }
+
+    pub fn stalled_count(&self) -> usize {
+        self.stalled.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for StalledRpcProxy {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
@@ -1,10 +1,9 @@
 use {
-    hapi_core::client::events::EventName,
-    hapi_indexer::{PushEvent, PushPayload},
+    hapi_indexer::{PushEvent, PushPayload, PUSH_SCHEMA_VERSION},
     mockito::{Matcher, Mock, Server, ServerGuard},
 };
 
-use super::TestBatch;
+use super::{TestBatch, TestData};
 
 pub struct WebhookServiceMock {
     mocks: Vec<Mock>,
@@ -19,34 +18,72 @@ impl WebhookServiceMock {
         }
     }
     pub fn set_mocks(&mut self, batch: &TestBatch) {
-        for event in batch {
-            if let Some(data) = &event.data {
-                if event.name != EventName::ConfirmAddress && event.name != EventName::ConfirmAsset
-                {
-                    let payload = PushPayload {
-                        network_data: event.network_data.clone(),
-                        event: PushEvent {
-                            name: event.name.clone(),
-                            tx_hash: event.hash.clone(),
-                            tx_index: 0,
-                            timestamp: 123,
-                        },
-                        data: data.clone(),
-                    };
-
-                    let mock = self
-                        .server
-                        .mock("POST", "/events")
-                        .with_status(200)
-                        .match_body(Matcher::PartialJsonString(
-                            serde_json::to_string(&payload).expect("Failed to serialize payload"),
-                        ))
-                        .expect(1)
-                        .create();
-
-                    self.mocks.push(mock);
-                }
-            }
+        for payload in batch.iter().filter_map(create_payload) {
+            let mock = self
+                .server
+                .mock("POST", "/events")
+                .with_status(200)
+                .match_body(payload_matcher(&payload))
+                .expect(1)
+                .create();
+
+            self.mocks.push(mock);
+        }
+    }
+
+    // Expects payloads withdrawing the events
+    pub fn set_removed_mocks(&mut self, events: &[TestData]) {
+        for mut payload in events.iter().filter_map(create_payload) {
+            payload.event.removed = true;
+
+            let mock = self
+                .server
+                .mock("POST", "/events")
+                .with_status(200)
+                .match_body(payload_matcher(&payload))
+                .expect(1)
+                .create();
+
+            self.mocks.push(mock);
+        }
+    }
+
+    // Rejects every payload the given number of times, the rejections are served before
+    // the regular mocks. Should be called before the regular mocks are created
+    pub fn set_flaky_mocks(&mut self, batch: &TestBatch, failures: u32) -> usize {
+        let payloads: Vec<PushPayload> = batch.iter().filter_map(create_payload).collect();
+
+        for payload in &payloads {
+            let mock = self
+                .server
+                .mock("POST", "/events")
+                .with_status(503)
+                .match_body(payload_matcher(payload))
+                .expect(failures as usize)
+                .create();
+
+            self.mocks.push(mock);
+        }
+
+        payloads.len()
+    }
+
+    // Rejects all payloads: only the first `attempted` ones are expected to be sent
+    pub fn set_unavailable_mocks(&mut self, batch: &TestBatch, attempted: usize) {
+        for (index, payload) in batch.iter().filter_map(create_payload).enumerate() {
+            let mock = self
+                .server
+                .mock("POST", "/events")
+                .with_status(503)
+                .match_body(payload_matcher(&payload));
+
+            let mock = if index < attempted {
+                mock.expect_at_least(1)
+            } else {
+                mock.expect(0)
+            };
+
+            self.mocks.push(mock.create());
         }
     }
 
@@ -56,3 +93,22 @@ impl WebhookServiceMock {
         }
     }
 }
+
+fn create_payload(event: &TestData) -> Option<PushPayload> {
+    event.data.as_ref().map(|data| PushPayload {
+        schema_version: PUSH_SCHEMA_VERSION,
+        network_data: event.network_data.clone(),
+        event: PushEvent {
+            name: event.name.clone(),
+            tx_hash: event.hash.clone(),
+            tx_index: 0,
+            timestamp: 123,
+            removed: false,
+        },
+        data: data.clone(),
+    })
+}
+
+fn payload_matcher(payload: &PushPayload) -> Matcher {
+    Matcher::PartialJsonString(serde_json::to_string(payload).expect("Failed to serialize payload"))
+}
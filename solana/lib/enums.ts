@@ -199,10 +199,10 @@ export function getCategoryIndex(category: typeof Category) {
 }
 
 export const ACCOUNT_SIZE: Readonly<Record<HapiCoreAccount, number>> = {
-  network: 251,
+  network: 252,
   reporter: 397,
-  case: 380,
-  address: 181,
+  case: 772,
+  address: 182,
   asset: 213,
   confirmation: 123,
 };
@@ -275,6 +275,23 @@ export class HapiCoreProgram {
     return transactionHash;
   }
 
+  public async retireNetwork(networkName: string, wallet?: Signer | Wallet) {
+    const [network] = this.findNetworkAddress(networkName);
+
+    const signer = this.getSigner(wallet);
+
+    const transactionHash = await this.program.methods
+      .retireNetwork()
+      .accounts({
+        authority: this.program.provider.publicKey,
+        network,
+      })
+      .signers([signer])
+      .rpc();
+
+    return transactionHash;
+  }
+
   public async updateStakeConfiguration(
     networkName: string,
     token?: PublicKey,
@@ -391,6 +408,11 @@ export class HapiCoreProgram {
     const [network] = this.findNetworkAddress(networkName);
     const [reporterAccount, bump] = this.findReporterAddress(network, id);
 
+    // The program would reject a taken account with an opaque "already in use" error
+    if (await this.program.account.reporter.fetchNullable(reporterAccount)) {
+      throw new Error(`Reporter ${id} already exists`);
+    }
+
     const signer = this.getSigner(wallet);
 
     const transactionHash = await this.program.methods
@@ -447,6 +469,30 @@ export class HapiCoreProgram {
     return transactionHash;
   }
 
+  async setReporterUrl(
+    networkName: string,
+    id: string,
+    url: string,
+    wallet?: Signer | Wallet
+  ) {
+    const [network] = this.findNetworkAddress(networkName);
+    const [reporter] = this.findReporterAddress(network, id);
+
+    const signer = this.getSigner(wallet);
+
+    const transactionHash = await this.program.methods
+      .setReporterUrl(url)
+      .accounts({
+        signer: signer.publicKey,
+        network,
+        reporter,
+      })
+      .signers([signer])
+      .rpc();
+
+    return transactionHash;
+  }
+
   async activateReporter(
     networkName: string,
     id: string,
@@ -542,6 +588,31 @@ export class HapiCoreProgram {
     return transactionHash;
   }
 
+  async rotateReporterKey(
+    networkName: string,
+    id: string,
+    newAccount: Signer,
+    wallet?: Signer | Wallet
+  ) {
+    const [network] = this.findNetworkAddress(networkName);
+    const [reporter] = this.findReporterAddress(network, id);
+
+    const signer = this.getSigner(wallet);
+
+    const transactionHash = await this.program.methods
+      .rotateReporterKey()
+      .accounts({
+        signer: signer.publicKey,
+        newAccount: newAccount.publicKey,
+        network,
+        reporter,
+      })
+      .signers([signer, newAccount])
+      .rpc();
+
+    return transactionHash;
+  }
+
   async createCase(
     networkName: string,
     id: string,
@@ -608,6 +679,36 @@ export class HapiCoreProgram {
     return transactionHash;
   }
 
+  async linkCases(
+    networkName: string,
+    reporterId: string,
+    caseId: string,
+    relatedCaseId: string,
+    wallet?: Signer | Wallet
+  ) {
+    const [network] = this.findNetworkAddress(networkName);
+    const [reporter] = this.findReporterAddress(network, reporterId);
+    const [caseAccount] = this.findCaseAddress(network, caseId);
+    const [relatedCaseAccount] = this.findCaseAddress(network, relatedCaseId);
+
+    const signer = this.getSigner(wallet);
+
+    const transactionHash = await this.program.methods
+      .linkCases()
+      .accounts({
+        sender: signer.publicKey,
+        network,
+        reporter,
+        case: caseAccount,
+        relatedCase: relatedCaseAccount,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+
+    return transactionHash;
+  }
+
   async createAddress(
     networkName: string,
     address: string,
@@ -726,6 +827,85 @@ export class HapiCoreProgram {
     return transactionHash;
   }
 
+  async confirmAddresses(
+    networkName: string,
+    addresses: string[],
+    reporterId: string,
+    wallet?: Signer | Wallet
+  ) {
+    const [network] = this.findNetworkAddress(networkName);
+    const [reporter] = this.findReporterAddress(network, reporterId);
+
+    const addressAccounts = addresses.map(
+      (address) => this.findAddressAddress(network, encodeAddress(address))[0]
+    );
+
+    // All addresses of the batch belong to the same case
+    const addressData = await this.program.account.address.fetch(
+      addressAccounts[0]
+    );
+    const [caseAccount] = this.findCaseAddress(
+      network,
+      bnToUuid(addressData.caseId)
+    );
+
+    const remainingAccounts: web3.AccountMeta[] = [];
+    for (const addressAccount of addressAccounts) {
+      const [confirmationAccount] = this.findConfirmationAddress(
+        addressAccount,
+        reporterId
+      );
+
+      remainingAccounts.push(
+        { pubkey: addressAccount, isSigner: false, isWritable: true },
+        { pubkey: confirmationAccount, isSigner: false, isWritable: true }
+      );
+    }
+
+    const signer = this.getSigner(wallet);
+
+    const transactionHash = await this.program.methods
+      .confirmAddresses()
+      .accounts({
+        sender: signer.publicKey,
+        network,
+        reporter,
+        case: caseAccount,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([signer])
+      .rpc();
+
+    return transactionHash;
+  }
+
+  async invalidateAddress(
+    networkName: string,
+    address: string,
+    wallet?: Signer | Wallet
+  ) {
+    const [network] = this.findNetworkAddress(networkName);
+    const [addressAccount] = this.findAddressAddress(
+      network,
+      encodeAddress(address)
+    );
+
+    const signer = this.getSigner(wallet);
+
+    const transactionHash = await this.program.methods
+      .invalidateAddress()
+      .accounts({
+        authority: signer.publicKey,
+        network,
+        address: addressAccount,
+      })
+      .signers([signer])
+      .rpc();
+
+    return transactionHash;
+  }
+
   async createAsset(
     networkName: string,
     address: string,
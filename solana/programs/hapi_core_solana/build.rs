@@ -0,0 +1,35 @@
+use std::{env, fs, path::Path};
+
+/// Lists the `ErrorCode` variants in declaration order, which is the order of their codes,
+/// so that clients can map a custom error code of a failed transaction back to the variant
+fn main() {
+    let source = "src/error.rs";
+    println!("cargo:rerun-if-changed={source}");
+
+    let errors = fs::read_to_string(source).expect("Failed to read error codes");
+
+    let variants: Vec<&str> = errors
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| !line.starts_with("pub enum ErrorCode"))
+        .skip(1)
+        .take_while(|line| *line != "}")
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with("//"))
+        .map(|line| line.trim_end_matches(','))
+        .collect();
+
+    assert!(!variants.is_empty(), "No error codes found in {source}");
+
+    let list: String = variants
+        .iter()
+        .map(|variant| format!("    ErrorCode::{variant},\n"))
+        .collect();
+
+    let generated = format!(
+        "impl ErrorCode {{\n    /// Every error in the order of its code\n    pub const ALL: &'static [ErrorCode] = &[\n{list}    ];\n}}\n"
+    );
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+    fs::write(Path::new(&out_dir).join("error_codes.rs"), generated)
+        .expect("Failed to write error codes");
+}
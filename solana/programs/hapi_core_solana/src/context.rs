@@ -124,6 +124,20 @@ pub struct SetAuthority<'info> {
     pub program_data: Account<'info, ProgramData>,
 }
 
+#[derive(Accounts)]
+pub struct RetireNetwork<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::AuthorityMismatch,
+        constraint = !network.is_retired @ ErrorCode::NetworkRetired,
+        seeds = [b"network".as_ref(), network.name.as_ref()],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, Network>,
+}
+
 #[derive(Accounts)]
 #[instruction(
     reporter_id: u128,
@@ -174,6 +188,26 @@ pub struct UpdateReporter<'info> {
     pub reporter: Account<'info, Reporter>,
 }
 
+#[derive(Accounts)]
+pub struct SetReporterUrl<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"network".as_ref(), network.name.as_ref()],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, Network>,
+
+    #[account(
+        mut,
+        owner = id(),
+        constraint = reporter.account == signer.key() @ ErrorCode::InvalidReporter,
+        seeds = [b"reporter".as_ref(), network.key().as_ref(), &reporter.id.to_be_bytes()],
+        bump = reporter.bump,
+    )]
+    pub reporter: Account<'info, Reporter>,
+}
+
 #[derive(Accounts)]
 pub struct ActivateReporter<'info> {
     #[account(mut)]
@@ -279,6 +313,34 @@ pub struct Unstake<'info> {
     pub token_program: Program<'info, Token>,
 }
 
+#[derive(Accounts)]
+pub struct RotateReporterKey<'info> {
+    #[account(
+        constraint = signer.key() == reporter.account || signer.key() == network.authority @ ErrorCode::Unauthorized,
+    )]
+    pub signer: Signer<'info>,
+
+    /// The new key signs as well, so that the reporter can't be moved to an account nobody controls
+    #[account(
+        constraint = new_account.key() != reporter.account @ ErrorCode::InvalidData,
+    )]
+    pub new_account: Signer<'info>,
+
+    #[account(
+        seeds = [b"network".as_ref(), network.name.as_ref()],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, Network>,
+
+    #[account(
+        mut,
+        owner = id(),
+        seeds = [b"reporter".as_ref(), network.key().as_ref(), &reporter.id.to_be_bytes()],
+        bump = reporter.bump,
+    )]
+    pub reporter: Account<'info, Reporter>,
+}
+
 #[derive(Accounts)]
 #[instruction(
     case_id: u128,
@@ -350,6 +412,93 @@ pub struct UpdateCase<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct AddCaseEvidence<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        seeds = [b"network".as_ref(), network.name.as_ref()],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, Network>,
+
+    #[account(
+        owner = id(),
+        constraint = case.reporter_id == reporter.id @ ErrorCode::Unauthorized,
+        constraint = reporter.account == sender.key() @ ErrorCode::InvalidReporter,
+        constraint = reporter.status == ReporterStatus::Active @ ErrorCode::InvalidReporterStatus,
+        seeds = [b"reporter".as_ref(), network.key().as_ref(), &reporter.id.to_be_bytes()],
+        bump = reporter.bump,
+    )]
+    pub reporter: Account<'info, Reporter>,
+
+    // Cases created before the evidence field are resized on the first append
+    #[account(
+        mut,
+        owner = id(),
+        seeds = [b"case".as_ref(), network.key().as_ref(), &case.id.to_be_bytes()],
+        bump = case.bump,
+        realloc = Case::LEN + ACCOUNT_RESERVE_SPACE,
+        realloc::payer = sender,
+        realloc::zero = false,
+    )]
+    pub case: Account<'info, Case>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LinkCases<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        seeds = [b"network".as_ref(), network.name.as_ref()],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, Network>,
+
+    #[account(
+        owner = id(),
+        constraint = (reporter.role == ReporterRole::Publisher
+            && case.reporter_id == reporter.id
+            && related_case.reporter_id == reporter.id) || reporter.role == ReporterRole::Authority @ ErrorCode::Unauthorized,
+        constraint = reporter.account == sender.key() @ ErrorCode::InvalidReporter,
+        constraint = reporter.status == ReporterStatus::Active @ ErrorCode::InvalidReporterStatus,
+        seeds = [b"reporter".as_ref(), network.key().as_ref(), &reporter.id.to_be_bytes()],
+        bump = reporter.bump,
+    )]
+    pub reporter: Account<'info, Reporter>,
+
+    // Cases created before the related cases field are resized on the first link
+    #[account(
+        mut,
+        owner = id(),
+        seeds = [b"case".as_ref(), network.key().as_ref(), &case.id.to_be_bytes()],
+        bump = case.bump,
+        realloc = Case::LEN + ACCOUNT_RESERVE_SPACE,
+        realloc::payer = sender,
+        realloc::zero = false,
+    )]
+    pub case: Account<'info, Case>,
+
+    // Checked by the network field rather than by seeds, so that a case of another
+    // network fails with a mismatch
+    #[account(
+        mut,
+        owner = id(),
+        constraint = related_case.network == network.key() @ ErrorCode::CaseMismatch,
+        constraint = related_case.id != case.id @ ErrorCode::InvalidData,
+        realloc = Case::LEN + ACCOUNT_RESERVE_SPACE,
+        realloc::payer = sender,
+        realloc::zero = false,
+    )]
+    pub related_case: Account<'info, Case>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 #[instruction(addr: [u8; 64], bump: u8)]
 pub struct CreateAddress<'info> {
@@ -357,6 +506,7 @@ pub struct CreateAddress<'info> {
     pub sender: Signer<'info>,
 
     #[account(
+        constraint = !network.is_retired @ ErrorCode::NetworkRetired,
         seeds = [b"network".as_ref(), network.name.as_ref()],
         bump = network.bump,
     )]
@@ -434,6 +584,7 @@ pub struct UpdateAddress<'info> {
     #[account(
         mut,
         owner = id(),
+        constraint = !address.is_invalidated @ ErrorCode::AddressInvalidated,
         seeds = [
             b"address".as_ref(),
             network.key().as_ref(),
@@ -447,6 +598,32 @@ pub struct UpdateAddress<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct InvalidateAddress<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ ErrorCode::AuthorityMismatch,
+        seeds = [b"network".as_ref(), network.name.as_ref()],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, Network>,
+
+    #[account(
+        mut,
+        owner = id(),
+        constraint = !address.is_invalidated @ ErrorCode::AddressInvalidated,
+        seeds = [
+            b"address".as_ref(),
+            network.key().as_ref(),
+            address.address[0..32].as_ref(),
+            address.address[32..64].as_ref(),
+        ],
+        bump = address.bump
+    )]
+    pub address: Account<'info, Address>,
+}
+
 #[derive(Accounts)]
 #[instruction(bump: u8)]
 
@@ -484,6 +661,7 @@ pub struct ConfirmAddress<'info> {
     #[account(
         mut,
         owner = id(),
+        constraint = !address.is_invalidated @ ErrorCode::AddressInvalidated,
         seeds = [
             b"address".as_ref(),
             network.key().as_ref(),
@@ -507,6 +685,40 @@ pub struct ConfirmAddress<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Address and confirmation accounts are passed in pairs as remaining accounts
+#[derive(Accounts)]
+pub struct ConfirmAddresses<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        seeds = [b"network".as_ref(), network.name.as_ref()],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, Network>,
+
+    #[account(
+        owner = id(),
+        constraint = reporter.role == ReporterRole::Validator
+        || reporter.role == ReporterRole::Publisher @ ErrorCode::Unauthorized,
+        constraint = reporter.account == sender.key() @ ErrorCode::InvalidReporter,
+        constraint = reporter.status == ReporterStatus::Active @ ErrorCode::InvalidReporterStatus,
+        seeds = [b"reporter".as_ref(), network.key().as_ref(), &reporter.id.to_be_bytes()],
+        bump = reporter.bump,
+    )]
+    pub reporter: Account<'info, Reporter>,
+
+    #[account(
+        owner = id(),
+        constraint = case.status == CaseStatus::Open @ ErrorCode::CaseClosed,
+        seeds = [b"case".as_ref(), network.key().as_ref(), &case.id.to_be_bytes()],
+        bump = case.bump,
+    )]
+    pub case: Account<'info, Case>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 #[instruction(addr: [u8; 64], asset_id: [u8; 32], bump: u8)]
 pub struct CreateAsset<'info> {
@@ -514,6 +726,7 @@ pub struct CreateAsset<'info> {
     pub sender: Signer<'info>,
 
     #[account(
+        constraint = !network.is_retired @ ErrorCode::NetworkRetired,
         seeds = [b"network".as_ref(), network.name.as_ref()],
         bump = network.bump,
     )]
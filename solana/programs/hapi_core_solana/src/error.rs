@@ -36,8 +36,29 @@ pub enum ErrorCode {
     CaseMismatch,
     #[msg("Risk score must be in 0..10 range")]
     RiskOutOfRange,
+    #[msg("Case evidence limit is reached")]
+    EvidenceLimitReached,
+    #[msg("URL is too long")]
+    UrlTooLong,
+    #[msg("Invalid address account")]
+    InvalidAddress,
+    #[msg("Invalid confirmation account")]
+    InvalidConfirmation,
+    #[msg("Invalid number of addresses in the batch")]
+    InvalidBatchSize,
+    #[msg("Network is retired")]
+    NetworkRetired,
+    #[msg("Address is invalidated")]
+    AddressInvalidated,
+    #[msg("Related case limit is reached")]
+    RelatedCasesLimitReached,
+    #[msg("Cases are already linked")]
+    CasesAlreadyLinked,
 }
 
+// `ErrorCode::ALL`, generated from the enum above by the build script
+include!(concat!(env!("OUT_DIR"), "/error_codes.rs"));
+
 pub fn print_error(error: ErrorCode) -> Result<()> {
     msg!("Error: {}", error);
     Err(error.into())
@@ -1,4 +1,4 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program};
 use anchor_spl::token::{self, Transfer};
 
 mod context;
@@ -6,13 +6,15 @@ mod error;
 mod state;
 
 use context::*;
-use error::{print_error, ErrorCode};
+use error::print_error;
 use state::confirmation::*;
 
+pub use error::ErrorCode;
 pub use state::{
     address::Address,
     asset::Asset,
     case::{Case, CaseStatus},
+    confirmation::Confirmation,
     network::{Network, RewardConfiguration, StakeConfiguration},
     reporter::{Reporter, ReporterRole, ReporterStatus},
     utils::{bytes_to_string, Category},
@@ -44,6 +46,7 @@ pub mod hapi_core_solana {
         network.stake_mint = ctx.accounts.stake_mint.key();
         network.stake_configuration = stake_info;
         network.version = Network::VERSION;
+        network.is_retired = false;
 
         msg!(
             "Network created, data:
@@ -105,6 +108,16 @@ pub mod hapi_core_solana {
         Ok(())
     }
 
+    pub fn retire_network(ctx: Context<RetireNetwork>) -> Result<()> {
+        let network = &mut ctx.accounts.network;
+
+        network.is_retired = true;
+
+        msg!("Network retired: {}", bytes_to_string(&network.name)?);
+
+        Ok(())
+    }
+
     pub fn create_reporter(
         ctx: Context<CreateReporter>,
         reporter_id: u128,
@@ -120,6 +133,10 @@ pub mod hapi_core_solana {
             return print_error(ErrorCode::InvalidUUID);
         }
 
+        if url.len() > Reporter::MAX_URL_LENGTH {
+            return print_error(ErrorCode::UrlTooLong);
+        }
+
         let reporter = &mut ctx.accounts.reporter;
 
         reporter.bump = bump;
@@ -153,6 +170,10 @@ pub mod hapi_core_solana {
         role: ReporterRole,
         url: String,
     ) -> Result<()> {
+        if url.len() > Reporter::MAX_URL_LENGTH {
+            return print_error(ErrorCode::UrlTooLong);
+        }
+
         let reporter = &mut ctx.accounts.reporter;
 
         reporter.account = account;
@@ -172,6 +193,20 @@ pub mod hapi_core_solana {
         Ok(())
     }
 
+    pub fn set_reporter_url(ctx: Context<SetReporterUrl>, url: String) -> Result<()> {
+        if url.len() > Reporter::MAX_URL_LENGTH {
+            return print_error(ErrorCode::UrlTooLong);
+        }
+
+        let reporter = &mut ctx.accounts.reporter;
+
+        reporter.url = url;
+
+        msg!("Reporter url set to {}", reporter.url);
+
+        Ok(())
+    }
+
     pub fn activate_reporter(ctx: Context<ActivateReporter>) -> Result<()> {
         let stake_configuration = &ctx.accounts.network.stake_configuration;
         let reporter = &mut ctx.accounts.reporter;
@@ -253,6 +288,22 @@ pub mod hapi_core_solana {
         Ok(())
     }
 
+    pub fn rotate_reporter_key(ctx: Context<RotateReporterKey>) -> Result<()> {
+        let reporter = &mut ctx.accounts.reporter;
+
+        msg!(
+            "Reporter key rotated
+            from {} to {}",
+            reporter.account,
+            ctx.accounts.new_account.key()
+        );
+
+        // The reporter address is derived from its id, so stake, role and status stay in place
+        reporter.account = ctx.accounts.new_account.key();
+
+        Ok(())
+    }
+
     pub fn create_case(
         ctx: Context<CreateCase>,
         case_id: u128,
@@ -311,6 +362,54 @@ pub mod hapi_core_solana {
         Ok(())
     }
 
+    pub fn add_case_evidence(ctx: Context<AddCaseEvidence>, hash: [u8; 32]) -> Result<()> {
+        let case = &mut ctx.accounts.case;
+
+        if case.evidence.len() >= Case::MAX_EVIDENCE {
+            return print_error(ErrorCode::EvidenceLimitReached);
+        }
+
+        case.evidence.push(hash);
+
+        msg!(
+            "Case evidence added, data:
+            id: {}, count: {}",
+            uuid::Uuid::from_u128(case.id),
+            case.evidence.len(),
+        );
+
+        Ok(())
+    }
+
+    pub fn link_cases(ctx: Context<LinkCases>) -> Result<()> {
+        let case = &mut ctx.accounts.case;
+        let related_case = &mut ctx.accounts.related_case;
+
+        if case.related_cases.contains(&related_case.id) {
+            return print_error(ErrorCode::CasesAlreadyLinked);
+        }
+
+        if case.related_cases.len() >= Case::MAX_RELATED_CASES
+            || related_case.related_cases.len() >= Case::MAX_RELATED_CASES
+        {
+            return print_error(ErrorCode::RelatedCasesLimitReached);
+        }
+
+        // Links are mutual, so that the graph can be walked from either case
+        case.related_cases.push(related_case.id);
+        related_case.related_cases.push(case.id);
+
+        msg!(
+            "Cases linked, data:
+            network: {}, case_id: {}, related_case_id: {}",
+            case.network,
+            uuid::Uuid::from_u128(case.id),
+            uuid::Uuid::from_u128(related_case.id),
+        );
+
+        Ok(())
+    }
+
     pub fn create_address(
         ctx: Context<CreateAddress>,
         addr: [u8; 64],
@@ -332,6 +431,7 @@ pub mod hapi_core_solana {
         address.case_id = ctx.accounts.case.id;
         address.reporter_id = ctx.accounts.reporter.id;
         address.version = Address::VERSION;
+        address.is_invalidated = false;
 
         msg!(
             "Address created, data:
@@ -369,6 +469,19 @@ pub mod hapi_core_solana {
         Ok(())
     }
 
+    pub fn invalidate_address(ctx: Context<InvalidateAddress>) -> Result<()> {
+        let address = &mut ctx.accounts.address;
+
+        address.is_invalidated = true;
+
+        msg!(
+            "Address invalidated: {}",
+            bytes_to_string(&address.address)?
+        );
+
+        Ok(())
+    }
+
     pub fn confirm_address(ctx: Context<ConfirmAddress>, bump: u8) -> Result<()> {
         let address = &mut ctx.accounts.address;
         let confirmation = &mut ctx.accounts.confirmation;
@@ -390,6 +503,171 @@ pub mod hapi_core_solana {
         Ok(())
     }
 
+    pub fn confirm_addresses<'info>(
+        ctx: Context<'_, '_, '_, 'info, ConfirmAddresses<'info>>,
+    ) -> Result<()> {
+        let accounts = ctx.remaining_accounts;
+
+        if accounts.is_empty()
+            || accounts.len() % 2 != 0
+            || accounts.len() / 2 > Confirmation::MAX_BATCH_SIZE
+        {
+            return print_error(ErrorCode::InvalidBatchSize);
+        }
+
+        let network = ctx.accounts.network.key();
+        let reporter_id = ctx.accounts.reporter.id;
+        let rent = Rent::get()?;
+        let mut confirmed = 0;
+
+        for pair in accounts.chunks(2) {
+            let (address_info, confirmation_info) = (&pair[0], &pair[1]);
+
+            if !address_info.is_writable || !confirmation_info.is_writable {
+                return print_error(ErrorCode::InvalidData);
+            }
+
+            // Checks the owner and the discriminator of the account
+            let mut address = Account::<Address>::try_from(address_info)?;
+
+            let expected_address = Pubkey::create_program_address(
+                &[
+                    b"address".as_ref(),
+                    network.as_ref(),
+                    address.address[0..32].as_ref(),
+                    address.address[32..64].as_ref(),
+                    &[address.bump],
+                ],
+                &id(),
+            )
+            .map_err(|_| ErrorCode::InvalidAddress)?;
+
+            if expected_address != address_info.key() || address.network != network {
+                return print_error(ErrorCode::InvalidAddress);
+            }
+
+            if address.is_invalidated {
+                return print_error(ErrorCode::AddressInvalidated);
+            }
+
+            if address.case_id != ctx.accounts.case.id {
+                return print_error(ErrorCode::CaseMismatch);
+            }
+
+            if address.reporter_id == reporter_id {
+                return print_error(ErrorCode::Unauthorized);
+            }
+
+            let reporter_seed = reporter_id.to_be_bytes();
+            let (expected_confirmation, bump) = Pubkey::find_program_address(
+                &[
+                    b"confirmation".as_ref(),
+                    address_info.key.as_ref(),
+                    &reporter_seed,
+                ],
+                &id(),
+            );
+
+            if expected_confirmation != confirmation_info.key() {
+                return print_error(ErrorCode::InvalidConfirmation);
+            }
+
+            // The confirmation account exists once the reporter has confirmed the address
+            if confirmation_info.owner == &id() {
+                msg!("Address is already confirmed by {}, skipping", reporter_id);
+                continue;
+            }
+
+            let space = Confirmation::LEN + ACCOUNT_RESERVE_SPACE;
+            let required_lamports = rent.minimum_balance(space);
+            let system_program = ctx.accounts.system_program.to_account_info();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                b"confirmation".as_ref(),
+                address_info.key.as_ref(),
+                &reporter_seed,
+                &[bump],
+            ]];
+
+            if confirmation_info.lamports() == 0 {
+                system_program::create_account(
+                    CpiContext::new_with_signer(
+                        system_program,
+                        system_program::CreateAccount {
+                            from: ctx.accounts.sender.to_account_info(),
+                            to: confirmation_info.clone(),
+                        },
+                        signer_seeds,
+                    ),
+                    required_lamports,
+                    space as u64,
+                    &id(),
+                )?;
+            } else {
+                // The address is predictable and anyone can fund it, which makes
+                // `create_account` fail, so the account is set up step by step
+                let top_up = required_lamports.saturating_sub(confirmation_info.lamports());
+
+                if top_up > 0 {
+                    system_program::transfer(
+                        CpiContext::new(
+                            system_program.clone(),
+                            system_program::Transfer {
+                                from: ctx.accounts.sender.to_account_info(),
+                                to: confirmation_info.clone(),
+                            },
+                        ),
+                        top_up,
+                    )?;
+                }
+
+                system_program::allocate(
+                    CpiContext::new_with_signer(
+                        system_program.clone(),
+                        system_program::Allocate {
+                            account_to_allocate: confirmation_info.clone(),
+                        },
+                        signer_seeds,
+                    ),
+                    space as u64,
+                )?;
+
+                system_program::assign(
+                    CpiContext::new_with_signer(
+                        system_program,
+                        system_program::Assign {
+                            account_to_assign: confirmation_info.clone(),
+                        },
+                        signer_seeds,
+                    ),
+                    &id(),
+                )?;
+            }
+
+            let confirmation = Confirmation {
+                version: Confirmation::VERSION,
+                bump,
+                network,
+                account: address_info.key(),
+                reporter_id,
+            };
+            confirmation.try_serialize(&mut &mut confirmation_info.try_borrow_mut_data()?[..])?;
+
+            address.confirmations += 1;
+            address.exit(&id())?;
+
+            confirmed += 1;
+        }
+
+        msg!(
+            "{} of {} addresses confirmed by {}",
+            confirmed,
+            accounts.len() / 2,
+            reporter_id
+        );
+
+        Ok(())
+    }
+
     pub fn create_asset(
         ctx: Context<CreateAsset>,
         addr: [u8; 64],
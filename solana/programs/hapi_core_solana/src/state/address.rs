@@ -29,9 +29,12 @@ pub struct Address {
 
     /// Confirmation count for this address
     pub confirmations: u64,
+
+    /// Address reported in error, kept for audit but no longer updated or confirmed
+    pub is_invalidated: bool,
 }
 
 impl Address {
-    pub const LEN: usize = DISCRIMINATOR_LENGTH + (2 + 1 + 32 + 64 + 1 + 1 + 16 + 16 + 8);
+    pub const LEN: usize = DISCRIMINATOR_LENGTH + (2 + 1 + 32 + 64 + 1 + 1 + 16 + 16 + 8 + 1);
     pub const VERSION: u16 = 1;
 }
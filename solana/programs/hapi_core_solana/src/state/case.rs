@@ -26,10 +26,25 @@ pub struct Case {
 
     /// A link to publicly available case documentation
     pub url: String,
+
+    /// Content hashes of the off-chain investigation artifacts
+    pub evidence: Vec<[u8; 32]>,
+
+    /// UUIDs of the linked cases of the same network
+    pub related_cases: Vec<u128>,
 }
 
 impl Case {
-    pub const LEN: usize = DISCRIMINATOR_LENGTH + (2 + 1 + 16 + 32 + 128 + 32 + 1 + 128);
+    /// The maximum number of evidence hashes per case
+    pub const MAX_EVIDENCE: usize = 8;
+
+    /// The maximum number of linked cases per case
+    pub const MAX_RELATED_CASES: usize = 8;
+
+    pub const LEN: usize = DISCRIMINATOR_LENGTH
+        + (2 + 1 + 16 + 32 + 128 + 32 + 1 + 128)
+        + (4 + 32 * Case::MAX_EVIDENCE)
+        + (4 + 16 * Case::MAX_RELATED_CASES);
     pub const VERSION: u16 = 1;
 }
 
@@ -22,4 +22,8 @@ pub struct Confirmation {
 impl Confirmation {
     pub const LEN: usize = DISCRIMINATOR_LENGTH + (2 + 1 + 32 + 32 + 16);
     pub const VERSION: u16 = 1;
+
+    /// The maximum number of addresses confirmed in one transaction, each of them takes
+    /// two account keys within the 1232 byte transaction size limit
+    pub const MAX_BATCH_SIZE: usize = 10;
 }
@@ -26,10 +26,13 @@ pub struct Network {
 
     /// Reward configuration info
     pub reward_configuration: RewardConfiguration,
+
+    /// Retired network is no longer monitored and doesn't accept new reports
+    pub is_retired: bool,
 }
 
 impl Network {
-    pub const LEN: usize = DISCRIMINATOR_LENGTH + (2 + 1 + 32 + 32 + 32 + 48 + 32 + 32);
+    pub const LEN: usize = DISCRIMINATOR_LENGTH + (2 + 1 + 32 + 32 + 32 + 48 + 32 + 32 + 1);
     pub const VERSION: u16 = 1;
 }
 
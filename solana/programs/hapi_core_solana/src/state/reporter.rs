@@ -38,8 +38,11 @@ pub struct Reporter {
 }
 
 impl Reporter {
-    pub const LEN: usize =
-        DISCRIMINATOR_LENGTH + (2 + 1 + 16 + 32 + 32 + 128 + 1 + 1 + 8 + 8 + 128);
+    /// The longest URL in bytes, that fits into the account along with its length prefix
+    pub const MAX_URL_LENGTH: usize = 124;
+
+    pub const LEN: usize = DISCRIMINATOR_LENGTH
+        + (2 + 1 + 16 + 32 + 32 + 128 + 1 + 1 + 8 + 8 + (4 + Reporter::MAX_URL_LENGTH));
     pub const VERSION: u16 = 1;
 }
 
@@ -22,6 +22,7 @@ import {
   uuidToBn,
   CaseStatus,
   decodeAddress,
+  encodeAddress,
 } from "../lib";
 
 describe("HapiCore Address", () => {
@@ -1107,4 +1108,456 @@ describe("HapiCore Address", () => {
       );
     });
   });
+
+  describe("confirm_addresses", () => {
+    const cs = CASES.secondCase;
+
+    const BATCH = [
+      "9d5a5f2b1c6e4b7a8f3c2d1e0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a1b",
+      "1b2a3f4e5d6c7b8a9f0e1d2c3b4a5f6e7d8c9b0a1f2e3d4c5b6a7f8e9d0c1b2a",
+    ].map(encodeAddress);
+
+    function remainingAccounts(addresses: Buffer[], reporterId: string) {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const accounts: web3.AccountMeta[] = [];
+
+      for (const address of addresses) {
+        const [addressAccount] = program.findAddressAddress(
+          networkAccount,
+          address
+        );
+        const [confirmationAccount] = program.findConfirmationAddress(
+          addressAccount,
+          reporterId
+        );
+
+        accounts.push(
+          { pubkey: addressAccount, isSigner: false, isWritable: true },
+          { pubkey: confirmationAccount, isSigner: false, isWritable: true }
+        );
+      }
+
+      return accounts;
+    }
+
+    async function confirmations(addresses: Buffer[]) {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      return Promise.all(
+        addresses.map(async (address) => {
+          const [addressAccount] = program.findAddressAddress(
+            networkAccount,
+            address
+          );
+
+          return (await program.program.account.address.fetch(addressAccount))
+            .confirmations;
+        })
+      );
+    }
+
+    function confirmAddresses(
+      reporter: (typeof REPORTERS)[keyof typeof REPORTERS],
+      accounts: web3.AccountMeta[]
+    ) {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporter.id
+      );
+      const [caseAccount] = program.findCaseAddress(networkAccount, cs.id);
+
+      return program.program.methods
+        .confirmAddresses()
+        .accounts({
+          sender: reporter.keypair.publicKey,
+          network: networkAccount,
+          reporter: reporterAccount,
+          case: caseAccount,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .remainingAccounts(accounts)
+        .signers([reporter.keypair])
+        .rpc();
+    }
+
+    beforeAll(async () => {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      const reporter = REPORTERS.tracer;
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporter.id
+      );
+      const [caseAccount] = program.findCaseAddress(networkAccount, cs.id);
+
+      for (const address of BATCH) {
+        const [addressAccount, bump] = program.findAddressAddress(
+          networkAccount,
+          address
+        );
+
+        await program.program.methods
+          .createAddress([...address], Category.Scam, 5, bump)
+          .accounts({
+            sender: reporter.keypair.publicKey,
+            network: networkAccount,
+            reporter: reporterAccount,
+            case: caseAccount,
+            address: addressAccount,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([reporter.keypair])
+          .rpc();
+      }
+    });
+
+    it("fail - empty batch", async () => {
+      await expectThrowError(
+        () => confirmAddresses(REPORTERS.validator, []),
+        programError("InvalidBatchSize")
+      );
+    });
+
+    it("fail - address of another case", async () => {
+      const addresses = [...BATCH, ADDRESSES.thirdAddress.address];
+
+      await expectThrowError(
+        () =>
+          confirmAddresses(
+            REPORTERS.validator,
+            remainingAccounts(addresses, REPORTERS.validator.id)
+          ),
+        programError("CaseMismatch")
+      );
+
+      // The failed batch is reverted as a whole
+      const counts = await confirmations(BATCH);
+      expect(counts.every((count) => count.isZero())).toBeTruthy();
+    });
+
+    it("fail - confirmation of another reporter", async () => {
+      const accounts = remainingAccounts(BATCH, REPORTERS.publisher.id);
+
+      await expectThrowError(
+        () => confirmAddresses(REPORTERS.validator, accounts),
+        programError("InvalidConfirmation")
+      );
+    });
+
+    it("success - validator confirms several addresses", async () => {
+      const reporter = REPORTERS.validator;
+
+      await confirmAddresses(reporter, remainingAccounts(BATCH, reporter.id));
+
+      const counts = await confirmations(BATCH);
+      expect(counts.every((count) => count.eqn(1))).toBeTruthy();
+
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      for (const address of BATCH) {
+        const [addressAccount] = program.findAddressAddress(
+          networkAccount,
+          address
+        );
+        const [confirmationAccount, bump] = program.findConfirmationAddress(
+          addressAccount,
+          reporter.id
+        );
+
+        const fetchedConfirmationAccount =
+          await program.program.account.confirmation.fetch(
+            confirmationAccount
+          );
+
+        expect(fetchedConfirmationAccount.bump).toEqual(bump);
+        expect(fetchedConfirmationAccount.network).toEqual(networkAccount);
+        expect(fetchedConfirmationAccount.account).toEqual(addressAccount);
+        expect(
+          fetchedConfirmationAccount.reporterId.eq(uuidToBn(reporter.id))
+        ).toBeTruthy();
+
+        const confirmationInfo =
+          await provider.connection.getAccountInfoAndContext(
+            confirmationAccount
+          );
+        expect(confirmationInfo.value.owner).toEqual(program.programId);
+        expect(confirmationInfo.value.data).toHaveLength(
+          ACCOUNT_SIZE.confirmation
+        );
+      }
+    });
+
+    it("success - already confirmed addresses are skipped", async () => {
+      // The publisher has confirmed the second address one by one
+      const addresses = [ADDRESSES.secondAddress.address, ...BATCH];
+      const before = await confirmations(addresses);
+
+      await confirmAddresses(
+        REPORTERS.publisher,
+        remainingAccounts(addresses, REPORTERS.publisher.id)
+      );
+
+      const after = await confirmations(addresses);
+      expect(after[0].eq(before[0])).toBeTruthy();
+      expect(after[1].eq(before[1].addn(1))).toBeTruthy();
+      expect(after[2].eq(before[2].addn(1))).toBeTruthy();
+
+      // Repeated batch changes nothing
+      await confirmAddresses(
+        REPORTERS.publisher,
+        remainingAccounts(addresses, REPORTERS.publisher.id)
+      );
+
+      const repeated = await confirmations(addresses);
+      expect(repeated.every((count, i) => count.eq(after[i]))).toBeTruthy();
+    });
+
+    it("success - pre-funded confirmation account is created", async () => {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const tracer = REPORTERS.tracer;
+      const [tracerAccount] = program.findReporterAddress(
+        networkAccount,
+        tracer.id
+      );
+      const [caseAccount] = program.findCaseAddress(networkAccount, cs.id);
+
+      const addresses = [
+        "5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b3a2f1e0d9c8b7a6f5e4d",
+        "0f1e2d3c4b5a69788796a5b4c3d2e1f00f1e2d3c4b5a69788796a5b4c3d2e1f0",
+      ].map(encodeAddress);
+
+      for (const address of addresses) {
+        const [addressAccount, bump] = program.findAddressAddress(
+          networkAccount,
+          address
+        );
+
+        await program.program.methods
+          .createAddress([...address], Category.Scam, 5, bump)
+          .accounts({
+            sender: tracer.keypair.publicKey,
+            network: networkAccount,
+            reporter: tracerAccount,
+            case: caseAccount,
+            address: addressAccount,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([tracer.keypair])
+          .rpc();
+      }
+
+      const reporter = REPORTERS.validator;
+      const accounts = remainingAccounts(addresses, reporter.id);
+
+      // Anyone can send lamports to the predictable confirmation address
+      const prefunded = accounts[1].pubkey;
+      await provider.sendAndConfirm(
+        new web3.Transaction().add(
+          web3.SystemProgram.transfer({
+            fromPubkey: provider.wallet.publicKey,
+            toPubkey: prefunded,
+            lamports: 1000,
+          })
+        )
+      );
+
+      await confirmAddresses(reporter, accounts);
+
+      const counts = await confirmations(addresses);
+      expect(counts.every((count) => count.eqn(1))).toBeTruthy();
+
+      for (const { pubkey } of [accounts[1], accounts[3]]) {
+        const confirmationInfo =
+          await provider.connection.getAccountInfoAndContext(pubkey);
+        expect(confirmationInfo.value.owner).toEqual(program.programId);
+        expect(confirmationInfo.value.data).toHaveLength(
+          ACCOUNT_SIZE.confirmation
+        );
+
+        const rentExempt =
+          await provider.connection.getMinimumBalanceForRentExemption(
+            ACCOUNT_SIZE.confirmation
+          );
+        expect(confirmationInfo.value.lamports).toBeGreaterThanOrEqual(
+          rentExempt
+        );
+      }
+    });
+  });
+
+  describe("invalidate_address", () => {
+    const address = ADDRESSES.thirdAddress;
+
+    function invalidateAddress(authority: web3.Keypair | null) {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [addressAccount] = program.findAddressAddress(
+        networkAccount,
+        address.address
+      );
+
+      const method = program.program.methods.invalidateAddress().accounts({
+        authority: authority?.publicKey ?? provider.wallet.publicKey,
+        network: networkAccount,
+        address: addressAccount,
+      });
+
+      return (authority ? method.signers([authority]) : method).rpc();
+    }
+
+    it("fail - authority mismatch", async () => {
+      await expectThrowError(
+        () => invalidateAddress(REPORTERS.authority.keypair),
+        programError("AuthorityMismatch")
+      );
+    });
+
+    it("success - network authority invalidates the address", async () => {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [addressAccount] = program.findAddressAddress(
+        networkAccount,
+        address.address
+      );
+
+      const before = await program.program.account.address.fetch(
+        addressAccount
+      );
+      expect(before.isInvalidated).toBeFalsy();
+
+      await invalidateAddress(null);
+
+      // The report is kept for audit
+      const after = await program.program.account.address.fetch(
+        addressAccount
+      );
+      expect(after.isInvalidated).toBeTruthy();
+      expect(after.reporterId.eq(before.reporterId)).toBeTruthy();
+      expect(after.caseId.eq(before.caseId)).toBeTruthy();
+      expect(after.riskScore).toEqual(before.riskScore);
+      expect(after.category).toEqual(before.category);
+    });
+
+    it("fail - address is already invalidated", async () => {
+      await expectThrowError(
+        () => invalidateAddress(null),
+        programError("AddressInvalidated")
+      );
+    });
+
+    it("fail - invalidated address can't be updated", async () => {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      const reporter = REPORTERS.authority;
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporter.id
+      );
+
+      const [caseAccount] = program.findCaseAddress(
+        networkAccount,
+        CASES.secondCase.id
+      );
+
+      const [addressAccount] = program.findAddressAddress(
+        networkAccount,
+        address.address
+      );
+
+      await expectThrowError(
+        () =>
+          program.program.methods
+            .updateAddress(Category.Scam, 1)
+            .accounts({
+              sender: reporter.keypair.publicKey,
+              network: networkAccount,
+              reporter: reporterAccount,
+              case: caseAccount,
+              address: addressAccount,
+              systemProgram: web3.SystemProgram.programId,
+            })
+            .signers([reporter.keypair])
+            .rpc(),
+        programError("AddressInvalidated")
+      );
+    });
+  });
+
+  describe("retired network", () => {
+    it("success - network authority retires the network", async () => {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      await program.program.methods
+        .retireNetwork()
+        .accounts({
+          authority: provider.wallet.publicKey,
+          network: networkAccount,
+        })
+        .rpc();
+
+      const fetchedNetworkAccount = await program.program.account.network.fetch(
+        networkAccount
+      );
+      expect(fetchedNetworkAccount.isRetired).toBeTruthy();
+    });
+
+    it("fail - network is retired", async () => {
+      const address = encodeAddress(
+        "5a1e2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f8"
+      );
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      const reporter = REPORTERS.publisher;
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporter.id
+      );
+
+      const cs = CASES.secondCase;
+      const [caseAccount] = await program.findCaseAddress(
+        networkAccount,
+        cs.id
+      );
+
+      const [addressAccount, bump] = await program.findAddressAddress(
+        networkAccount,
+        address
+      );
+
+      await expectThrowError(
+        () =>
+          program.program.methods
+            .createAddress([...address], Category.Scam, 5, bump)
+            .accounts({
+              sender: reporter.keypair.publicKey,
+              network: networkAccount,
+              reporter: reporterAccount,
+              case: caseAccount,
+              address: addressAccount,
+              systemProgram: web3.SystemProgram.programId,
+            })
+            .signers([reporter.keypair])
+            .rpc(),
+        programError("NetworkRetired")
+      );
+    });
+
+    it("success - addresses of a retired network are readable", async () => {
+      const address = ADDRESSES.firstAddress;
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      const [addressAccount] = await program.findAddressAddress(
+        networkAccount,
+        address.address
+      );
+
+      const fetchedAddressAccount = await program.program.account.address.fetch(
+        addressAccount
+      );
+
+      expect(fetchedAddressAccount.network).toEqual(networkAccount);
+      expect(decodeAddress(fetchedAddressAccount.address)).toEqual(
+        decodeAddress(address.address)
+      );
+    });
+  });
 });
@@ -22,6 +22,7 @@ import {
   uuidToBn,
   CaseStatus,
   decodeAddress,
+  bufferFromString,
 } from "../lib";
 
 describe("HapiCoreAsset ", () => {
@@ -1139,4 +1140,90 @@ describe("HapiCoreAsset ", () => {
       );
     });
   });
+
+  describe("retired network", () => {
+    it("success - network authority retires the network", async () => {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      await program.program.methods
+        .retireNetwork()
+        .accounts({
+          authority: provider.wallet.publicKey,
+          network: networkAccount,
+        })
+        .rpc();
+
+      const fetchedNetworkAccount = await program.program.account.network.fetch(
+        networkAccount
+      );
+      expect(fetchedNetworkAccount.isRetired).toBeTruthy();
+    });
+
+    it("fail - network is retired", async () => {
+      const asset = ASSETS.firstAsset;
+      const assetId = bufferFromString("87654321", 32);
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      const reporter = REPORTERS.publisher;
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporter.id
+      );
+
+      const cs = CASES.secondCase;
+      const [caseAccount] = await program.findCaseAddress(
+        networkAccount,
+        cs.id
+      );
+
+      const [assetAccount, bump] = await program.findAssetAddress(
+        networkAccount,
+        asset.address,
+        assetId
+      );
+
+      await expectThrowError(
+        () =>
+          program.program.methods
+            .createAsset(
+              [...asset.address],
+              [...assetId],
+              Category[asset.category],
+              asset.riskScore,
+              bump
+            )
+            .accounts({
+              sender: reporter.keypair.publicKey,
+              network: networkAccount,
+              reporter: reporterAccount,
+              case: caseAccount,
+              asset: assetAccount,
+              systemProgram: web3.SystemProgram.programId,
+            })
+            .signers([reporter.keypair])
+            .rpc(),
+        programError("NetworkRetired")
+      );
+    });
+
+    it("success - assets of a retired network are readable", async () => {
+      const asset = ASSETS.firstAsset;
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+
+      const [assetAccount] = await program.findAssetAddress(
+        networkAccount,
+        asset.address,
+        asset.id
+      );
+
+      const fetchedAssetAccount = await program.program.account.asset.fetch(
+        assetAccount
+      );
+
+      expect(fetchedAssetAccount.network).toEqual(networkAccount);
+      expect(decodeAddress(fetchedAssetAccount.address)).toEqual(
+        decodeAddress(asset.address)
+      );
+    });
+  });
 });
@@ -1,6 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { web3, BN } from "@coral-xyz/anchor";
 import { v1 as uuidv1 } from "uuid";
+import { createHash } from "crypto";
 
 import { TestToken } from "./util/token";
 import { expectThrowError } from "./util/console";
@@ -12,6 +13,7 @@ import {
   setupReporters,
   getCases,
   HAPI_CORE_TEST_ID,
+  Reporter,
 } from "./util/setup";
 
 import {
@@ -32,9 +34,10 @@ describe("HapiCore Case", () => {
   let rewardToken: TestToken;
 
   const mainNetwork = "CaseMainNetwork";
+  const otherNetwork = "CaseOtherNetwork";
 
   const REPORTERS = getReporters();
-  const NETWORKS = getNetworks([mainNetwork]);
+  const NETWORKS = getNetworks([mainNetwork, otherNetwork]);
   const CASES = getCases();
 
   beforeAll(async () => {
@@ -611,4 +614,235 @@ describe("HapiCore Case", () => {
       expect(fetchedCaseAccount.status).toEqual(CaseStatus.Closed);
     });
   });
+
+  describe("add_case_evidence", () => {
+    const MAX_EVIDENCE = 8;
+
+    const evidenceHash = (index: number) =>
+      Array.from(createHash("sha256").update(`evidence ${index}`).digest());
+
+    const addEvidence = (
+      reporter: Reporter,
+      caseId: string,
+      hash: number[]
+    ) => {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporter.id
+      );
+      const [caseAccount] = program.findCaseAddress(networkAccount, caseId);
+
+      return program.program.methods
+        .addCaseEvidence(hash)
+        .accounts({
+          sender: reporter.keypair.publicKey,
+          network: networkAccount,
+          reporter: reporterAccount,
+          case: caseAccount,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([reporter.keypair])
+        .rpc();
+    };
+
+    it("fail - only the case reporter can add evidence", async () => {
+      await expectThrowError(
+        () =>
+          addEvidence(
+            REPORTERS.authority,
+            CASES.firstCase.id,
+            evidenceHash(0)
+          ),
+        programError("Unauthorized")
+      );
+    });
+
+    it("success - publisher adds evidence to first case", async () => {
+      const cs = CASES.firstCase;
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [caseAccount] = program.findCaseAddress(networkAccount, cs.id);
+
+      await addEvidence(REPORTERS.publisher, cs.id, evidenceHash(0));
+
+      const fetchedCaseAccount = await program.program.account.case.fetch(
+        caseAccount
+      );
+
+      expect(fetchedCaseAccount.evidence).toEqual([evidenceHash(0)]);
+
+      const caseInfo = await provider.connection.getAccountInfoAndContext(
+        caseAccount
+      );
+      expect(caseInfo.value.data.length).toEqual(ACCOUNT_SIZE.case);
+    });
+
+    it("success - publisher fills up the evidence list", async () => {
+      const cs = CASES.firstCase;
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [caseAccount] = program.findCaseAddress(networkAccount, cs.id);
+
+      for (let index = 1; index < MAX_EVIDENCE; index++) {
+        await addEvidence(REPORTERS.publisher, cs.id, evidenceHash(index));
+      }
+
+      const fetchedCaseAccount = await program.program.account.case.fetch(
+        caseAccount
+      );
+
+      expect(fetchedCaseAccount.evidence).toEqual(
+        [...Array(MAX_EVIDENCE).keys()].map(evidenceHash)
+      );
+    });
+
+    it("fail - evidence limit is reached", async () => {
+      await expectThrowError(
+        () =>
+          addEvidence(
+            REPORTERS.publisher,
+            CASES.firstCase.id,
+            evidenceHash(MAX_EVIDENCE)
+          ),
+        programError("EvidenceLimitReached")
+      );
+    });
+  });
+
+  describe("link_cases", () => {
+    const otherCase = getCases().firstCase;
+
+    beforeAll(async () => {
+      await setupReporters(
+        program,
+        { authority: REPORTERS.authority },
+        otherNetwork,
+        stakeToken
+      );
+
+      await program.createCase(
+        otherNetwork,
+        otherCase.id,
+        otherCase.name,
+        otherCase.url,
+        REPORTERS.authority.id,
+        REPORTERS.authority.keypair
+      );
+    });
+
+    const fetchRelatedCases = async (caseId: string) => {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [caseAccount] = program.findCaseAddress(networkAccount, caseId);
+
+      const fetchedCaseAccount = await program.program.account.case.fetch(
+        caseAccount
+      );
+
+      return (fetchedCaseAccount.relatedCases as BN[]).map((id) =>
+        id.toString()
+      );
+    };
+
+    it("fail - publisher can't link another reporter's case", async () => {
+      await expectThrowError(
+        () =>
+          program.linkCases(
+            mainNetwork,
+            REPORTERS.publisher.id,
+            CASES.firstCase.id,
+            CASES.secondCase.id,
+            REPORTERS.publisher.keypair
+          ),
+        programError("Unauthorized")
+      );
+    });
+
+    it("fail - case can't be linked to itself", async () => {
+      await expectThrowError(
+        () =>
+          program.linkCases(
+            mainNetwork,
+            REPORTERS.authority.id,
+            CASES.firstCase.id,
+            CASES.firstCase.id,
+            REPORTERS.authority.keypair
+          ),
+        programError("InvalidData")
+      );
+    });
+
+    it("fail - case of another network can't be linked", async () => {
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [otherNetworkAccount] = program.findNetworkAddress(otherNetwork);
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        REPORTERS.authority.id
+      );
+      const [caseAccount] = program.findCaseAddress(
+        networkAccount,
+        CASES.firstCase.id
+      );
+      const [otherCaseAccount] = program.findCaseAddress(
+        otherNetworkAccount,
+        otherCase.id
+      );
+
+      await expectThrowError(
+        () =>
+          program.program.methods
+            .linkCases()
+            .accounts({
+              sender: REPORTERS.authority.keypair.publicKey,
+              network: networkAccount,
+              reporter: reporterAccount,
+              case: caseAccount,
+              relatedCase: otherCaseAccount,
+              systemProgram: web3.SystemProgram.programId,
+            })
+            .signers([REPORTERS.authority.keypair])
+            .rpc(),
+        programError("CaseMismatch")
+      );
+    });
+
+    it("success - authority links first and second cases", async () => {
+      await program.linkCases(
+        mainNetwork,
+        REPORTERS.authority.id,
+        CASES.firstCase.id,
+        CASES.secondCase.id,
+        REPORTERS.authority.keypair
+      );
+
+      expect(await fetchRelatedCases(CASES.firstCase.id)).toEqual([
+        uuidToBn(CASES.secondCase.id).toString(),
+      ]);
+      expect(await fetchRelatedCases(CASES.secondCase.id)).toEqual([
+        uuidToBn(CASES.firstCase.id).toString(),
+      ]);
+
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [caseAccount] = program.findCaseAddress(
+        networkAccount,
+        CASES.secondCase.id
+      );
+      const caseInfo = await provider.connection.getAccountInfoAndContext(
+        caseAccount
+      );
+      expect(caseInfo.value.data.length).toEqual(ACCOUNT_SIZE.case);
+    });
+
+    it("fail - cases are already linked", async () => {
+      await expectThrowError(
+        () =>
+          program.linkCases(
+            mainNetwork,
+            REPORTERS.authority.id,
+            CASES.secondCase.id,
+            CASES.firstCase.id,
+            REPORTERS.authority.keypair
+          ),
+        programError("CasesAlreadyLinked")
+      );
+    });
+  });
 });
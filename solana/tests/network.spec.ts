@@ -389,6 +389,37 @@ describe("HapiCore Network", () => {
         programError("UpdatedMint")
       );
     });
+
+    it("success - every field is updated on its own", async () => {
+      const [networkAccount] = program.findNetworkAddress(networkName);
+
+      const fields = Object.keys(stakeConfiguration) as Array<
+        keyof typeof stakeConfiguration
+      >;
+      const expected = { ...stakeConfiguration };
+
+      for (const field of fields) {
+        expected[field] = expected[field].add(new BN(100));
+
+        await program.program.methods
+          .updateStakeConfiguration(expected)
+          .accounts({
+            authority: authority.publicKey,
+            network: networkAccount,
+            stakeMint: stakeToken.mintAccount,
+          })
+          .rpc();
+
+        const fetchedNetworkAccount =
+          await program.program.account.network.fetch(networkAccount);
+
+        for (const key of fields) {
+          expect(
+            fetchedNetworkAccount.stakeConfiguration[key].eq(expected[key])
+          ).toBeTruthy();
+        }
+      }
+    });
   });
 
   describe("update_reward_configuration", () => {
@@ -563,4 +594,61 @@ describe("HapiCore Network", () => {
       expect(fetchedNetworkAccount.authority).toEqual(authority.publicKey);
     });
   });
+
+  describe("retire_network", () => {
+    it("fail - authority mismatch", async () => {
+      const [networkAccount] = program.findNetworkAddress(networkName);
+
+      await expectThrowError(
+        () =>
+          program.program.methods
+            .retireNetwork()
+            .accounts({
+              authority: another_authority.publicKey,
+              network: networkAccount,
+            })
+            .signers([another_authority])
+            .rpc(),
+        programError("AuthorityMismatch")
+      );
+    });
+
+    it("success", async () => {
+      const [networkAccount] = program.findNetworkAddress(networkName);
+
+      let fetchedNetworkAccount = await program.program.account.network.fetch(
+        networkAccount
+      );
+      expect(fetchedNetworkAccount.isRetired).toBeFalsy();
+
+      await program.program.methods
+        .retireNetwork()
+        .accounts({
+          authority: authority.publicKey,
+          network: networkAccount,
+        })
+        .rpc();
+
+      fetchedNetworkAccount = await program.program.account.network.fetch(
+        networkAccount
+      );
+      expect(fetchedNetworkAccount.isRetired).toBeTruthy();
+    });
+
+    it("fail - network is already retired", async () => {
+      const [networkAccount] = program.findNetworkAddress(networkName);
+
+      await expectThrowError(
+        () =>
+          program.program.methods
+            .retireNetwork()
+            .accounts({
+              authority: authority.publicKey,
+              network: networkAccount,
+            })
+            .rpc(),
+        programError("NetworkRetired")
+      );
+    });
+  });
 });
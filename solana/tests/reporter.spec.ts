@@ -369,6 +369,23 @@ describe("HapiCore Reporter", () => {
         /custom program error: 0x0/
       );
     });
+
+    it("fail - reporter already exists, checked by the client", async () => {
+      const reporter = REPORTERS.publisher;
+
+      await expectThrowError(
+        () =>
+          program.createReporter(
+            mainNetwork,
+            reporter.id,
+            reporter.role,
+            reporter.keypair.publicKey,
+            reporter.name,
+            reporter.url
+          ),
+        `Reporter ${reporter.id} already exists`
+      );
+    });
   });
 
   describe("update_reporter", () => {
@@ -500,6 +517,85 @@ describe("HapiCore Reporter", () => {
     });
   });
 
+  describe("set_reporter_url", () => {
+    const setUrl = (
+      networkName: string,
+      reporterId: string,
+      signer: web3.Keypair,
+      url: string
+    ) => {
+      const [networkAccount] = program.findNetworkAddress(networkName);
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporterId
+      );
+
+      return program.program.methods
+        .setReporterUrl(url)
+        .accounts({
+          signer: signer.publicKey,
+          network: networkAccount,
+          reporter: reporterAccount,
+        })
+        .signers([signer])
+        .rpc();
+    };
+
+    it("fail - signer is not the reporter", async () => {
+      const reporter = REPORTERS.publisher;
+
+      await expectThrowError(
+        () =>
+          setUrl(
+            mainNetwork,
+            reporter.id,
+            another_authority,
+            "https://alice.profile"
+          ),
+        programError("InvalidReporter")
+      );
+    });
+
+    it("fail - url is too long", async () => {
+      const reporter = REPORTERS.publisher;
+      const url = "https://" + "a".repeat(117);
+
+      await expectThrowError(
+        () => setUrl(mainNetwork, reporter.id, reporter.keypair, url),
+        programError("UrlTooLong")
+      );
+    });
+
+    it("success", async () => {
+      const reporter = REPORTERS.publisher;
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporter.id
+      );
+
+      // The longest url that fits into the account
+      const url = "https://" + "a".repeat(116);
+
+      await setUrl(mainNetwork, reporter.id, reporter.keypair, url);
+
+      let fetchedReporterAccount = await program.program.account.reporter.fetch(
+        reporterAccount
+      );
+
+      expect(fetchedReporterAccount.url).toEqual(url);
+      expect(fetchedReporterAccount.name).toEqual(reporter.name);
+
+      await setUrl(mainNetwork, reporter.id, reporter.keypair, reporter.url);
+
+      fetchedReporterAccount = await program.program.account.reporter.fetch(
+        reporterAccount
+      );
+
+      expect(fetchedReporterAccount.url).toEqual(reporter.url);
+    });
+  });
+
   describe("activate_reporter", () => {
     it("fail - network mismatch", async () => {
       const reporter = REPORTERS.publisher;
@@ -1127,4 +1223,138 @@ describe("HapiCore Reporter", () => {
       expect(fetchedReporterAccount.unlockTimestamp.isZero()).toBeTruthy();
     });
   });
+
+  describe("rotate_reporter_key", () => {
+    const rotateKey = (
+      networkName: string,
+      reporterId: string,
+      signer: web3.Keypair | undefined,
+      newAccount: web3.Keypair,
+      signers: web3.Keypair[]
+    ) => {
+      const [networkAccount] = program.findNetworkAddress(networkName);
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporterId
+      );
+
+      return program.program.methods
+        .rotateReporterKey()
+        .accounts({
+          signer: signer ? signer.publicKey : authority.publicKey,
+          newAccount: newAccount.publicKey,
+          network: networkAccount,
+          reporter: reporterAccount,
+        })
+        .signers(signers)
+        .rpc();
+    };
+
+    const newTracerKey = web3.Keypair.generate();
+
+    it("fail - signer is neither the reporter nor the authority", async () => {
+      const reporter = REPORTERS.tracer;
+      const newAccount = web3.Keypair.generate();
+
+      await expectThrowError(
+        () =>
+          rotateKey(
+            secondaryNetwork,
+            reporter.id,
+            another_authority,
+            newAccount,
+            [another_authority, newAccount]
+          ),
+        programError("Unauthorized")
+      );
+    });
+
+    it("fail - new key must sign", async () => {
+      const reporter = REPORTERS.tracer;
+
+      await expectThrowError(
+        () =>
+          rotateKey(
+            secondaryNetwork,
+            reporter.id,
+            reporter.keypair,
+            newTracerKey,
+            [reporter.keypair]
+          ),
+        /Signature verification failed/
+      );
+    });
+
+    it("success - bob rotates his key", async () => {
+      const reporter = REPORTERS.tracer;
+      const network = NETWORKS[secondaryNetwork];
+      const [networkAccount] = program.findNetworkAddress(network.name);
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporter.id
+      );
+
+      const before = await program.program.account.reporter.fetch(
+        reporterAccount
+      );
+
+      await rotateKey(
+        secondaryNetwork,
+        reporter.id,
+        reporter.keypair,
+        newTracerKey,
+        [reporter.keypair, newTracerKey]
+      );
+
+      const fetchedReporterAccount =
+        await program.program.account.reporter.fetch(reporterAccount);
+
+      expect(fetchedReporterAccount.account).toEqual(newTracerKey.publicKey);
+      expect(
+        fetchedReporterAccount.stake.eq(network.stakeConfiguration.tracerStake)
+      ).toBeTruthy();
+      expect(fetchedReporterAccount.role).toEqual(before.role);
+      expect(fetchedReporterAccount.status).toEqual(ReporterStatus.Unstaking);
+      expect(
+        fetchedReporterAccount.unlockTimestamp.eq(before.unlockTimestamp)
+      ).toBeTruthy();
+    });
+
+    it("fail - old key is no longer accepted", async () => {
+      const reporter = REPORTERS.tracer;
+      const newAccount = web3.Keypair.generate();
+
+      await expectThrowError(
+        () =>
+          rotateKey(
+            secondaryNetwork,
+            reporter.id,
+            reporter.keypair,
+            newAccount,
+            [reporter.keypair, newAccount]
+          ),
+        programError("Unauthorized")
+      );
+    });
+
+    it("success - authority rotates alice's key", async () => {
+      const reporter = REPORTERS.publisher;
+      const newAccount = web3.Keypair.generate();
+      const [networkAccount] = program.findNetworkAddress(mainNetwork);
+      const [reporterAccount] = program.findReporterAddress(
+        networkAccount,
+        reporter.id
+      );
+
+      await rotateKey(mainNetwork, reporter.id, undefined, newAccount, [
+        newAccount,
+      ]);
+
+      const fetchedReporterAccount =
+        await program.program.account.reporter.fetch(reporterAccount);
+
+      expect(fetchedReporterAccount.account).toEqual(newAccount.publicKey);
+      expect(fetchedReporterAccount.status).toEqual(ReporterStatus.Inactive);
+    });
+  });
 });
@@ -104,7 +104,7 @@ export const ACCOUNT_SIZE: Readonly<Record<HapiCoreAccount, number>> = {
   assetV0: 216,
   case: 148,
   caseV0: 120,
-  community: 172,
+  community: 173,
   communityV0: 192,
   network: 180,
   networkV0: 176,
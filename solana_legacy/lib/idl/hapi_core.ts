@@ -200,6 +200,27 @@ export type HapiCore = {
       ],
       "args": []
     },
+    {
+      "name": "setCommunityPause",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "community",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "isPaused",
+          "type": "bool"
+        }
+      ]
+    },
     {
       "name": "createNetwork",
       "accounts": [
@@ -1471,6 +1492,38 @@ export type HapiCore = {
       ],
       "args": []
     },
+    {
+      "name": "freezeReporters",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "community",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unfreezeReporters",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "community",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "updateReplicationPrice",
       "accounts": [
@@ -1859,6 +1912,13 @@ export type HapiCore = {
               "Amount of stake required from a reporter of appraiser type"
             ],
             "type": "u64"
+          },
+          {
+            "name": "isPaused",
+            "docs": [
+              "Reporting is suspended by the authority"
+            ],
+            "type": "bool"
           }
         ]
       }
@@ -2732,6 +2792,11 @@ export type HapiCore = {
       "code": 6021,
       "name": "InvalidAccountVersion",
       "msg": "Invalid account version"
+    },
+    {
+      "code": 6022,
+      "name": "CommunityPaused",
+      "msg": "Community is paused"
     }
   ]
 };
@@ -2938,6 +3003,27 @@ export const IDL: HapiCore = {
       ],
       "args": []
     },
+    {
+      "name": "setCommunityPause",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "community",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "isPaused",
+          "type": "bool"
+        }
+      ]
+    },
     {
       "name": "createNetwork",
       "accounts": [
@@ -4209,6 +4295,38 @@ export const IDL: HapiCore = {
       ],
       "args": []
     },
+    {
+      "name": "freezeReporters",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "community",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unfreezeReporters",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "community",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "updateReplicationPrice",
       "accounts": [
@@ -4597,6 +4715,13 @@ export const IDL: HapiCore = {
               "Amount of stake required from a reporter of appraiser type"
             ],
             "type": "u64"
+          },
+          {
+            "name": "isPaused",
+            "docs": [
+              "Reporting is suspended by the authority"
+            ],
+            "type": "bool"
           }
         ]
       }
@@ -5470,6 +5595,11 @@ export const IDL: HapiCore = {
       "code": 6021,
       "name": "InvalidAccountVersion",
       "msg": "Invalid account version"
+    },
+    {
+      "code": 6022,
+      "name": "CommunityPaused",
+      "msg": "Community is paused"
     }
   ]
 };
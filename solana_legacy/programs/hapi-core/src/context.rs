@@ -146,6 +146,21 @@ pub struct SetCommunityAuthority<'info> {
     pub new_authority: AccountInfo<'info>,
 }
 
+#[derive(Accounts)]
+#[instruction(is_paused: bool)]
+pub struct SetCommunityPause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        owner = id(),
+        has_one = authority @ ErrorCode::AuthorityMismatch,
+        seeds = [b"community".as_ref(), &community.id.to_le_bytes()],
+        bump = community.bump,
+    )]
+    pub community: Account<'info, Community>,
+}
+
 #[derive(Accounts)]
 #[instruction(
     name: [u8; 32],
@@ -516,6 +531,20 @@ pub struct UnfreezeReporter<'info> {
     pub reporter: Account<'info, Reporter>,
 }
 
+#[derive(Accounts)]
+pub struct FreezeReporters<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        owner = id(),
+        has_one = authority @ ErrorCode::AuthorityMismatch,
+        seeds = [b"community".as_ref(), &community.id.to_le_bytes()],
+        bump = community.bump,
+    )]
+    pub community: Account<'info, Community>,
+    // Reporter accounts are passed as writable remaining accounts
+}
+
 #[derive(Accounts)]
 #[instruction(case_id: u64, name: [u8; 32], bump: u8)]
 pub struct CreateCase<'info> {
@@ -525,6 +554,7 @@ pub struct CreateCase<'info> {
     #[account(
         mut,
         owner = id(),
+        constraint = !community.is_paused @ ErrorCode::CommunityPaused,
         seeds = [b"community".as_ref(), &community.id.to_le_bytes()],
         bump = community.bump,
     )]
@@ -645,6 +675,7 @@ pub struct CreateAddress<'info> {
     pub sender: Signer<'info>,
 
     #[account(
+        constraint = !community.is_paused @ ErrorCode::CommunityPaused,
         seeds = [b"community".as_ref(), &community.id.to_le_bytes()],
         bump = community.bump,
     )]
@@ -990,6 +1021,7 @@ pub struct CreateAsset<'info> {
     pub sender: Signer<'info>,
 
     #[account(
+        constraint = !community.is_paused @ ErrorCode::CommunityPaused,
         seeds = [b"community".as_ref(), &community.id.to_le_bytes()],
         bump = community.bump,
     )]
@@ -1278,6 +1310,7 @@ pub struct ActivateReporter<'info> {
 
     #[account(
         owner = id(),
+        constraint = !community.is_paused @ ErrorCode::CommunityPaused,
         seeds = [b"community".as_ref(), &community.id.to_le_bytes()],
         bump = community.bump,
     )]
@@ -46,6 +46,8 @@ pub enum ErrorCode {
     UnexpectedLength,
     #[msg("Invalid account version")]
     InvalidAccountVersion,
+    #[msg("Community is paused")]
+    CommunityPaused,
 }
 
 pub fn print_error(error: ErrorCode) -> Result<()> {
@@ -19,7 +19,7 @@ use state::{
     network::Network,
     reporter::{Reporter, ReporterReward},
 };
-use utils::close;
+use utils::{close, set_reporters_frozen};
 
 pub use state::{
     address::{Address, Category},
@@ -58,6 +58,7 @@ pub mod hapi_core {
         community.full_stake = full_stake;
         community.authority_stake = authority_stake;
         community.appraiser_stake = appraiser_stake;
+        community.is_paused = false;
         community.version = Community::VERSION;
 
         Ok(())
@@ -153,6 +154,14 @@ pub mod hapi_core {
         Ok(())
     }
 
+    pub fn set_community_pause(ctx: Context<SetCommunityPause>, is_paused: bool) -> Result<()> {
+        let community = &mut ctx.accounts.community;
+
+        community.is_paused = is_paused;
+
+        Ok(())
+    }
+
     pub fn create_network(
         ctx: Context<CreateNetwork>,
         name: [u8; 32],
@@ -863,6 +872,18 @@ pub mod hapi_core {
         Ok(())
     }
 
+    pub fn freeze_reporters<'info>(
+        ctx: Context<'_, '_, '_, 'info, FreezeReporters<'info>>,
+    ) -> Result<()> {
+        set_reporters_frozen(&ctx.accounts.community, ctx.remaining_accounts, true)
+    }
+
+    pub fn unfreeze_reporters<'info>(
+        ctx: Context<'_, '_, '_, 'info, FreezeReporters<'info>>,
+    ) -> Result<()> {
+        set_reporters_frozen(&ctx.accounts.community, ctx.remaining_accounts, false)
+    }
+
     pub fn update_replication_price(
         ctx: Context<UpdateReplicationPrice>,
         price: u64,
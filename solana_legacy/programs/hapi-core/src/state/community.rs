@@ -41,9 +41,12 @@ pub struct Community {
 
     /// Amount of stake required from a reporter of appraiser type
     pub appraiser_stake: u64,
+
+    /// Reporting is suspended by the authority
+    pub is_paused: bool,
 }
 
 impl Community {
-    pub const LEN: usize = DISCRIMINATOR_LENGTH + (2 + 32 + 8 + 1 + 8 + 1 + 8 + 32 + 8 + 8 + 8 + 8 + 8);
+    pub const LEN: usize = DISCRIMINATOR_LENGTH + (2 + 32 + 8 + 1 + 8 + 1 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1);
     pub const VERSION: u16 = 1;
 }
@@ -1,4 +1,8 @@
-use crate::error::{print_error, ErrorCode};
+use crate::{
+    error::{print_error, ErrorCode},
+    id,
+    state::{community::Community, reporter::Reporter},
+};
 use anchor_lang::{__private::CLOSED_ACCOUNT_DISCRIMINATOR, prelude::*};
 use std::{
     io::{Cursor, Write},
@@ -84,3 +88,50 @@ pub fn close<'info>(account: AccountInfo<'info>, destination: AccountInfo<'info>
 
     Ok(())
 }
+
+/// Sets the frozen flag on every reporter account in `reporters`
+///
+/// All accounts are validated before the instruction succeeds, so a single reporter
+/// of another community fails the whole batch and leaves every reporter untouched.
+pub fn set_reporters_frozen<'info>(
+    community: &Account<'info, Community>,
+    reporters: &[AccountInfo<'info>],
+    is_frozen: bool,
+) -> Result<()> {
+    if reporters.is_empty() {
+        return print_error(ErrorCode::UnexpectedAccount);
+    }
+
+    for info in reporters {
+        if !info.is_writable {
+            return print_error(ErrorCode::UnexpectedAccount);
+        }
+
+        // Checks the owner and the discriminator of the account
+        let mut reporter = Account::<Reporter>::try_from(info)?;
+
+        if reporter.community != community.key() {
+            return print_error(ErrorCode::CommunityMismatch);
+        }
+
+        let expected_address = Pubkey::create_program_address(
+            &[
+                b"reporter".as_ref(),
+                community.key().as_ref(),
+                reporter.pubkey.as_ref(),
+                &[reporter.bump],
+            ],
+            &id(),
+        )
+        .map_err(|_| ErrorCode::InvalidReporter)?;
+
+        if expected_address != info.key() {
+            return print_error(ErrorCode::InvalidReporter);
+        }
+
+        reporter.is_frozen = is_frozen;
+        reporter.exit(&id())?;
+    }
+
+    Ok(())
+}
@@ -535,6 +535,85 @@ describe("HapiCore Address", () => {
       );
     });
 
+    it("fail - community is paused", async () => {
+      const addr = ADDRESSES.blackhole1;
+
+      const reporter = REPORTERS[addr.reporter].keypair;
+
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const [networkAccount] = await program.pda.findNetworkAddress(
+        communityAccount,
+        addr.network
+      );
+
+      const [addressAccount, bump] = await program.pda.findAddressAddress(
+        networkAccount,
+        addr.pubkey
+      );
+
+      const [reporterAccount] = await program.pda.findReporterAddress(
+        communityAccount,
+        reporter.publicKey
+      );
+
+      const [caseAccount] = await program.pda.findCaseAddress(
+        communityAccount,
+        addr.caseId
+      );
+
+      const treasuryTokenAccount = await NETWORKS[
+        addr.network
+      ].rewardToken.getTokenAccount(networkAccount, true);
+
+      const reporterPaymentTokenAccount = await NETWORKS[
+        addr.network
+      ].rewardToken.getTokenAccount(reporter.publicKey);
+
+      await program.rpc.setCommunityPause(true, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+
+      await expectThrowError(
+        () =>
+          program.rpc.createAddress(
+            [...addr.pubkey],
+            Category[addr.category],
+            addr.risk,
+            bump,
+            {
+              accounts: {
+                sender: reporter.publicKey,
+                address: addressAccount,
+                community: communityAccount,
+                network: networkAccount,
+                reporter: reporterAccount,
+                case: caseAccount,
+                reporterPaymentTokenAccount,
+                treasuryTokenAccount,
+                tokenProgram: stakeToken.programId,
+                systemProgram: web3.SystemProgram.programId,
+              },
+              signers: [reporter],
+            }
+          ),
+        programError("CommunityPaused")
+      );
+
+      // The address is reported by the next test once the community is unpaused
+      await program.rpc.setCommunityPause(false, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+    });
+
     it("success - blackhole1", async () => {
       const addr = ADDRESSES.blackhole1;
 
@@ -531,6 +531,87 @@ describe("HapiCore Asset", () => {
       );
     });
 
+    it("fail - community is paused", async () => {
+      const asset = ASSETS.stolenNft;
+
+      const reporter = REPORTERS[asset.reporter].keypair;
+
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const [networkAccount] = await program.pda.findNetworkAddress(
+        communityAccount,
+        asset.network
+      );
+
+      const [assetAccount, bump] = await program.pda.findAssetAddress(
+        networkAccount,
+        asset.mint,
+        asset.assetId
+      );
+
+      const [reporterAccount] = await program.pda.findReporterAddress(
+        communityAccount,
+        reporter.publicKey
+      );
+
+      const [caseAccount] = await program.pda.findCaseAddress(
+        communityAccount,
+        asset.caseId
+      );
+
+      const treasuryTokenAccount = await NETWORKS[
+        asset.network
+      ].rewardToken.getTokenAccount(networkAccount, true);
+
+      const reporterPaymentTokenAccount = await NETWORKS[
+        asset.network
+      ].rewardToken.getTokenAccount(reporter.publicKey);
+
+      await program.rpc.setCommunityPause(true, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+
+      await expectThrowError(
+        () =>
+          program.rpc.createAsset(
+            [...asset.mint],
+            [...asset.assetId],
+            Category[asset.category],
+            asset.risk,
+            bump,
+            {
+              accounts: {
+                sender: reporter.publicKey,
+                asset: assetAccount,
+                community: communityAccount,
+                network: networkAccount,
+                reporter: reporterAccount,
+                case: caseAccount,
+                reporterPaymentTokenAccount,
+                treasuryTokenAccount,
+                tokenProgram: stakeToken.programId,
+                systemProgram: web3.SystemProgram.programId,
+              },
+              signers: [reporter],
+            }
+          ),
+        programError("CommunityPaused")
+      );
+
+      // The asset is reported by the next test once the community is unpaused
+      await program.rpc.setCommunityPause(false, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+    });
+
     it("success - stolenNft", async () => {
       const asset = ASSETS.stolenNft;
 
@@ -392,6 +392,103 @@ describe("HapiCore Case", () => {
       );
     });
 
+    it("fail - case id must follow the community case counter", async () => {
+      const cs = CASES.safe;
+
+      const reporter = REPORTERS[cs.reporter].keypair;
+
+      const caseName = bufferFromString(cs.name, 32);
+
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      // No cases are reported yet, so the next id is 1
+      const caseId = cs.caseId.addn(1);
+
+      const [caseAccount, bump] = await program.pda.findCaseAddress(
+        communityAccount,
+        caseId
+      );
+
+      const [reporterAccount] = await program.pda.findReporterAddress(
+        communityAccount,
+        reporter.publicKey
+      );
+
+      await expectThrowError(
+        () =>
+          program.rpc.createCase(caseId, caseName.toJSON().data, bump, {
+            accounts: {
+              reporter: reporterAccount,
+              sender: reporter.publicKey,
+              community: communityAccount,
+              case: caseAccount,
+              systemProgram: web3.SystemProgram.programId,
+            },
+            signers: [reporter],
+          }),
+        programError("NonSequentialCaseId")
+      );
+
+      const communityData = await program.account.community.fetch(
+        communityAccount
+      );
+      expect(communityData.cases.toNumber()).toEqual(0);
+    });
+
+    it("fail - community is paused", async () => {
+      const cs = CASES.safe;
+
+      const reporter = REPORTERS[cs.reporter].keypair;
+
+      const caseName = bufferFromString(cs.name, 32);
+
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const [caseAccount, bump] = await program.pda.findCaseAddress(
+        communityAccount,
+        cs.caseId
+      );
+
+      const [reporterAccount] = await program.pda.findReporterAddress(
+        communityAccount,
+        reporter.publicKey
+      );
+
+      await program.rpc.setCommunityPause(true, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+
+      await expectThrowError(
+        () =>
+          program.rpc.createCase(cs.caseId, caseName.toJSON().data, bump, {
+            accounts: {
+              reporter: reporterAccount,
+              sender: reporter.publicKey,
+              community: communityAccount,
+              case: caseAccount,
+              systemProgram: web3.SystemProgram.programId,
+            },
+            signers: [reporter],
+          }),
+        programError("CommunityPaused")
+      );
+
+      // The case is reported by the next test once the community is unpaused
+      await program.rpc.setCommunityPause(false, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+    });
+
     it("success - alice reports case 'safe'", async () => {
       const cs = CASES.safe;
 
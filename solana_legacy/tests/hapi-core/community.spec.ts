@@ -170,6 +170,7 @@ describe("HapiCore Community", () => {
       expect(communityData.stakeMint).toEqual(stakeToken.mintAccount);
       expect(communityData.bump).toEqual(communityBump);
       expect(communityData.id.eq(communityId)).toBeTruthy();
+      expect(communityData.isPaused).toBe(false);
 
       const communityInfo = await provider.connection.getAccountInfoAndContext(
         communityAccount
@@ -346,6 +347,80 @@ describe("HapiCore Community", () => {
       }
     });
 
+    it("success - each field", async () => {
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const current = {
+        stakeUnlockEpochs: new BN(5),
+        confirmationThreshold: 6,
+        validatorStake: new BN(11_000),
+        tracerStake: new BN(12_000),
+        fullStake: new BN(13_000),
+        authorityStake: new BN(14_000),
+        appraiserStake: new BN(15_000),
+      };
+
+      const changes = [
+        { stakeUnlockEpochs: new BN(7) },
+        { confirmationThreshold: 8 },
+        { validatorStake: new BN(21_000) },
+        { tracerStake: new BN(22_000) },
+        { fullStake: new BN(23_000) },
+        { authorityStake: new BN(24_000) },
+        { appraiserStake: new BN(25_000) },
+      ];
+
+      for (const change of changes) {
+        Object.assign(current, change);
+
+        const tx = await program.rpc.updateCommunity(
+          current.stakeUnlockEpochs,
+          current.confirmationThreshold,
+          current.validatorStake,
+          current.tracerStake,
+          current.fullStake,
+          current.authorityStake,
+          current.appraiserStake,
+          {
+            accounts: {
+              authority: authority.publicKey,
+              community: communityAccount,
+            },
+          }
+        );
+
+        expect(tx).toBeTruthy();
+
+        const communityData = await program.account.community.fetch(
+          communityAccount
+        );
+
+        expect(communityData.stakeUnlockEpochs.toNumber()).toEqual(
+          current.stakeUnlockEpochs.toNumber()
+        );
+        expect(communityData.confirmationThreshold).toEqual(
+          current.confirmationThreshold
+        );
+        expect(communityData.validatorStake.toNumber()).toEqual(
+          current.validatorStake.toNumber()
+        );
+        expect(communityData.tracerStake.toNumber()).toEqual(
+          current.tracerStake.toNumber()
+        );
+        expect(communityData.fullStake.toNumber()).toEqual(
+          current.fullStake.toNumber()
+        );
+        expect(communityData.authorityStake.toNumber()).toEqual(
+          current.authorityStake.toNumber()
+        );
+        expect(communityData.appraiserStake.toNumber()).toEqual(
+          current.appraiserStake.toNumber()
+        );
+      }
+    });
+
     it("fail - invalid authority", async () => {
       const [communityAccount] = await program.pda.findCommunityAddress(
         communityId
@@ -505,4 +580,99 @@ describe("HapiCore Community", () => {
       );
     });
   });
+
+  describe("set_community_pause", () => {
+    const communityId = new BN(12);
+
+    beforeAll(async () => {
+      const [communityAccount, communityBump] =
+        await program.pda.findCommunityAddress(communityId);
+
+      const tokenAccount = await stakeToken.getTokenAccount(
+        communityAccount,
+        true
+      );
+
+      await program.rpc.initializeCommunity(
+        communityId,
+        communityBump,
+        new BN(3),
+        3,
+        new BN(1_000),
+        new BN(2_000),
+        new BN(3_000),
+        new BN(4_000),
+        new BN(5_000),
+        {
+          accounts: {
+            authority: authority.publicKey,
+            community: communityAccount,
+            stakeMint: stakeToken.mintAccount,
+            tokenAccount,
+            systemProgram: web3.SystemProgram.programId,
+          },
+        }
+      );
+    });
+
+    it("fail - invalid authority", async () => {
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      await expectThrowError(
+        () =>
+          program.rpc.setCommunityPause(true, {
+            accounts: {
+              authority: nobody.publicKey,
+              community: communityAccount,
+            },
+            signers: [nobody],
+          }),
+        programError("AuthorityMismatch")
+      );
+    });
+
+    it("success - pause", async () => {
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const tx = await program.rpc.setCommunityPause(true, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+
+      expect(tx).toBeTruthy();
+
+      const communityData = await program.account.community.fetch(
+        communityAccount
+      );
+
+      expect(communityData.isPaused).toBe(true);
+    });
+
+    it("success - unpause", async () => {
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const tx = await program.rpc.setCommunityPause(false, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+
+      expect(tx).toBeTruthy();
+
+      const communityData = await program.account.community.fetch(
+        communityAccount
+      );
+
+      expect(communityData.isPaused).toBe(false);
+    });
+  });
 });
@@ -963,6 +963,60 @@ describe("HapiCore Reporter", () => {
       expect(balance.add(stake).toString(10)).toEqual("1000000");
     });
 
+    it("fail - community is paused", async () => {
+      const reporter = REPORTERS.bob;
+
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const [reporterAccount] = await program.pda.findReporterAddress(
+        communityAccount,
+        reporter.keypair.publicKey
+      );
+
+      const reporterTokenAccount = await stakeToken.getTokenAccount(
+        reporter.keypair.publicKey
+      );
+
+      const communityTokenAccount = await stakeToken.getTokenAccount(
+        communityAccount,
+        true
+      );
+
+      await program.rpc.setCommunityPause(true, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+
+      await expectThrowError(
+        () =>
+          program.rpc.activateReporter({
+            accounts: {
+              sender: reporter.keypair.publicKey,
+              community: communityAccount,
+              reporter: reporterAccount,
+              stakeMint: stakeToken.mintAccount,
+              reporterTokenAccount,
+              communityTokenAccount,
+              tokenProgram: stakeToken.programId,
+            },
+            signers: [reporter.keypair],
+          }),
+        programError("CommunityPaused")
+      );
+
+      // Bob is activated by the next test once the community is unpaused
+      await program.rpc.setCommunityPause(false, {
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+      });
+    });
+
     it("success - bob", async () => {
       const reporter = REPORTERS.bob;
 
@@ -1952,4 +2006,163 @@ describe("HapiCore Reporter", () => {
       expect(fetchedReporterAccount.isFrozen).toBe(false);
     });
   });
+
+  describe("freeze_reporters", () => {
+    const findReporterAccounts = async (
+      id: BN,
+      names: (keyof typeof REPORTERS)[]
+    ) => {
+      const [communityAccount] = await program.pda.findCommunityAddress(id);
+
+      return Promise.all(
+        names.map(async (name) => {
+          const [reporterAccount] = await program.pda.findReporterAddress(
+            communityAccount,
+            REPORTERS[name].keypair.publicKey
+          );
+
+          return reporterAccount;
+        })
+      );
+    };
+
+    const asRemainingAccounts = (accounts: web3.PublicKey[]) =>
+      accounts.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      }));
+
+    it("fail - invalid authority", async () => {
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const reporterAccounts = await findReporterAccounts(communityId, [
+        "dave",
+        "erin",
+      ]);
+
+      await expectThrowError(
+        () =>
+          program.rpc.freezeReporters({
+            accounts: {
+              authority: nobody.publicKey,
+              community: communityAccount,
+            },
+            remainingAccounts: asRemainingAccounts(reporterAccounts),
+            signers: [nobody],
+          }),
+        programError("AuthorityMismatch")
+      );
+    });
+
+    it("fail - reporter from another community", async () => {
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const reporterAccounts = await findReporterAccounts(communityId, [
+        "dave",
+        "erin",
+      ]);
+
+      const [otherReporterAccount] = await findReporterAccounts(
+        otherCommunityId,
+        ["alice"]
+      );
+
+      await expectThrowError(
+        () =>
+          program.rpc.freezeReporters({
+            accounts: {
+              authority: authority.publicKey,
+              community: communityAccount,
+            },
+            remainingAccounts: asRemainingAccounts([
+              ...reporterAccounts,
+              otherReporterAccount,
+            ]),
+          }),
+        programError("CommunityMismatch")
+      );
+
+      // The batch is atomic, so valid reporters must stay unfrozen
+      for (const reporterAccount of reporterAccounts) {
+        const fetchedReporterAccount = await program.account.reporter.fetch(
+          reporterAccount
+        );
+        expect(fetchedReporterAccount.isFrozen).toBe(false);
+      }
+    });
+
+    it("success", async () => {
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const reporterAccounts = await findReporterAccounts(communityId, [
+        "dave",
+        "erin",
+        "carol",
+      ]);
+
+      const tx = await program.rpc.freezeReporters({
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+        remainingAccounts: asRemainingAccounts(reporterAccounts),
+      });
+
+      expect(tx).toBeTruthy();
+
+      for (const reporterAccount of reporterAccounts) {
+        const fetchedReporterAccount = await program.account.reporter.fetch(
+          reporterAccount
+        );
+        expect(fetchedReporterAccount.isFrozen).toBe(true);
+      }
+
+      // Reporters of the other community are not affected
+      const [otherReporterAccount] = await findReporterAccounts(
+        otherCommunityId,
+        ["alice"]
+      );
+
+      const fetchedOtherReporterAccount = await program.account.reporter.fetch(
+        otherReporterAccount
+      );
+      expect(fetchedOtherReporterAccount.isFrozen).toBe(false);
+    });
+
+    it("success - unfreeze", async () => {
+      const [communityAccount] = await program.pda.findCommunityAddress(
+        communityId
+      );
+
+      const reporterAccounts = await findReporterAccounts(communityId, [
+        "dave",
+        "erin",
+        "carol",
+      ]);
+
+      const tx = await program.rpc.unfreezeReporters({
+        accounts: {
+          authority: authority.publicKey,
+          community: communityAccount,
+        },
+        remainingAccounts: asRemainingAccounts(reporterAccounts),
+      });
+
+      expect(tx).toBeTruthy();
+
+      for (const reporterAccount of reporterAccounts) {
+        const fetchedReporterAccount = await program.account.reporter.fetch(
+          reporterAccount
+        );
+        expect(fetchedReporterAccount.isFrozen).toBe(false);
+      }
+    });
+  });
 });